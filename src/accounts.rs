@@ -0,0 +1,182 @@
+//! Named GitHub accounts.
+//!
+//! The `default` account is whatever the usual token resolution finds; other
+//! accounts come from the credential store (`fgp-github login --account`).
+//! Each account has its own client, so rate-limit standing is tracked
+//! separately per account. Calls that name no account are routed by the
+//! config file's `repo_accounts` rules, falling back to `default`. Rules
+//! naming an account that is not configured are dropped at load.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Pick accounts by repository using config rules
+//! 10/16/2026 - Initial implementation
+
+use anyhow::{bail, Result};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::api::GitHubClient;
+use crate::config::{DaemonConfig, RepoAccount, GITHUB_HOST};
+use crate::credentials::{CredentialStore, DEFAULT_ACCOUNT};
+
+/// One configured account.
+#[derive(Clone)]
+pub struct Account {
+    pub client: Arc<GitHubClient>,
+    /// Login recorded when the token was stored, if any.
+    pub login: Option<String>,
+}
+
+impl Account {
+    /// The default account, from `token` or the usual resolution order.
+    fn default_account(
+        token: Option<String>,
+        host: Option<&str>,
+        config: &DaemonConfig,
+    ) -> Result<Self> {
+        Ok(Self {
+            client: Arc::new(
+                GitHubClient::new(token, config.endpoints(host))?.with_throttle(&config.throttle),
+            ),
+            login: None,
+        })
+    }
+
+    /// A named account from the credential store, against the host it was
+    /// stored for; `None` when its token is gone.
+    fn stored_account(
+        store: &CredentialStore,
+        name: &str,
+        config: &DaemonConfig,
+    ) -> Result<Option<Self>> {
+        let Some(stored) = store.token(name)? else {
+            return Ok(None);
+        };
+        let endpoints = config.endpoints_for(stored.host.as_deref().unwrap_or(GITHUB_HOST));
+        Ok(Some(Self {
+            client: Arc::new(
+                GitHubClient::from_credential_store(stored.token, endpoints)?
+                    .with_throttle(&config.throttle),
+            ),
+            login: stored.login,
+        }))
+    }
+}
+
+/// Clients for every configured account, keyed by name.
+pub struct AccountPool {
+    accounts: BTreeMap<String, Account>,
+    /// Repository-to-account rules from the config file.
+    rules: Vec<RepoAccount>,
+}
+
+impl AccountPool {
+    /// Build the default account from `token` (or the usual resolution
+    /// order) against `host`, plus every named account in the credential
+    /// store against the host it was stored for.
+    pub fn load(token: Option<String>, host: Option<&str>) -> Result<Self> {
+        let config = DaemonConfig::load()?;
+        let mut accounts = BTreeMap::new();
+        accounts.insert(
+            DEFAULT_ACCOUNT.to_string(),
+            Account::default_account(token, host, &config)?,
+        );
+
+        let store = CredentialStore::new();
+        for name in store.load()?.accounts.into_keys() {
+            if name == DEFAULT_ACCOUNT {
+                continue;
+            }
+            let account = match Account::stored_account(&store, &name, &config) {
+                Ok(Some(account)) => account,
+                Ok(None) => {
+                    tracing::warn!("Account '{}' has no token in the keychain; skipping", name);
+                    continue;
+                }
+                Err(e) => {
+                    tracing::warn!("Account '{}' token unreadable; skipping: {:#}", name, e);
+                    continue;
+                }
+            };
+            accounts.insert(name, account);
+        }
+
+        let mut rules = config.repo_accounts;
+        rules.retain(|rule| {
+            let known = accounts.contains_key(&rule.account);
+            if !known {
+                tracing::warn!(
+                    "Ignoring repo_accounts rule '{}': unknown account '{}'; its repositories use the default account",
+                    rule.repo,
+                    rule.account
+                );
+            }
+            known
+        });
+
+        Ok(Self { accounts, rules })
+    }
+
+    /// The default account's client.
+    pub fn default_client(&self) -> Arc<GitHubClient> {
+        self.accounts[DEFAULT_ACCOUNT].client.clone()
+    }
+
+    /// The client for a named account, or the default when `name` is `None`.
+    pub fn get(&self, name: Option<&str>) -> Result<Arc<GitHubClient>> {
+        let name = name.unwrap_or(DEFAULT_ACCOUNT);
+        match self.accounts.get(name) {
+            Some(account) => Ok(account.client.clone()),
+            None => bail!(
+                "Unknown account '{}'. Configured accounts: {}",
+                name,
+                self.accounts.keys().cloned().collect::<Vec<_>>().join(", ")
+            ),
+        }
+    }
+
+    /// The account the first matching `repo_accounts` rule names for
+    /// `owner/name` (or for the owner itself when `name` is `None`).
+    pub fn account_for_repo(&self, owner: &str, name: Option<&str>) -> Option<&str> {
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(owner, name))
+            .map(|rule| rule.account.as_str())
+    }
+
+    /// A copy of the pool with one account's token re-read from its source,
+    /// or `None` when the token has not changed.
+    pub fn reload(
+        &self,
+        name: &str,
+        token: Option<String>,
+        host: Option<&str>,
+    ) -> Result<Option<Self>> {
+        let config = DaemonConfig::load()?;
+        let account = if name == DEFAULT_ACCOUNT {
+            Account::default_account(token, host, &config)?
+        } else {
+            match Account::stored_account(&CredentialStore::new(), name, &config)? {
+                Some(account) => account,
+                None => bail!("Account '{}' no longer has a stored token", name),
+            }
+        };
+        if let Some(current) = self.accounts.get(name) {
+            if current.client.same_token(&account.client) {
+                return Ok(None);
+            }
+        }
+
+        let mut accounts = self.accounts.clone();
+        accounts.insert(name.to_string(), account);
+        Ok(Some(Self {
+            accounts,
+            rules: self.rules.clone(),
+        }))
+    }
+
+    /// All accounts in name order.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Account)> {
+        self.accounts.iter()
+    }
+}
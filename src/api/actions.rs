@@ -0,0 +1,312 @@
+//! GitHub Actions endpoints (workflow runs, jobs, check-run annotations).
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Initial implementation with run annotations
+
+use anyhow::Result;
+use chrono::DateTime;
+use serde::Deserialize;
+
+use super::GitHubClient;
+use crate::models::{
+    ActionsSettings, ActionsSettingsUpdate, Annotation, SelectedActions, WorkflowRun,
+    WorkflowStatus,
+};
+
+impl GitHubClient {
+    /// Collect check-run annotations across all jobs of a workflow run.
+    ///
+    /// Each Actions job is backed by a check run with the same ID, so the
+    /// annotations endpoint is queried once per job.
+    pub async fn get_run_annotations(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: u64,
+    ) -> Result<Vec<Annotation>> {
+        let jobs: JobsRaw = self
+            .rest_get(&format!(
+                "/repos/{}/{}/actions/runs/{}/jobs?per_page=100",
+                owner, repo, run_id
+            ))
+            .await?;
+
+        let mut annotations = Vec::new();
+        for job in jobs.jobs {
+            let raw: Vec<AnnotationRaw> = self
+                .rest_get(&format!(
+                    "/repos/{}/{}/check-runs/{}/annotations?per_page=100",
+                    owner, repo, job.id
+                ))
+                .await?;
+
+            annotations.extend(raw.into_iter().map(|a| Annotation {
+                job_id: job.id,
+                job_name: job.name.clone(),
+                path: a.path,
+                start_line: a.start_line,
+                end_line: a.end_line,
+                level: a.annotation_level,
+                title: a.title,
+                message: a.message,
+            }));
+        }
+
+        Ok(annotations)
+    }
+
+    /// Get the latest run for every workflow in a repository.
+    ///
+    /// Runs are filtered to `branch`, defaulting to the repository's default
+    /// branch. Returns the branch used alongside the per-workflow summary.
+    pub async fn get_ci_summary(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: Option<&str>,
+    ) -> Result<(String, Vec<WorkflowStatus>)> {
+        let branch = match branch {
+            Some(b) => b.to_string(),
+            None => self.get_default_branch(owner, repo).await?,
+        };
+
+        let workflows: WorkflowsRaw = self
+            .rest_get(&format!(
+                "/repos/{}/{}/actions/workflows?per_page=100",
+                owner, repo
+            ))
+            .await?;
+
+        let mut summary = Vec::with_capacity(workflows.workflows.len());
+        for workflow in workflows.workflows {
+            let runs: WorkflowRunsRaw = self
+                .rest_get(&format!(
+                    "/repos/{}/{}/actions/workflows/{}/runs?branch={}&per_page=1",
+                    owner, repo, workflow.id, branch
+                ))
+                .await?;
+
+            summary.push(WorkflowStatus {
+                workflow_id: workflow.id,
+                name: workflow.name,
+                path: workflow.path,
+                state: workflow.state,
+                latest_run: runs.workflow_runs.into_iter().next().map(Into::into),
+            });
+        }
+
+        Ok((branch, summary))
+    }
+
+    /// Get a repository's Actions permission settings.
+    pub async fn get_actions_settings(&self, owner: &str, repo: &str) -> Result<ActionsSettings> {
+        let base = format!("/repos/{}/{}/actions/permissions", owner, repo);
+
+        let permissions: ActionsPermissionsRaw = self.rest_get(&base).await?;
+        let workflow: WorkflowPermissionsRaw = self.rest_get(&format!("{}/workflow", base)).await?;
+
+        let selected_actions = if permissions.allowed_actions.as_deref() == Some("selected") {
+            Some(
+                self.rest_get::<SelectedActions>(&format!("{}/selected-actions", base))
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        // Only available for public repositories; private repos return 404
+        let fork_pr_approval_policy = self
+            .rest_get::<ForkApprovalRaw>(&format!("{}/fork-pr-contributor-approval", base))
+            .await
+            .ok()
+            .map(|f| f.approval_policy);
+
+        Ok(ActionsSettings {
+            enabled: permissions.enabled,
+            allowed_actions: permissions.allowed_actions,
+            selected_actions,
+            default_workflow_permissions: workflow.default_workflow_permissions,
+            can_approve_pull_request_reviews: workflow.can_approve_pull_request_reviews,
+            fork_pr_approval_policy,
+        })
+    }
+
+    /// Apply a partial update to a repository's Actions settings.
+    ///
+    /// Returns the settings as they stand after the update.
+    pub async fn update_actions_settings(
+        &self,
+        owner: &str,
+        repo: &str,
+        update: &ActionsSettingsUpdate,
+    ) -> Result<ActionsSettings> {
+        let base = format!("/repos/{}/{}/actions/permissions", owner, repo);
+        let current = self.get_actions_settings(owner, repo).await?;
+
+        if update.enabled.is_some() || update.allowed_actions.is_some() {
+            let mut body = serde_json::json!({
+                "enabled": update.enabled.unwrap_or(current.enabled),
+            });
+            if let Some(allowed) = &update.allowed_actions {
+                body["allowed_actions"] = serde_json::json!(allowed);
+            }
+            self.rest_put(&base, &body).await?;
+        }
+
+        if let Some(selected) = &update.selected_actions {
+            self.rest_put(
+                &format!("{}/selected-actions", base),
+                &serde_json::json!(selected),
+            )
+            .await?;
+        }
+
+        if update.default_workflow_permissions.is_some()
+            || update.can_approve_pull_request_reviews.is_some()
+        {
+            let body = serde_json::json!({
+                "default_workflow_permissions": update
+                    .default_workflow_permissions
+                    .as_deref()
+                    .unwrap_or(&current.default_workflow_permissions),
+                "can_approve_pull_request_reviews": update
+                    .can_approve_pull_request_reviews
+                    .unwrap_or(current.can_approve_pull_request_reviews),
+            });
+            self.rest_put(&format!("{}/workflow", base), &body).await?;
+        }
+
+        if let Some(policy) = &update.fork_pr_approval_policy {
+            self.rest_put(
+                &format!("{}/fork-pr-contributor-approval", base),
+                &serde_json::json!({ "approval_policy": policy }),
+            )
+            .await?;
+        }
+
+        self.get_actions_settings(owner, repo).await
+    }
+}
+
+/// Seconds between two RFC 3339 timestamps, if both parse.
+fn duration_secs(start: &str, end: &str) -> Option<i64> {
+    let start = DateTime::parse_from_rfc3339(start).ok()?;
+    let end = DateTime::parse_from_rfc3339(end).ok()?;
+    Some((end - start).num_seconds())
+}
+
+/// Raw jobs listing from REST API.
+#[derive(Deserialize)]
+struct JobsRaw {
+    jobs: Vec<JobRaw>,
+}
+
+#[derive(Deserialize)]
+struct JobRaw {
+    id: u64,
+    name: String,
+}
+
+/// Raw workflow listing from REST API.
+#[derive(Deserialize)]
+struct WorkflowsRaw {
+    workflows: Vec<WorkflowRaw>,
+}
+
+#[derive(Deserialize)]
+struct WorkflowRaw {
+    id: u64,
+    name: String,
+    path: String,
+    state: String,
+}
+
+/// Raw workflow run listing from REST API.
+#[derive(Deserialize)]
+struct WorkflowRunsRaw {
+    workflow_runs: Vec<WorkflowRunRaw>,
+}
+
+#[derive(Deserialize)]
+struct WorkflowRunRaw {
+    id: u64,
+    name: Option<String>,
+    status: Option<String>,
+    conclusion: Option<String>,
+    head_branch: Option<String>,
+    head_sha: String,
+    event: String,
+    html_url: String,
+    created_at: String,
+    updated_at: String,
+    run_started_at: Option<String>,
+}
+
+impl From<WorkflowRunRaw> for WorkflowRun {
+    fn from(r: WorkflowRunRaw) -> Self {
+        // Only completed runs have a meaningful duration
+        let duration_secs = match (r.status.as_deref(), r.run_started_at.as_deref()) {
+            (Some("completed"), Some(started)) => duration_secs(started, &r.updated_at),
+            _ => None,
+        };
+
+        WorkflowRun {
+            id: r.id,
+            name: r.name,
+            status: r.status,
+            conclusion: r.conclusion,
+            branch: r.head_branch,
+            head_sha: r.head_sha,
+            event: r.event,
+            url: r.html_url,
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+            duration_secs,
+        }
+    }
+}
+
+/// Raw Actions permissions from REST API.
+#[derive(Deserialize)]
+struct ActionsPermissionsRaw {
+    enabled: bool,
+    allowed_actions: Option<String>,
+}
+
+/// Raw default workflow permissions from REST API.
+#[derive(Deserialize)]
+struct WorkflowPermissionsRaw {
+    default_workflow_permissions: String,
+    can_approve_pull_request_reviews: bool,
+}
+
+/// Raw fork PR contributor approval policy from REST API.
+#[derive(Deserialize)]
+struct ForkApprovalRaw {
+    approval_policy: String,
+}
+
+/// Raw check-run annotation from REST API.
+#[derive(Deserialize)]
+struct AnnotationRaw {
+    path: String,
+    start_line: i32,
+    end_line: i32,
+    annotation_level: String,
+    title: Option<String>,
+    message: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration_secs() {
+        assert_eq!(
+            duration_secs("2024-01-14T00:00:00Z", "2024-01-14T00:05:30Z"),
+            Some(330)
+        );
+        assert_eq!(duration_secs("not a date", "2024-01-14T00:05:30Z"), None);
+    }
+}
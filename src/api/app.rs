@@ -0,0 +1,303 @@
+//! GitHub App authentication.
+//!
+//! The app signs short-lived JWTs with its private key and exchanges them for
+//! installation tokens, which expire after an hour and are refreshed shortly
+//! before then.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Initial implementation with JWT minting and installation tokens
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{Mutex, OnceLock};
+
+use super::client::ensure_success;
+use super::GitHubClient;
+use crate::models::Installation;
+
+/// Refresh installation tokens this long before GitHub expires them.
+const REFRESH_MARGIN_MINUTES: i64 = 5;
+
+/// How often the background refresher re-checks the token's expiry.
+const REFRESH_CHECK: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Credentials and token state for a GitHub App installation.
+pub(super) struct AppAuth {
+    app_id: String,
+    key: EncodingKey,
+    /// From configuration, or discovered when the app has one installation.
+    installation_id: OnceLock<u64>,
+    expires_at: Mutex<Option<DateTime<Utc>>>,
+}
+
+impl AppAuth {
+    pub(super) fn new(
+        app_id: &str,
+        private_key_pem: &str,
+        installation_id: Option<u64>,
+    ) -> Result<Self> {
+        let key = EncodingKey::from_rsa_pem(private_key_pem.as_bytes())
+            .context("Invalid GitHub App private key (expected an RSA PEM)")?;
+        let slot = OnceLock::new();
+        if let Some(id) = installation_id {
+            let _ = slot.set(id);
+        }
+
+        Ok(Self {
+            app_id: app_id.to_string(),
+            key,
+            installation_id: slot,
+            expires_at: Mutex::new(None),
+        })
+    }
+
+    /// Read app credentials from the environment:
+    ///
+    /// - `GITHUB_APP_ID`
+    /// - `GITHUB_APP_PRIVATE_KEY` (PEM contents) or
+    ///   `GITHUB_APP_PRIVATE_KEY_PATH`
+    /// - `GITHUB_APP_INSTALLATION_ID` (optional)
+    ///
+    /// Returns `None` when `GITHUB_APP_ID` is unset.
+    pub(super) fn from_env() -> Result<Option<Self>> {
+        let Some(app_id) = env_var("GITHUB_APP_ID") else {
+            return Ok(None);
+        };
+
+        let pem = match (
+            env_var("GITHUB_APP_PRIVATE_KEY"),
+            env_var("GITHUB_APP_PRIVATE_KEY_PATH"),
+        ) {
+            (Some(pem), _) => pem,
+            (None, Some(path)) => {
+                let path = shellexpand::tilde(&path).to_string();
+                std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read GitHub App private key {}", path))?
+            }
+            (None, None) => bail!(
+                "GITHUB_APP_ID is set but no private key was given. \
+                 Set GITHUB_APP_PRIVATE_KEY or GITHUB_APP_PRIVATE_KEY_PATH."
+            ),
+        };
+
+        let installation_id = env_var("GITHUB_APP_INSTALLATION_ID")
+            .map(|id| {
+                id.parse::<u64>()
+                    .with_context(|| format!("Invalid GITHUB_APP_INSTALLATION_ID: {}", id))
+            })
+            .transpose()?;
+
+        Self::new(&app_id, &pem, installation_id).map(Some)
+    }
+
+    /// Mint an app JWT, valid for nine minutes.
+    ///
+    /// `iat` is backdated a minute to tolerate clock drift; GitHub rejects
+    /// JWTs living longer than ten minutes.
+    fn jwt(&self) -> Result<String> {
+        #[derive(Serialize)]
+        struct Claims<'a> {
+            iat: i64,
+            exp: i64,
+            iss: &'a str,
+        }
+
+        let now = Utc::now().timestamp();
+        let claims = Claims {
+            iat: now - 60,
+            exp: now + 9 * 60,
+            iss: &self.app_id,
+        };
+        jsonwebtoken::encode(&Header::new(Algorithm::RS256), &claims, &self.key)
+            .context("Failed to sign GitHub App JWT")
+    }
+
+    /// Whether the current installation token is missing or about to expire.
+    fn needs_refresh(&self) -> bool {
+        let expires_at = *self.expires_at.lock().unwrap_or_else(|e| e.into_inner());
+        match expires_at {
+            Some(at) => at - Duration::minutes(REFRESH_MARGIN_MINUTES) <= Utc::now(),
+            None => true,
+        }
+    }
+}
+
+/// Raw installation from the REST API.
+#[derive(Deserialize)]
+struct InstallationRaw {
+    id: u64,
+    account: Option<AccountRaw>,
+    target_type: String,
+    repository_selection: String,
+    #[serde(default)]
+    permissions: BTreeMap<String, String>,
+    #[serde(default)]
+    events: Vec<String>,
+    created_at: String,
+    suspended_at: Option<String>,
+    html_url: String,
+}
+
+#[derive(Deserialize)]
+struct AccountRaw {
+    login: String,
+}
+
+impl From<InstallationRaw> for Installation {
+    fn from(raw: InstallationRaw) -> Self {
+        Installation {
+            id: raw.id,
+            account: raw.account.map(|a| a.login).unwrap_or_default(),
+            target_type: raw.target_type,
+            repository_selection: raw.repository_selection,
+            permissions: raw.permissions,
+            events: raw.events,
+            created_at: raw.created_at,
+            suspended: raw.suspended_at.is_some(),
+            url: raw.html_url,
+        }
+    }
+}
+
+impl GitHubClient {
+    /// Whether this client authenticates as a GitHub App installation.
+    pub fn is_app(&self) -> bool {
+        self.app.is_some()
+    }
+
+    /// Send a request authenticated as the app itself (JWT), as required by
+    /// the `/app` endpoints.
+    async fn app_send(&self, method: Method, path: &str) -> Result<reqwest::Response> {
+        let Some(app) = &self.app else {
+            bail!(
+                "This method requires GitHub App authentication. \
+                 Set GITHUB_APP_ID and GITHUB_APP_PRIVATE_KEY_PATH."
+            );
+        };
+
+        let url = format!("{}{}", self.endpoints.rest, path);
+        let mutation = method != Method::GET;
+        let response = self
+            .send(self.request_as(method, &url, &app.jwt()?), mutation)
+            .await
+            .context("Failed to send GitHub App request")?;
+        ensure_success(response).await
+    }
+
+    /// List the installations of the authenticated GitHub App.
+    pub async fn list_installations(&self, limit: i32) -> Result<Vec<Installation>> {
+        let path = format!("/app/installations?per_page={}", limit.clamp(1, 100));
+        let response = self.app_send(Method::GET, &path).await?;
+        let raw: Vec<InstallationRaw> = response.json().await.context("Failed to parse JSON")?;
+        Ok(raw.into_iter().map(Installation::from).collect())
+    }
+
+    /// Mint a new installation token if the current one is missing or within
+    /// a few minutes of expiring. Returns whether a token was minted; a no-op
+    /// for token-authenticated clients.
+    pub async fn refresh_installation_token(&self) -> Result<bool> {
+        let Some(app) = &self.app else {
+            return Ok(false);
+        };
+        if !app.needs_refresh() {
+            return Ok(false);
+        }
+
+        let installation_id = match app.installation_id.get() {
+            Some(id) => *id,
+            None => {
+                let installations = self.list_installations(100).await?;
+                let id = match installations.as_slice() {
+                    [only] => only.id,
+                    [] => bail!("GitHub App {} has no installations", app.app_id),
+                    many => bail!(
+                        "GitHub App {} has {} installations; set GITHUB_APP_INSTALLATION_ID to one of: {}",
+                        app.app_id,
+                        many.len(),
+                        many.iter()
+                            .map(|i| format!("{} ({})", i.id, i.account))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                };
+                *app.installation_id.get_or_init(|| id)
+            }
+        };
+
+        #[derive(Deserialize)]
+        struct AccessToken {
+            token: String,
+            expires_at: DateTime<Utc>,
+        }
+
+        let path = format!("/app/installations/{}/access_tokens", installation_id);
+        let response = self.app_send(Method::POST, &path).await?;
+        let minted: AccessToken = response.json().await.context("Failed to parse JSON")?;
+
+        self.set_token(minted.token);
+        *app.expires_at.lock().unwrap_or_else(|e| e.into_inner()) = Some(minted.expires_at);
+        tracing::debug!(
+            "Minted installation token for installation {}, expires {}",
+            installation_id,
+            minted.expires_at
+        );
+        Ok(true)
+    }
+
+    /// When the installation token expires, for app-authenticated clients.
+    pub fn installation_token_expiry(&self) -> Option<DateTime<Utc>> {
+        let app = self.app.as_ref()?;
+        *app.expires_at.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Keep the installation token fresh for the life of the daemon, so
+    /// background work such as the notification poller never sees an
+    /// expired token.
+    ///
+    /// Expiry is checked against the wall clock every minute rather than by
+    /// sleeping until it, which would oversleep across a system suspend.
+    pub async fn keep_installation_token_fresh(self: std::sync::Arc<Self>) {
+        loop {
+            tokio::time::sleep(REFRESH_CHECK).await;
+            if let Err(e) = self.refresh_installation_token().await {
+                tracing::warn!("Failed to refresh installation token: {}", e);
+            }
+        }
+    }
+}
+
+/// A non-empty environment variable.
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_installation_from_raw() {
+        let raw: InstallationRaw = serde_json::from_value(serde_json::json!({
+            "id": 42,
+            "account": {"login": "my-org", "type": "Organization"},
+            "target_type": "Organization",
+            "repository_selection": "selected",
+            "permissions": {"contents": "read", "issues": "write"},
+            "events": ["push"],
+            "created_at": "2026-01-01T00:00:00Z",
+            "suspended_at": null,
+            "html_url": "https://github.com/organizations/my-org/settings/installations/42"
+        }))
+        .unwrap();
+
+        let installation = Installation::from(raw);
+        assert_eq!(installation.id, 42);
+        assert_eq!(installation.account, "my-org");
+        assert_eq!(installation.permissions["issues"], "write");
+        assert!(!installation.suspended);
+    }
+}
@@ -0,0 +1,207 @@
+//! Token inspection.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Track token expiry and warn when it is near
+//! 10/16/2026 - Parse token expiration timestamps
+//! 10/16/2026 - Track granted scopes from response headers
+//! 10/16/2026 - Initial implementation with token scope inspection
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use reqwest::header::HeaderMap;
+use reqwest::Method;
+use serde::Deserialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+use super::GitHubClient;
+use crate::models::TokenInfo;
+
+/// Days before a token expires that the daemon starts warning about it.
+pub const EXPIRY_WARNING_DAYS: i64 = 7;
+
+impl GitHubClient {
+    /// Describe the current token: its type, owner, granted scopes (classic
+    /// and OAuth tokens only), and expiry when GitHub reports one.
+    pub async fn get_token_info(&self) -> Result<TokenInfo> {
+        let response = self.rest_send(Method::GET, "/user", None).await?;
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string())
+        };
+
+        // Fine-grained PATs and app tokens omit X-OAuth-Scopes entirely.
+        let scopes = header("x-oauth-scopes").map(|s| parse_scopes(&s));
+        let expires_at = header("github-authentication-token-expiration");
+
+        #[derive(Deserialize)]
+        struct Viewer {
+            login: String,
+        }
+
+        let viewer: Viewer = response.json().await.context("Failed to parse JSON")?;
+
+        Ok(TokenInfo {
+            login: viewer.login,
+            token_type: token_type(&self.token()).to_string(),
+            scopes,
+            expires_at,
+        })
+    }
+}
+
+/// OAuth scopes reported in `X-OAuth-Scopes` response headers.
+///
+/// `None` until a response carries the header, which fine-grained PATs and
+/// app tokens never do.
+#[derive(Default)]
+pub(super) struct GrantedScopes(RwLock<Option<Vec<String>>>);
+
+impl GrantedScopes {
+    pub(super) fn record(&self, headers: &HeaderMap) {
+        if let Some(header) = headers.get("x-oauth-scopes").and_then(|v| v.to_str().ok()) {
+            *self.0.write().unwrap_or_else(|e| e.into_inner()) = Some(parse_scopes(header));
+        }
+    }
+}
+
+/// Expiry reported in `GitHub-Authentication-Token-Expiration` response
+/// headers, which fine-grained PATs and expiring OAuth tokens carry.
+#[derive(Default)]
+pub(super) struct TokenExpiry {
+    expires_at: RwLock<Option<DateTime<Utc>>>,
+    /// Whether the near-expiry warning has been logged.
+    warned: AtomicBool,
+}
+
+impl TokenExpiry {
+    /// Record the expiry header, logging a warning the first time the token
+    /// is seen within [`EXPIRY_WARNING_DAYS`] of expiring.
+    pub(super) fn record(&self, headers: &HeaderMap) {
+        let Some(expiry) = headers
+            .get("github-authentication-token-expiration")
+            .and_then(|v| v.to_str().ok())
+            .and_then(parse_expiration)
+        else {
+            return;
+        };
+        *self.expires_at.write().unwrap_or_else(|e| e.into_inner()) = Some(expiry);
+
+        if expires_soon(expiry) && !self.warned.swap(true, Ordering::Relaxed) {
+            tracing::warn!(
+                "GitHub token expires {} ({} days left); rotate it and call github.reload_auth",
+                expiry.to_rfc3339(),
+                (expiry - Utc::now()).num_days()
+            );
+        }
+    }
+}
+
+/// Whether `expiry` is within [`EXPIRY_WARNING_DAYS`] (or already past).
+pub fn expires_soon(expiry: DateTime<Utc>) -> bool {
+    expiry - Utc::now() < Duration::days(EXPIRY_WARNING_DAYS)
+}
+
+impl GitHubClient {
+    /// Scopes the token was last reported to have; `None` when unknown or
+    /// when the token type does not report scopes.
+    pub fn granted_scopes(&self) -> Option<Vec<String>> {
+        self.scopes
+            .0
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// When the token expires: the current installation token for apps,
+    /// otherwise the expiry GitHub last reported. `None` for tokens that
+    /// never expire, or before the first response.
+    pub fn token_expiry(&self) -> Option<DateTime<Utc>> {
+        self.installation_token_expiry().or_else(|| {
+            *self
+                .expiry
+                .expires_at
+                .read()
+                .unwrap_or_else(|e| e.into_inner())
+        })
+    }
+}
+
+/// Split a comma-separated scope header.
+fn parse_scopes(header: &str) -> Vec<String> {
+    header
+        .split(',')
+        .map(|scope| scope.trim().to_string())
+        .filter(|scope| !scope.is_empty())
+        .collect()
+}
+
+/// Parse a `GitHub-Authentication-Token-Expiration` value such as
+/// `2026-11-01 12:00:00 UTC` (or with a numeric offset).
+pub fn parse_expiration(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Some(utc) = value.strip_suffix(" UTC") {
+        return NaiveDateTime::parse_from_str(utc, "%Y-%m-%d %H:%M:%S")
+            .ok()
+            .map(|t| t.and_utc());
+    }
+    DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S %z")
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// Classify a token by its prefix.
+fn token_type(token: &str) -> &'static str {
+    match token.split('_').next() {
+        _ if token.starts_with("github_pat_") => "fine_grained",
+        Some("ghp") => "classic",
+        Some("gho") => "oauth",
+        Some("ghu") => "app_user",
+        Some("ghs") => "app_installation",
+        _ => "unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_type() {
+        assert_eq!(token_type("ghp_abc123"), "classic");
+        assert_eq!(token_type("github_pat_11ABC_def"), "fine_grained");
+        assert_eq!(token_type("ghs_abc123"), "app_installation");
+        assert_eq!(token_type("0123456789abcdef"), "unknown");
+    }
+
+    #[test]
+    fn test_parse_expiration() {
+        let utc = parse_expiration("2026-11-01 12:00:00 UTC").unwrap();
+        assert_eq!(utc.to_rfc3339(), "2026-11-01T12:00:00+00:00");
+
+        let offset = parse_expiration("2026-11-01 05:00:00 -0700").unwrap();
+        assert_eq!(offset, utc);
+
+        assert!(parse_expiration("soon").is_none());
+    }
+
+    #[test]
+    fn test_token_expiry_record() {
+        let expiry = TokenExpiry::default();
+        expiry.record(&HeaderMap::new());
+        assert!(expiry.expires_at.read().unwrap().is_none());
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "github-authentication-token-expiration",
+            "2020-01-01 00:00:00 UTC".parse().unwrap(),
+        );
+        expiry.record(&headers);
+        let recorded = expiry.expires_at.read().unwrap().unwrap();
+        assert!(expires_soon(recorded));
+        assert!(expiry.warned.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,297 @@
+//! Changelog assembly from the compare API and PR associations.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Initial implementation
+
+use anyhow::Result;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use super::GitHubClient;
+use crate::models::{Changelog, ChangelogCommit, ChangelogPr};
+
+/// Commits looked up per GraphQL request when resolving associated PRs.
+const PR_LOOKUP_BATCH: usize = 50;
+
+impl GitHubClient {
+    /// Build a changelog of merged PRs (grouped by label) between two refs.
+    ///
+    /// The compare API returns at most 250 commits; larger ranges are
+    /// truncated to that window.
+    pub async fn compare_releases(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Changelog> {
+        let compare: CompareRaw = self
+            .rest_get(&format!(
+                "/repos/{}/{}/compare/{}...{}",
+                owner, repo, base, head
+            ))
+            .await?;
+
+        let shas: Vec<&str> = compare.commits.iter().map(|c| c.sha.as_str()).collect();
+        let mut prs_by_commit: HashMap<String, Vec<ChangelogPr>> = HashMap::new();
+        for batch in shas.chunks(PR_LOOKUP_BATCH) {
+            prs_by_commit.extend(self.merged_prs_for_commits(owner, repo, batch).await?);
+        }
+
+        let commits = compare
+            .commits
+            .into_iter()
+            .map(|c| ChangelogCommit {
+                message: c.commit.message.lines().next().unwrap_or("").to_string(),
+                author: c
+                    .author
+                    .map(|a| a.login)
+                    .or(c.commit.author.map(|a| a.name)),
+                sha: c.sha,
+            })
+            .collect::<Vec<_>>();
+
+        let commit_count = commits.len();
+        let (groups, pull_request_count, direct_commits) =
+            group_changelog(commits, &mut prs_by_commit);
+
+        Ok(Changelog {
+            base: base.to_string(),
+            head: head.to_string(),
+            ahead_by: compare.ahead_by,
+            commit_count,
+            pull_request_count,
+            groups,
+            direct_commits,
+        })
+    }
+
+    /// Look up merged PRs associated with each commit in one GraphQL request.
+    async fn merged_prs_for_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        shas: &[&str],
+    ) -> Result<HashMap<String, Vec<ChangelogPr>>> {
+        let mut query = String::from(
+            "query($owner: String!, $name: String!) {\n  repository(owner: $owner, name: $name) {\n",
+        );
+        for (i, sha) in shas.iter().enumerate() {
+            query.push_str(&format!(
+                "    c{}: object(oid: \"{}\") {{ ...PrFields }}\n",
+                i, sha
+            ));
+        }
+        query.push_str(
+            r#"  }
+}
+fragment PrFields on Commit {
+  associatedPullRequests(first: 5) {
+    nodes {
+      number
+      title
+      url
+      mergedAt
+      author {
+        login
+      }
+      labels(first: 20) {
+        nodes {
+          name
+        }
+      }
+    }
+  }
+}"#,
+        );
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: HashMap<String, Option<CommitNode>>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CommitNode {
+            associated_pull_requests: PrNodes,
+        }
+
+        #[derive(Deserialize)]
+        struct PrNodes {
+            nodes: Vec<PrNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct PrNode {
+            number: i32,
+            title: String,
+            url: String,
+            merged_at: Option<String>,
+            author: Option<AuthorNode>,
+            labels: LabelNodes,
+        }
+
+        #[derive(Deserialize)]
+        struct AuthorNode {
+            login: String,
+        }
+
+        #[derive(Deserialize)]
+        struct LabelNodes {
+            nodes: Vec<LabelNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct LabelNode {
+            name: String,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo
+        });
+        let result: RepoResponse = self.graphql(&query, Some(variables)).await?;
+
+        let mut prs = HashMap::new();
+        for (alias, node) in result.repository {
+            let Some(index) = alias
+                .strip_prefix('c')
+                .and_then(|i| i.parse::<usize>().ok())
+            else {
+                continue;
+            };
+            let Some(node) = node else { continue };
+
+            let merged = node
+                .associated_pull_requests
+                .nodes
+                .into_iter()
+                .filter(|pr| pr.merged_at.is_some())
+                .map(|pr| ChangelogPr {
+                    number: pr.number,
+                    title: pr.title,
+                    url: pr.url,
+                    author: pr.author.map(|a| a.login),
+                    labels: pr.labels.nodes.into_iter().map(|l| l.name).collect(),
+                    merged_at: pr.merged_at,
+                })
+                .collect();
+            prs.insert(shas[index].to_string(), merged);
+        }
+
+        Ok(prs)
+    }
+}
+
+/// Group PRs by label (deduplicated) and split out commits without a PR.
+///
+/// Returns the label groups, the number of distinct PRs, and direct commits.
+fn group_changelog(
+    commits: Vec<ChangelogCommit>,
+    prs_by_commit: &mut HashMap<String, Vec<ChangelogPr>>,
+) -> (
+    BTreeMap<String, Vec<ChangelogPr>>,
+    usize,
+    Vec<ChangelogCommit>,
+) {
+    let mut groups: BTreeMap<String, Vec<ChangelogPr>> = BTreeMap::new();
+    let mut seen = HashSet::new();
+    let mut direct_commits = Vec::new();
+
+    for commit in commits {
+        let prs = prs_by_commit.remove(&commit.sha).unwrap_or_default();
+        if prs.is_empty() {
+            direct_commits.push(commit);
+            continue;
+        }
+
+        for pr in prs {
+            if !seen.insert(pr.number) {
+                continue;
+            }
+            if pr.labels.is_empty() {
+                groups.entry("unlabeled".to_string()).or_default().push(pr);
+            } else {
+                for label in &pr.labels {
+                    groups.entry(label.clone()).or_default().push(pr.clone());
+                }
+            }
+        }
+    }
+
+    (groups, seen.len(), direct_commits)
+}
+
+/// Raw compare response from REST API.
+#[derive(Deserialize)]
+struct CompareRaw {
+    ahead_by: i32,
+    commits: Vec<CompareCommitRaw>,
+}
+
+#[derive(Deserialize)]
+struct CompareCommitRaw {
+    sha: String,
+    commit: CommitDataRaw,
+    author: Option<CommitUserRaw>,
+}
+
+#[derive(Deserialize)]
+struct CommitDataRaw {
+    message: String,
+    author: Option<GitAuthorRaw>,
+}
+
+#[derive(Deserialize)]
+struct GitAuthorRaw {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct CommitUserRaw {
+    login: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pr(number: i32, labels: &[&str]) -> ChangelogPr {
+        ChangelogPr {
+            number,
+            title: format!("PR {}", number),
+            url: format!("https://github.com/octocat/repo/pull/{}", number),
+            author: None,
+            labels: labels.iter().map(|l| l.to_string()).collect(),
+            merged_at: Some("2024-01-14T00:00:00Z".to_string()),
+        }
+    }
+
+    fn commit(sha: &str) -> ChangelogCommit {
+        ChangelogCommit {
+            sha: sha.to_string(),
+            message: "msg".to_string(),
+            author: None,
+        }
+    }
+
+    #[test]
+    fn test_group_changelog() {
+        let commits = vec![commit("a"), commit("b"), commit("c"), commit("d")];
+        let mut prs = HashMap::new();
+        prs.insert("a".to_string(), vec![pr(1, &["bug", "ui"])]);
+        // Second commit of the same PR must not duplicate it
+        prs.insert("b".to_string(), vec![pr(1, &["bug", "ui"])]);
+        prs.insert("c".to_string(), vec![pr(2, &[])]);
+
+        let (groups, pr_count, direct) = group_changelog(commits, &mut prs);
+
+        assert_eq!(pr_count, 2);
+        assert_eq!(groups["bug"].len(), 1);
+        assert_eq!(groups["ui"].len(), 1);
+        assert_eq!(groups["unlabeled"][0].number, 2);
+        assert_eq!(direct.len(), 1);
+        assert_eq!(direct[0].sha, "d");
+    }
+}
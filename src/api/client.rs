@@ -1,38 +1,100 @@
 //! GitHub GraphQL and REST API client with connection pooling.
 //!
 //! # CHANGELOG (recent first, max 5 entries)
-//! 01/14/2026 - Initial implementation with GraphQL + REST (Claude)
+//! 10/16/2026 - Retry rate-limited requests after Retry-After and cap concurrent mutations
+//! 10/16/2026 - Persist read results in SQLite and serve them stale when offline
+//! 10/16/2026 - Revalidate REST GETs with ETags and serve 304s from memory
+//! 10/16/2026 - Track token expiry, report it in health, and warn when near
+//! 10/16/2026 - Surface SAML SSO authorization errors and add github.sso_status
 
 use anyhow::{bail, Context, Result};
-use reqwest::Client;
+use reqwest::{Client, Method, RequestBuilder, Response};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::path::PathBuf;
-
-use crate::models::{GraphQLResponse, Issue, Notification, PullRequest, Repository, User};
-
-const GRAPHQL_ENDPOINT: &str = "https://api.github.com/graphql";
-const REST_ENDPOINT: &str = "https://api.github.com";
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::OnceCell;
+
+use super::app::AppAuth;
+use super::auth::{GrantedScopes, TokenExpiry};
+use super::etag::EtagCache;
+use super::rate_limit::RateLimits;
+use super::sso::{self, SsoOrgs};
+use super::throttle::Throttle;
+use crate::config::{Endpoints, GITHUB_HOST};
+use crate::credentials::{CredentialStore, DEFAULT_ACCOUNT};
+use crate::keychain;
+use crate::models::{Download, GraphQLResponse, Issue, PullRequest, Repository, User};
 
 /// GitHub API client with persistent connection pooling.
 pub struct GitHubClient {
     client: Client,
-    token: String,
+    /// Swapped in place when an installation token is refreshed.
+    token: RwLock<String>,
+    /// Set when authenticating as a GitHub App installation.
+    pub(super) app: Option<AppAuth>,
+    /// Latest rate-limit headers seen, per resource.
+    pub(super) rate_limits: RateLimits,
+    /// OAuth scopes reported by the most recent response.
+    pub(super) scopes: GrantedScopes,
+    /// Expiry reported by responses, for expiring tokens.
+    pub(super) expiry: TokenExpiry,
+    /// Validated GET responses, revalidated with conditional requests.
+    pub(super) etags: EtagCache,
+    /// Organizations withheld from results pending SSO authorization.
+    pub(super) sso_orgs: SsoOrgs,
+    /// Rate-limit retries and mutation concurrency.
+    pub(super) throttle: Throttle,
+    /// API base URLs for the host this client talks to.
+    pub(super) endpoints: Endpoints,
+    /// Where the token came from, e.g. `keychain` or `GITHUB_TOKEN`.
+    source: &'static str,
+    /// Login the token authenticates as, looked up on first use.
+    login: OnceCell<String>,
 }
 
 impl GitHubClient {
-    /// Create a new GitHub client.
+    /// Create a new GitHub client for the host described by `endpoints`.
     ///
     /// Token resolution order:
     /// 1. Explicit token parameter
-    /// 2. GITHUB_TOKEN environment variable
-    /// 3. gh CLI config (~/.config/gh/hosts.yml)
-    pub fn new(token: Option<String>) -> Result<Self> {
-        let token = match token {
-            Some(t) => t,
-            None => Self::resolve_token()?,
+    /// 2. OS keychain entry for the default account (`fgp-github auth set-token`)
+    /// 3. GITHUB_TOKEN / GH_TOKEN environment variables (github.com), or
+    ///    GH_ENTERPRISE_TOKEN / GITHUB_ENTERPRISE_TOKEN (other hosts)
+    /// 4. GitHub App credentials (GITHUB_APP_ID + private key)
+    /// 5. Token stored by `fgp-github login` for this host
+    /// 6. gh CLI config (~/.config/gh/hosts.yml) entry for this host
+    pub fn new(token: Option<String>, endpoints: Endpoints) -> Result<Self> {
+        let token = token
+            .map(|t| (t, "explicit"))
+            .or_else(|| Self::keychain_token(&endpoints).map(|t| (t, "keychain")))
+            .or_else(|| Self::env_token(&endpoints));
+
+        let (token, source) = match token {
+            Some(resolved) => resolved,
+            None => {
+                if let Some(app) = AppAuth::from_env()? {
+                    return Self::build(String::new(), Some(app), endpoints, "github_app");
+                }
+                Self::resolve_token(&endpoints)?
+            }
         };
+        Self::build(token, None, endpoints, source)
+    }
+
+    /// Client for a named account's token from the credential store.
+    pub fn from_credential_store(token: String, endpoints: Endpoints) -> Result<Self> {
+        Self::build(token, None, endpoints, "credential_store")
+    }
 
+    fn build(
+        token: String,
+        app: Option<AppAuth>,
+        endpoints: Endpoints,
+        source: &'static str,
+    ) -> Result<Self> {
         let client = Client::builder()
             .pool_max_idle_per_host(5)
             .timeout(std::time::Duration::from_secs(30))
@@ -40,96 +102,186 @@ impl GitHubClient {
             .build()
             .context("Failed to build HTTP client")?;
 
-        Ok(Self { client, token })
+        Ok(Self {
+            client,
+            token: RwLock::new(token),
+            app,
+            rate_limits: RateLimits::default(),
+            scopes: GrantedScopes::default(),
+            expiry: TokenExpiry::default(),
+            etags: EtagCache::default(),
+            sso_orgs: SsoOrgs::default(),
+            throttle: Throttle::default(),
+            endpoints,
+            source,
+            login: OnceCell::new(),
+        })
+    }
+
+    /// Where the token came from: `explicit`, `keychain`, the environment
+    /// variable name, `github_app`, `credential_store`, `gh_hosts`, or
+    /// `gh_auth_token`.
+    pub fn token_source(&self) -> &'static str {
+        self.source
     }
 
-    /// Resolve GitHub token from environment or gh CLI config.
-    fn resolve_token() -> Result<String> {
-        // Try GITHUB_TOKEN env var first
-        if let Ok(token) = std::env::var("GITHUB_TOKEN") {
-            if !token.is_empty() {
-                return Ok(token);
-            }
-        }
+    /// Whether `other` authenticates with the same token as this client.
+    pub fn same_token(&self, other: &GitHubClient) -> bool {
+        self.token() == other.token()
+    }
+
+    /// The GitHub host this client talks to.
+    pub fn host(&self) -> &str {
+        &self.endpoints.host
+    }
+
+    /// Token from the environment, using gh's variable names: enterprise
+    /// hosts read their own variables so a github.com token is never sent
+    /// to them.
+    fn env_token(endpoints: &Endpoints) -> Option<(String, &'static str)> {
+        let vars: &[&str] = if endpoints.is_github_com() {
+            &["GITHUB_TOKEN", "GH_TOKEN"]
+        } else {
+            &["GH_ENTERPRISE_TOKEN", "GITHUB_ENTERPRISE_TOKEN"]
+        };
+
+        vars.iter()
+            .filter_map(|var| std::env::var(var).ok().map(|token| (token, *var)))
+            .find(|(token, _)| !token.is_empty())
+    }
+
+    /// The default account's token from the OS keychain. An unavailable
+    /// keychain (e.g. no Secret Service on a headless machine) counts as no
+    /// entry.
+    fn keychain_token(endpoints: &Endpoints) -> Option<String> {
+        keychain::get_token(DEFAULT_ACCOUNT, &endpoints.host)
+            .unwrap_or_else(|e| {
+                tracing::debug!("Skipping keychain: {:#}", e);
+                None
+            })
+            .filter(|token| !token.is_empty())
+    }
 
-        // Try GH_TOKEN (alternative env var used by gh CLI)
-        if let Ok(token) = std::env::var("GH_TOKEN") {
-            if !token.is_empty() {
-                return Ok(token);
+    /// Resolve a GitHub token from the daemon's credential store or gh CLI
+    /// config. An unreadable credential store (e.g. a locked keychain
+    /// holding its secret) is logged and skipped.
+    fn resolve_token(endpoints: &Endpoints) -> Result<(String, &'static str)> {
+        // Try the token saved by `fgp-github login`
+        match CredentialStore::new().token(DEFAULT_ACCOUNT) {
+            Ok(Some(stored)) if stored.host.as_deref().unwrap_or(GITHUB_HOST) == endpoints.host => {
+                return Ok((stored.token, "credential_store"));
             }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Skipping stored credentials: {:#}", e),
         }
 
         // Fall back to gh CLI config
-        Self::read_gh_token()
+        Self::read_gh_token(&endpoints.host)
     }
 
-    /// Read token from gh CLI config file.
-    fn read_gh_token() -> Result<String> {
+    /// Read the token for `host` from gh CLI: hosts.yml first, then
+    /// `gh auth token`, which also covers tokens gh keeps in the system
+    /// keyring with only a stub in hosts.yml.
+    fn read_gh_token(host: &str) -> Result<(String, &'static str)> {
         let config_path = Self::gh_config_path()?;
 
-        if !config_path.exists() {
-            bail!(
-                "No GitHub token found. Set GITHUB_TOKEN env var or run 'gh auth login'.\n\
-                 Config path checked: {}",
-                config_path.display()
-            );
-        }
+        if config_path.exists() {
+            let content =
+                std::fs::read_to_string(&config_path).context("Failed to read gh config file")?;
 
-        let content =
-            std::fs::read_to_string(&config_path).context("Failed to read gh config file")?;
+            // Parse YAML config
+            let config: Value =
+                serde_yaml::from_str(&content).context("Failed to parse gh config")?;
 
-        // Parse YAML config
-        let config: Value = serde_yaml::from_str(&content).context("Failed to parse gh config")?;
+            if let Some(token) = gh_hosts_token(&config, host) {
+                return Ok((token, "gh_hosts"));
+            }
+        }
 
-        // Extract token for github.com
-        let token = config
-            .get("github.com")
-            .and_then(|host| host.get("oauth_token"))
-            .and_then(|t| t.as_str())
-            .map(|s| s.to_string());
+        if let Some(token) = gh_auth_token(host) {
+            return Ok((token, "gh_auth_token"));
+        }
 
-        token.ok_or_else(|| {
-            anyhow::anyhow!(
-                "No oauth_token found for github.com in {}",
-                config_path.display()
-            )
-        })
+        bail!(
+            "No GitHub token found for {}. Set GITHUB_TOKEN, run 'fgp-github login', or run 'gh auth login'.\n\
+             Config path checked: {}",
+            host,
+            config_path.display()
+        );
     }
 
-    /// Get gh CLI config path.
+    /// Get gh CLI config path, following gh's own lookup: GH_CONFIG_DIR,
+    /// then XDG_CONFIG_HOME, then ~/.config.
     fn gh_config_path() -> Result<PathBuf> {
+        Self::gh_config_path_from(
+            std::env::var_os("GH_CONFIG_DIR").map(PathBuf::from),
+            std::env::var_os("XDG_CONFIG_HOME").map(PathBuf::from),
+            dirs::home_dir(),
+        )
+    }
+
+    /// [`gh_config_path`](Self::gh_config_path) for the given environment.
+    fn gh_config_path_from(
+        gh_config_dir: Option<PathBuf>,
+        xdg_config_home: Option<PathBuf>,
+        home: Option<PathBuf>,
+    ) -> Result<PathBuf> {
+        if let Some(dir) = gh_config_dir {
+            return Ok(dir.join("hosts.yml"));
+        }
+
         // Check XDG_CONFIG_HOME first
-        if let Ok(xdg) = std::env::var("XDG_CONFIG_HOME") {
-            return Ok(PathBuf::from(xdg).join("gh").join("hosts.yml"));
+        if let Some(xdg) = xdg_config_home {
+            return Ok(xdg.join("gh").join("hosts.yml"));
         }
 
         // Fall back to ~/.config/gh/hosts.yml
-        let home = dirs::home_dir().context("Could not determine home directory")?;
+        let home = home.context("Could not determine home directory")?;
 
         Ok(home.join(".config").join("gh").join("hosts.yml"))
     }
 
     /// Execute a GraphQL query.
-    async fn graphql<T: for<'de> Deserialize<'de>>(
+    pub(super) async fn graphql<T: for<'de> Deserialize<'de>>(
+        &self,
+        query: &str,
+        variables: Option<Value>,
+    ) -> Result<T> {
+        self.graphql_accepting(query, variables, None).await
+    }
+
+    /// Execute a GraphQL query with a custom `Accept` media type, for schema
+    /// previews.
+    pub(super) async fn graphql_accepting<T: for<'de> Deserialize<'de>>(
         &self,
         query: &str,
         variables: Option<Value>,
+        accept: Option<&str>,
     ) -> Result<T> {
         let body = GraphQLRequest {
             query: query.to_string(),
             variables,
         };
 
-        let response = self
+        let mut request = self
             .client
-            .post(GRAPHQL_ENDPOINT)
-            .header("Authorization", format!("Bearer {}", self.token))
-            .json(&body)
-            .send()
+            .post(&self.endpoints.graphql)
+            .header("Authorization", format!("Bearer {}", self.token()));
+        if let Some(accept) = accept {
+            request = request.header("Accept", accept);
+        }
+
+        let mutation = query.trim_start().starts_with("mutation");
+        let response = self
+            .send(request.json(&body), mutation)
             .await
             .context("Failed to send GraphQL request")?;
+        let sso_required = sso::required(response.headers());
 
         if !response.status().is_success() {
+            if let Some(sso) = sso_required {
+                return Err(sso.into());
+            }
             let status = response.status();
             let text = response.text().await.unwrap_or_default();
             bail!("GraphQL request failed: {} - {}", status, text);
@@ -149,6 +301,9 @@ impl GitHubClient {
         if result.data.is_none() {
             if let Some(errors) = result.errors {
                 if !errors.is_empty() {
+                    if let Some(sso) = sso_required {
+                        return Err(sso.into());
+                    }
                     let messages: Vec<_> = errors.iter().map(|e| e.message.as_str()).collect();
                     bail!("GraphQL errors: {}", messages.join(", "));
                 }
@@ -158,32 +313,278 @@ impl GitHubClient {
         result.data.context("GraphQL response missing data field")
     }
 
-    /// Execute a REST API request (GET).
-    async fn rest_get<T: for<'de> Deserialize<'de>>(&self, path: &str) -> Result<T> {
-        let url = format!("{}{}", REST_ENDPOINT, path);
+    /// The token requests are authenticated with.
+    pub(super) fn token(&self) -> String {
+        self.token.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Replace the token, e.g. after minting a new installation token.
+    pub(super) fn set_token(&self, token: String) {
+        *self.token.write().unwrap_or_else(|e| e.into_inner()) = token;
+    }
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.token))
+    /// Record what a response says about the token: rate-limit standing,
+    /// granted OAuth scopes, expiry, and organizations withheld for SSO.
+    pub(super) fn observe(&self, headers: &reqwest::header::HeaderMap) {
+        self.rate_limits.record(headers);
+        self.scopes.record(headers);
+        self.expiry.record(headers);
+        self.sso_orgs.record(headers);
+    }
+
+    /// Build a request to an absolute URL with auth and API version headers.
+    pub(super) fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        self.request_accepting(method, url, "application/vnd.github+json")
+    }
+
+    /// Build a request with a custom `Accept` media type.
+    pub(super) fn request_accepting(
+        &self,
+        method: Method,
+        url: &str,
+        accept: &str,
+    ) -> RequestBuilder {
+        self.client
+            .request(method, url)
+            .header("Authorization", format!("Bearer {}", self.token()))
+            .header("Accept", accept)
+            .header("X-GitHub-Api-Version", "2022-11-28")
+    }
+
+    /// Build a request authenticated with an explicit bearer token instead of
+    /// the client's own, e.g. a GitHub App JWT.
+    pub(super) fn request_as(&self, method: Method, url: &str, token: &str) -> RequestBuilder {
+        self.client
+            .request(method, url)
+            .header("Authorization", format!("Bearer {}", token))
             .header("Accept", "application/vnd.github+json")
             .header("X-GitHub-Api-Version", "2022-11-28")
-            .send()
+    }
+
+    /// Stream a binary REST resource to a local file, hashing it on the way.
+    ///
+    /// Redirects (e.g. to release-asset or archive storage) are followed;
+    /// reqwest drops the Authorization header when the host changes.
+    pub(super) async fn download_to_file(
+        &self,
+        path: &str,
+        accept: &str,
+        dest: &Path,
+    ) -> Result<Download> {
+        let url = format!("{}{}", self.endpoints.rest, path);
+        let start = std::time::Instant::now();
+
+        let response = self
+            .send(
+                self.request_accepting(Method::GET, &url, accept)
+                    .timeout(std::time::Duration::from_secs(30 * 60)),
+                false,
+            )
+            .await
+            .context("Failed to send download request")?;
+        let mut response = ensure_success(response).await?;
+
+        if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .context("Failed to create destination directory")?;
+        }
+        let mut file = tokio::fs::File::create(dest)
+            .await
+            .with_context(|| format!("Failed to create {}", dest.display()))?;
+
+        let mut hasher = Sha256::new();
+        let mut bytes = 0u64;
+        while let Some(chunk) = response.chunk().await.context("Download interrupted")? {
+            hasher.update(&chunk);
+            file.write_all(&chunk)
+                .await
+                .context("Failed to write file")?;
+            bytes += chunk.len() as u64;
+        }
+        file.flush().await.context("Failed to write file")?;
+
+        let sha256 = hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+
+        Ok(Download {
+            path: dest.display().to_string(),
+            bytes,
+            sha256,
+            elapsed_ms: start.elapsed().as_millis() as u64,
+        })
+    }
+
+    /// Send a REST API request and fail on non-success status.
+    pub(super) async fn rest_send(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<Response> {
+        let url = format!("{}{}", self.endpoints.rest, path);
+
+        let mutation = method != Method::GET;
+        let mut request = self.request(method, &url);
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+
+        let response = self
+            .send(request, mutation)
             .await
             .context("Failed to send REST request")?;
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let text = response.text().await.unwrap_or_default();
-            bail!("REST request failed: {} - {}", status, text);
+        ensure_success(response).await
+    }
+
+    /// Execute a REST API request (GET), revalidating cached responses
+    /// with their ETag.
+    pub(super) async fn rest_get<T: for<'de> Deserialize<'de>>(&self, path: &str) -> Result<T> {
+        let url = format!("{}{}", self.endpoints.rest, path);
+        let (body, _) = self.get_conditional(&url).await?;
+        serde_json::from_str(&body).context("Failed to parse JSON")
+    }
+
+    /// Execute a paginated REST API GET, following `Link: rel="next"` headers
+    /// until `limit` items are collected or the last page is reached.
+    ///
+    /// `per_page` is appended to `path`; any other query (e.g. a starting
+    /// `page`) should already be on it.
+    pub(super) async fn rest_get_paged<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        limit: usize,
+    ) -> Result<Vec<T>> {
+        self.rest_get_paged_filter_map(path, limit, limit, Some)
+            .await
+    }
+
+    /// Like [`rest_get_paged`](Self::rest_get_paged), but keeps paging until
+    /// `limit` items pass `f` (which also converts them), fetching
+    /// `per_page` items per request.
+    pub(super) async fn rest_get_paged_filter_map<T, U>(
+        &self,
+        path: &str,
+        per_page: usize,
+        limit: usize,
+        mut f: impl FnMut(T) -> Option<U>,
+    ) -> Result<Vec<U>>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let separator = if path.contains('?') { '&' } else { '?' };
+        let per_page = per_page.clamp(1, 100);
+        let mut url = format!(
+            "{}{}{}per_page={}",
+            self.endpoints.rest, path, separator, per_page
+        );
+        let mut items: Vec<U> = Vec::new();
+
+        loop {
+            let (body, link) = self.get_conditional(&url).await?;
+            let next = link.as_deref().and_then(|v| link_rel(v, "next"));
+
+            let page: Vec<T> = serde_json::from_str(&body).context("Failed to parse JSON")?;
+            let empty = page.is_empty();
+            items.extend(page.into_iter().filter_map(&mut f));
+
+            match next {
+                Some(next) if !empty && items.len() < limit => url = next,
+                _ => break,
+            }
         }
 
+        items.truncate(limit);
+        Ok(items)
+    }
+
+    /// Execute a REST API request (POST).
+    pub(super) async fn rest_post<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        body: &Value,
+    ) -> Result<T> {
+        let response = self.rest_send(Method::POST, path, Some(body)).await?;
+        let result = response.json().await.context("Failed to parse JSON")?;
+        Ok(result)
+    }
+
+    /// Execute a REST API request (PATCH).
+    pub(super) async fn rest_patch<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        body: &Value,
+    ) -> Result<T> {
+        let response = self.rest_send(Method::PATCH, path, Some(body)).await?;
         let result = response.json().await.context("Failed to parse JSON")?;
         Ok(result)
     }
 
+    /// Execute a REST API request (DELETE).
+    pub(super) async fn rest_delete(&self, path: &str) -> Result<()> {
+        self.rest_send(Method::DELETE, path, None).await?;
+        Ok(())
+    }
+
+    /// Execute a REST API request (PUT) that returns a JSON body.
+    pub(super) async fn rest_put_json<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        body: &Value,
+    ) -> Result<T> {
+        let response = self.rest_send(Method::PUT, path, Some(body)).await?;
+        let result = response.json().await.context("Failed to parse JSON")?;
+        Ok(result)
+    }
+
+    /// Execute a REST API request (PUT) that returns no content.
+    pub(super) async fn rest_put(&self, path: &str, body: &Value) -> Result<()> {
+        self.rest_send(Method::PUT, path, Some(body)).await?;
+        Ok(())
+    }
+
+    /// Login the token authenticates as, looked up once per client.
+    /// Installation tokens have no viewer and fail.
+    pub async fn login(&self) -> Result<String> {
+        let login = self
+            .login
+            .get_or_try_init(|| async {
+                #[derive(Deserialize)]
+                struct ViewerResponse {
+                    viewer: Viewer,
+                }
+
+                #[derive(Deserialize)]
+                struct Viewer {
+                    login: String,
+                }
+
+                let query = "query { viewer { login } }";
+                let result: ViewerResponse = self.graphql(query, None).await?;
+                anyhow::Ok(result.viewer.login)
+            })
+            .await?;
+        Ok(login.clone())
+    }
+
+    /// The login, if it has been looked up already.
+    pub fn known_login(&self) -> Option<String> {
+        self.login.get().cloned()
+    }
+
     /// Check if the client can connect to GitHub API.
     pub async fn ping(&self) -> Result<bool> {
+        // Installation tokens have no viewer; list an accessible repo instead.
+        if self.is_app() {
+            self.rest_send(Method::GET, "/installation/repositories?per_page=1", None)
+                .await?;
+            return Ok(true);
+        }
+
         let query = r#"
             query {
                 viewer {
@@ -541,28 +942,6 @@ impl GitHubClient {
         Ok(issues)
     }
 
-    /// Get unread notifications.
-    pub async fn get_notifications(&self) -> Result<Vec<Notification>> {
-        // Use REST API for notifications (simpler)
-        let notifications: Vec<NotificationRaw> = self.rest_get("/notifications").await?;
-
-        let result = notifications
-            .into_iter()
-            .map(|n| Notification {
-                id: n.id,
-                unread: n.unread,
-                reason: n.reason,
-                subject_title: n.subject.title,
-                subject_type: n.subject.type_field,
-                subject_url: n.subject.url,
-                repo_full_name: n.repository.full_name,
-                updated_at: n.updated_at,
-            })
-            .collect();
-
-        Ok(result)
-    }
-
     /// Get pull request details with status checks and reviews.
     pub async fn get_pr(&self, owner: &str, repo: &str, pr_number: i32) -> Result<PullRequest> {
         let query = r#"
@@ -990,7 +1369,7 @@ impl GitHubClient {
     }
 
     /// Get repository node ID (needed for mutations).
-    async fn get_repo_id(&self, owner: &str, repo: &str) -> Result<String> {
+    pub(super) async fn get_repo_id(&self, owner: &str, repo: &str) -> Result<String> {
         let query = r#"
             query($owner: String!, $name: String!) {
                 repository(owner: $owner, name: $name) {
@@ -1017,47 +1396,345 @@ impl GitHubClient {
         let result: RepoResponse = self.graphql(query, Some(variables)).await?;
         Ok(result.repository.id)
     }
+
+    /// Resolve a branch, tag, or SHA to a full commit SHA.
+    pub(super) async fn resolve_commit_sha(
+        &self,
+        owner: &str,
+        repo: &str,
+        reference: &str,
+    ) -> Result<String> {
+        #[derive(Deserialize)]
+        struct CommitSha {
+            sha: String,
+        }
+
+        let commit: CommitSha = self
+            .rest_get(&format!("/repos/{}/{}/commits/{}", owner, repo, reference))
+            .await?;
+        Ok(commit.sha)
+    }
+
+    /// Get the name of a repository's default branch.
+    pub(super) async fn get_default_branch(&self, owner: &str, repo: &str) -> Result<String> {
+        let query = r#"
+            query($owner: String!, $name: String!) {
+                repository(owner: $owner, name: $name) {
+                    defaultBranchRef {
+                        name
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoData {
+            default_branch_ref: Option<BranchRef>,
+        }
+
+        #[derive(Deserialize)]
+        struct BranchRef {
+            name: String,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        result
+            .repository
+            .default_branch_ref
+            .map(|r| r.name)
+            .context("Repository has no default branch")
+    }
 }
 
-/// GraphQL request body.
-#[derive(Serialize)]
-struct GraphQLRequest {
-    query: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    variables: Option<Value>,
+/// Extract the URL for `rel` (e.g. `next`, `last`) from a `Link` response header.
+pub(super) fn link_rel(header: &str, rel: &str) -> Option<String> {
+    let wanted = format!("rel=\"{}\"", rel);
+    header.split(',').find_map(|part| {
+        let (url, params) = part.split_once(';')?;
+        params.split(';').any(|p| p.trim() == wanted).then(|| {
+            url.trim()
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .to_string()
+        })
+    })
 }
 
-/// Raw notification from REST API.
-#[derive(Deserialize)]
-struct NotificationRaw {
-    id: String,
-    unread: bool,
-    reason: String,
-    subject: NotificationSubject,
-    repository: NotificationRepo,
-    updated_at: String,
+/// Percent-encode a value for use in a URL query string.
+pub(super) fn encode_query(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
 }
 
-#[derive(Deserialize)]
-struct NotificationSubject {
-    title: String,
-    #[serde(rename = "type")]
-    type_field: String,
-    url: Option<String>,
+/// Fail with the status and body text if a REST response is not a success.
+pub(super) async fn ensure_success(response: Response) -> Result<Response> {
+    if !response.status().is_success() {
+        if let Some(sso) = sso::required(response.headers()) {
+            return Err(sso.into());
+        }
+        let status = response.status();
+        let accepted = accepted_permissions(response.headers());
+        let text = response.text().await.unwrap_or_default();
+        if status == reqwest::StatusCode::FORBIDDEN {
+            if let Some(accepted) = accepted {
+                bail!(
+                    "Missing permission: this request needs {} - {}",
+                    accepted,
+                    github_message(&text)
+                );
+            }
+        }
+        bail!("REST request failed: {} - {}", status, text);
+    }
+    Ok(response)
+}
+
+/// Token for `host` in a parsed gh hosts.yml.
+///
+/// Handles the legacy layout (`oauth_token` on the host entry) and the
+/// multi-account layout, where `users` maps logins to their settings and
+/// `user` names the active one. Host keys match case-insensitively.
+fn gh_hosts_token(config: &Value, host: &str) -> Option<String> {
+    let entry = config
+        .as_object()?
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(host))
+        .map(|(_, entry)| entry)?;
+    let token = |v: &Value| {
+        v.get("oauth_token")
+            .and_then(|t| t.as_str())
+            .filter(|t| !t.is_empty())
+            .map(|t| t.to_string())
+    };
+
+    token(entry).or_else(|| {
+        let active = entry.get("user")?.as_str()?;
+        entry.get("users")?.get(active).and_then(token)
+    })
+}
+
+/// Ask gh for its token, if gh is installed and logged in to `host`.
+fn gh_auth_token(host: &str) -> Option<String> {
+    let output = std::process::Command::new("gh")
+        .args(["auth", "token", "--hostname", host])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let token = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!token.is_empty()).then_some(token)
+}
+
+/// Whether an error came from GitHub rejecting the token (HTTP 401).
+pub fn is_unauthorized(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        let message = cause.to_string();
+        message.starts_with("REST request failed: 401")
+            || message.starts_with("GraphQL request failed: 401")
+    })
+}
+
+/// Whether an error means GitHub could not be reached: a connection failure
+/// or timeout, or a 502/503/504 from GitHub's edge.
+pub fn is_unreachable(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        if let Some(e) = cause.downcast_ref::<reqwest::Error>() {
+            return e.is_connect() || e.is_timeout();
+        }
+        let message = cause.to_string();
+        ["REST", "GraphQL"].iter().any(|api| {
+            ["502", "503", "504"]
+                .iter()
+                .any(|status| message.starts_with(&format!("{} request failed: {}", api, status)))
+        })
+    })
+}
+
+/// What GitHub says a rejected request needed: fine-grained permissions
+/// (`X-Accepted-GitHub-Permissions`, e.g. `contents=write`) or OAuth scopes
+/// (`X-Accepted-OAuth-Scopes`).
+fn accepted_permissions(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+    };
+
+    if let Some(permissions) = header("x-accepted-github-permissions") {
+        return Some(format!("permission {}", permissions));
+    }
+    header("x-accepted-oauth-scopes").map(|scopes| format!("scope {}", scopes))
+}
+
+/// The `message` field of a GitHub error body, or the body itself.
+fn github_message(text: &str) -> String {
+    serde_json::from_str::<Value>(text)
+        .ok()
+        .and_then(|body| body.get("message")?.as_str().map(|m| m.to_string()))
+        .unwrap_or_else(|| text.to_string())
 }
 
+/// Raw repository from REST API.
 #[derive(Deserialize)]
-struct NotificationRepo {
+pub(super) struct RepoRaw {
+    name: String,
     full_name: String,
+    description: Option<String>,
+    html_url: String,
+    private: bool,
+    fork: bool,
+    stargazers_count: i32,
+    forks_count: i32,
+    language: Option<String>,
+    updated_at: String,
+    pushed_at: Option<String>,
+}
+
+impl From<RepoRaw> for Repository {
+    fn from(r: RepoRaw) -> Self {
+        Repository {
+            name: r.name,
+            full_name: r.full_name,
+            description: r.description,
+            url: r.html_url,
+            is_private: r.private,
+            is_fork: r.fork,
+            stars: r.stargazers_count,
+            forks: r.forks_count,
+            language: r.language,
+            updated_at: r.updated_at,
+            pushed_at: r.pushed_at,
+        }
+    }
+}
+
+/// GraphQL request body.
+#[derive(Serialize)]
+struct GraphQLRequest {
+    query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variables: Option<Value>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_link_rel() {
+        let header = "<https://api.github.com/notifications?page=2>; rel=\"next\", \
+                      <https://api.github.com/notifications?page=5>; rel=\"last\"";
+        assert_eq!(
+            link_rel(header, "next").as_deref(),
+            Some("https://api.github.com/notifications?page=2")
+        );
+        assert_eq!(
+            link_rel(header, "last").as_deref(),
+            Some("https://api.github.com/notifications?page=5")
+        );
+
+        let last = "<https://api.github.com/notifications?page=4>; rel=\"prev\"";
+        assert_eq!(link_rel(last, "next"), None);
+    }
+
+    #[test]
+    fn test_encode_query() {
+        assert_eq!(
+            encode_query("2024-01-14T00:00:00Z"),
+            "2024-01-14T00%3A00%3A00Z"
+        );
+        assert_eq!(
+            encode_query("is:open label:\"good first\""),
+            "is%3Aopen%20label%3A%22good%20first%22"
+        );
+        assert_eq!(encode_query("a+b/c"), "a%2Bb%2Fc");
+    }
+
     #[test]
     fn test_gh_config_path() {
-        let path = GitHubClient::gh_config_path().unwrap();
-        assert!(path.to_string_lossy().contains("gh/hosts.yml"));
+        let path = |gh: Option<&str>, xdg: Option<&str>, home: Option<&str>| {
+            GitHubClient::gh_config_path_from(
+                gh.map(PathBuf::from),
+                xdg.map(PathBuf::from),
+                home.map(PathBuf::from),
+            )
+        };
+
+        assert_eq!(
+            path(Some("/etc/gh"), Some("/xdg"), Some("/home/u")).unwrap(),
+            PathBuf::from("/etc/gh/hosts.yml")
+        );
+        assert_eq!(
+            path(None, Some("/xdg"), Some("/home/u")).unwrap(),
+            PathBuf::from("/xdg/gh/hosts.yml")
+        );
+        assert_eq!(
+            path(None, None, Some("/home/u")).unwrap(),
+            PathBuf::from("/home/u/.config/gh/hosts.yml")
+        );
+        assert!(path(None, None, None).is_err());
+    }
+
+    #[test]
+    fn test_is_unauthorized() {
+        let rest = anyhow::anyhow!("REST request failed: 401 Unauthorized - Bad credentials");
+        assert!(is_unauthorized(&rest.context("Failed to list issues")));
+        let forbidden = anyhow::anyhow!("REST request failed: 403 Forbidden - nope");
+        assert!(!is_unauthorized(&forbidden));
+    }
+
+    const HOSTS_YML: &str = "\
+github.com:
+    oauth_token: gho_legacy
+GHE.example.com:
+    user: alice
+    users:
+        alice:
+            oauth_token: gho_alice
+        bob:
+            oauth_token: gho_bob
+keyring.example.com:
+    user: carol
+    users:
+        carol: {}
+";
+
+    #[test]
+    fn test_gh_hosts_token_layouts() {
+        let config: Value = serde_yaml::from_str(HOSTS_YML).unwrap();
+
+        assert_eq!(
+            gh_hosts_token(&config, "github.com").as_deref(),
+            Some("gho_legacy")
+        );
+        assert_eq!(
+            gh_hosts_token(&config, "ghe.example.com").as_deref(),
+            Some("gho_alice")
+        );
+        assert_eq!(gh_hosts_token(&config, "keyring.example.com"), None);
+        assert_eq!(gh_hosts_token(&config, "missing.example.com"), None);
     }
 }
@@ -0,0 +1,788 @@
+//! Commit history endpoints.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Added paged range commits past the 250-commit compare limit
+//! 10/16/2026 - Added file history with rename following
+//! 10/16/2026 - Added signature verification
+//! 10/16/2026 - Added file blame
+//! 10/16/2026 - Added ref comparison
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::users::date_bound;
+use super::GitHubClient;
+use crate::models::{
+    BlameRange, Commit, CommitDetail, CommitVerification, Comparison, FileChange, FileHistoryEntry,
+    FileRename,
+};
+
+/// Most renames followed by `get_file_history`.
+const MAX_RENAME_HOPS: usize = 10;
+
+/// Commits per page when paging a comparison.
+const COMPARE_PAGE_SIZE: usize = 100;
+
+/// Filters for listing commit history.
+#[derive(Debug, Default)]
+pub struct CommitFilter {
+    /// Only commits touching this path.
+    pub path: Option<String>,
+    /// GitHub login or commit author email.
+    pub author: Option<String>,
+    /// `YYYY-MM-DD` or an ISO 8601 timestamp.
+    pub since: Option<String>,
+    pub until: Option<String>,
+}
+
+impl GitHubClient {
+    /// List commits reachable from `git_ref` (default branch when `None`),
+    /// newest first, with their combined CI state.
+    ///
+    /// Returns the commits and a cursor for the next page, if any.
+    pub async fn list_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        git_ref: Option<&str>,
+        filter: &CommitFilter,
+        limit: i32,
+        after: Option<&str>,
+    ) -> Result<(Vec<Commit>, Option<String>)> {
+        let query = r#"
+            query($owner: String!, $name: String!, $ref: String!, $first: Int!, $after: String,
+                  $path: String, $author: CommitAuthor, $since: GitTimestamp,
+                  $until: GitTimestamp) {
+                repository(owner: $owner, name: $name) {
+                    object(expression: $ref) {
+                        ... on Commit {
+                            history(first: $first, after: $after, path: $path, author: $author,
+                                    since: $since, until: $until) {
+                                pageInfo {
+                                    hasNextPage
+                                    endCursor
+                                }
+                                nodes {
+                                    oid
+                                    messageHeadline
+                                    url
+                                    committedDate
+                                    author {
+                                        name
+                                        email
+                                        user { login }
+                                    }
+                                    statusCheckRollup { state }
+                                    signature {
+                                        isValid
+                                        state
+                                        signer { login }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let author = match filter.author.as_deref() {
+            Some(email) if email.contains('@') => serde_json::json!({ "emails": [email] }),
+            Some(login) => serde_json::json!({ "id": self.user_node_id(login).await? }),
+            None => Value::Null,
+        };
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "ref": git_ref.unwrap_or("HEAD"),
+            "first": limit.clamp(1, 100),
+            "after": after,
+            "path": filter.path,
+            "author": author,
+            "since": filter.since.as_deref().map(|d| date_bound(d, "T00:00:00Z")),
+            "until": filter.until.as_deref().map(|d| date_bound(d, "T23:59:59Z")),
+        });
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: Option<RepoData>,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            object: Option<CommitObject>,
+        }
+
+        #[derive(Deserialize)]
+        struct CommitObject {
+            history: HistoryConnection,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct HistoryConnection {
+            page_info: PageInfo,
+            nodes: Vec<CommitNode>,
+        }
+
+        let response: RepoResponse = self.graphql(query, Some(variables)).await?;
+        let history = response
+            .repository
+            .context("Repository not found")?
+            .object
+            .with_context(|| format!("Ref '{}' not found", git_ref.unwrap_or("HEAD")))?
+            .history;
+
+        Ok((
+            history.nodes.into_iter().map(Into::into).collect(),
+            history.page_info.next_cursor(),
+        ))
+    }
+
+    /// Commits that modified `path`, newest first, up to `limit`.
+    ///
+    /// With `follow`, when the history of a path runs out at a commit that
+    /// renamed the file, the walk continues from that commit's parent under
+    /// the old name.
+    pub async fn get_file_history(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        git_ref: Option<&str>,
+        limit: usize,
+        follow: bool,
+    ) -> Result<(Vec<FileHistoryEntry>, Vec<FileRename>)> {
+        let mut entries: Vec<FileHistoryEntry> = Vec::new();
+        let mut renames = Vec::new();
+        let mut current_path = path.trim_start_matches('/').to_string();
+        let mut current_ref = git_ref.unwrap_or("HEAD").to_string();
+
+        loop {
+            let filter = CommitFilter {
+                path: Some(current_path.clone()),
+                ..Default::default()
+            };
+            let mut after: Option<String> = None;
+            let mut oldest: Option<String> = None;
+
+            loop {
+                let remaining = limit - entries.len();
+                let (commits, next) = self
+                    .list_commits(
+                        owner,
+                        repo,
+                        Some(&current_ref),
+                        &filter,
+                        remaining.min(100) as i32,
+                        after.as_deref(),
+                    )
+                    .await?;
+                if let Some(last) = commits.last() {
+                    oldest = Some(last.sha.clone());
+                }
+                entries.extend(commits.into_iter().map(|commit| FileHistoryEntry {
+                    path: current_path.clone(),
+                    commit,
+                }));
+
+                match next {
+                    Some(cursor) if entries.len() < limit => after = Some(cursor),
+                    _ => break,
+                }
+            }
+
+            if !follow || entries.len() >= limit || renames.len() >= MAX_RENAME_HOPS {
+                break;
+            }
+            let Some(oldest) = oldest else { break };
+
+            // The commit that introduced the path may have renamed it
+            let detail = self.get_commit(owner, repo, &oldest, false).await?;
+            let previous = detail
+                .files
+                .iter()
+                .find(|f| f.filename == current_path && f.status == "renamed")
+                .and_then(|f| f.previous_filename.clone());
+            let (Some(previous), Some(parent)) = (previous, detail.parents.first()) else {
+                break;
+            };
+
+            renames.push(FileRename {
+                sha: oldest,
+                from: previous.clone(),
+                to: current_path,
+            });
+            current_path = previous;
+            current_ref = parent.clone();
+        }
+
+        Ok((entries, renames))
+    }
+
+    /// Get a single commit with its parents, stats, and changed files.
+    ///
+    /// Patches are dropped unless `patches` is set. GitHub returns at most
+    /// 300 files per commit on this endpoint.
+    pub async fn get_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        git_ref: &str,
+        patches: bool,
+    ) -> Result<CommitDetail> {
+        #[derive(Deserialize)]
+        struct CommitRaw {
+            sha: String,
+            html_url: String,
+            commit: GitCommitRaw,
+            author: Option<LoginNode>,
+            committer: Option<LoginNode>,
+            parents: Vec<ShaNode>,
+            stats: Option<StatsRaw>,
+            #[serde(default)]
+            files: Vec<FileRaw>,
+        }
+
+        #[derive(Deserialize)]
+        struct GitCommitRaw {
+            message: String,
+            author: Option<GitSignature>,
+            committer: Option<GitSignature>,
+            verification: Option<VerificationRaw>,
+        }
+
+        #[derive(Deserialize)]
+        struct StatsRaw {
+            additions: u32,
+            deletions: u32,
+        }
+
+        let raw: CommitRaw = self
+            .rest_get(&format!("/repos/{}/{}/commits/{}", owner, repo, git_ref))
+            .await?;
+
+        let git_author = raw.commit.author;
+        let git_committer = raw.commit.committer;
+        let stats = raw.stats;
+
+        Ok(CommitDetail {
+            sha: raw.sha,
+            message: raw.commit.message,
+            author: raw
+                .author
+                .map(|u| u.login)
+                .or_else(|| git_author.as_ref().and_then(|a| a.name.clone())),
+            author_email: git_author.as_ref().and_then(|a| a.email.clone()),
+            committer: raw
+                .committer
+                .map(|u| u.login)
+                .or_else(|| git_committer.and_then(|c| c.name)),
+            date: git_author.and_then(|a| a.date),
+            parents: raw.parents.into_iter().map(|p| p.sha).collect(),
+            additions: stats.as_ref().map_or(0, |s| s.additions),
+            deletions: stats.map_or(0, |s| s.deletions),
+            files: raw
+                .files
+                .into_iter()
+                .map(|f| f.into_change(patches))
+                .collect(),
+            verification: raw.commit.verification.map(Into::into),
+            url: raw.html_url,
+        })
+    }
+
+    /// Compare two refs (branches, tags, or SHAs; `owner:branch` for forks).
+    ///
+    /// Patches are dropped unless `patches` is set.
+    pub async fn compare_refs(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+        patches: bool,
+    ) -> Result<Comparison> {
+        #[derive(Deserialize)]
+        struct CompareRaw {
+            html_url: String,
+            status: String,
+            ahead_by: u32,
+            behind_by: u32,
+            total_commits: u32,
+            merge_base_commit: ShaNode,
+            commits: Vec<RestCommitRaw>,
+            #[serde(default)]
+            files: Vec<FileRaw>,
+        }
+
+        let raw: CompareRaw = self
+            .rest_get(&format!(
+                "/repos/{}/{}/compare/{}...{}",
+                owner, repo, base, head
+            ))
+            .await?;
+
+        Ok(Comparison {
+            base: base.to_string(),
+            head: head.to_string(),
+            status: raw.status,
+            ahead_by: raw.ahead_by,
+            behind_by: raw.behind_by,
+            merge_base: raw.merge_base_commit.sha,
+            commits: raw.commits.into_iter().map(Into::into).collect(),
+            total_commits: raw.total_commits,
+            files: raw
+                .files
+                .into_iter()
+                .map(|f| f.into_change(patches))
+                .collect(),
+            url: raw.html_url,
+        })
+    }
+
+    /// Commits between two refs, oldest first, paging the comparison past
+    /// the 250 commits it lists unpaged. Returns up to `limit` commits and
+    /// the number in the whole range.
+    pub async fn compare_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+        limit: usize,
+    ) -> Result<(Vec<Commit>, u32)> {
+        #[derive(Deserialize)]
+        struct ComparePageRaw {
+            total_commits: u32,
+            commits: Vec<RestCommitRaw>,
+        }
+
+        let mut commits = Vec::new();
+        let mut total = 0;
+        for page in 1.. {
+            let raw: ComparePageRaw = self
+                .rest_get(&format!(
+                    "/repos/{}/{}/compare/{}...{}?per_page={}&page={}",
+                    owner, repo, base, head, COMPARE_PAGE_SIZE, page
+                ))
+                .await?;
+            total = raw.total_commits;
+            let last_page = raw.commits.len() < COMPARE_PAGE_SIZE;
+            commits.extend(raw.commits.into_iter().map(Commit::from));
+
+            if last_page || commits.len() >= limit.min(total as usize) {
+                break;
+            }
+        }
+
+        commits.truncate(limit);
+        Ok((commits, total))
+    }
+
+    /// Blame `path` at `git_ref` (default branch when `None`), optionally
+    /// limited to ranges overlapping `lines` (inclusive, 1-based).
+    pub async fn get_blame(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        git_ref: Option<&str>,
+        lines: Option<(u32, u32)>,
+    ) -> Result<Vec<BlameRange>> {
+        let query = r#"
+            query($owner: String!, $name: String!, $ref: String!, $path: String!) {
+                repository(owner: $owner, name: $name) {
+                    object(expression: $ref) {
+                        ... on Commit {
+                            blame(path: $path) {
+                                ranges {
+                                    startingLine
+                                    endingLine
+                                    age
+                                    commit {
+                                        oid
+                                        messageHeadline
+                                        url
+                                        committedDate
+                                        author {
+                                            name
+                                            email
+                                            user { login }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "ref": git_ref.unwrap_or("HEAD"),
+            "path": path.trim_start_matches('/'),
+        });
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: Option<RepoData>,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            object: Option<BlameObject>,
+        }
+
+        #[derive(Deserialize)]
+        struct BlameObject {
+            blame: BlameNode,
+        }
+
+        #[derive(Deserialize)]
+        struct BlameNode {
+            ranges: Vec<RangeNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RangeNode {
+            starting_line: u32,
+            ending_line: u32,
+            age: u8,
+            commit: BlameCommitNode,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct BlameCommitNode {
+            oid: String,
+            message_headline: String,
+            url: String,
+            committed_date: String,
+            author: Option<GitActorNode>,
+        }
+
+        let response: RepoResponse = self.graphql(query, Some(variables)).await?;
+        let ranges = response
+            .repository
+            .context("Repository not found")?
+            .object
+            .with_context(|| format!("Ref '{}' not found", git_ref.unwrap_or("HEAD")))?
+            .blame
+            .ranges;
+
+        Ok(ranges
+            .into_iter()
+            .filter(|r| {
+                lines.is_none_or(|(start, end)| r.ending_line >= start && r.starting_line <= end)
+            })
+            .map(|r| {
+                let (author, author_email) = match r.commit.author {
+                    Some(a) => (a.user.map(|u| u.login).or(a.name), a.email),
+                    None => (None, None),
+                };
+                BlameRange {
+                    start_line: r.starting_line,
+                    end_line: r.ending_line,
+                    age: r.age,
+                    sha: r.commit.oid,
+                    message: r.commit.message_headline,
+                    author,
+                    author_email,
+                    date: r.commit.committed_date,
+                    url: r.commit.url,
+                }
+            })
+            .collect())
+    }
+
+    /// Get a user's node ID from their login.
+    async fn user_node_id(&self, login: &str) -> Result<String> {
+        let query = r#"
+            query($login: String!) {
+                user(login: $login) {
+                    id
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct UserResponse {
+            user: Option<IdNode>,
+        }
+
+        let variables = serde_json::json!({ "login": login });
+        let response: UserResponse = self.graphql(query, Some(variables)).await?;
+        response
+            .user
+            .map(|u| u.id)
+            .with_context(|| format!("User '{}' not found", login))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+impl PageInfo {
+    fn next_cursor(self) -> Option<String> {
+        self.end_cursor.filter(|_| self.has_next_page)
+    }
+}
+
+#[derive(Deserialize)]
+struct IdNode {
+    id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommitNode {
+    oid: String,
+    message_headline: String,
+    url: String,
+    committed_date: String,
+    author: Option<GitActorNode>,
+    status_check_rollup: Option<RollupNode>,
+    signature: Option<SignatureNode>,
+}
+
+#[derive(Deserialize)]
+struct GitActorNode {
+    name: Option<String>,
+    email: Option<String>,
+    user: Option<LoginNode>,
+}
+
+#[derive(Deserialize)]
+struct LoginNode {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct RollupNode {
+    state: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignatureNode {
+    is_valid: bool,
+    state: String,
+    signer: Option<LoginNode>,
+}
+
+#[derive(Deserialize)]
+struct ShaNode {
+    sha: String,
+}
+
+#[derive(Deserialize)]
+struct GitSignature {
+    name: Option<String>,
+    email: Option<String>,
+    date: Option<String>,
+}
+
+/// Raw commit entry from the REST compare endpoint.
+#[derive(Deserialize)]
+struct RestCommitRaw {
+    sha: String,
+    html_url: String,
+    commit: RestGitCommit,
+    author: Option<LoginNode>,
+}
+
+#[derive(Deserialize)]
+struct RestGitCommit {
+    message: String,
+    author: Option<GitSignature>,
+    verification: Option<VerificationRaw>,
+}
+
+/// Raw REST `verification` object.
+#[derive(Deserialize)]
+struct VerificationRaw {
+    verified: bool,
+    reason: String,
+}
+
+impl From<VerificationRaw> for CommitVerification {
+    fn from(v: VerificationRaw) -> Self {
+        CommitVerification {
+            verified: v.verified,
+            reason: v.reason,
+            signer: None,
+        }
+    }
+}
+
+impl From<RestCommitRaw> for Commit {
+    fn from(c: RestCommitRaw) -> Self {
+        let git_author = c.commit.author;
+
+        Commit {
+            sha: c.sha,
+            message: c
+                .commit
+                .message
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            author: c
+                .author
+                .map(|u| u.login)
+                .or_else(|| git_author.as_ref().and_then(|a| a.name.clone())),
+            author_email: git_author.as_ref().and_then(|a| a.email.clone()),
+            date: git_author.and_then(|a| a.date).unwrap_or_default(),
+            ci_state: None,
+            verification: c.commit.verification.map(Into::into),
+            url: c.html_url,
+        }
+    }
+}
+
+/// Raw changed file from the REST commits and compare endpoints.
+#[derive(Deserialize)]
+struct FileRaw {
+    filename: String,
+    status: String,
+    additions: u32,
+    deletions: u32,
+    previous_filename: Option<String>,
+    patch: Option<String>,
+}
+
+impl FileRaw {
+    fn into_change(self, patches: bool) -> FileChange {
+        FileChange {
+            filename: self.filename,
+            status: self.status,
+            additions: self.additions,
+            deletions: self.deletions,
+            previous_filename: self.previous_filename,
+            patch: self.patch.filter(|_| patches),
+        }
+    }
+}
+
+impl From<CommitNode> for Commit {
+    fn from(c: CommitNode) -> Self {
+        let (author, author_email) = match c.author {
+            Some(a) => (a.user.map(|u| u.login).or(a.name), a.email),
+            None => (None, None),
+        };
+
+        Commit {
+            sha: c.oid,
+            message: c.message_headline,
+            author,
+            author_email,
+            date: c.committed_date,
+            ci_state: c.status_check_rollup.map(|r| r.state),
+            // GraphQL reports the same reasons as REST, upper-cased
+            verification: Some(match c.signature {
+                Some(sig) => CommitVerification {
+                    verified: sig.is_valid,
+                    reason: sig.state.to_lowercase(),
+                    signer: sig.signer.map(|s| s.login),
+                },
+                None => CommitVerification {
+                    verified: false,
+                    reason: "unsigned".to_string(),
+                    signer: None,
+                },
+            }),
+            url: c.url,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rest_commit_uses_headline_and_git_author_fallback() {
+        let raw: RestCommitRaw = serde_json::from_value(serde_json::json!({
+            "sha": "abc123",
+            "html_url": "https://github.com/o/r/commit/abc123",
+            "commit": {
+                "message": "Fix parser\n\nLonger explanation.",
+                "author": {"name": "Mona", "email": "mona@example.com", "date": "2026-10-01T12:00:00Z"},
+                "verification": {"verified": false, "reason": "unsigned", "signature": null}
+            },
+            "author": null
+        }))
+        .unwrap();
+
+        let commit: Commit = raw.into();
+        assert_eq!(commit.message, "Fix parser");
+        assert_eq!(commit.author.as_deref(), Some("Mona"));
+        assert_eq!(commit.date, "2026-10-01T12:00:00Z");
+        assert_eq!(commit.verification.unwrap().reason, "unsigned");
+    }
+
+    #[test]
+    fn test_graphql_commit_verification() {
+        let node = |signature: serde_json::Value| -> CommitNode {
+            serde_json::from_value(serde_json::json!({
+                "oid": "abc123",
+                "messageHeadline": "Fix parser",
+                "url": "https://github.com/o/r/commit/abc123",
+                "committedDate": "2026-10-01T12:00:00Z",
+                "author": {"name": "Mona", "email": "mona@example.com", "user": {"login": "mona"}},
+                "statusCheckRollup": {"state": "SUCCESS"},
+                "signature": signature
+            }))
+            .unwrap()
+        };
+
+        let signed: Commit = node(serde_json::json!({
+            "isValid": true,
+            "state": "VALID",
+            "signer": {"login": "mona"}
+        }))
+        .into();
+        assert_eq!(signed.author.as_deref(), Some("mona"));
+        assert_eq!(signed.ci_state.as_deref(), Some("SUCCESS"));
+        let verification = signed.verification.unwrap();
+        assert!(verification.verified);
+        assert_eq!(verification.reason, "valid");
+        assert_eq!(verification.signer.as_deref(), Some("mona"));
+
+        let unsigned: Commit = node(serde_json::Value::Null).into();
+        let verification = unsigned.verification.unwrap();
+        assert!(!verification.verified);
+        assert_eq!(verification.reason, "unsigned");
+    }
+
+    #[test]
+    fn test_file_change_drops_patch_unless_asked() {
+        let file = || -> FileRaw {
+            serde_json::from_value(serde_json::json!({
+                "filename": "src/new.rs",
+                "status": "renamed",
+                "additions": 3,
+                "deletions": 1,
+                "previous_filename": "src/old.rs",
+                "patch": "@@ -1 +1 @@"
+            }))
+            .unwrap()
+        };
+
+        assert!(file().into_change(false).patch.is_none());
+        let change = file().into_change(true);
+        assert_eq!(change.patch.as_deref(), Some("@@ -1 +1 @@"));
+        assert_eq!(change.previous_filename.as_deref(), Some("src/old.rs"));
+    }
+}
@@ -0,0 +1,627 @@
+//! Repository contents endpoints.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Added CODEOWNERS lookup
+//! 10/16/2026 - Added repository archive downloads
+//! 10/16/2026 - Added multi-file commits via createCommitOnBranch
+//! 10/16/2026 - Added repository tree listing
+//! 10/16/2026 - Added file deletion
+
+use anyhow::{Context, Result};
+use base64::Engine;
+use reqwest::Method;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use super::client::ensure_success;
+use super::GitHubClient;
+use crate::codeowners::{CodeOwners, CODEOWNERS_PATHS};
+use crate::models::{Download, FileCommit, FileContent, GitCommit, RepoTree, TreeEntry};
+
+impl GitHubClient {
+    /// Get a file's decoded contents at `git_ref` (default branch when `None`).
+    ///
+    /// Text is cut to `max_bytes` on a character boundary; binary files are
+    /// reported without content.
+    pub async fn get_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        git_ref: Option<&str>,
+        max_bytes: usize,
+    ) -> Result<FileContent> {
+        let meta = self.file_meta(owner, repo, path, git_ref).await?;
+
+        // The JSON response only inlines files up to 1 MB
+        let bytes = match meta.encoding.as_deref() {
+            Some("base64") => decode_base64(meta.content.as_deref().unwrap_or_default())?,
+            _ => {
+                let url = format!(
+                    "{}{}",
+                    self.endpoints.rest,
+                    contents_path(owner, repo, path, git_ref)
+                );
+                let response = self
+                    .send(
+                        self.request_accepting(
+                            Method::GET,
+                            &url,
+                            "application/vnd.github.raw+json",
+                        ),
+                        false,
+                    )
+                    .await
+                    .context("Failed to send REST request")?;
+                let response = ensure_success(response).await?;
+                response
+                    .bytes()
+                    .await
+                    .context("Failed to read file")?
+                    .to_vec()
+            }
+        };
+
+        let (content, truncated, binary) = match String::from_utf8(bytes) {
+            Ok(text) => {
+                let (text, truncated) = truncate_at_boundary(text, max_bytes);
+                (Some(text), truncated, false)
+            }
+            Err(_) => (None, false, true),
+        };
+
+        Ok(FileContent {
+            path: meta.path,
+            sha: meta.sha,
+            size: meta.size,
+            content,
+            truncated,
+            binary,
+            url: meta.html_url,
+        })
+    }
+
+    /// Stream a file at `git_ref` to `dest` without inlining it.
+    ///
+    /// Returns the blob metadata along with the download summary.
+    pub async fn download_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        git_ref: Option<&str>,
+        dest: &Path,
+    ) -> Result<(FileContent, Download)> {
+        let meta = self.file_meta(owner, repo, path, git_ref).await?;
+        let download = self
+            .download_to_file(
+                &contents_path(owner, repo, path, git_ref),
+                "application/vnd.github.raw+json",
+                dest,
+            )
+            .await?;
+
+        Ok((
+            FileContent {
+                path: meta.path,
+                sha: meta.sha,
+                size: meta.size,
+                content: None,
+                truncated: false,
+                binary: false,
+                url: meta.html_url,
+            },
+            download,
+        ))
+    }
+
+    /// Create or update a file on `branch` (default branch when `None`) as a
+    /// single commit.
+    ///
+    /// `expected_sha` is the blob SHA the caller last read; the write fails
+    /// with a conflict if the file has changed since. Omit it to create a new
+    /// file.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn put_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        content: &[u8],
+        message: &str,
+        branch: Option<&str>,
+        expected_sha: Option<&str>,
+    ) -> Result<FileCommit> {
+        let mut body = serde_json::json!({
+            "message": message,
+            "content": base64::engine::general_purpose::STANDARD.encode(content),
+        });
+        if let Some(branch) = branch {
+            body["branch"] = serde_json::json!(branch);
+        }
+        if let Some(sha) = expected_sha {
+            body["sha"] = serde_json::json!(sha);
+        }
+
+        self.write_contents(Method::PUT, owner, repo, path, &body)
+            .await
+    }
+
+    /// Delete a file on `branch` (default branch when `None`) as a single
+    /// commit. `sha` is the blob SHA the caller expects to remove.
+    pub async fn delete_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        message: &str,
+        branch: Option<&str>,
+        sha: &str,
+    ) -> Result<FileCommit> {
+        let mut body = serde_json::json!({
+            "message": message,
+            "sha": sha,
+        });
+        if let Some(branch) = branch {
+            body["branch"] = serde_json::json!(branch);
+        }
+
+        self.write_contents(Method::DELETE, owner, repo, path, &body)
+            .await
+    }
+
+    /// Commit several file changes to `branch` in one commit.
+    ///
+    /// `files` maps paths to new contents, or `None` to delete. Commits made
+    /// this way are signed by GitHub and show as verified. Without
+    /// `expected_head` the branch's current head is used, so concurrent
+    /// pushes between the read and the write still fail cleanly.
+    pub async fn commit_files(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        message: &str,
+        files: &BTreeMap<String, Option<String>>,
+        expected_head: Option<&str>,
+    ) -> Result<GitCommit> {
+        let query = r#"
+            mutation($input: CreateCommitOnBranchInput!) {
+                createCommitOnBranch(input: $input) {
+                    commit {
+                        oid
+                        message
+                        url
+                        tree { oid }
+                        parents(first: 2) {
+                            nodes { oid }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let expected_head = match expected_head {
+            Some(sha) => sha.to_string(),
+            None => self.resolve_commit_sha(owner, repo, branch).await?,
+        };
+
+        let engine = base64::engine::general_purpose::STANDARD;
+        let additions: Vec<_> = files
+            .iter()
+            .filter_map(|(path, content)| {
+                content.as_ref().map(|c| {
+                    serde_json::json!({
+                        "path": path.trim_start_matches('/'),
+                        "contents": engine.encode(c),
+                    })
+                })
+            })
+            .collect();
+        let deletions: Vec<_> = files
+            .iter()
+            .filter(|(_, content)| content.is_none())
+            .map(|(path, _)| serde_json::json!({ "path": path.trim_start_matches('/') }))
+            .collect();
+
+        let (headline, body) = split_message(message);
+        let variables = serde_json::json!({
+            "input": {
+                "branch": {
+                    "repositoryNameWithOwner": format!("{}/{}", owner, repo),
+                    "branchName": branch,
+                },
+                "message": {
+                    "headline": headline,
+                    "body": body,
+                },
+                "expectedHeadOid": expected_head,
+                "fileChanges": {
+                    "additions": additions,
+                    "deletions": deletions,
+                },
+            }
+        });
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CommitResponse {
+            create_commit_on_branch: CommitPayload,
+        }
+
+        #[derive(Deserialize)]
+        struct CommitPayload {
+            commit: CommitNode,
+        }
+
+        #[derive(Deserialize)]
+        struct CommitNode {
+            oid: String,
+            message: String,
+            url: String,
+            tree: OidNode,
+            parents: ParentConnection,
+        }
+
+        #[derive(Deserialize)]
+        struct ParentConnection {
+            nodes: Vec<OidNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct OidNode {
+            oid: String,
+        }
+
+        let response: CommitResponse = self.graphql(query, Some(variables)).await?;
+        let commit = response.create_commit_on_branch.commit;
+
+        Ok(GitCommit {
+            sha: commit.oid,
+            message: commit.message,
+            tree: commit.tree.oid,
+            parents: commit.parents.nodes.into_iter().map(|p| p.oid).collect(),
+            url: commit.url,
+        })
+    }
+
+    /// List the tree at `git_ref` (default branch when `None`), optionally
+    /// under a subdirectory.
+    ///
+    /// Returns at most `max_entries` entries; `capped` is set when more
+    /// existed.
+    pub async fn get_tree(
+        &self,
+        owner: &str,
+        repo: &str,
+        git_ref: Option<&str>,
+        path: Option<&str>,
+        recursive: bool,
+        max_entries: usize,
+    ) -> Result<RepoTree> {
+        #[derive(Deserialize)]
+        struct TreeRaw {
+            sha: String,
+            tree: Vec<TreeEntry>,
+            #[serde(default)]
+            truncated: bool,
+        }
+
+        let git_ref = match git_ref {
+            Some(r) => r.to_string(),
+            None => self.get_default_branch(owner, repo).await?,
+        };
+        let prefix = path.map(|p| p.trim_matches('/')).filter(|p| !p.is_empty());
+
+        // Subdirectories are selected from the full tree
+        let query = if recursive || prefix.is_some() {
+            "?recursive=1"
+        } else {
+            ""
+        };
+        let raw: TreeRaw = self
+            .rest_get(&format!(
+                "/repos/{}/{}/git/trees/{}{}",
+                owner, repo, git_ref, query
+            ))
+            .await?;
+
+        let (entries, capped) = select_entries(raw.tree, prefix, recursive, max_entries);
+
+        Ok(RepoTree {
+            git_ref,
+            sha: raw.sha,
+            path: prefix.map(|p| p.to_string()),
+            entries,
+            truncated: raw.truncated,
+            capped,
+        })
+    }
+
+    /// Send a contents API write and report the resulting commit.
+    ///
+    /// SHA mismatches surface as a readable conflict error instead of the
+    /// raw 409/422 body.
+    async fn write_contents(
+        &self,
+        method: Method,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        body: &Value,
+    ) -> Result<FileCommit> {
+        #[derive(Deserialize)]
+        struct WriteResponse {
+            content: Option<WrittenContent>,
+            commit: WrittenCommit,
+        }
+
+        #[derive(Deserialize)]
+        struct WrittenContent {
+            sha: String,
+        }
+
+        #[derive(Deserialize)]
+        struct WrittenCommit {
+            sha: String,
+            html_url: String,
+        }
+
+        let url = format!(
+            "{}{}",
+            self.endpoints.rest,
+            contents_path(owner, repo, path, None)
+        );
+        let mutation = method != Method::GET;
+        let response = self
+            .send(self.request(method, &url).json(body), mutation)
+            .await
+            .context("Failed to send REST request")?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::CONFLICT {
+            anyhow::bail!(
+                "Conflict: '{}' changed since SHA {}; re-read it with github.file and retry",
+                path,
+                body["sha"].as_str().unwrap_or("(none)")
+            );
+        }
+        if status == reqwest::StatusCode::UNPROCESSABLE_ENTITY {
+            let text = response.text().await.unwrap_or_default();
+            if text.contains("\"sha\" wasn't supplied") {
+                anyhow::bail!(
+                    "'{}' already exists; pass its current sha to overwrite it",
+                    path
+                );
+            }
+            anyhow::bail!("REST request failed: {} - {}", status, text);
+        }
+        let response = ensure_success(response).await?;
+        let written: WriteResponse = response.json().await.context("Failed to parse JSON")?;
+
+        Ok(FileCommit {
+            path: path.trim_start_matches('/').to_string(),
+            created: status == reqwest::StatusCode::CREATED,
+            sha: written.content.map(|c| c.sha),
+            commit_sha: written.commit.sha,
+            commit_url: written.commit.html_url,
+        })
+    }
+
+    /// Download a `tarball` or `zipball` snapshot at `git_ref` (default
+    /// branch when `None`) to `dest`.
+    pub async fn download_archive(
+        &self,
+        owner: &str,
+        repo: &str,
+        git_ref: Option<&str>,
+        format: &str,
+        dest: &Path,
+    ) -> Result<Download> {
+        let path = match git_ref {
+            Some(r) => format!("/repos/{}/{}/{}/{}", owner, repo, format, r),
+            None => format!("/repos/{}/{}/{}", owner, repo, format),
+        };
+        self.download_to_file(&path, "application/vnd.github+json", dest)
+            .await
+    }
+
+    /// Fetch and parse the repository's CODEOWNERS file at `git_ref`
+    /// (default branch when `None`).
+    ///
+    /// Returns the file's location with the parsed rules, or `None` when
+    /// the repository has no CODEOWNERS.
+    pub async fn get_codeowners(
+        &self,
+        owner: &str,
+        repo: &str,
+        git_ref: Option<&str>,
+    ) -> Result<Option<(String, CodeOwners)>> {
+        let git_ref = match git_ref {
+            Some(r) => r.to_string(),
+            None => self.get_default_branch(owner, repo).await?,
+        };
+
+        for path in CODEOWNERS_PATHS {
+            if let Some(content) = self.get_raw_file(owner, repo, path, &git_ref).await? {
+                return Ok(Some((path.to_string(), CodeOwners::parse(&content))));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Contents API metadata for a single file.
+    async fn file_meta(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        git_ref: Option<&str>,
+    ) -> Result<ContentRaw> {
+        let raw: serde_json::Value = self
+            .rest_get(&contents_path(owner, repo, path, git_ref))
+            .await?;
+        if raw.is_array() {
+            anyhow::bail!("'{}' is a directory; use github.tree to list it", path);
+        }
+
+        let meta: ContentRaw = serde_json::from_value(raw).context("Failed to parse contents")?;
+        if meta.content_type != "file" {
+            anyhow::bail!("'{}' is a {}, not a file", path, meta.content_type);
+        }
+        Ok(meta)
+    }
+}
+
+/// Raw contents API entry.
+#[derive(Deserialize)]
+struct ContentRaw {
+    #[serde(rename = "type")]
+    content_type: String,
+    path: String,
+    sha: String,
+    size: u64,
+    encoding: Option<String>,
+    content: Option<String>,
+    html_url: Option<String>,
+}
+
+/// REST path for a file in the contents API.
+fn contents_path(owner: &str, repo: &str, path: &str, git_ref: Option<&str>) -> String {
+    let path = path.trim_start_matches('/');
+    match git_ref {
+        Some(r) => format!("/repos/{}/{}/contents/{}?ref={}", owner, repo, path, r),
+        None => format!("/repos/{}/{}/contents/{}", owner, repo, path),
+    }
+}
+
+/// Keep entries under `prefix` (relative paths preserved), one level deep
+/// unless `recursive`, up to `max` entries.
+fn select_entries(
+    entries: Vec<TreeEntry>,
+    prefix: Option<&str>,
+    recursive: bool,
+    max: usize,
+) -> (Vec<TreeEntry>, bool) {
+    let mut selected: Vec<TreeEntry> = entries
+        .into_iter()
+        .filter(|e| {
+            let rest = match prefix {
+                Some(prefix) => match e.path.strip_prefix(prefix) {
+                    Some(rest) => match rest.strip_prefix('/') {
+                        Some(rest) => rest,
+                        None => return false,
+                    },
+                    None => return false,
+                },
+                None => e.path.as_str(),
+            };
+            recursive || !rest.contains('/')
+        })
+        .collect();
+
+    let capped = selected.len() > max;
+    selected.truncate(max);
+    (selected, capped)
+}
+
+/// Split a commit message into its headline and body.
+fn split_message(message: &str) -> (&str, Option<&str>) {
+    let message = message.trim();
+    match message.split_once('\n') {
+        Some((headline, body)) => {
+            let body = body.trim();
+            (headline.trim_end(), (!body.is_empty()).then_some(body))
+        }
+        None => (message, None),
+    }
+}
+
+/// Decode the contents API's line-wrapped base64.
+fn decode_base64(content: &str) -> Result<Vec<u8>> {
+    let compact: String = content
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace())
+        .collect();
+    base64::engine::general_purpose::STANDARD
+        .decode(compact)
+        .context("Failed to decode file content")
+}
+
+/// Cut `text` to at most `max_bytes`, backing off to a character boundary.
+fn truncate_at_boundary(mut text: String, max_bytes: usize) -> (String, bool) {
+    if text.len() <= max_bytes {
+        return (text, false);
+    }
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    text.truncate(end);
+    (text, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(path: &str, entry_type: &str) -> TreeEntry {
+        TreeEntry {
+            path: path.to_string(),
+            entry_type: entry_type.to_string(),
+            size: None,
+            sha: "abc".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_select_entries_under_prefix() {
+        let tree = vec![
+            entry("README.md", "blob"),
+            entry("src", "tree"),
+            entry("src/main.rs", "blob"),
+            entry("src/api", "tree"),
+            entry("src/api/mod.rs", "blob"),
+            entry("srcx/other.rs", "blob"),
+        ];
+
+        let (top, _) = select_entries(tree.clone(), Some("src"), false, 100);
+        let paths: Vec<&str> = top.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, ["src/main.rs", "src/api"]);
+
+        let (all, _) = select_entries(tree.clone(), Some("src"), true, 100);
+        assert_eq!(all.len(), 3);
+
+        let (root, capped) = select_entries(tree, None, false, 1);
+        assert_eq!(root.len(), 1);
+        assert!(capped);
+    }
+
+    #[test]
+    fn test_split_message() {
+        assert_eq!(split_message("Fix typo"), ("Fix typo", None));
+        assert_eq!(
+            split_message("Bump deps\n\nRefreshes the lockfile.\n"),
+            ("Bump deps", Some("Refreshes the lockfile."))
+        );
+    }
+
+    #[test]
+    fn test_decode_wrapped_base64() {
+        let bytes = decode_base64("aGVsbG8s\nIHdvcmxk\n").unwrap();
+        assert_eq!(bytes, b"hello, world");
+    }
+
+    #[test]
+    fn test_truncate_backs_off_to_char_boundary() {
+        let (text, truncated) = truncate_at_boundary("héllo".to_string(), 2);
+        assert_eq!(text, "h");
+        assert!(truncated);
+
+        let (text, truncated) = truncate_at_boundary("hello".to_string(), 10);
+        assert_eq!(text, "hello");
+        assert!(!truncated);
+    }
+}
@@ -0,0 +1,637 @@
+//! Dependabot version update configuration.
+//!
+//! Validates `.github/dependabot.yml` against the documented v2 schema and
+//! compares the configured updates with the package manifests found in the
+//! repository tree.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Initial implementation with config validation and coverage
+
+use anyhow::{Context, Result};
+use reqwest::Method;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::client::ensure_success;
+use super::GitHubClient;
+use crate::models::{ConfigIssue, DependabotConfigReport, DependabotUpdate, EcosystemCoverage};
+
+/// Locations Dependabot reads its configuration from, in order.
+const CONFIG_PATHS: &[&str] = &[".github/dependabot.yml", ".github/dependabot.yaml"];
+
+/// Supported `package-ecosystem` values.
+const ECOSYSTEMS: &[&str] = &[
+    "bun",
+    "bundler",
+    "cargo",
+    "composer",
+    "devcontainers",
+    "docker",
+    "docker-compose",
+    "dotnet-sdk",
+    "elm",
+    "github-actions",
+    "gitsubmodule",
+    "gomod",
+    "gradle",
+    "helm",
+    "maven",
+    "mix",
+    "npm",
+    "nuget",
+    "pip",
+    "pub",
+    "swift",
+    "terraform",
+    "uv",
+];
+
+const TOP_LEVEL_KEYS: &[&str] = &[
+    "version",
+    "updates",
+    "registries",
+    "enable-beta-ecosystems",
+    "multi-ecosystem-groups",
+];
+
+const UPDATE_KEYS: &[&str] = &[
+    "allow",
+    "assignees",
+    "commit-message",
+    "cooldown",
+    "directories",
+    "directory",
+    "exclude-paths",
+    "groups",
+    "ignore",
+    "insecure-external-code-execution",
+    "labels",
+    "milestone",
+    "multi-ecosystem-group",
+    "open-pull-requests-limit",
+    "package-ecosystem",
+    "patterns",
+    "pull-request-branch-name",
+    "rebase-strategy",
+    "registries",
+    "reviewers",
+    "schedule",
+    "target-branch",
+    "vendor",
+    "versioning-strategy",
+];
+
+const INTERVALS: &[&str] = &[
+    "daily",
+    "weekly",
+    "monthly",
+    "quarterly",
+    "semiannually",
+    "yearly",
+    "cron",
+];
+
+const WEEKDAYS: &[&str] = &[
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+impl GitHubClient {
+    /// Fetch and validate the repository's Dependabot configuration and
+    /// report which manifest directories it covers on the default branch.
+    pub async fn get_dependabot_config(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<DependabotConfigReport> {
+        #[derive(Deserialize)]
+        struct DefaultBranch {
+            default_branch: String,
+        }
+
+        #[derive(Deserialize)]
+        struct Tree {
+            tree: Vec<TreeEntry>,
+            #[serde(default)]
+            truncated: bool,
+        }
+
+        #[derive(Deserialize)]
+        struct TreeEntry {
+            path: String,
+            #[serde(rename = "type")]
+            entry_type: String,
+        }
+
+        let branch = self
+            .rest_get::<DefaultBranch>(&format!("/repos/{}/{}", owner, repo))
+            .await?
+            .default_branch;
+        let tree: Tree = self
+            .rest_get(&format!(
+                "/repos/{}/{}/git/trees/{}?recursive=1",
+                owner, repo, branch
+            ))
+            .await?;
+        let detected = detect_manifests(
+            tree.tree
+                .iter()
+                .filter(|e| e.entry_type == "blob")
+                .map(|e| e.path.as_str()),
+        );
+
+        let mut config = None;
+        for path in CONFIG_PATHS {
+            if let Some(content) = self.get_raw_file(owner, repo, path, &branch).await? {
+                config = Some((path.to_string(), content));
+                break;
+            }
+        }
+
+        let (path, issues, updates) = match config {
+            Some((path, content)) => {
+                let (issues, updates) = validate_config(&content);
+                (Some(path), issues, updates)
+            }
+            None => (None, Vec::new(), Vec::new()),
+        };
+        let (coverage, unmatched_updates) = coverage(&detected, &updates);
+
+        Ok(DependabotConfigReport {
+            repo: format!("{}/{}", owner, repo),
+            branch,
+            valid: path.is_some() && !issues.iter().any(|i| i.severity == "error"),
+            path,
+            issues,
+            updates,
+            coverage,
+            unmatched_updates,
+            tree_truncated: tree.truncated,
+        })
+    }
+
+    /// Raw file contents at `git_ref`, or `None` when the file does not exist.
+    pub(super) async fn get_raw_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        git_ref: &str,
+    ) -> Result<Option<String>> {
+        let url = format!(
+            "{}/repos/{}/{}/contents/{}?ref={}",
+            self.endpoints.rest, owner, repo, path, git_ref
+        );
+        let response = self
+            .send(
+                self.request_accepting(Method::GET, &url, "application/vnd.github.raw+json"),
+                false,
+            )
+            .await
+            .context("Failed to send REST request")?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let response = ensure_success(response).await?;
+        Ok(Some(response.text().await.context("Failed to read file")?))
+    }
+}
+
+/// Ecosystem a manifest file belongs to, by path.
+fn manifest_ecosystem(path: &str) -> Option<&'static str> {
+    let (dir, file) = path.rsplit_once('/').unwrap_or(("", path));
+
+    if dir == ".github/workflows" && (file.ends_with(".yml") || file.ends_with(".yaml")) {
+        return Some("github-actions");
+    }
+    if (dir.ends_with(".devcontainer") && file == "devcontainer.json")
+        || file == ".devcontainer.json"
+    {
+        return Some("devcontainers");
+    }
+
+    let ecosystem = match file {
+        "package.json" => "npm",
+        "requirements.txt" | "Pipfile" | "setup.py" | "pyproject.toml" => "pip",
+        "uv.lock" => "uv",
+        "Cargo.toml" => "cargo",
+        "go.mod" => "gomod",
+        "Gemfile" => "bundler",
+        "composer.json" => "composer",
+        "pom.xml" => "maven",
+        "build.gradle" | "build.gradle.kts" => "gradle",
+        "packages.config" => "nuget",
+        "global.json" => "dotnet-sdk",
+        "mix.exs" => "mix",
+        "pubspec.yaml" => "pub",
+        "Package.swift" => "swift",
+        "elm.json" => "elm",
+        "Chart.yaml" => "helm",
+        ".gitmodules" => "gitsubmodule",
+        "docker-compose.yml" | "docker-compose.yaml" | "compose.yml" | "compose.yaml" => {
+            "docker-compose"
+        }
+        _ if file == "Dockerfile" || file.starts_with("Dockerfile.") => "docker",
+        _ if file.ends_with(".csproj") || file.ends_with(".fsproj") => "nuget",
+        _ if file.ends_with(".tf") => "terraform",
+        _ => return None,
+    };
+    Some(ecosystem)
+}
+
+/// Manifest directories (as Dependabot `directory` values) per ecosystem.
+fn detect_manifests<'a>(
+    paths: impl Iterator<Item = &'a str>,
+) -> BTreeMap<String, BTreeSet<String>> {
+    let mut detected: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for path in paths {
+        // Vendored and installed dependencies are not the repo's own manifests.
+        if path
+            .split('/')
+            .any(|segment| segment == "node_modules" || segment == "vendor")
+        {
+            continue;
+        }
+        let Some(ecosystem) = manifest_ecosystem(path) else {
+            continue;
+        };
+        // Actions and submodules are configured from the repository root.
+        let dir = match ecosystem {
+            "github-actions" | "gitsubmodule" => "/".to_string(),
+            _ => match path.rsplit_once('/') {
+                Some((dir, _)) => format!("/{}", dir),
+                None => "/".to_string(),
+            },
+        };
+        detected
+            .entry(ecosystem.to_string())
+            .or_default()
+            .insert(dir);
+    }
+    detected
+}
+
+/// Validate a `dependabot.yml` document, returning the issues found and the
+/// update entries that could be read.
+fn validate_config(content: &str) -> (Vec<ConfigIssue>, Vec<DependabotUpdate>) {
+    let mut issues = Vec::new();
+    let mut updates = Vec::new();
+
+    let config: Value = match serde_yaml::from_str(content) {
+        Ok(config) => config,
+        Err(e) => {
+            issues.push(ConfigIssue::error("", format!("Invalid YAML: {}", e)));
+            return (issues, updates);
+        }
+    };
+    let Some(root) = config.as_object() else {
+        issues.push(ConfigIssue::error("", "Configuration must be a mapping"));
+        return (issues, updates);
+    };
+
+    for key in root.keys() {
+        if !TOP_LEVEL_KEYS.contains(&key.as_str()) {
+            issues.push(ConfigIssue::warning(key, "Unknown key"));
+        }
+    }
+    if root.get("version").and_then(Value::as_i64) != Some(2) {
+        issues.push(ConfigIssue::error("version", "Must be 2"));
+    }
+
+    let entries = match root.get("updates").and_then(Value::as_array) {
+        Some(entries) if !entries.is_empty() => entries,
+        _ => {
+            issues.push(ConfigIssue::error("updates", "Must be a non-empty list"));
+            return (issues, updates);
+        }
+    };
+
+    let mut seen = BTreeSet::new();
+    for (i, entry) in entries.iter().enumerate() {
+        let at = |key: &str| format!("updates[{}].{}", i, key);
+        let Some(entry) = entry.as_object() else {
+            issues.push(ConfigIssue::error(
+                format!("updates[{}]", i),
+                "Must be a mapping",
+            ));
+            continue;
+        };
+
+        for key in entry.keys() {
+            if !UPDATE_KEYS.contains(&key.as_str()) {
+                issues.push(ConfigIssue::warning(at(key), "Unknown key"));
+            }
+        }
+
+        let ecosystem = entry.get("package-ecosystem").and_then(Value::as_str);
+        match ecosystem {
+            None => issues.push(ConfigIssue::error(at("package-ecosystem"), "Required")),
+            Some(e) if !ECOSYSTEMS.contains(&e) => issues.push(ConfigIssue::error(
+                at("package-ecosystem"),
+                format!("Unknown ecosystem '{}'", e),
+            )),
+            Some(_) => {}
+        }
+
+        let directories: Vec<String> = match (entry.get("directory"), entry.get("directories")) {
+            (Some(_), Some(_)) => {
+                issues.push(ConfigIssue::error(
+                    at("directory"),
+                    "Use either directory or directories, not both",
+                ));
+                Vec::new()
+            }
+            (Some(Value::String(dir)), None) => vec![dir.clone()],
+            (None, Some(Value::Array(dirs))) if !dirs.is_empty() => dirs
+                .iter()
+                .filter_map(|d| d.as_str().map(|d| d.to_string()))
+                .collect(),
+            (None, None) => {
+                issues.push(ConfigIssue::error(
+                    at("directory"),
+                    "Required (or directories)",
+                ));
+                Vec::new()
+            }
+            _ => {
+                issues.push(ConfigIssue::error(
+                    at("directory"),
+                    "Must be a string, or directories a non-empty list of strings",
+                ));
+                Vec::new()
+            }
+        };
+
+        let schedule = entry.get("schedule").and_then(Value::as_object);
+        let interval = schedule
+            .and_then(|s| s.get("interval"))
+            .and_then(Value::as_str);
+        match interval {
+            None => issues.push(ConfigIssue::error(at("schedule.interval"), "Required")),
+            Some(i) if !INTERVALS.contains(&i) => issues.push(ConfigIssue::error(
+                at("schedule.interval"),
+                format!("Unknown interval '{}'", i),
+            )),
+            Some("cron") if schedule.and_then(|s| s.get("cronjob")).is_none() => issues.push(
+                ConfigIssue::error(at("schedule.cronjob"), "Required when interval is cron"),
+            ),
+            Some(_) => {}
+        }
+        if let Some(day) = schedule.and_then(|s| s.get("day")) {
+            if !day.as_str().is_some_and(|d| WEEKDAYS.contains(&d)) {
+                issues.push(ConfigIssue::error(
+                    at("schedule.day"),
+                    "Must be a weekday name",
+                ));
+            }
+        }
+        if let Some(time) = schedule.and_then(|s| s.get("time")) {
+            let valid = time
+                .as_str()
+                .and_then(|t| chrono::NaiveTime::parse_from_str(t, "%H:%M").ok())
+                .is_some();
+            if !valid {
+                issues.push(ConfigIssue::error(at("schedule.time"), "Must be HH:MM"));
+            }
+        }
+
+        if let Some(limit) = entry.get("open-pull-requests-limit") {
+            if limit.as_u64().is_none() {
+                issues.push(ConfigIssue::error(
+                    at("open-pull-requests-limit"),
+                    "Must be a non-negative integer",
+                ));
+            }
+        }
+
+        let target_branch = entry
+            .get("target-branch")
+            .and_then(Value::as_str)
+            .map(|b| b.to_string());
+
+        if let Some(ecosystem) = ecosystem {
+            for dir in &directories {
+                let key = (
+                    ecosystem.to_string(),
+                    normalize_dir(dir),
+                    target_branch.clone(),
+                );
+                if !seen.insert(key) {
+                    issues.push(ConfigIssue::error(
+                        format!("updates[{}]", i),
+                        format!("Duplicate update for {} in {}", ecosystem, dir),
+                    ));
+                }
+            }
+
+            updates.push(DependabotUpdate {
+                ecosystem: ecosystem.to_string(),
+                directories,
+                interval: interval.map(|i| i.to_string()),
+                target_branch,
+            });
+        }
+    }
+
+    (issues, updates)
+}
+
+/// Compare detected manifest directories with configured updates. Returns
+/// coverage per detected ecosystem and the configured `ecosystem directory`
+/// entries that match no manifest.
+fn coverage(
+    detected: &BTreeMap<String, BTreeSet<String>>,
+    updates: &[DependabotUpdate],
+) -> (Vec<EcosystemCoverage>, Vec<String>) {
+    let patterns_for = |ecosystem: &str| -> Vec<String> {
+        updates
+            .iter()
+            .filter(|u| u.ecosystem == ecosystem)
+            .flat_map(|u| u.directories.iter().map(|d| normalize_dir(d)))
+            .collect()
+    };
+
+    let coverage = detected
+        .iter()
+        .map(|(ecosystem, dirs)| {
+            let patterns = patterns_for(ecosystem);
+            let uncovered: Vec<String> = dirs
+                .iter()
+                .filter(|dir| !patterns.iter().any(|p| dir_matches(p, dir)))
+                .cloned()
+                .collect();
+            EcosystemCoverage {
+                ecosystem: ecosystem.clone(),
+                configured: !patterns.is_empty(),
+                directories: dirs.iter().cloned().collect(),
+                uncovered,
+            }
+        })
+        .collect();
+
+    let unmatched = updates
+        .iter()
+        .flat_map(|u| u.directories.iter().map(move |d| (u, d)))
+        .filter(|(u, dir)| {
+            let pattern = normalize_dir(dir);
+            !detected
+                .get(&u.ecosystem)
+                .is_some_and(|dirs| dirs.iter().any(|d| dir_matches(&pattern, d)))
+        })
+        .map(|(u, dir)| format!("{} {}", u.ecosystem, dir))
+        .collect();
+
+    (coverage, unmatched)
+}
+
+/// Normalize a configured directory to `/path` without a trailing slash.
+fn normalize_dir(dir: &str) -> String {
+    let trimmed = dir.trim().trim_matches('/');
+    format!("/{}", trimmed)
+}
+
+/// Match a directory against a configured pattern, where `*` matches one
+/// path segment and `**` any number of segments.
+fn dir_matches(pattern: &str, dir: &str) -> bool {
+    fn matches(pattern: &[&str], dir: &[&str]) -> bool {
+        match (pattern.first(), dir.first()) {
+            (None, None) => true,
+            (Some(&"**"), _) => {
+                matches(&pattern[1..], dir) || (!dir.is_empty() && matches(pattern, &dir[1..]))
+            }
+            (Some(p), Some(d)) => (*p == "*" || p == d) && matches(&pattern[1..], &dir[1..]),
+            _ => false,
+        }
+    }
+
+    let pattern: Vec<&str> = pattern.split('/').filter(|s| !s.is_empty()).collect();
+    let dir: Vec<&str> = dir.split('/').filter(|s| !s.is_empty()).collect();
+    matches(&pattern, &dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_manifests() {
+        let detected = detect_manifests(
+            [
+                "package.json",
+                "web/package.json",
+                "web/node_modules/left-pad/package.json",
+                "crates/core/Cargo.toml",
+                ".github/workflows/ci.yml",
+                "docker/Dockerfile.prod",
+                "README.md",
+            ]
+            .into_iter(),
+        );
+
+        assert_eq!(
+            detected["npm"].iter().collect::<Vec<_>>(),
+            vec!["/", "/web"]
+        );
+        assert!(detected["cargo"].contains("/crates/core"));
+        assert!(detected["github-actions"].contains("/"));
+        assert!(detected["docker"].contains("/docker"));
+        assert_eq!(detected.len(), 4);
+    }
+
+    #[test]
+    fn test_validate_config() {
+        let (issues, updates) = validate_config(
+            r#"
+version: 2
+updates:
+  - package-ecosystem: npm
+    directory: "/"
+    schedule:
+      interval: weekly
+      day: funday
+  - package-ecosystem: npm
+    directory: /
+    schedule:
+      interval: daily
+  - package-ecosystem: cargo
+    directories: ["/crates/*"]
+    schedule:
+      interval: cron
+    reviewrs: ["octocat"]
+"#,
+        );
+
+        let messages: Vec<String> = issues
+            .iter()
+            .map(|i| format!("{} {}: {}", i.severity, i.path, i.message))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "error updates[0].schedule.day: Must be a weekday name",
+                "error updates[1]: Duplicate update for npm in /",
+                "warning updates[2].reviewrs: Unknown key",
+                "error updates[2].schedule.cronjob: Required when interval is cron",
+            ]
+        );
+        assert_eq!(updates.len(), 3);
+        assert_eq!(updates[2].directories, vec!["/crates/*"]);
+    }
+
+    #[test]
+    fn test_coverage() {
+        let detected = detect_manifests(
+            [
+                "package.json",
+                "web/package.json",
+                "crates/core/Cargo.toml",
+                "crates/cli/Cargo.toml",
+            ]
+            .into_iter(),
+        );
+        let updates = vec![
+            DependabotUpdate {
+                ecosystem: "npm".to_string(),
+                directories: vec!["/".to_string()],
+                interval: Some("weekly".to_string()),
+                target_branch: None,
+            },
+            DependabotUpdate {
+                ecosystem: "cargo".to_string(),
+                directories: vec!["/crates/*".to_string()],
+                interval: Some("weekly".to_string()),
+                target_branch: None,
+            },
+            DependabotUpdate {
+                ecosystem: "pip".to_string(),
+                directories: vec!["/".to_string()],
+                interval: Some("weekly".to_string()),
+                target_branch: None,
+            },
+        ];
+
+        let (coverage, unmatched) = coverage(&detected, &updates);
+        let npm = coverage.iter().find(|c| c.ecosystem == "npm").unwrap();
+        assert_eq!(npm.uncovered, vec!["/web"]);
+        let cargo = coverage.iter().find(|c| c.ecosystem == "cargo").unwrap();
+        assert!(cargo.uncovered.is_empty());
+        assert_eq!(unmatched, vec!["pip /"]);
+    }
+
+    #[test]
+    fn test_dir_matches() {
+        assert!(dir_matches("/", "/"));
+        assert!(dir_matches("/packages/*", "/packages/api"));
+        assert!(!dir_matches("/packages/*", "/packages/api/nested"));
+        assert!(dir_matches("/**", "/packages/api/nested"));
+        assert!(dir_matches("/**", "/"));
+        assert!(!dir_matches("/web", "/"));
+    }
+}
@@ -0,0 +1,237 @@
+//! Dependency graph endpoints.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Added dependency review between refs
+//! 10/16/2026 - Initial implementation with SBOM export and manifest listing
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::client::encode_query;
+use super::GitHubClient;
+use crate::models::{
+    DependencyChange, DependencyManifest, DependencyVulnerability, ManifestDependency,
+};
+
+/// Schema preview that exposes `dependencyGraphManifests` over GraphQL.
+const DEPENDENCY_GRAPH_PREVIEW: &str = "application/vnd.github.hawkgirl-preview+json";
+
+impl GitHubClient {
+    /// Export the repository's dependency graph as an SPDX 2.3 SBOM document.
+    pub async fn get_sbom(&self, owner: &str, repo: &str) -> Result<Value> {
+        #[derive(Deserialize)]
+        struct SbomResponse {
+            sbom: Value,
+        }
+
+        let response: SbomResponse = self
+            .rest_get(&format!("/repos/{}/{}/dependency-graph/sbom", owner, repo))
+            .await?;
+        Ok(response.sbom)
+    }
+
+    /// Compare dependencies between `base` and `head` (any refs), optionally
+    /// limited to one manifest path.
+    pub async fn compare_dependencies(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+        manifest: Option<&str>,
+    ) -> Result<Vec<DependencyChange>> {
+        // Refs go in the path as-is; branch names may contain `/`.
+        let mut path = format!(
+            "/repos/{}/{}/dependency-graph/compare/{}...{}",
+            owner, repo, base, head
+        );
+        if let Some(manifest) = manifest {
+            path.push_str(&format!("?name={}", encode_query(manifest)));
+        }
+
+        let changes: Vec<DependencyChangeRaw> = self.rest_get(&path).await?;
+        Ok(changes.into_iter().map(Into::into).collect())
+    }
+
+    /// List dependency manifests detected in the repository with the
+    /// dependencies each declares (up to `per_manifest` per manifest).
+    pub async fn list_dependency_manifests(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_manifest: i32,
+    ) -> Result<Vec<DependencyManifest>> {
+        let query = r#"
+            query($owner: String!, $repo: String!, $perManifest: Int!) {
+                repository(owner: $owner, name: $repo) {
+                    dependencyGraphManifests(first: 100, withDependencies: true) {
+                        nodes {
+                            filename
+                            blobPath
+                            dependenciesCount
+                            exceedsMaxSize
+                            parseable
+                            dependencies(first: $perManifest) {
+                                nodes {
+                                    packageName
+                                    packageManager
+                                    requirements
+                                    hasDependencies
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct Response {
+            repository: Option<RepoData>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoData {
+            dependency_graph_manifests: Nodes<ManifestNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct Nodes<T> {
+            nodes: Vec<T>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ManifestNode {
+            filename: String,
+            blob_path: String,
+            dependencies_count: Option<i32>,
+            exceeds_max_size: bool,
+            parseable: bool,
+            dependencies: Option<Nodes<DependencyNode>>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct DependencyNode {
+            package_name: String,
+            package_manager: Option<String>,
+            requirements: String,
+            has_dependencies: bool,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "repo": repo,
+            "perManifest": per_manifest,
+        });
+        let result: Response = self
+            .graphql_accepting(query, Some(variables), Some(DEPENDENCY_GRAPH_PREVIEW))
+            .await?;
+        let repository = result
+            .repository
+            .with_context(|| format!("Repository not found: {}/{}", owner, repo))?;
+
+        Ok(repository
+            .dependency_graph_manifests
+            .nodes
+            .into_iter()
+            .map(|m| {
+                let path = manifest_path(&m.blob_path, &m.filename);
+                DependencyManifest {
+                    filename: m.filename,
+                    path,
+                    dependencies_count: m.dependencies_count.unwrap_or(0),
+                    parseable: m.parseable && !m.exceeds_max_size,
+                    dependencies: m
+                        .dependencies
+                        .map(|d| d.nodes)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|d| ManifestDependency {
+                            package: d.package_name,
+                            package_manager: d.package_manager.map(|p| p.to_lowercase()),
+                            requirements: Some(d.requirements).filter(|r| !r.is_empty()),
+                            has_dependencies: d.has_dependencies,
+                        })
+                        .collect(),
+                }
+            })
+            .collect())
+    }
+}
+
+/// Raw dependency review change from REST API.
+#[derive(Deserialize)]
+struct DependencyChangeRaw {
+    change_type: String,
+    manifest: String,
+    ecosystem: String,
+    name: String,
+    version: String,
+    license: Option<String>,
+    scope: Option<String>,
+    #[serde(default)]
+    vulnerabilities: Vec<VulnerabilityRaw>,
+}
+
+#[derive(Deserialize)]
+struct VulnerabilityRaw {
+    severity: String,
+    advisory_ghsa_id: String,
+    advisory_summary: String,
+    advisory_url: String,
+}
+
+impl From<DependencyChangeRaw> for DependencyChange {
+    fn from(c: DependencyChangeRaw) -> Self {
+        DependencyChange {
+            change_type: c.change_type,
+            manifest: c.manifest,
+            ecosystem: c.ecosystem,
+            name: c.name,
+            version: c.version,
+            license: c.license,
+            scope: c.scope,
+            vulnerabilities: c
+                .vulnerabilities
+                .into_iter()
+                .map(|v| DependencyVulnerability {
+                    severity: v.severity,
+                    ghsa_id: v.advisory_ghsa_id,
+                    summary: v.advisory_summary,
+                    url: v.advisory_url,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Repository-relative path from a manifest's `blobPath`
+/// (`/owner/repo/blob/<ref>/<path>`).
+fn manifest_path(blob_path: &str, filename: &str) -> String {
+    blob_path
+        .splitn(6, '/')
+        .nth(5)
+        .unwrap_or(filename)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_path() {
+        assert_eq!(
+            manifest_path(
+                "/octocat/repo/blob/main/crates/core/Cargo.toml",
+                "Cargo.toml"
+            ),
+            "crates/core/Cargo.toml"
+        );
+        assert_eq!(manifest_path("", "package.json"), "package.json");
+    }
+}
@@ -0,0 +1,622 @@
+//! GitHub Discussions (GraphQL only).
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Added answer marking
+//! 10/16/2026 - Added discussion category listing
+//! 10/16/2026 - Added discussion creation and comments
+//! 10/16/2026 - Initial implementation with discussion listing and threads
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use super::GitHubClient;
+use crate::models::{Discussion, DiscussionCategory, DiscussionComment, DiscussionDetail};
+
+/// Fields shared by discussion list and detail queries.
+const DISCUSSION_FIELDS: &str = r#"
+    fragment DiscussionFields on Discussion {
+        id
+        number
+        title
+        url
+        createdAt
+        updatedAt
+        isAnswered
+        locked
+        upvoteCount
+        author { login }
+        category { name }
+        comments { totalCount }
+    }
+"#;
+
+/// Fields of a discussion comment or reply.
+const COMMENT_FIELDS: &str = r#"
+    fragment CommentFields on DiscussionComment {
+        id
+        body
+        url
+        createdAt
+        isAnswer
+        upvoteCount
+        author { login }
+    }
+"#;
+
+impl GitHubClient {
+    /// List discussions, most recently updated first, optionally limited to
+    /// one category (by name or slug) and answered state.
+    ///
+    /// Returns the discussions and a cursor for the next page, if any.
+    pub async fn list_discussions(
+        &self,
+        owner: &str,
+        repo: &str,
+        category: Option<&str>,
+        answered: Option<bool>,
+        limit: i32,
+        after: Option<&str>,
+    ) -> Result<(Vec<Discussion>, Option<String>)> {
+        let category_id = match category {
+            Some(category) => Some(self.discussion_category_id(owner, repo, category).await?),
+            None => None,
+        };
+
+        let query = format!(
+            r#"
+            query($owner: String!, $name: String!, $first: Int!, $after: String,
+                  $categoryId: ID, $answered: Boolean) {{
+                repository(owner: $owner, name: $name) {{
+                    discussions(first: $first, after: $after, categoryId: $categoryId,
+                                answered: $answered,
+                                orderBy: {{field: UPDATED_AT, direction: DESC}}) {{
+                        pageInfo {{
+                            hasNextPage
+                            endCursor
+                        }}
+                        nodes {{
+                            ...DiscussionFields
+                        }}
+                    }}
+                }}
+            }}
+            {}
+        "#,
+            DISCUSSION_FIELDS
+        );
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "first": limit.clamp(1, 100),
+            "after": after,
+            "categoryId": category_id,
+            "answered": answered,
+        });
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: Option<RepoData>,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            discussions: DiscussionConnection,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct DiscussionConnection {
+            page_info: PageInfo,
+            nodes: Vec<DiscussionNode>,
+        }
+
+        let response: RepoResponse = self.graphql(&query, Some(variables)).await?;
+        let discussions = response
+            .repository
+            .context("Repository not found")?
+            .discussions;
+
+        Ok((
+            discussions.nodes.into_iter().map(Into::into).collect(),
+            discussions.page_info.next_cursor(),
+        ))
+    }
+
+    /// Get a discussion with its body and comment tree: up to `comments`
+    /// top-level comments, oldest first, each with up to `replies` replies.
+    pub async fn get_discussion(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+        comments: i32,
+        replies: i32,
+    ) -> Result<DiscussionDetail> {
+        let query = format!(
+            r#"
+            query($owner: String!, $name: String!, $number: Int!, $comments: Int!,
+                  $replies: Int!) {{
+                repository(owner: $owner, name: $name) {{
+                    discussion(number: $number) {{
+                        ...DiscussionFields
+                        body
+                        answer {{ id }}
+                        thread: comments(first: $comments) {{
+                            nodes {{
+                                ...CommentFields
+                                replies(first: $replies) {{
+                                    totalCount
+                                    nodes {{
+                                        ...CommentFields
+                                    }}
+                                }}
+                            }}
+                        }}
+                    }}
+                }}
+            }}
+            {}
+            {}
+        "#,
+            DISCUSSION_FIELDS, COMMENT_FIELDS
+        );
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "number": number,
+            "comments": comments.clamp(1, 100),
+            "replies": replies.clamp(0, 100),
+        });
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: Option<RepoData>,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            discussion: Option<DetailNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct DetailNode {
+            #[serde(flatten)]
+            discussion: DiscussionNode,
+            body: String,
+            answer: Option<IdNode>,
+            thread: CommentConnection,
+        }
+
+        #[derive(Deserialize)]
+        struct CommentConnection {
+            nodes: Vec<CommentNode>,
+        }
+
+        let response: RepoResponse = self.graphql(&query, Some(variables)).await?;
+        let detail = response
+            .repository
+            .context("Repository not found")?
+            .discussion
+            .with_context(|| format!("Discussion #{} not found", number))?;
+
+        Ok(DiscussionDetail {
+            discussion: detail.discussion.into(),
+            body: detail.body,
+            answer_id: detail.answer.map(|a| a.id),
+            comments: detail.thread.nodes.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    /// Start a discussion in a category (by name or slug).
+    pub async fn create_discussion(
+        &self,
+        owner: &str,
+        repo: &str,
+        category: &str,
+        title: &str,
+        body: &str,
+    ) -> Result<Discussion> {
+        let query = format!(
+            r#"
+            mutation($repositoryId: ID!, $categoryId: ID!, $title: String!, $body: String!) {{
+                createDiscussion(input: {{repositoryId: $repositoryId, categoryId: $categoryId,
+                                         title: $title, body: $body}}) {{
+                    discussion {{
+                        ...DiscussionFields
+                    }}
+                }}
+            }}
+            {}
+        "#,
+            DISCUSSION_FIELDS
+        );
+
+        let repo_id = self.get_repo_id(owner, repo).await?;
+        let category_id = self.discussion_category_id(owner, repo, category).await?;
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CreateResponse {
+            create_discussion: CreateData,
+        }
+
+        #[derive(Deserialize)]
+        struct CreateData {
+            discussion: DiscussionNode,
+        }
+
+        let variables = serde_json::json!({
+            "repositoryId": repo_id,
+            "categoryId": category_id,
+            "title": title,
+            "body": body,
+        });
+
+        let result: CreateResponse = self.graphql(&query, Some(variables)).await?;
+        Ok(result.create_discussion.discussion.into())
+    }
+
+    /// Comment on a discussion, or reply to a top-level comment when
+    /// `reply_to` (a comment node ID) is given.
+    pub async fn add_discussion_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+        body: &str,
+        reply_to: Option<&str>,
+    ) -> Result<DiscussionComment> {
+        let query = format!(
+            r#"
+            mutation($discussionId: ID!, $body: String!, $replyToId: ID) {{
+                addDiscussionComment(input: {{discussionId: $discussionId, body: $body,
+                                             replyToId: $replyToId}}) {{
+                    comment {{
+                        ...CommentFields
+                    }}
+                }}
+            }}
+            {}
+        "#,
+            COMMENT_FIELDS
+        );
+
+        let discussion_id = self.discussion_id(owner, repo, number).await?;
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CommentResponse {
+            add_discussion_comment: CommentData,
+        }
+
+        #[derive(Deserialize)]
+        struct CommentData {
+            comment: CommentNode,
+        }
+
+        let variables = serde_json::json!({
+            "discussionId": discussion_id,
+            "body": body,
+            "replyToId": reply_to,
+        });
+
+        let result: CommentResponse = self.graphql(&query, Some(variables)).await?;
+        Ok(result.add_discussion_comment.comment.into())
+    }
+
+    /// Mark a comment (by node ID) as its discussion's answer, or clear it.
+    /// Returns the updated discussion.
+    pub async fn set_discussion_answer(
+        &self,
+        comment_id: &str,
+        answer: bool,
+    ) -> Result<Discussion> {
+        let mutation = if answer {
+            "markDiscussionCommentAsAnswer"
+        } else {
+            "unmarkDiscussionCommentAsAnswer"
+        };
+        let query = format!(
+            r#"
+            mutation($id: ID!) {{
+                result: {}(input: {{id: $id}}) {{
+                    discussion {{
+                        ...DiscussionFields
+                    }}
+                }}
+            }}
+            {}
+        "#,
+            mutation, DISCUSSION_FIELDS
+        );
+
+        #[derive(Deserialize)]
+        struct AnswerResponse {
+            result: AnswerData,
+        }
+
+        #[derive(Deserialize)]
+        struct AnswerData {
+            discussion: DiscussionNode,
+        }
+
+        let variables = serde_json::json!({ "id": comment_id });
+        let response: AnswerResponse = self.graphql(&query, Some(variables)).await?;
+        Ok(response.result.discussion.into())
+    }
+
+    /// Get a discussion's node ID from its number.
+    async fn discussion_id(&self, owner: &str, repo: &str, number: i32) -> Result<String> {
+        let query = r#"
+            query($owner: String!, $name: String!, $number: Int!) {
+                repository(owner: $owner, name: $name) {
+                    discussion(number: $number) {
+                        id
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: Option<RepoData>,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            discussion: Option<IdNode>,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "number": number,
+        });
+
+        let response: RepoResponse = self.graphql(query, Some(variables)).await?;
+        response
+            .repository
+            .context("Repository not found")?
+            .discussion
+            .map(|d| d.id)
+            .with_context(|| format!("Discussion #{} not found", number))
+    }
+
+    /// List a repository's discussion categories.
+    pub async fn list_discussion_categories(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<DiscussionCategory>> {
+        let query = r#"
+            query($owner: String!, $name: String!) {
+                repository(owner: $owner, name: $name) {
+                    discussionCategories(first: 100) {
+                        nodes {
+                            id
+                            name
+                            slug
+                            emoji
+                            description
+                            isAnswerable
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: Option<RepoData>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoData {
+            discussion_categories: CategoryConnection,
+        }
+
+        #[derive(Deserialize)]
+        struct CategoryConnection {
+            nodes: Vec<CategoryNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CategoryNode {
+            id: String,
+            name: String,
+            slug: String,
+            emoji: String,
+            description: Option<String>,
+            is_answerable: bool,
+        }
+
+        let variables = serde_json::json!({ "owner": owner, "name": repo });
+        let response: RepoResponse = self.graphql(query, Some(variables)).await?;
+
+        Ok(response
+            .repository
+            .context("Repository not found")?
+            .discussion_categories
+            .nodes
+            .into_iter()
+            .map(|c| DiscussionCategory {
+                id: c.id,
+                name: c.name,
+                slug: c.slug,
+                emoji: c.emoji,
+                description: c.description.filter(|d| !d.is_empty()),
+                is_answerable: c.is_answerable,
+            })
+            .collect())
+    }
+
+    /// Resolve a discussion category name or slug to its node ID.
+    async fn discussion_category_id(
+        &self,
+        owner: &str,
+        repo: &str,
+        category: &str,
+    ) -> Result<String> {
+        self.list_discussion_categories(owner, repo)
+            .await?
+            .into_iter()
+            .find(|c| c.slug == category || c.name.eq_ignore_ascii_case(category))
+            .map(|c| c.id)
+            .with_context(|| format!("Discussion category '{}' not found", category))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+impl PageInfo {
+    fn next_cursor(self) -> Option<String> {
+        self.end_cursor.filter(|_| self.has_next_page)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiscussionNode {
+    id: String,
+    number: i32,
+    title: String,
+    url: String,
+    created_at: String,
+    updated_at: String,
+    is_answered: Option<bool>,
+    locked: bool,
+    upvote_count: i32,
+    author: Option<AuthorNode>,
+    category: CategoryName,
+    comments: TotalCount,
+}
+
+#[derive(Deserialize)]
+struct IdNode {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct AuthorNode {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct CategoryName {
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TotalCount {
+    total_count: i32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommentNode {
+    id: String,
+    body: String,
+    url: String,
+    created_at: String,
+    is_answer: bool,
+    upvote_count: i32,
+    author: Option<AuthorNode>,
+    /// Absent on replies; GitHub threads are one level deep.
+    replies: Option<ReplyConnection>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReplyConnection {
+    total_count: i32,
+    nodes: Vec<CommentNode>,
+}
+
+impl From<DiscussionNode> for Discussion {
+    fn from(d: DiscussionNode) -> Self {
+        Discussion {
+            id: d.id,
+            number: d.number,
+            title: d.title,
+            category: d.category.name,
+            author: d.author.map(|a| a.login),
+            // Only answerable (Q&A) categories report an answered state.
+            is_answered: d.is_answered.unwrap_or(false),
+            locked: d.locked,
+            upvote_count: d.upvote_count,
+            comment_count: d.comments.total_count,
+            url: d.url,
+            created_at: d.created_at,
+            updated_at: d.updated_at,
+        }
+    }
+}
+
+impl From<CommentNode> for DiscussionComment {
+    fn from(c: CommentNode) -> Self {
+        let (reply_count, replies) = match c.replies {
+            Some(r) => (r.total_count, r.nodes.into_iter().map(Into::into).collect()),
+            None => (0, Vec::new()),
+        };
+
+        DiscussionComment {
+            id: c.id,
+            author: c.author.map(|a| a.login),
+            body: c.body,
+            is_answer: c.is_answer,
+            upvote_count: c.upvote_count,
+            url: c.url,
+            created_at: c.created_at,
+            reply_count,
+            replies,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comment_tree_from_node() {
+        let node: CommentNode = serde_json::from_value(serde_json::json!({
+            "id": "DC_1",
+            "body": "How do I configure this?",
+            "url": "https://github.com/o/r/discussions/1#discussioncomment-1",
+            "createdAt": "2026-01-01T00:00:00Z",
+            "isAnswer": false,
+            "upvoteCount": 2,
+            "author": {"login": "octocat"},
+            "replies": {
+                "totalCount": 3,
+                "nodes": [{
+                    "id": "DC_2",
+                    "body": "See the docs.",
+                    "url": "https://github.com/o/r/discussions/1#discussioncomment-2",
+                    "createdAt": "2026-01-02T00:00:00Z",
+                    "isAnswer": true,
+                    "upvoteCount": 0,
+                    "author": null,
+                }],
+            },
+        }))
+        .unwrap();
+
+        let comment = DiscussionComment::from(node);
+        assert_eq!(comment.reply_count, 3);
+        assert_eq!(comment.replies.len(), 1);
+        assert!(comment.replies[0].is_answer);
+        assert!(comment.replies[0].author.is_none());
+        assert!(comment.replies[0].replies.is_empty());
+    }
+}
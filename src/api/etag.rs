@@ -0,0 +1,236 @@
+//! Conditional REST requests.
+//!
+//! GET responses carrying an `ETag` or `Last-Modified` validator are kept in
+//! memory per URL. Repeating the request sends `If-None-Match` /
+//! `If-Modified-Since`, and a `304 Not Modified` is answered from the cache.
+//! GitHub does not count 304s against the rate limit, which matters for
+//! callers that poll. The cache is bounded by entry count and bytes; bodies
+//! too large to be worth keeping are not cached.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Cap cached bytes per entry and in total
+//! 10/16/2026 - Initial implementation
+
+use anyhow::{Context, Result};
+use reqwest::header::{HeaderMap, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED, LINK};
+use reqwest::{Method, StatusCode};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use super::client::ensure_success;
+use super::GitHubClient;
+
+/// Responses kept per client before the oldest is evicted.
+const MAX_ENTRIES: usize = 512;
+
+/// Largest body cached; bigger responses are always fetched in full.
+const MAX_ENTRY_BYTES: usize = 1024 * 1024;
+
+/// Bytes of bodies kept per client before the oldest are evicted.
+const MAX_TOTAL_BYTES: usize = 32 * 1024 * 1024;
+
+/// A cached GET response body with its validators.
+#[derive(Clone)]
+struct CachedBody {
+    body: String,
+    /// `Link` header, so cached pages still paginate.
+    link: Option<String>,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    /// Insertion order, for eviction.
+    seq: u64,
+}
+
+impl CachedBody {
+    /// Build an entry from a fresh response, if it has a validator and is
+    /// small enough to keep.
+    fn from_response(headers: &HeaderMap, body: &str) -> Option<Self> {
+        if body.len() > MAX_ENTRY_BYTES {
+            return None;
+        }
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string())
+        };
+        let etag = header(ETAG);
+        let last_modified = header(LAST_MODIFIED);
+        if etag.is_none() && last_modified.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            body: body.to_string(),
+            link: header(LINK),
+            etag,
+            last_modified,
+            seq: 0,
+        })
+    }
+
+    /// Bytes the entry holds.
+    fn size(&self) -> usize {
+        self.body.len() + self.link.as_ref().map_or(0, |link| link.len())
+    }
+}
+
+/// Validated GET responses by URL.
+#[derive(Default)]
+pub(super) struct EtagCache {
+    entries: Mutex<HashMap<String, CachedBody>>,
+    next_seq: AtomicU64,
+    /// Requests answered with 304 from the cache.
+    hits: AtomicU64,
+}
+
+impl EtagCache {
+    fn get(&self, url: &str) -> Option<CachedBody> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(url)
+            .cloned()
+    }
+
+    fn insert(&self, url: &str, mut entry: CachedBody) {
+        entry.seq = self.next_seq.fetch_add(1, Ordering::Relaxed);
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.remove(url);
+        let mut bytes: usize = entries.values().map(CachedBody::size).sum();
+        while entries.len() >= MAX_ENTRIES || bytes + entry.size() > MAX_TOTAL_BYTES {
+            let Some(oldest) = entries
+                .iter()
+                .min_by_key(|(_, e)| e.seq)
+                .map(|(url, _)| url.clone())
+            else {
+                break;
+            };
+            if let Some(evicted) = entries.remove(&oldest) {
+                bytes -= evicted.size();
+            }
+        }
+        entries.insert(url.to_string(), entry);
+    }
+
+    fn remove(&self, url: &str) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(url);
+    }
+}
+
+impl GitHubClient {
+    /// GET an absolute REST URL, revalidating any cached copy. Returns the
+    /// body and its `Link` header.
+    pub(super) async fn get_conditional(&self, url: &str) -> Result<(String, Option<String>)> {
+        let cached = self.etags.get(url);
+
+        let mut request = self.request(Method::GET, url);
+        if let Some(cached) = &cached {
+            if let Some(etag) = &cached.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &cached.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let response = self
+            .send(request, false)
+            .await
+            .context("Failed to send REST request")?;
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let Some(cached) = cached {
+                self.etags.hits.fetch_add(1, Ordering::Relaxed);
+                return Ok((cached.body, cached.link));
+            }
+        }
+
+        let response = match ensure_success(response).await {
+            Ok(response) => response,
+            Err(e) => {
+                self.etags.remove(url);
+                return Err(e);
+            }
+        };
+        let headers = response.headers().clone();
+        let body = response.text().await.context("Failed to read response")?;
+
+        let link = headers
+            .get(LINK)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string());
+        match CachedBody::from_response(&headers, &body) {
+            Some(entry) => self.etags.insert(url, entry),
+            None => self.etags.remove(url),
+        }
+        Ok((body, link))
+    }
+
+    /// Requests answered from the ETag cache with a 304 so far.
+    pub fn etag_cache_hits(&self) -> u64 {
+        self.etags.hits.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_cache_entries_need_a_validator() {
+        assert!(CachedBody::from_response(&HeaderMap::new(), "{}").is_none());
+
+        let mut headers = HeaderMap::new();
+        headers.insert(ETAG, HeaderValue::from_static("W/\"abc\""));
+        let entry = CachedBody::from_response(&headers, "[]").unwrap();
+        assert_eq!(entry.etag.as_deref(), Some("W/\"abc\""));
+        assert_eq!(entry.body, "[]");
+
+        let large = "x".repeat(MAX_ENTRY_BYTES + 1);
+        assert!(CachedBody::from_response(&headers, &large).is_none());
+    }
+
+    #[test]
+    fn test_cache_evicts_oldest_past_byte_cap() {
+        let cache = EtagCache::default();
+        let mut headers = HeaderMap::new();
+        headers.insert(ETAG, HeaderValue::from_static("\"x\""));
+        let body = "x".repeat(MAX_ENTRY_BYTES);
+
+        let fit = MAX_TOTAL_BYTES / MAX_ENTRY_BYTES;
+        for i in 0..=fit {
+            let entry = CachedBody::from_response(&headers, &body).unwrap();
+            cache.insert(&format!("https://api.github.com/{}", i), entry);
+        }
+
+        assert_eq!(cache.entries.lock().unwrap().len(), fit);
+        assert!(cache.get("https://api.github.com/0").is_none());
+        assert!(cache
+            .get(&format!("https://api.github.com/{}", fit))
+            .is_some());
+    }
+
+    #[test]
+    fn test_cache_evicts_oldest_when_full() {
+        let cache = EtagCache::default();
+        let mut headers = HeaderMap::new();
+        headers.insert(ETAG, HeaderValue::from_static("\"x\""));
+
+        for i in 0..=MAX_ENTRIES {
+            let entry = CachedBody::from_response(&headers, "{}").unwrap();
+            cache.insert(&format!("https://api.github.com/{}", i), entry);
+        }
+
+        assert_eq!(cache.entries.lock().unwrap().len(), MAX_ENTRIES);
+        assert!(cache.get("https://api.github.com/0").is_none());
+        assert!(cache
+            .get(&format!("https://api.github.com/{}", MAX_ENTRIES))
+            .is_some());
+    }
+}
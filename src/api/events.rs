@@ -0,0 +1,180 @@
+//! Activity event feeds.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Initial implementation with received, public, and repo feeds
+
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::GitHubClient;
+use crate::models::Event;
+
+/// GitHub only serves the most recent 300 events of any feed.
+const MAX_FEED_EVENTS: usize = 300;
+
+/// Which event feed to read.
+pub enum EventFeed {
+    /// Events received by a user (activity from people and repos they watch).
+    Received { login: Option<String> },
+    /// Public events performed by a user.
+    Public { login: Option<String> },
+    /// Events in a repository.
+    Repo { owner: String, repo: String },
+}
+
+impl GitHubClient {
+    /// Read up to `limit` events from `feed`, newest first, keeping only
+    /// `types` when non-empty (e.g. `PushEvent` or `push`).
+    pub async fn list_events(
+        &self,
+        feed: &EventFeed,
+        types: &[String],
+        limit: usize,
+        include_payload: bool,
+    ) -> Result<Vec<Event>> {
+        let path = match feed {
+            EventFeed::Received { login } => {
+                format!(
+                    "/users/{}/received_events",
+                    self.login_or_viewer(login).await?
+                )
+            }
+            EventFeed::Public { login } => {
+                format!(
+                    "/users/{}/events/public",
+                    self.login_or_viewer(login).await?
+                )
+            }
+            EventFeed::Repo { owner, repo } => format!("/repos/{}/{}/events", owner, repo),
+        };
+
+        let types: Vec<String> = types.iter().map(|t| event_type_name(t)).collect();
+        // Filtering is client-side, so scan the whole feed when filtering.
+        let fetch = if types.is_empty() {
+            limit
+        } else {
+            MAX_FEED_EVENTS
+        };
+        let events: Vec<EventRaw> = self.rest_get_paged(&path, fetch).await?;
+
+        Ok(events
+            .into_iter()
+            .filter(|e| types.is_empty() || types.contains(&e.event_type))
+            .take(limit)
+            .map(|e| e.into_model(include_payload))
+            .collect())
+    }
+
+    async fn login_or_viewer(&self, login: &Option<String>) -> Result<String> {
+        match login {
+            Some(login) => Ok(login.clone()),
+            None => Ok(self.get_user().await?.login),
+        }
+    }
+}
+
+/// Normalize a type filter to the API's event name: `pull_request` and
+/// `PullRequest` both become `PullRequestEvent`.
+fn event_type_name(name: &str) -> String {
+    if name.ends_with("Event") {
+        return name.to_string();
+    }
+
+    let mut camel: String = name
+        .split(['_', '-'])
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    camel.push_str("Event");
+    camel
+}
+
+/// Raw event from REST API.
+#[derive(Deserialize)]
+struct EventRaw {
+    id: String,
+    #[serde(rename = "type")]
+    event_type: String,
+    actor: ActorRaw,
+    repo: EventRepoRaw,
+    #[serde(default)]
+    public: bool,
+    created_at: String,
+    #[serde(default)]
+    payload: Value,
+}
+
+#[derive(Deserialize)]
+struct ActorRaw {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct EventRepoRaw {
+    name: String,
+}
+
+impl EventRaw {
+    fn into_model(self, include_payload: bool) -> Event {
+        Event {
+            id: self.id,
+            action: self
+                .payload
+                .get("action")
+                .and_then(|a| a.as_str())
+                .map(|a| a.to_string()),
+            event_type: self.event_type,
+            actor: self.actor.login,
+            repo: self.repo.name,
+            public: self.public,
+            created_at: self.created_at,
+            payload: include_payload.then_some(self.payload),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_type_name() {
+        assert_eq!(event_type_name("push"), "PushEvent");
+        assert_eq!(
+            event_type_name("pull_request_review"),
+            "PullRequestReviewEvent"
+        );
+        assert_eq!(event_type_name("IssueComment"), "IssueCommentEvent");
+        assert_eq!(event_type_name("WatchEvent"), "WatchEvent");
+    }
+
+    #[test]
+    fn test_event_from_raw() {
+        let raw = || -> EventRaw {
+            serde_json::from_value(serde_json::json!({
+                "id": "1",
+                "type": "IssuesEvent",
+                "actor": {"login": "octocat"},
+                "repo": {"name": "octocat/Hello-World"},
+                "public": true,
+                "created_at": "2024-01-15T00:00:00Z",
+                "payload": {"action": "opened", "issue": {"number": 1}}
+            }))
+            .unwrap()
+        };
+
+        let event = raw().into_model(false);
+        assert_eq!(event.action.as_deref(), Some("opened"));
+        assert_eq!(event.actor, "octocat");
+        assert!(event.payload.is_none());
+
+        let event = raw().into_model(true);
+        assert_eq!(event.payload.unwrap()["issue"]["number"], 1);
+    }
+}
@@ -0,0 +1,157 @@
+//! Gist endpoints.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Initial implementation with gist listing, retrieval, and writes
+
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use super::GitHubClient;
+use crate::models::{Gist, GistFile};
+
+impl GitHubClient {
+    /// List the authenticated user's gists (file metadata only).
+    pub async fn list_gists(&self, limit: i32, page: i32) -> Result<Vec<Gist>> {
+        let gists: Vec<GistRaw> = self
+            .rest_get(&format!("/gists?per_page={}&page={}", limit, page))
+            .await?;
+
+        Ok(gists.into_iter().map(Into::into).collect())
+    }
+
+    /// Get a single gist including file contents.
+    pub async fn get_gist(&self, id: &str) -> Result<Gist> {
+        let gist: GistRaw = self.rest_get(&format!("/gists/{}", id)).await?;
+        Ok(gist.into())
+    }
+
+    /// Create a gist from a REST `files` object (`{"name": {"content": ...}}`).
+    pub async fn create_gist(
+        &self,
+        files: &Value,
+        description: Option<&str>,
+        public: bool,
+    ) -> Result<Gist> {
+        let mut body = serde_json::json!({
+            "files": files,
+            "public": public,
+        });
+        if let Some(description) = description {
+            body["description"] = serde_json::json!(description);
+        }
+
+        let gist: GistRaw = self.rest_post("/gists", &body).await?;
+        Ok(gist.into())
+    }
+
+    /// Update a gist. A `null` file entry deletes that file.
+    pub async fn update_gist(
+        &self,
+        id: &str,
+        files: Option<&Value>,
+        description: Option<&str>,
+    ) -> Result<Gist> {
+        let mut body = serde_json::json!({});
+        if let Some(files) = files {
+            body["files"] = files.clone();
+        }
+        if let Some(description) = description {
+            body["description"] = serde_json::json!(description);
+        }
+
+        let gist: GistRaw = self.rest_patch(&format!("/gists/{}", id), &body).await?;
+        Ok(gist.into())
+    }
+}
+
+/// Raw gist from REST API.
+#[derive(Deserialize)]
+struct GistRaw {
+    id: String,
+    description: Option<String>,
+    public: bool,
+    html_url: String,
+    owner: Option<GistOwner>,
+    files: BTreeMap<String, GistFileRaw>,
+    comments: i32,
+    created_at: String,
+    updated_at: String,
+}
+
+#[derive(Deserialize)]
+struct GistOwner {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct GistFileRaw {
+    filename: String,
+    language: Option<String>,
+    size: u64,
+    raw_url: String,
+    content: Option<String>,
+    #[serde(default)]
+    truncated: bool,
+}
+
+impl From<GistRaw> for Gist {
+    fn from(g: GistRaw) -> Self {
+        Gist {
+            id: g.id,
+            description: g.description,
+            is_public: g.public,
+            url: g.html_url,
+            owner: g.owner.map(|o| o.login),
+            files: g
+                .files
+                .into_values()
+                .map(|f| GistFile {
+                    filename: f.filename,
+                    language: f.language,
+                    size: f.size,
+                    raw_url: f.raw_url,
+                    content: f.content,
+                    truncated: f.truncated,
+                })
+                .collect(),
+            comment_count: g.comments,
+            created_at: g.created_at,
+            updated_at: g.updated_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gist_from_raw() {
+        let raw: GistRaw = serde_json::from_value(serde_json::json!({
+            "id": "aa5a315d61ae9438b18d",
+            "description": "Hello World Examples",
+            "public": false,
+            "html_url": "https://gist.github.com/aa5a315d61ae9438b18d",
+            "owner": {"login": "octocat"},
+            "files": {
+                "b.rb": {"filename": "b.rb", "language": "Ruby", "size": 12, "raw_url": "https://gist.githubusercontent.com/b.rb"},
+                "a.md": {"filename": "a.md", "language": "Markdown", "size": 20, "raw_url": "https://gist.githubusercontent.com/a.md", "content": "# Hi", "truncated": true}
+            },
+            "comments": 2,
+            "created_at": "2024-01-15T00:00:00Z",
+            "updated_at": "2024-01-16T00:00:00Z"
+        }))
+        .unwrap();
+
+        let gist: Gist = raw.into();
+        assert!(!gist.is_public);
+        assert_eq!(gist.owner.as_deref(), Some("octocat"));
+        assert_eq!(gist.comment_count, 2);
+        // Files come out in name order
+        assert_eq!(gist.files[0].filename, "a.md");
+        assert!(gist.files[0].truncated);
+        assert!(!gist.files[1].truncated && gist.files[1].content.is_none());
+    }
+}
@@ -0,0 +1,604 @@
+//! Git database endpoints (refs, blobs, trees, commits) and branch status.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Added branch ahead/behind status
+//! 10/16/2026 - Added blob, tree, commit, and ref writes
+//! 10/16/2026 - Initial implementation with ref listing and resolution
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::GitHubClient;
+use crate::models::{BranchPr, BranchStatus, GitCommit, GitRef, NewTreeEntry, ResolvedRef};
+
+impl GitHubClient {
+    /// List refs whose name starts with `prefix` (e.g. `heads/`, `tags/v1.`).
+    pub async fn list_refs(
+        &self,
+        owner: &str,
+        repo: &str,
+        prefix: &str,
+        limit: usize,
+    ) -> Result<Vec<GitRef>> {
+        let prefix = prefix.trim_start_matches("refs/").trim_start_matches('/');
+        let refs: Vec<RefRaw> = self
+            .rest_get_paged(
+                &format!("/repos/{}/{}/git/matching-refs/{}", owner, repo, prefix),
+                limit,
+            )
+            .await?;
+
+        Ok(refs.into_iter().map(Into::into).collect())
+    }
+
+    /// Resolve a branch, tag, or commit expression to a commit SHA.
+    ///
+    /// Tags take precedence over branches of the same name, as in git;
+    /// annotated tags are peeled to the commit they point at.
+    pub async fn resolve_ref(&self, owner: &str, repo: &str, name: &str) -> Result<ResolvedRef> {
+        let query = r#"
+            query($owner: String!, $name: String!, $branch: String!, $tag: String!,
+                  $expression: String!) {
+                repository(owner: $owner, name: $name) {
+                    tag: ref(qualifiedName: $tag) {
+                        target { ...Peeled }
+                    }
+                    branch: ref(qualifiedName: $branch) {
+                        target { ...Peeled }
+                    }
+                    object(expression: $expression) { ...Peeled }
+                }
+            }
+
+            fragment Peeled on GitObject {
+                __typename
+                oid
+                ... on Tag {
+                    target { oid }
+                }
+            }
+        "#;
+
+        let short = name
+            .trim_start_matches("refs/heads/")
+            .trim_start_matches("refs/tags/");
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "tag": format!("refs/tags/{}", short),
+            "branch": format!("refs/heads/{}", short),
+            "expression": name,
+        });
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: Option<RepoData>,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            tag: Option<RefNode>,
+            branch: Option<RefNode>,
+            object: Option<ObjectNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct RefNode {
+            target: ObjectNode,
+        }
+
+        #[derive(Deserialize)]
+        struct ObjectNode {
+            #[serde(rename = "__typename")]
+            typename: String,
+            oid: String,
+            target: Option<OidNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct OidNode {
+            oid: String,
+        }
+
+        impl ObjectNode {
+            fn peeled(self) -> String {
+                self.target.map(|t| t.oid).unwrap_or(self.oid)
+            }
+        }
+
+        let response: RepoResponse = self.graphql(query, Some(variables)).await?;
+        let data = response.repository.context("Repository not found")?;
+
+        let (kind, qualified, sha) = if let Some(tag) = data.tag {
+            (
+                "tag",
+                Some(format!("refs/tags/{}", short)),
+                tag.target.peeled(),
+            )
+        } else if let Some(branch) = data.branch {
+            (
+                "branch",
+                Some(format!("refs/heads/{}", short)),
+                branch.target.peeled(),
+            )
+        } else if let Some(object) = data.object {
+            if object.typename != "Commit" && object.typename != "Tag" {
+                anyhow::bail!("'{}' is a {}, not a commit", name, object.typename);
+            }
+            ("commit", None, object.peeled())
+        } else {
+            anyhow::bail!("Ref '{}' not found", name);
+        };
+
+        Ok(ResolvedRef {
+            input: name.to_string(),
+            kind: kind.to_string(),
+            qualified_name: qualified,
+            sha,
+        })
+    }
+
+    /// Store `content` as a blob. `encoding` is `utf-8` or `base64`.
+    pub async fn create_blob(
+        &self,
+        owner: &str,
+        repo: &str,
+        content: &str,
+        encoding: &str,
+    ) -> Result<String> {
+        let created: ShaRaw = self
+            .rest_post(
+                &format!("/repos/{}/{}/git/blobs", owner, repo),
+                &serde_json::json!({
+                    "content": content,
+                    "encoding": encoding,
+                }),
+            )
+            .await?;
+        Ok(created.sha)
+    }
+
+    /// Create a tree, optionally layered over `base_tree`, and return its SHA.
+    pub async fn create_tree(
+        &self,
+        owner: &str,
+        repo: &str,
+        base_tree: Option<&str>,
+        entries: &[NewTreeEntry],
+    ) -> Result<String> {
+        let tree: Vec<Value> = entries
+            .iter()
+            .map(|e| {
+                let mode = if e.mode.is_empty() { "100644" } else { &e.mode };
+                let entry_type = match (e.entry_type.as_str(), mode) {
+                    ("", "040000") => "tree",
+                    ("", "160000") => "commit",
+                    ("", _) => "blob",
+                    (t, _) => t,
+                };
+                let mut entry = serde_json::json!({
+                    "path": e.path,
+                    "mode": mode,
+                    "type": entry_type,
+                });
+                match (&e.content, &e.sha) {
+                    (Some(content), _) => entry["content"] = serde_json::json!(content),
+                    // A null SHA deletes the path
+                    (None, sha) => entry["sha"] = serde_json::json!(sha),
+                }
+                entry
+            })
+            .collect();
+
+        let mut body = serde_json::json!({ "tree": tree });
+        if let Some(base) = base_tree {
+            body["base_tree"] = serde_json::json!(base);
+        }
+
+        let created: ShaRaw = self
+            .rest_post(&format!("/repos/{}/{}/git/trees", owner, repo), &body)
+            .await?;
+        Ok(created.sha)
+    }
+
+    /// Create a commit object for `tree` on top of `parents`.
+    ///
+    /// The commit is not on any branch until a ref is moved to it.
+    pub async fn create_git_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        message: &str,
+        tree: &str,
+        parents: &[String],
+    ) -> Result<GitCommit> {
+        #[derive(Deserialize)]
+        struct CommitRaw {
+            sha: String,
+            message: String,
+            tree: ShaRaw,
+            parents: Vec<ShaRaw>,
+            html_url: String,
+        }
+
+        let commit: CommitRaw = self
+            .rest_post(
+                &format!("/repos/{}/{}/git/commits", owner, repo),
+                &serde_json::json!({
+                    "message": message,
+                    "tree": tree,
+                    "parents": parents,
+                }),
+            )
+            .await?;
+
+        Ok(GitCommit {
+            sha: commit.sha,
+            message: commit.message,
+            tree: commit.tree.sha,
+            parents: commit.parents.into_iter().map(|p| p.sha).collect(),
+            url: commit.html_url,
+        })
+    }
+
+    /// Point `name` (e.g. `heads/feature`) at `sha`.
+    ///
+    /// Non-fast-forward moves need `force`; with `create` the ref is created
+    /// instead of updated.
+    pub async fn update_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        sha: &str,
+        force: bool,
+        create: bool,
+    ) -> Result<GitRef> {
+        let name = name.trim_start_matches("refs/");
+        let raw: RefRaw = if create {
+            self.rest_post(
+                &format!("/repos/{}/{}/git/refs", owner, repo),
+                &serde_json::json!({
+                    "ref": format!("refs/{}", name),
+                    "sha": sha,
+                }),
+            )
+            .await?
+        } else {
+            self.rest_patch(
+                &format!("/repos/{}/{}/git/refs/{}", owner, repo, name),
+                &serde_json::json!({
+                    "sha": sha,
+                    "force": force,
+                }),
+            )
+            .await?
+        };
+
+        Ok(raw.into())
+    }
+
+    /// Ahead/behind status of `branch` against `base` (default branch when
+    /// `None`), with its last commit and open pull request.
+    pub async fn get_branch_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        base: Option<&str>,
+    ) -> Result<BranchStatus> {
+        let query = format!(
+            r#"
+            query($owner: String!, $name: String!, $branch: String!, $base: String!) {{
+                repository(owner: $owner, name: $name) {{
+                    ref(qualifiedName: $branch) {{
+                        {}
+                    }}
+                }}
+            }}
+        "#,
+            BRANCH_FIELDS
+        );
+
+        let base = match base {
+            Some(b) => b.to_string(),
+            None => self.get_default_branch(owner, repo).await?,
+        };
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "branch": format!("refs/heads/{}", branch.trim_start_matches("refs/heads/")),
+            "base": base,
+        });
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: Option<RepoData>,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            #[serde(rename = "ref")]
+            branch: Option<BranchNode>,
+        }
+
+        let response: RepoResponse = self.graphql(&query, Some(variables)).await?;
+        let node = response
+            .repository
+            .context("Repository not found")?
+            .branch
+            .with_context(|| format!("Branch '{}' not found", branch))?;
+
+        Ok(node.into_status(&base))
+    }
+
+    /// Ahead/behind status for every branch against `base` (default branch
+    /// when `None`).
+    ///
+    /// Returns the statuses and a cursor for the next page, if any.
+    pub async fn list_branch_statuses(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: Option<&str>,
+        limit: i32,
+        after: Option<&str>,
+    ) -> Result<(Vec<BranchStatus>, Option<String>)> {
+        let query = format!(
+            r#"
+            query($owner: String!, $name: String!, $base: String!, $first: Int!,
+                  $after: String) {{
+                repository(owner: $owner, name: $name) {{
+                    refs(refPrefix: "refs/heads/", first: $first, after: $after) {{
+                        pageInfo {{
+                            hasNextPage
+                            endCursor
+                        }}
+                        nodes {{
+                            {}
+                        }}
+                    }}
+                }}
+            }}
+        "#,
+            BRANCH_FIELDS
+        );
+
+        let base = match base {
+            Some(b) => b.to_string(),
+            None => self.get_default_branch(owner, repo).await?,
+        };
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "base": base,
+            "first": limit.clamp(1, 100),
+            "after": after,
+        });
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: Option<RepoData>,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            refs: RefConnection,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RefConnection {
+            page_info: PageInfo,
+            nodes: Vec<BranchNode>,
+        }
+
+        let response: RepoResponse = self.graphql(&query, Some(variables)).await?;
+        let refs = response.repository.context("Repository not found")?.refs;
+
+        Ok((
+            refs.nodes
+                .into_iter()
+                .filter(|n| n.name != base)
+                .map(|n| n.into_status(&base))
+                .collect(),
+            refs.page_info.next_cursor(),
+        ))
+    }
+}
+
+/// Fields selected on a branch `Ref`; `$base` must be declared.
+const BRANCH_FIELDS: &str = r#"
+    name
+    target {
+        ... on Commit {
+            oid
+            committedDate
+            author {
+                name
+                user { login }
+            }
+        }
+    }
+    compare(headRef: $base) {
+        aheadBy
+        behindBy
+        status
+    }
+    associatedPullRequests(states: OPEN, first: 1, orderBy: {field: UPDATED_AT, direction: DESC}) {
+        nodes {
+            number
+            title
+            isDraft
+            url
+        }
+    }
+"#;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+impl PageInfo {
+    fn next_cursor(self) -> Option<String> {
+        self.end_cursor.filter(|_| self.has_next_page)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BranchNode {
+    name: String,
+    target: Option<BranchTarget>,
+    compare: Option<CompareNode>,
+    associated_pull_requests: PrConnection,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BranchTarget {
+    oid: Option<String>,
+    committed_date: Option<String>,
+    author: Option<AuthorNode>,
+}
+
+#[derive(Deserialize)]
+struct AuthorNode {
+    name: Option<String>,
+    user: Option<LoginNode>,
+}
+
+#[derive(Deserialize)]
+struct LoginNode {
+    login: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CompareNode {
+    ahead_by: u32,
+    behind_by: u32,
+    status: String,
+}
+
+#[derive(Deserialize)]
+struct PrConnection {
+    nodes: Vec<PrNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PrNode {
+    number: i32,
+    title: String,
+    is_draft: bool,
+    url: String,
+}
+
+impl BranchNode {
+    /// `compare` runs from the branch towards `base`, so its counts and
+    /// status are flipped to read from the branch's side.
+    fn into_status(self, base: &str) -> BranchStatus {
+        let (ahead_by, behind_by, status) = match self.compare {
+            Some(c) => {
+                let status = match c.status.as_str() {
+                    "AHEAD" => "behind",
+                    "BEHIND" => "ahead",
+                    "DIVERGED" => "diverged",
+                    _ => "identical",
+                };
+                (c.behind_by, c.ahead_by, status.to_string())
+            }
+            None => (0, 0, "unknown".to_string()),
+        };
+        let target = self.target;
+
+        BranchStatus {
+            name: self.name,
+            base: base.to_string(),
+            ahead_by,
+            behind_by,
+            status,
+            last_commit_sha: target.as_ref().and_then(|t| t.oid.clone()),
+            last_commit_date: target.as_ref().and_then(|t| t.committed_date.clone()),
+            last_author: target
+                .and_then(|t| t.author)
+                .and_then(|a| a.user.map(|u| u.login).or(a.name)),
+            pull_request: self
+                .associated_pull_requests
+                .nodes
+                .into_iter()
+                .next()
+                .map(|pr| BranchPr {
+                    number: pr.number,
+                    title: pr.title,
+                    is_draft: pr.is_draft,
+                    url: pr.url,
+                }),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ShaRaw {
+    sha: String,
+}
+
+/// Raw ref from the REST git refs endpoints.
+#[derive(Deserialize)]
+struct RefRaw {
+    #[serde(rename = "ref")]
+    name: String,
+    object: ObjectRaw,
+}
+
+#[derive(Deserialize)]
+struct ObjectRaw {
+    sha: String,
+    #[serde(rename = "type")]
+    object_type: String,
+}
+
+impl From<RefRaw> for GitRef {
+    fn from(r: RefRaw) -> Self {
+        GitRef {
+            name: r.name,
+            sha: r.object.sha,
+            object_type: r.object.object_type,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_branch_status_reads_from_branch_side() {
+        let node: BranchNode = serde_json::from_value(serde_json::json!({
+            "name": "feature/login",
+            "target": {
+                "oid": "abc123",
+                "committedDate": "2026-09-01T10:00:00Z",
+                "author": {"name": "Mona", "user": null}
+            },
+            "compare": {"aheadBy": 12, "behindBy": 3, "status": "DIVERGED"},
+            "associatedPullRequests": {"nodes": [
+                {"number": 42, "title": "Add login", "isDraft": true, "url": "https://github.com/o/r/pull/42"}
+            ]}
+        }))
+        .unwrap();
+
+        let status = node.into_status("main");
+        // main is 12 ahead of the branch, so the branch is 12 behind
+        assert_eq!(status.behind_by, 12);
+        assert_eq!(status.ahead_by, 3);
+        assert_eq!(status.status, "diverged");
+        assert_eq!(status.last_author.as_deref(), Some("Mona"));
+        assert_eq!(status.pull_request.unwrap().number, 42);
+    }
+}
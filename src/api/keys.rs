@@ -0,0 +1,141 @@
+//! SSH and GPG key endpoints for the authenticated user.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Initial implementation with SSH and GPG key management
+
+use anyhow::Result;
+use serde::Deserialize;
+
+use super::GitHubClient;
+use crate::models::{GpgKey, SshKey};
+
+impl GitHubClient {
+    /// List the viewer's SSH keys.
+    pub async fn list_ssh_keys(&self, limit: usize) -> Result<Vec<SshKey>> {
+        let keys: Vec<SshKeyRaw> = self.rest_get_paged("/user/keys", limit).await?;
+        Ok(keys.into_iter().map(Into::into).collect())
+    }
+
+    /// Register an SSH public key for the viewer.
+    pub async fn add_ssh_key(&self, title: &str, key: &str) -> Result<SshKey> {
+        let body = serde_json::json!({ "title": title, "key": key });
+        let key: SshKeyRaw = self.rest_post("/user/keys", &body).await?;
+        Ok(key.into())
+    }
+
+    /// Delete one of the viewer's SSH keys.
+    pub async fn delete_ssh_key(&self, id: u64) -> Result<()> {
+        self.rest_delete(&format!("/user/keys/{}", id)).await
+    }
+
+    /// List the viewer's GPG keys.
+    pub async fn list_gpg_keys(&self, limit: usize) -> Result<Vec<GpgKey>> {
+        let keys: Vec<GpgKeyRaw> = self.rest_get_paged("/user/gpg_keys", limit).await?;
+        Ok(keys.into_iter().map(Into::into).collect())
+    }
+
+    /// Register an ASCII-armored GPG public key for the viewer.
+    pub async fn add_gpg_key(
+        &self,
+        armored_public_key: &str,
+        name: Option<&str>,
+    ) -> Result<GpgKey> {
+        let mut body = serde_json::json!({ "armored_public_key": armored_public_key });
+        if let Some(name) = name {
+            body["name"] = serde_json::json!(name);
+        }
+        let key: GpgKeyRaw = self.rest_post("/user/gpg_keys", &body).await?;
+        Ok(key.into())
+    }
+
+    /// Delete one of the viewer's GPG keys.
+    pub async fn delete_gpg_key(&self, id: u64) -> Result<()> {
+        self.rest_delete(&format!("/user/gpg_keys/{}", id)).await
+    }
+}
+
+/// Raw SSH key from REST API.
+#[derive(Deserialize)]
+struct SshKeyRaw {
+    id: u64,
+    title: Option<String>,
+    key: String,
+    created_at: Option<String>,
+    #[serde(default)]
+    verified: bool,
+    #[serde(default)]
+    read_only: bool,
+}
+
+impl From<SshKeyRaw> for SshKey {
+    fn from(k: SshKeyRaw) -> Self {
+        SshKey {
+            id: k.id,
+            title: k.title,
+            key: k.key,
+            created_at: k.created_at,
+            verified: k.verified,
+            read_only: k.read_only,
+        }
+    }
+}
+
+/// Raw GPG key from REST API.
+#[derive(Deserialize)]
+struct GpgKeyRaw {
+    id: u64,
+    name: Option<String>,
+    key_id: String,
+    #[serde(default)]
+    emails: Vec<GpgEmailRaw>,
+    #[serde(default)]
+    can_sign: bool,
+    created_at: Option<String>,
+    expires_at: Option<String>,
+    #[serde(default)]
+    revoked: bool,
+}
+
+#[derive(Deserialize)]
+struct GpgEmailRaw {
+    email: String,
+}
+
+impl From<GpgKeyRaw> for GpgKey {
+    fn from(k: GpgKeyRaw) -> Self {
+        GpgKey {
+            id: k.id,
+            name: k.name,
+            key_id: k.key_id,
+            emails: k.emails.into_iter().map(|e| e.email).collect(),
+            can_sign: k.can_sign,
+            created_at: k.created_at,
+            expires_at: k.expires_at,
+            revoked: k.revoked,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gpg_key_from_raw() {
+        let raw: GpgKeyRaw = serde_json::from_value(serde_json::json!({
+            "id": 3,
+            "name": "Work key",
+            "key_id": "3262EFF25BA0D270",
+            "emails": [{"email": "mona@example.com", "verified": true}],
+            "can_sign": true,
+            "created_at": "2024-01-15T00:00:00Z",
+            "expires_at": null
+        }))
+        .unwrap();
+
+        let key: GpgKey = raw.into();
+        assert_eq!(key.emails, vec!["mona@example.com"]);
+        assert!(key.can_sign);
+        assert!(!key.revoked);
+    }
+}
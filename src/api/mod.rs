@@ -1,5 +1,36 @@
 //! GitHub API client module.
 
+mod actions;
+mod app;
+mod auth;
+mod changelog;
 mod client;
+mod commits;
+mod contents;
+mod dependabot;
+mod dependencies;
+mod discussions;
+mod etag;
+mod events;
+mod gists;
+mod git;
+mod keys;
+mod notifications;
+mod orgs;
+mod projects;
+mod rate_limit;
+mod releases;
+mod search;
+mod security;
+mod sso;
+mod stats;
+mod throttle;
+mod users;
 
-pub use client::GitHubClient;
+pub use auth::{expires_soon, parse_expiration, EXPIRY_WARNING_DAYS};
+pub use client::{is_unauthorized, is_unreachable, GitHubClient};
+pub use commits::CommitFilter;
+pub use events::EventFeed;
+pub use notifications::NotificationPoll;
+pub use projects::STATUS_FIELD;
+pub use security::{AlertFilter, AlertScope};
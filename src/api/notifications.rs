@@ -0,0 +1,530 @@
+//! Notification endpoints.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Poll every page of unread notifications
+//! 10/16/2026 - Count notification pages in units of limit, after filtering
+//! 10/16/2026 - Page until limit reason/repo matches; per-repo endpoint for exact repos
+//! 10/16/2026 - Added grouped notification digest
+//! 10/16/2026 - Added repository watch settings
+
+use anyhow::{Context, Result};
+use futures::stream::{self, StreamExt};
+use reqwest::Method;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashSet};
+
+use super::client::{encode_query, ensure_success, link_rel};
+use super::GitHubClient;
+use crate::models::{
+    Notification, NotificationDigest, NotificationFilter, NotificationGroup, RepoSubscription,
+    SubjectDetail, ThreadSubscription,
+};
+
+/// Maximum concurrent subject lookups when hydrating notifications.
+const HYDRATE_CONCURRENCY: usize = 8;
+
+/// Poll interval used when GitHub does not send `X-Poll-Interval`.
+const DEFAULT_POLL_INTERVAL_SECS: u64 = 60;
+
+/// Result of a conditional notifications poll.
+#[derive(Debug)]
+pub struct NotificationPoll {
+    /// Current unread notifications, or `None` if unchanged since the last poll.
+    pub notifications: Option<Vec<Notification>>,
+    /// `Last-Modified` value to send as `If-Modified-Since` next time.
+    pub last_modified: Option<String>,
+    /// Minimum seconds to wait before polling again (`X-Poll-Interval`).
+    pub poll_interval_secs: u64,
+}
+
+impl GitHubClient {
+    /// Get notifications, unread only unless `filter.all` is set.
+    ///
+    /// Returns page `page` of the notifications matching `filter`, in units
+    /// of `limit`. Without reason/repo filters this maps straight onto
+    /// GitHub's pages; with them, matches are counted from the newest
+    /// notification, paging until `page * limit` have been seen or the pages
+    /// run out. An exact `owner/repo` filter uses that repository's endpoint.
+    pub async fn get_notifications(
+        &self,
+        filter: &NotificationFilter,
+        limit: usize,
+        page: u32,
+    ) -> Result<Vec<Notification>> {
+        let mut query = vec![
+            format!("all={}", filter.all),
+            format!("participating={}", filter.participating),
+        ];
+        if let Some(since) = &filter.since {
+            query.push(format!("since={}", encode_query(since)));
+        }
+        if let Some(before) = &filter.before {
+            query.push(format!("before={}", encode_query(before)));
+        }
+
+        let path = match filter.repo.as_deref() {
+            Some(repo) if !repo.ends_with("/*") && repo.contains('/') => {
+                format!("/repos/{}/notifications", repo)
+            }
+            _ => "/notifications".to_string(),
+        };
+        let skip = (page.max(1) as usize - 1) * limit;
+
+        if !filter.reasons.is_empty() || filter.repo.is_some() {
+            // Client-side filters can reject most of a page, so fetch full
+            // pages and count matches from the start
+            let mut matches = self
+                .rest_get_paged_filter_map(
+                    &format!("{}?{}", path, query.join("&")),
+                    100,
+                    skip + limit,
+                    |raw: NotificationRaw| Some(raw.into_model()).filter(|n| filter.matches(n)),
+                )
+                .await?;
+            return Ok(matches.split_off(skip.min(matches.len())));
+        }
+
+        let per_page = limit.clamp(1, 100);
+        query.push(format!("page={}", skip / per_page + 1));
+        let offset = skip % per_page;
+        let mut notifications = self
+            .rest_get_paged_filter_map(
+                &format!("{}?{}", path, query.join("&")),
+                per_page,
+                offset + limit,
+                |raw: NotificationRaw| Some(raw.into_model()),
+            )
+            .await?;
+        Ok(notifications.split_off(offset.min(notifications.len())))
+    }
+
+    /// Summarize up to `limit` unread notifications by repository and reason,
+    /// keeping the `top` most recent items per group.
+    pub async fn get_notification_digest(
+        &self,
+        limit: usize,
+        top: usize,
+    ) -> Result<NotificationDigest> {
+        let notifications = self
+            .get_notifications(&NotificationFilter::default(), limit, 1)
+            .await?;
+        Ok(digest_notifications(notifications, top))
+    }
+
+    /// Conditionally fetch all unread notifications.
+    ///
+    /// Sends `If-Modified-Since` when `last_modified` is given; a 304 reply
+    /// does not count against the rate limit and yields `notifications: None`.
+    /// Otherwise every page is read, following the `Link` header, so a burst
+    /// of new threads is never cut off at the first page.
+    pub async fn poll_notifications(
+        &self,
+        last_modified: Option<&str>,
+    ) -> Result<NotificationPoll> {
+        let url = format!("{}/notifications?per_page=100", self.endpoints.rest);
+        let mut request = self.request(Method::GET, &url);
+        if let Some(last_modified) = last_modified {
+            request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+        }
+
+        let response = self
+            .send(request, false)
+            .await
+            .context("Failed to send REST request")?;
+
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string())
+        };
+        let poll_interval_secs = header("x-poll-interval")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_POLL_INTERVAL_SECS);
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            return Ok(NotificationPoll {
+                notifications: None,
+                last_modified: last_modified.map(|v| v.to_string()),
+                poll_interval_secs,
+            });
+        }
+
+        let new_last_modified = header("last-modified");
+        let mut next = header("link").and_then(|v| link_rel(&v, "next"));
+        let mut raw: Vec<NotificationRaw> = ensure_success(response)
+            .await?
+            .json()
+            .await
+            .context("Failed to parse JSON")?;
+
+        while let Some(url) = next {
+            let (body, link) = self.get_conditional(&url).await?;
+            let page: Vec<NotificationRaw> =
+                serde_json::from_str(&body).context("Failed to parse JSON")?;
+            if page.is_empty() {
+                break;
+            }
+            raw.extend(page);
+            next = link.as_deref().and_then(|v| link_rel(v, "next"));
+        }
+
+        Ok(NotificationPoll {
+            notifications: Some(raw.into_iter().map(NotificationRaw::into_model).collect()),
+            last_modified: new_last_modified,
+            poll_interval_secs,
+        })
+    }
+
+    /// Resolve each notification's API subject URL into its web URL, number,
+    /// and state. Lookups run concurrently; subjects that fail to resolve
+    /// (deleted, inaccessible, or without a URL) are left unhydrated.
+    pub async fn hydrate_notifications(&self, notifications: &mut [Notification]) {
+        let details: Vec<Option<SubjectDetail>> = stream::iter(notifications.iter())
+            .map(|n| async move {
+                let url = n.subject_url.as_deref()?;
+                self.get_subject_detail(url).await.ok()
+            })
+            .buffered(HYDRATE_CONCURRENCY)
+            .collect()
+            .await;
+
+        for (notification, detail) in notifications.iter_mut().zip(details) {
+            notification.subject_detail = detail;
+        }
+    }
+
+    /// Fetch a notification subject by its API URL.
+    async fn get_subject_detail(&self, url: &str) -> Result<SubjectDetail> {
+        let response = self
+            .send(self.request(Method::GET, url), false)
+            .await
+            .context("Failed to send REST request")?;
+        let raw: SubjectRaw = ensure_success(response)
+            .await?
+            .json()
+            .await
+            .context("Failed to parse JSON")?;
+
+        let state = if raw.merged == Some(true) {
+            Some("merged".to_string())
+        } else if let Some(draft) = raw.draft.filter(|_| raw.tag_name.is_some()) {
+            Some(if draft { "draft" } else { "published" }.to_string())
+        } else {
+            raw.state
+        };
+
+        Ok(SubjectDetail {
+            html_url: raw.html_url,
+            number: raw.number,
+            state,
+        })
+    }
+
+    /// Get the viewer's subscription to a notification thread.
+    pub async fn get_thread_subscription(&self, thread_id: &str) -> Result<ThreadSubscription> {
+        let raw: SubscriptionRaw = self
+            .rest_get(&format!(
+                "/notifications/threads/{}/subscription",
+                thread_id
+            ))
+            .await?;
+        Ok(raw.into_model(thread_id))
+    }
+
+    /// Subscribe to a thread, or ignore (mute) it when `ignored` is true.
+    pub async fn set_thread_subscription(
+        &self,
+        thread_id: &str,
+        ignored: bool,
+    ) -> Result<ThreadSubscription> {
+        let raw: SubscriptionRaw = self
+            .rest_put_json(
+                &format!("/notifications/threads/{}/subscription", thread_id),
+                &serde_json::json!({ "ignored": ignored }),
+            )
+            .await?;
+        Ok(raw.into_model(thread_id))
+    }
+
+    /// Remove the thread subscription; notifications resume only when the
+    /// viewer is mentioned or participates again.
+    pub async fn delete_thread_subscription(&self, thread_id: &str) -> Result<()> {
+        self.rest_delete(&format!(
+            "/notifications/threads/{}/subscription",
+            thread_id
+        ))
+        .await
+    }
+
+    /// Get the viewer's watch setting for a repository.
+    pub async fn get_repo_subscription(&self, owner: &str, repo: &str) -> Result<RepoSubscription> {
+        let url = format!(
+            "{}/repos/{}/{}/subscription",
+            self.endpoints.rest, owner, repo
+        );
+        let response = self
+            .send(self.request(Method::GET, &url), false)
+            .await
+            .context("Failed to send REST request")?;
+
+        // 404 means the viewer is not watching: participating and @mentions only.
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(RepoSubscription {
+                repo: format!("{}/{}", owner, repo),
+                mode: "participating".to_string(),
+                subscribed: false,
+                ignored: false,
+                created_at: None,
+            });
+        }
+
+        let raw: SubscriptionRaw = ensure_success(response)
+            .await?
+            .json()
+            .await
+            .context("Failed to parse JSON")?;
+        Ok(raw.into_repo_model(owner, repo))
+    }
+
+    /// Watch a repository (all activity), or ignore it when `ignored` is true.
+    pub async fn set_repo_subscription(
+        &self,
+        owner: &str,
+        repo: &str,
+        ignored: bool,
+    ) -> Result<RepoSubscription> {
+        let raw: SubscriptionRaw = self
+            .rest_put_json(
+                &format!("/repos/{}/{}/subscription", owner, repo),
+                &serde_json::json!({ "subscribed": !ignored, "ignored": ignored }),
+            )
+            .await?;
+        Ok(raw.into_repo_model(owner, repo))
+    }
+
+    /// Stop watching a repository, returning to participating-only notifications.
+    pub async fn delete_repo_subscription(&self, owner: &str, repo: &str) -> Result<()> {
+        self.rest_delete(&format!("/repos/{}/{}/subscription", owner, repo))
+            .await
+    }
+}
+
+/// Group notifications by (repo, reason), largest groups first.
+fn digest_notifications(notifications: Vec<Notification>, top: usize) -> NotificationDigest {
+    let total = notifications.len();
+    let repo_count = notifications
+        .iter()
+        .map(|n| n.repo_full_name.as_str())
+        .collect::<HashSet<_>>()
+        .len();
+
+    let mut buckets: BTreeMap<(String, String), Vec<Notification>> = BTreeMap::new();
+    for notification in notifications {
+        buckets
+            .entry((
+                notification.repo_full_name.clone(),
+                notification.reason.clone(),
+            ))
+            .or_default()
+            .push(notification);
+    }
+
+    let mut groups: Vec<NotificationGroup> = buckets
+        .into_iter()
+        .map(|((repo, reason), mut items)| {
+            // ISO 8601 timestamps sort chronologically as strings.
+            items.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+            let count = items.len();
+            items.truncate(top);
+            NotificationGroup {
+                repo,
+                reason,
+                count,
+                items,
+            }
+        })
+        .collect();
+    // Stable sort keeps repo/reason order among equal counts.
+    groups.sort_by_key(|g| std::cmp::Reverse(g.count));
+
+    NotificationDigest {
+        total,
+        repo_count,
+        groups,
+    }
+}
+
+/// Raw notification from REST API.
+#[derive(Deserialize)]
+struct NotificationRaw {
+    id: String,
+    unread: bool,
+    reason: String,
+    subject: NotificationSubject,
+    repository: NotificationRepo,
+    updated_at: String,
+}
+
+impl NotificationRaw {
+    fn into_model(self) -> Notification {
+        Notification {
+            id: self.id,
+            unread: self.unread,
+            reason: self.reason,
+            subject_title: self.subject.title,
+            subject_type: self.subject.type_field,
+            subject_url: self.subject.url,
+            repo_full_name: self.repository.full_name,
+            updated_at: self.updated_at,
+            subject_detail: None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct NotificationSubject {
+    title: String,
+    #[serde(rename = "type")]
+    type_field: String,
+    url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct NotificationRepo {
+    full_name: String,
+}
+
+/// Raw notification subject: the common fields of issues, pull requests,
+/// releases, and commits.
+#[derive(Deserialize)]
+struct SubjectRaw {
+    html_url: Option<String>,
+    number: Option<i64>,
+    state: Option<String>,
+    merged: Option<bool>,
+    draft: Option<bool>,
+    tag_name: Option<String>,
+}
+
+/// Raw thread subscription from REST API.
+#[derive(Deserialize)]
+struct SubscriptionRaw {
+    subscribed: bool,
+    ignored: bool,
+    reason: Option<String>,
+    created_at: Option<String>,
+}
+
+impl SubscriptionRaw {
+    fn into_model(self, thread_id: &str) -> ThreadSubscription {
+        ThreadSubscription {
+            thread_id: thread_id.to_string(),
+            subscribed: self.subscribed,
+            ignored: self.ignored,
+            reason: self.reason,
+            created_at: self.created_at,
+        }
+    }
+
+    fn into_repo_model(self, owner: &str, repo: &str) -> RepoSubscription {
+        let mode = if self.ignored {
+            "ignore"
+        } else if self.subscribed {
+            "all"
+        } else {
+            "participating"
+        };
+        RepoSubscription {
+            repo: format!("{}/{}", owner, repo),
+            mode: mode.to_string(),
+            subscribed: self.subscribed,
+            ignored: self.ignored,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification(repo: &str, reason: &str, updated_at: &str) -> Notification {
+        Notification {
+            id: updated_at.to_string(),
+            unread: true,
+            reason: reason.to_string(),
+            subject_title: "Title".to_string(),
+            subject_type: "PullRequest".to_string(),
+            subject_url: None,
+            repo_full_name: repo.to_string(),
+            updated_at: updated_at.to_string(),
+            subject_detail: None,
+        }
+    }
+
+    #[test]
+    fn test_digest_notifications() {
+        let digest = digest_notifications(
+            vec![
+                notification("a/one", "mention", "2024-01-10T00:00:00Z"),
+                notification("b/two", "review_requested", "2024-01-11T00:00:00Z"),
+                notification("b/two", "review_requested", "2024-01-13T00:00:00Z"),
+                notification("b/two", "review_requested", "2024-01-12T00:00:00Z"),
+            ],
+            2,
+        );
+
+        assert_eq!(digest.total, 4);
+        assert_eq!(digest.repo_count, 2);
+        assert_eq!(digest.groups.len(), 2);
+
+        let first = &digest.groups[0];
+        assert_eq!((first.repo.as_str(), first.count), ("b/two", 3));
+        assert_eq!(first.items.len(), 2);
+        assert_eq!(first.items[0].updated_at, "2024-01-13T00:00:00Z");
+    }
+
+    #[test]
+    fn test_notification_from_raw() {
+        let raw: NotificationRaw = serde_json::from_value(serde_json::json!({
+            "id": "123",
+            "unread": true,
+            "reason": "review_requested",
+            "subject": {
+                "title": "Add feature",
+                "type": "PullRequest",
+                "url": "https://api.github.com/repos/octo/repo/pulls/7"
+            },
+            "repository": {"full_name": "octo/repo"},
+            "updated_at": "2024-01-14T00:00:00Z"
+        }))
+        .unwrap();
+
+        let notification = raw.into_model();
+        assert_eq!(notification.subject_type, "PullRequest");
+        assert_eq!(notification.repo_full_name, "octo/repo");
+        assert!(notification.subject_detail.is_none());
+    }
+
+    #[test]
+    fn test_repo_subscription_mode() {
+        let mode = |subscribed: bool, ignored: bool| {
+            SubscriptionRaw {
+                subscribed,
+                ignored,
+                reason: None,
+                created_at: None,
+            }
+            .into_repo_model("octo", "repo")
+            .mode
+        };
+
+        assert_eq!(mode(true, false), "all");
+        assert_eq!(mode(false, false), "participating");
+        assert_eq!(mode(false, true), "ignore");
+        // GitHub can report both; ignoring wins
+        assert_eq!(mode(true, true), "ignore");
+    }
+}
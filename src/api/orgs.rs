@@ -0,0 +1,1091 @@
+//! Organization endpoints.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Added multi-repo dashboard
+//! 10/16/2026 - Added audit log
+//! 10/16/2026 - Added organization invitations
+//! 10/16/2026 - Added team repository permissions
+//! 10/16/2026 - Added teams and team members
+
+use anyhow::{Context, Result};
+use futures::stream::{self, StreamExt};
+use reqwest::Method;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use super::client::{encode_query, link_rel, RepoRaw};
+use super::GitHubClient;
+use crate::models::{
+    AuditLogEntry, DashboardPr, OrgDashboard, OrgInvitation, OrgMember, OrgMembership, OrgPlan,
+    RepoDashboard, Repository, Team, TeamMember, TeamRepo,
+};
+
+/// Repositories per batched dashboard query; keeps each query well under
+/// GraphQL node limits.
+const DASHBOARD_BATCH_SIZE: usize = 25;
+
+/// Maximum concurrent organization lookups when listing memberships.
+const ORG_LOOKUP_CONCURRENCY: usize = 8;
+
+impl GitHubClient {
+    /// List an organization's repositories.
+    ///
+    /// `repo_type` is `all`, `public`, `private`, `forks`, `sources`, or
+    /// `member`; `sort` is `created`, `updated`, `pushed`, or `full_name`.
+    pub async fn list_org_repos(
+        &self,
+        org: &str,
+        repo_type: &str,
+        sort: &str,
+        direction: Option<&str>,
+        limit: i32,
+        page: i32,
+    ) -> Result<Vec<Repository>> {
+        let mut path = format!(
+            "/orgs/{}/repos?type={}&sort={}&per_page={}&page={}",
+            org,
+            encode_query(repo_type),
+            encode_query(sort),
+            limit,
+            page
+        );
+        if let Some(direction) = direction {
+            path.push_str(&format!("&direction={}", encode_query(direction)));
+        }
+
+        let repos: Vec<RepoRaw> = self.rest_get(&path).await?;
+        Ok(repos.into_iter().map(Into::into).collect())
+    }
+
+    /// List organization members with their role, one page at a time.
+    /// Returns the members and the cursor for the next page.
+    pub async fn list_org_members(
+        &self,
+        org: &str,
+        first: i32,
+        after: Option<&str>,
+    ) -> Result<(Vec<OrgMember>, Option<String>)> {
+        let query = r#"
+            query($org: String!, $first: Int!, $after: String) {
+                organization(login: $org) {
+                    membersWithRole(first: $first, after: $after) {
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                        edges {
+                            role
+                            hasTwoFactorEnabled
+                            node {
+                                login
+                                name
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct OrgResponse {
+            organization: Option<OrgData>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct OrgData {
+            members_with_role: MemberConnection,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct MemberConnection {
+            page_info: PageInfo,
+            edges: Vec<MemberEdge>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct MemberEdge {
+            role: String,
+            has_two_factor_enabled: Option<bool>,
+            node: MemberNode,
+        }
+
+        #[derive(Deserialize)]
+        struct MemberNode {
+            login: String,
+            name: Option<String>,
+        }
+
+        let variables = serde_json::json!({
+            "org": org,
+            "first": first.clamp(1, 100),
+            "after": after,
+        });
+
+        let result: OrgResponse = self.graphql(query, Some(variables)).await?;
+        let connection = result
+            .organization
+            .with_context(|| format!("Organization not found: {}", org))?
+            .members_with_role;
+
+        let members = connection
+            .edges
+            .into_iter()
+            .map(|e| OrgMember {
+                login: e.node.login,
+                name: e.node.name,
+                role: e.role.to_lowercase(),
+                two_factor_enabled: e.has_two_factor_enabled,
+            })
+            .collect();
+        Ok((members, connection.page_info.next_cursor()))
+    }
+
+    /// List an organization's teams, one page at a time.
+    /// Returns the teams and the cursor for the next page.
+    pub async fn list_teams(
+        &self,
+        org: &str,
+        first: i32,
+        after: Option<&str>,
+    ) -> Result<(Vec<Team>, Option<String>)> {
+        let query = r#"
+            query($org: String!, $first: Int!, $after: String) {
+                organization(login: $org) {
+                    teams(first: $first, after: $after, orderBy: {field: NAME, direction: ASC}) {
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                        nodes {
+                            slug
+                            name
+                            description
+                            privacy
+                            url
+                            members {
+                                totalCount
+                            }
+                            parentTeam {
+                                slug
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct OrgResponse {
+            organization: Option<OrgData>,
+        }
+
+        #[derive(Deserialize)]
+        struct OrgData {
+            teams: TeamConnection,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct TeamConnection {
+            page_info: PageInfo,
+            nodes: Vec<TeamNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct TeamNode {
+            slug: String,
+            name: String,
+            description: Option<String>,
+            privacy: String,
+            url: String,
+            members: TotalCount,
+            parent_team: Option<SlugNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct SlugNode {
+            slug: String,
+        }
+
+        let variables = serde_json::json!({
+            "org": org,
+            "first": first.clamp(1, 100),
+            "after": after,
+        });
+
+        let result: OrgResponse = self.graphql(query, Some(variables)).await?;
+        let connection = result
+            .organization
+            .with_context(|| format!("Organization not found: {}", org))?
+            .teams;
+
+        let teams = connection
+            .nodes
+            .into_iter()
+            .map(|t| Team {
+                slug: t.slug,
+                name: t.name,
+                description: t.description.filter(|d| !d.is_empty()),
+                privacy: t.privacy.to_lowercase(),
+                member_count: t.members.total_count,
+                parent: t.parent_team.map(|p| p.slug),
+                url: t.url,
+            })
+            .collect();
+
+        Ok((teams, connection.page_info.next_cursor()))
+    }
+
+    /// List a team's members. Members of child teams are included unless
+    /// `immediate_only` is set.
+    pub async fn list_team_members(
+        &self,
+        org: &str,
+        team_slug: &str,
+        immediate_only: bool,
+        first: i32,
+        after: Option<&str>,
+    ) -> Result<(Vec<TeamMember>, Option<String>)> {
+        let query = r#"
+            query($org: String!, $slug: String!, $membership: TeamMembershipType!, $first: Int!, $after: String) {
+                organization(login: $org) {
+                    team(slug: $slug) {
+                        members(first: $first, after: $after, membership: $membership) {
+                            pageInfo {
+                                hasNextPage
+                                endCursor
+                            }
+                            edges {
+                                role
+                                node {
+                                    login
+                                    name
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct OrgResponse {
+            organization: Option<OrgData>,
+        }
+
+        #[derive(Deserialize)]
+        struct OrgData {
+            team: Option<TeamData>,
+        }
+
+        #[derive(Deserialize)]
+        struct TeamData {
+            members: MemberConnection,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct MemberConnection {
+            page_info: PageInfo,
+            edges: Vec<MemberEdge>,
+        }
+
+        #[derive(Deserialize)]
+        struct MemberEdge {
+            role: String,
+            node: MemberNode,
+        }
+
+        #[derive(Deserialize)]
+        struct MemberNode {
+            login: String,
+            name: Option<String>,
+        }
+
+        let variables = serde_json::json!({
+            "org": org,
+            "slug": team_slug,
+            "membership": if immediate_only { "IMMEDIATE" } else { "ALL" },
+            "first": first.clamp(1, 100),
+            "after": after,
+        });
+
+        let result: OrgResponse = self.graphql(query, Some(variables)).await?;
+        let connection = result
+            .organization
+            .with_context(|| format!("Organization not found: {}", org))?
+            .team
+            .with_context(|| format!("Team not found: {}/{}", org, team_slug))?
+            .members;
+
+        let members = connection
+            .edges
+            .into_iter()
+            .map(|e| TeamMember {
+                login: e.node.login,
+                name: e.node.name,
+                role: e.role.to_lowercase(),
+            })
+            .collect();
+
+        Ok((members, connection.page_info.next_cursor()))
+    }
+
+    /// List repositories a team can access, with its permission on each.
+    pub async fn list_team_repos(
+        &self,
+        org: &str,
+        team_slug: &str,
+        limit: i32,
+        page: i32,
+    ) -> Result<Vec<TeamRepo>> {
+        let repos: Vec<TeamRepoRaw> = self
+            .rest_get(&format!(
+                "/orgs/{}/teams/{}/repos?per_page={}&page={}",
+                org, team_slug, limit, page
+            ))
+            .await?;
+
+        Ok(repos
+            .into_iter()
+            .map(|r| TeamRepo {
+                permission: r.permission(),
+                full_name: r.full_name,
+                is_private: r.private,
+                url: r.html_url,
+            })
+            .collect())
+    }
+
+    /// Grant a team `permission` on a repository (or change its permission).
+    pub async fn add_team_repo(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+        permission: &str,
+    ) -> Result<()> {
+        self.rest_put(
+            &format!("/orgs/{}/teams/{}/repos/{}/{}", org, team_slug, owner, repo),
+            &serde_json::json!({ "permission": permission }),
+        )
+        .await
+    }
+
+    /// Remove a team's access to a repository.
+    pub async fn remove_team_repo(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<()> {
+        self.rest_delete(&format!(
+            "/orgs/{}/teams/{}/repos/{}/{}",
+            org, team_slug, owner, repo
+        ))
+        .await
+    }
+
+    /// List pending invitations to an organization.
+    pub async fn list_org_invitations(
+        &self,
+        org: &str,
+        limit: usize,
+    ) -> Result<Vec<OrgInvitation>> {
+        let invitations: Vec<InvitationRaw> = self
+            .rest_get_paged(&format!("/orgs/{}/invitations", org), limit)
+            .await?;
+
+        Ok(invitations
+            .into_iter()
+            .map(InvitationRaw::into_model)
+            .collect())
+    }
+
+    /// Invite a user (by login) or an email address to an organization.
+    pub async fn invite_to_org(
+        &self,
+        org: &str,
+        login: Option<&str>,
+        email: Option<&str>,
+        role: &str,
+        team_ids: &[u64],
+    ) -> Result<OrgInvitation> {
+        let mut body = serde_json::json!({ "role": role, "team_ids": team_ids });
+        match (login, email) {
+            (Some(login), _) => {
+                // The API takes a numeric user id, not a login.
+                let user: UserIdRaw = self.rest_get(&format!("/users/{}", login)).await?;
+                body["invitee_id"] = serde_json::json!(user.id);
+            }
+            (None, Some(email)) => body["email"] = serde_json::json!(email),
+            (None, None) => anyhow::bail!("Either login or email is required"),
+        }
+
+        let invitation: InvitationRaw = self
+            .rest_post(&format!("/orgs/{}/invitations", org), &body)
+            .await?;
+        Ok(invitation.into_model())
+    }
+
+    /// Cancel a pending organization invitation.
+    pub async fn cancel_org_invitation(&self, org: &str, invitation_id: u64) -> Result<()> {
+        self.rest_delete(&format!("/orgs/{}/invitations/{}", org, invitation_id))
+            .await
+    }
+
+    /// Read one page of an organization's audit log (GitHub Enterprise Cloud),
+    /// or an enterprise's when `enterprise` is set, optionally limited to
+    /// entries created between `since` and `until` (`YYYY-MM-DD`). Returns the
+    /// entries and the cursor for the next page.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn get_audit_log(
+        &self,
+        owner: &str,
+        enterprise: bool,
+        phrase: Option<&str>,
+        since: Option<&str>,
+        until: Option<&str>,
+        include: &str,
+        order: &str,
+        limit: i32,
+        after: Option<&str>,
+        include_raw: bool,
+    ) -> Result<(Vec<AuditLogEntry>, Option<String>)> {
+        let scope = if enterprise { "enterprises" } else { "orgs" };
+        let mut path = format!(
+            "/{}/{}/audit-log?include={}&order={}&per_page={}",
+            scope,
+            owner,
+            encode_query(include),
+            encode_query(order),
+            limit
+        );
+        if let Some(phrase) = audit_phrase(phrase, since, until) {
+            path.push_str(&format!("&phrase={}", encode_query(&phrase)));
+        }
+        if let Some(after) = after {
+            // Cursors come back from the Link header already encoded.
+            path.push_str(&format!("&after={}", after));
+        }
+
+        let response = self.rest_send(Method::GET, &path, None).await?;
+        let next_cursor = response
+            .headers()
+            .get(reqwest::header::LINK)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| link_rel(v, "next"))
+            .and_then(|url| after_param(&url));
+        let entries: Vec<serde_json::Value> =
+            response.json().await.context("Failed to parse JSON")?;
+
+        let entries = entries
+            .into_iter()
+            .map(|raw| audit_entry(raw, include_raw))
+            .collect();
+        Ok((entries, next_cursor))
+    }
+
+    /// Build a dashboard of open issues/PRs, the `oldest` open PRs, and
+    /// default-branch CI state for `repos` (`owner/name`), or for up to
+    /// `limit` of `org`'s most recently pushed non-archived repositories.
+    pub async fn get_org_dashboard(
+        &self,
+        org: Option<&str>,
+        repos: &[String],
+        limit: usize,
+        oldest: i32,
+    ) -> Result<OrgDashboard> {
+        let repos = match org {
+            Some(org) if repos.is_empty() => self.active_org_repo_names(org, limit).await?,
+            _ => repos.to_vec(),
+        };
+
+        let batches: Vec<Vec<Option<RepoDashboard>>> =
+            stream::iter(repos.chunks(DASHBOARD_BATCH_SIZE))
+                .map(|batch| self.dashboard_batch(batch, oldest))
+                .buffered(ORG_LOOKUP_CONCURRENCY)
+                .collect::<Vec<Result<_>>>()
+                .await
+                .into_iter()
+                .collect::<Result<_>>()?;
+
+        let mut dashboard = OrgDashboard {
+            org: org.map(|o| o.to_string()),
+            open_issues: 0,
+            open_prs: 0,
+            failing_ci: Vec::new(),
+            repos: Vec::new(),
+            missing: Vec::new(),
+        };
+        for (name, row) in repos.iter().zip(batches.into_iter().flatten()) {
+            let Some(row) = row else {
+                dashboard.missing.push(name.clone());
+                continue;
+            };
+            dashboard.open_issues += row.open_issues;
+            dashboard.open_prs += row.open_prs;
+            if matches!(row.ci_state.as_deref(), Some("failure" | "error")) {
+                dashboard.failing_ci.push(row.repo.clone());
+            }
+            dashboard.repos.push(row);
+        }
+
+        Ok(dashboard)
+    }
+
+    /// Names of an organization's non-archived repositories, most recently
+    /// pushed first.
+    async fn active_org_repo_names(&self, org: &str, limit: usize) -> Result<Vec<String>> {
+        let query = r#"
+            query($org: String!, $first: Int!, $after: String) {
+                organization(login: $org) {
+                    repositories(
+                        first: $first
+                        after: $after
+                        isArchived: false
+                        orderBy: { field: PUSHED_AT, direction: DESC }
+                    ) {
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                        nodes {
+                            nameWithOwner
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct Response {
+            organization: Option<Org>,
+        }
+
+        #[derive(Deserialize)]
+        struct Org {
+            repositories: Connection,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Connection {
+            page_info: PageInfo,
+            nodes: Vec<Node>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Node {
+            name_with_owner: String,
+        }
+
+        let mut names = Vec::new();
+        let mut after: Option<String> = None;
+        while names.len() < limit {
+            let variables = serde_json::json!({
+                "org": org,
+                "first": (limit - names.len()).min(100),
+                "after": after,
+            });
+            let result: Response = self.graphql(query, Some(variables)).await?;
+            let connection = result
+                .organization
+                .with_context(|| format!("Organization not found: {}", org))?
+                .repositories;
+
+            names.extend(connection.nodes.into_iter().map(|n| n.name_with_owner));
+            after = connection.page_info.next_cursor();
+            if after.is_none() {
+                break;
+            }
+        }
+
+        Ok(names)
+    }
+
+    /// Fetch dashboard rows for one batch of repositories in a single query.
+    /// Rows are `None` for repositories that do not exist or are not visible.
+    async fn dashboard_batch(
+        &self,
+        repos: &[String],
+        oldest: i32,
+    ) -> Result<Vec<Option<RepoDashboard>>> {
+        let mut declarations = vec!["$oldest: Int!".to_string()];
+        let mut selections = Vec::new();
+        let mut variables = serde_json::json!({ "oldest": oldest });
+        for (i, full_name) in repos.iter().enumerate() {
+            let (owner, name) = full_name
+                .split_once('/')
+                .with_context(|| format!("Invalid repository: {}", full_name))?;
+            declarations.push(format!("$o{i}: String!, $n{i}: String!"));
+            selections.push(format!(
+                "r{i}: repository(owner: $o{i}, name: $n{i}) {{ ...DashboardRepo }}"
+            ));
+            variables[format!("o{i}")] = serde_json::json!(owner);
+            variables[format!("n{i}")] = serde_json::json!(name);
+        }
+
+        let query = format!(
+            r#"
+            query({declarations}) {{
+                {selections}
+            }}
+
+            fragment DashboardRepo on Repository {{
+                nameWithOwner
+                url
+                issues(states: OPEN) {{
+                    totalCount
+                }}
+                pullRequests(states: OPEN) {{
+                    totalCount
+                }}
+                oldestPullRequests: pullRequests(
+                    states: OPEN
+                    first: $oldest
+                    orderBy: {{ field: CREATED_AT, direction: ASC }}
+                ) {{
+                    nodes {{
+                        number
+                        title
+                        url
+                        isDraft
+                        createdAt
+                        author {{
+                            login
+                        }}
+                    }}
+                }}
+                defaultBranchRef {{
+                    name
+                    target {{
+                        ... on Commit {{
+                            statusCheckRollup {{
+                                state
+                            }}
+                        }}
+                    }}
+                }}
+            }}
+        "#,
+            declarations = declarations.join(", "),
+            selections = selections.join("\n                "),
+        );
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoData {
+            name_with_owner: String,
+            url: String,
+            issues: TotalCount,
+            pull_requests: TotalCount,
+            oldest_pull_requests: PrNodes,
+            default_branch_ref: Option<BranchRef>,
+        }
+
+        #[derive(Deserialize)]
+        struct PrNodes {
+            nodes: Vec<PrNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct PrNode {
+            number: i32,
+            title: String,
+            url: String,
+            is_draft: bool,
+            created_at: String,
+            author: Option<Author>,
+        }
+
+        #[derive(Deserialize)]
+        struct Author {
+            login: String,
+        }
+
+        #[derive(Deserialize)]
+        struct BranchRef {
+            name: String,
+            target: Option<Target>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Target {
+            status_check_rollup: Option<Rollup>,
+        }
+
+        #[derive(Deserialize)]
+        struct Rollup {
+            state: String,
+        }
+
+        let mut result: HashMap<String, Option<RepoData>> =
+            self.graphql(&query, Some(variables)).await?;
+
+        Ok((0..repos.len())
+            .map(|i| {
+                let r = result.remove(&format!("r{i}")).flatten()?;
+                let (default_branch, ci_state) = match r.default_branch_ref {
+                    Some(b) => (
+                        Some(b.name),
+                        b.target
+                            .and_then(|t| t.status_check_rollup)
+                            .map(|s| s.state.to_lowercase()),
+                    ),
+                    None => (None, None),
+                };
+                Some(RepoDashboard {
+                    repo: r.name_with_owner,
+                    url: r.url,
+                    open_issues: r.issues.total_count,
+                    open_prs: r.pull_requests.total_count,
+                    oldest_prs: r
+                        .oldest_pull_requests
+                        .nodes
+                        .into_iter()
+                        .map(|pr| DashboardPr {
+                            number: pr.number,
+                            title: pr.title,
+                            author: pr.author.map(|a| a.login),
+                            is_draft: pr.is_draft,
+                            created_at: pr.created_at,
+                            url: pr.url,
+                        })
+                        .collect(),
+                    default_branch,
+                    ci_state,
+                })
+            })
+            .collect())
+    }
+
+    /// List the viewer's organization memberships. Plan details are filled
+    /// in where the viewer can see them (owners only).
+    pub async fn list_org_memberships(&self, state: Option<&str>) -> Result<Vec<OrgMembership>> {
+        let path = match state {
+            Some(state) => format!("/user/memberships/orgs?state={}", encode_query(state)),
+            None => "/user/memberships/orgs".to_string(),
+        };
+        let memberships: Vec<MembershipRaw> = self.rest_get_paged(&path, usize::MAX).await?;
+
+        let details: Vec<Option<OrgRaw>> = stream::iter(memberships.iter())
+            .map(|m| async move {
+                self.rest_get(&format!("/orgs/{}", m.organization.login))
+                    .await
+                    .ok()
+            })
+            .buffered(ORG_LOOKUP_CONCURRENCY)
+            .collect()
+            .await;
+
+        Ok(memberships
+            .into_iter()
+            .zip(details)
+            .map(|(m, detail)| {
+                let (name, plan) = match detail {
+                    Some(d) => (d.name, d.plan),
+                    None => (None, None),
+                };
+                OrgMembership {
+                    org: m.organization.login,
+                    name,
+                    description: m.organization.description,
+                    role: m.role,
+                    state: m.state,
+                    plan: plan.map(|p| OrgPlan {
+                        name: p.name,
+                        seats: p.seats,
+                        filled_seats: p.filled_seats,
+                    }),
+                }
+            })
+            .collect())
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+impl PageInfo {
+    fn next_cursor(self) -> Option<String> {
+        self.end_cursor.filter(|_| self.has_next_page)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TotalCount {
+    total_count: i32,
+}
+
+/// Raw team repository from REST API.
+#[derive(Deserialize)]
+struct TeamRepoRaw {
+    full_name: String,
+    private: bool,
+    html_url: String,
+    role_name: Option<String>,
+    permissions: Option<PermissionsRaw>,
+}
+
+#[derive(Deserialize)]
+struct PermissionsRaw {
+    #[serde(default)]
+    admin: bool,
+    #[serde(default)]
+    maintain: bool,
+    #[serde(default)]
+    push: bool,
+    #[serde(default)]
+    triage: bool,
+}
+
+impl TeamRepoRaw {
+    /// The team's role, falling back to the highest flag in `permissions`.
+    fn permission(&self) -> String {
+        if let Some(role) = &self.role_name {
+            // REST reports the base roles by their UI names.
+            return match role.as_str() {
+                "read" => "pull",
+                "write" => "push",
+                other => other,
+            }
+            .to_string();
+        }
+
+        match &self.permissions {
+            Some(p) if p.admin => "admin",
+            Some(p) if p.maintain => "maintain",
+            Some(p) if p.push => "push",
+            Some(p) if p.triage => "triage",
+            _ => "pull",
+        }
+        .to_string()
+    }
+}
+
+/// Build an audit log search phrase from free text and an optional
+/// `created:` date range.
+fn audit_phrase(phrase: Option<&str>, since: Option<&str>, until: Option<&str>) -> Option<String> {
+    let created = match (since, until) {
+        (Some(since), Some(until)) => Some(format!("created:{}..{}", since, until)),
+        (Some(since), None) => Some(format!("created:>={}", since)),
+        (None, Some(until)) => Some(format!("created:<={}", until)),
+        (None, None) => None,
+    };
+
+    let terms: Vec<&str> = phrase
+        .into_iter()
+        .chain(created.as_deref())
+        .filter(|t| !t.trim().is_empty())
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+/// Extract the `after` query parameter from a pagination URL.
+fn after_param(url: &str) -> Option<String> {
+    let (_, query) = url.split_once('?')?;
+    query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("after="))
+        .map(|a| a.to_string())
+}
+
+/// Pick the common fields out of an audit log entry; the rest of the shape
+/// varies by action.
+fn audit_entry(raw: serde_json::Value, include_raw: bool) -> AuditLogEntry {
+    let field = |key: &str| raw.get(key).and_then(|v| v.as_str()).map(|s| s.to_string());
+    let created_at = raw
+        .get("@timestamp")
+        .or_else(|| raw.get("created_at"))
+        .and_then(|v| v.as_i64())
+        .and_then(chrono::DateTime::from_timestamp_millis)
+        .map(|t| t.to_rfc3339());
+
+    AuditLogEntry {
+        id: field("_document_id"),
+        action: field("action").unwrap_or_default(),
+        actor: field("actor"),
+        user: field("user"),
+        repo: field("repo"),
+        org: field("org"),
+        country_code: raw
+            .pointer("/actor_location/country_code")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        created_at,
+        raw: include_raw.then_some(raw),
+    }
+}
+
+/// Raw organization invitation from REST API.
+#[derive(Deserialize)]
+struct InvitationRaw {
+    id: u64,
+    login: Option<String>,
+    email: Option<String>,
+    role: String,
+    inviter: Option<LoginRaw>,
+    #[serde(default)]
+    team_count: i32,
+    created_at: String,
+}
+
+impl InvitationRaw {
+    fn into_model(self) -> OrgInvitation {
+        OrgInvitation {
+            id: self.id,
+            login: self.login,
+            email: self.email,
+            role: self.role,
+            inviter: self.inviter.map(|i| i.login),
+            team_count: self.team_count,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct LoginRaw {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct UserIdRaw {
+    id: u64,
+}
+
+/// Raw organization membership from REST API.
+#[derive(Deserialize)]
+struct MembershipRaw {
+    state: String,
+    role: String,
+    organization: OrgSummaryRaw,
+}
+
+#[derive(Deserialize)]
+struct OrgSummaryRaw {
+    login: String,
+    description: Option<String>,
+}
+
+/// Raw organization details from REST API.
+#[derive(Deserialize)]
+struct OrgRaw {
+    name: Option<String>,
+    plan: Option<PlanRaw>,
+}
+
+#[derive(Deserialize)]
+struct PlanRaw {
+    name: String,
+    seats: Option<i64>,
+    filled_seats: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit_phrase() {
+        assert_eq!(audit_phrase(None, None, None), None);
+        assert_eq!(
+            audit_phrase(
+                Some("action:repo.create"),
+                Some("2024-01-01"),
+                Some("2024-01-31")
+            )
+            .as_deref(),
+            Some("action:repo.create created:2024-01-01..2024-01-31")
+        );
+        assert_eq!(
+            audit_phrase(None, Some("2024-01-01"), None).as_deref(),
+            Some("created:>=2024-01-01")
+        );
+    }
+
+    #[test]
+    fn test_audit_entry() {
+        let entry = audit_entry(
+            serde_json::json!({
+                "@timestamp": 1705276800000i64,
+                "_document_id": "abc123",
+                "action": "org.add_member",
+                "actor": "octocat",
+                "user": "hubot",
+                "org": "my-org",
+                "actor_location": {"country_code": "US"},
+            }),
+            false,
+        );
+
+        assert_eq!(entry.action, "org.add_member");
+        assert_eq!(entry.user.as_deref(), Some("hubot"));
+        assert_eq!(entry.country_code.as_deref(), Some("US"));
+        assert_eq!(
+            entry.created_at.as_deref(),
+            Some("2024-01-15T00:00:00+00:00")
+        );
+        assert!(entry.raw.is_none());
+    }
+
+    #[test]
+    fn test_after_param() {
+        assert_eq!(
+            after_param("https://api.github.com/orgs/o/audit-log?per_page=30&after=MS4y%3D")
+                .as_deref(),
+            Some("MS4y%3D")
+        );
+        assert_eq!(
+            after_param("https://api.github.com/orgs/o/audit-log?per_page=30"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_team_repo_permission() {
+        let raw = |role_name: Option<&str>, permissions: serde_json::Value| -> TeamRepoRaw {
+            serde_json::from_value(serde_json::json!({
+                "full_name": "my-org/api",
+                "private": true,
+                "html_url": "https://github.com/my-org/api",
+                "role_name": role_name,
+                "permissions": permissions,
+            }))
+            .unwrap()
+        };
+
+        assert_eq!(
+            raw(Some("write"), serde_json::Value::Null).permission(),
+            "push"
+        );
+        assert_eq!(
+            raw(Some("security-manager"), serde_json::Value::Null).permission(),
+            "security-manager"
+        );
+        assert_eq!(
+            raw(
+                None,
+                serde_json::json!({"admin": false, "maintain": true, "push": true})
+            )
+            .permission(),
+            "maintain"
+        );
+        assert_eq!(raw(None, serde_json::Value::Null).permission(), "pull");
+    }
+}
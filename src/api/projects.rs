@@ -0,0 +1,1288 @@
+//! Projects (v2) endpoints (GraphQL only).
+//!
+//! Projects belong to a user or organization. `owner: None` means the
+//! authenticated user.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Added progress reports
+//! 10/16/2026 - Added board snapshots grouped by status
+//! 10/16/2026 - Added item field updates by field name
+//! 10/16/2026 - Added adding issues and PRs to projects
+//! 10/16/2026 - Added project items with field values
+
+use anyhow::{bail, Context, Result};
+use chrono::{Datelike, NaiveDate};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use super::GitHubClient;
+use crate::models::{
+    BoardColumn, IterationProgress, Project, ProjectBoard, ProjectItem, ProjectIteration,
+    ProjectReport, WeekCount,
+};
+
+/// Name of the built-in single-select field items are grouped by.
+pub const STATUS_FIELD: &str = "Status";
+
+/// Column for items with no value in the grouping field.
+const NO_VALUE_COLUMN: &str = "No Status";
+
+/// Report bucket for items not scheduled in an iteration.
+const NO_ITERATION: &str = "No iteration";
+
+/// Maximum items read when building a board or report.
+const MAX_PROJECT_ITEMS: usize = 2000;
+
+/// Field values requested per item; projects allow at most 50 fields.
+const MAX_FIELD_VALUES: i32 = 50;
+
+impl GitHubClient {
+    /// List projects owned by a user or organization, most recently updated
+    /// first. Closed projects are skipped unless `include_closed` is set.
+    ///
+    /// Returns the projects and a cursor for the next page, if any.
+    pub async fn list_projects(
+        &self,
+        owner: Option<&str>,
+        include_closed: bool,
+        limit: i32,
+        after: Option<&str>,
+    ) -> Result<(Vec<Project>, Option<String>)> {
+        let (declaration, root) = project_owner(owner);
+        let query = format!(
+            r#"
+            query($first: Int!, $after: String, $query: String{declaration}) {{
+                account: {root} {{
+                    login
+                    ... on ProjectV2Owner {{
+                        projectsV2(first: $first, after: $after, query: $query,
+                                   orderBy: {{field: UPDATED_AT, direction: DESC}}) {{
+                            pageInfo {{
+                                hasNextPage
+                                endCursor
+                            }}
+                            nodes {{
+                                id
+                                number
+                                title
+                                shortDescription
+                                url
+                                closed
+                                public
+                                updatedAt
+                                items {{ totalCount }}
+                            }}
+                        }}
+                    }}
+                }}
+            }}
+        "#
+        );
+
+        let variables = serde_json::json!({
+            "login": owner,
+            "first": limit.clamp(1, 100),
+            "after": after,
+            "query": if include_closed { None } else { Some("is:open") },
+        });
+
+        #[derive(Deserialize)]
+        struct Response {
+            account: Option<Account>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Account {
+            login: String,
+            projects_v2: Option<ProjectConnection>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ProjectConnection {
+            page_info: PageInfo,
+            nodes: Vec<ProjectNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ProjectNode {
+            id: String,
+            number: i32,
+            title: String,
+            short_description: Option<String>,
+            url: String,
+            closed: bool,
+            public: bool,
+            updated_at: String,
+            items: TotalCount,
+        }
+
+        let response: Response = self.graphql(&query, Some(variables)).await?;
+        let account = response
+            .account
+            .with_context(|| format!("Owner '{}' not found", owner.unwrap_or("viewer")))?;
+        let connection = account
+            .projects_v2
+            .with_context(|| format!("'{}' cannot own projects", account.login))?;
+
+        let projects = connection
+            .nodes
+            .into_iter()
+            .map(|p| Project {
+                id: p.id,
+                number: p.number,
+                title: p.title,
+                owner: account.login.clone(),
+                description: p.short_description.filter(|d| !d.is_empty()),
+                url: p.url,
+                closed: p.closed,
+                public: p.public,
+                item_count: p.items.total_count,
+                updated_at: p.updated_at,
+            })
+            .collect();
+
+        Ok((projects, connection.page_info.next_cursor()))
+    }
+
+    /// List a project's items with their content, status, iteration, and
+    /// custom field values keyed by field name.
+    ///
+    /// Returns the project title, the items, and a cursor for the next page.
+    pub async fn list_project_items(
+        &self,
+        owner: Option<&str>,
+        number: i32,
+        limit: i32,
+        after: Option<&str>,
+    ) -> Result<(String, Vec<ProjectItem>, Option<String>)> {
+        let (declaration, root) = project_owner(owner);
+        let query = format!(
+            r#"
+            query($number: Int!, $first: Int!, $after: String, $fields: Int!{declaration}) {{
+                account: {root} {{
+                    ... on ProjectV2Owner {{
+                        projectV2(number: $number) {{
+                            title
+                            items(first: $first, after: $after) {{
+                                pageInfo {{
+                                    hasNextPage
+                                    endCursor
+                                }}
+                                nodes {{
+                                    id
+                                    type
+                                    isArchived
+                                    content {{
+                                        __typename
+                                        ... on Issue {{
+                                            number
+                                            title
+                                            state
+                                            url
+                                            closedAt
+                                            repository {{ nameWithOwner }}
+                                            assignees(first: 10) {{ nodes {{ login }} }}
+                                        }}
+                                        ... on PullRequest {{
+                                            number
+                                            title
+                                            state
+                                            url
+                                            closedAt
+                                            repository {{ nameWithOwner }}
+                                            assignees(first: 10) {{ nodes {{ login }} }}
+                                        }}
+                                        ... on DraftIssue {{
+                                            title
+                                            assignees(first: 10) {{ nodes {{ login }} }}
+                                        }}
+                                    }}
+                                    fieldValues(first: $fields) {{
+                                        nodes {{
+                                            __typename
+                                            ... on ProjectV2ItemFieldSingleSelectValue {{
+                                                name
+                                                field {{ ... on ProjectV2FieldCommon {{ name }} }}
+                                            }}
+                                            ... on ProjectV2ItemFieldIterationValue {{
+                                                title
+                                                startDate
+                                                duration
+                                                field {{ ... on ProjectV2FieldCommon {{ name }} }}
+                                            }}
+                                            ... on ProjectV2ItemFieldTextValue {{
+                                                text
+                                                field {{ ... on ProjectV2FieldCommon {{ name }} }}
+                                            }}
+                                            ... on ProjectV2ItemFieldNumberValue {{
+                                                number
+                                                field {{ ... on ProjectV2FieldCommon {{ name }} }}
+                                            }}
+                                            ... on ProjectV2ItemFieldDateValue {{
+                                                date
+                                                field {{ ... on ProjectV2FieldCommon {{ name }} }}
+                                            }}
+                                        }}
+                                    }}
+                                }}
+                            }}
+                        }}
+                    }}
+                }}
+            }}
+        "#
+        );
+
+        let variables = serde_json::json!({
+            "login": owner,
+            "number": number,
+            "first": limit.clamp(1, 100),
+            "after": after,
+            "fields": MAX_FIELD_VALUES,
+        });
+
+        #[derive(Deserialize)]
+        struct Response {
+            account: Option<Account>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Account {
+            project_v2: Option<ProjectData>,
+        }
+
+        #[derive(Deserialize)]
+        struct ProjectData {
+            title: String,
+            items: ItemConnection,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ItemConnection {
+            page_info: PageInfo,
+            nodes: Vec<ItemNode>,
+        }
+
+        let response: Response = self.graphql(&query, Some(variables)).await?;
+        let project = response
+            .account
+            .and_then(|a| a.project_v2)
+            .with_context(|| format!("Project #{} not found", number))?;
+
+        Ok((
+            project.title,
+            project.items.nodes.into_iter().map(Into::into).collect(),
+            project.items.page_info.next_cursor(),
+        ))
+    }
+
+    /// Add an issue or pull request to a project. Adding an item that is
+    /// already on the project returns the existing item.
+    ///
+    /// Returns the project item ID.
+    pub async fn add_project_item(
+        &self,
+        owner: Option<&str>,
+        number: i32,
+        repo_owner: &str,
+        repo: &str,
+        issue: i32,
+    ) -> Result<String> {
+        let query = r#"
+            mutation($projectId: ID!, $contentId: ID!) {
+                addProjectV2ItemById(input: {projectId: $projectId, contentId: $contentId}) {
+                    item {
+                        id
+                    }
+                }
+            }
+        "#;
+
+        let project_id = self.project_id(owner, number).await?;
+        let content_id = self.issue_or_pr_id(repo_owner, repo, issue).await?;
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct AddResponse {
+            add_project_v2_item_by_id: AddData,
+        }
+
+        #[derive(Deserialize)]
+        struct AddData {
+            item: IdNode,
+        }
+
+        let variables = serde_json::json!({
+            "projectId": project_id,
+            "contentId": content_id,
+        });
+
+        let result: AddResponse = self.graphql(query, Some(variables)).await?;
+        Ok(result.add_project_v2_item_by_id.item.id)
+    }
+
+    /// Set field values on a project item by field name. Single-select
+    /// values match option names, iterations match titles (or `@current` /
+    /// `@next`), and `null` clears a field. Fields are updated in order and
+    /// the first failure stops the run.
+    ///
+    /// Returns each updated field name with the value sent.
+    pub async fn update_project_item(
+        &self,
+        owner: Option<&str>,
+        number: i32,
+        item_id: &str,
+        updates: &[(String, Value)],
+    ) -> Result<Vec<(String, Value)>> {
+        let (project_id, fields) = self.project_fields(owner, number).await?;
+        let today = chrono::Utc::now().date_naive();
+
+        // Resolve everything before changing anything.
+        let mut resolved = Vec::new();
+        for (name, value) in updates {
+            let field = fields
+                .iter()
+                .find(|f| f.name.eq_ignore_ascii_case(name))
+                .with_context(|| format!("Project has no field named '{}'", name))?;
+            resolved.push((field, field_value(field, value, today)?));
+        }
+
+        let mut updated = Vec::new();
+        for (field, value) in resolved {
+            match &value {
+                Some(value) => {
+                    let query = r#"
+                        mutation($projectId: ID!, $itemId: ID!, $fieldId: ID!,
+                                 $value: ProjectV2FieldValue!) {
+                            updateProjectV2ItemFieldValue(input: {projectId: $projectId,
+                                    itemId: $itemId, fieldId: $fieldId, value: $value}) {
+                                projectV2Item { id }
+                            }
+                        }
+                    "#;
+                    let variables = serde_json::json!({
+                        "projectId": project_id,
+                        "itemId": item_id,
+                        "fieldId": field.id,
+                        "value": value,
+                    });
+                    self.graphql::<Value>(query, Some(variables)).await?;
+                }
+                None => {
+                    let query = r#"
+                        mutation($projectId: ID!, $itemId: ID!, $fieldId: ID!) {
+                            clearProjectV2ItemFieldValue(input: {projectId: $projectId,
+                                    itemId: $itemId, fieldId: $fieldId}) {
+                                projectV2Item { id }
+                            }
+                        }
+                    "#;
+                    let variables = serde_json::json!({
+                        "projectId": project_id,
+                        "itemId": item_id,
+                        "fieldId": field.id,
+                    });
+                    self.graphql::<Value>(query, Some(variables)).await?;
+                }
+            }
+            updated.push((field.name.clone(), value.unwrap_or(Value::Null)));
+        }
+
+        Ok(updated)
+    }
+
+    /// Snapshot a project as a board: unarchived items grouped into one
+    /// column per option of a single-select field (in the project's option
+    /// order), plus a column for items without a value. Each column keeps at
+    /// most `per_column` items but counts all of them.
+    pub async fn get_project_board(
+        &self,
+        owner: Option<&str>,
+        number: i32,
+        field: &str,
+        per_column: usize,
+    ) -> Result<ProjectBoard> {
+        let (_, fields) = self.project_fields(owner, number).await?;
+        let field_def = fields
+            .iter()
+            .find(|f| f.name.eq_ignore_ascii_case(field))
+            .with_context(|| format!("Project has no field named '{}'", field))?;
+        if field_def.data_type != "SINGLE_SELECT" {
+            bail!("Field '{}' is not a single-select field", field_def.name);
+        }
+        let options: Vec<&str> = field_def.options.iter().map(|o| o.name.as_str()).collect();
+
+        let (title, items, truncated) = self.all_project_items(owner, number).await?;
+        let items: Vec<ProjectItem> = items.into_iter().filter(|i| !i.archived).collect();
+
+        Ok(ProjectBoard {
+            project: title,
+            number,
+            field: field_def.name.clone(),
+            total: items.len(),
+            columns: group_by_field(items, &field_def.name, &options, per_column),
+            truncated,
+        })
+    }
+
+    /// Progress report for a project: items completed per week over the last
+    /// `weeks` weeks, open vs. closed items per iteration, and unassigned
+    /// counts.
+    pub async fn get_project_report(
+        &self,
+        owner: Option<&str>,
+        number: i32,
+        weeks: u32,
+    ) -> Result<ProjectReport> {
+        let (title, items, truncated) = self.all_project_items(owner, number).await?;
+        let today = chrono::Utc::now().date_naive();
+
+        Ok(ProjectReport {
+            project: title,
+            number,
+            truncated,
+            ..build_report(&items, weeks, today)
+        })
+    }
+
+    /// Read up to `MAX_PROJECT_ITEMS` items of a project. Returns the
+    /// project title, the items, and whether more items were left unread.
+    async fn all_project_items(
+        &self,
+        owner: Option<&str>,
+        number: i32,
+    ) -> Result<(String, Vec<ProjectItem>, bool)> {
+        let mut items = Vec::new();
+        let mut after: Option<String> = None;
+        loop {
+            let (title, page, next) = self
+                .list_project_items(owner, number, 100, after.as_deref())
+                .await?;
+            items.extend(page);
+            match next {
+                Some(cursor) if items.len() < MAX_PROJECT_ITEMS => after = Some(cursor),
+                next => return Ok((title, items, next.is_some())),
+            }
+        }
+    }
+
+    /// Get a project's node ID and field definitions.
+    async fn project_fields(
+        &self,
+        owner: Option<&str>,
+        number: i32,
+    ) -> Result<(String, Vec<FieldDef>)> {
+        let (declaration, root) = project_owner(owner);
+        let query = format!(
+            r#"
+            query($number: Int!{declaration}) {{
+                account: {root} {{
+                    ... on ProjectV2Owner {{
+                        projectV2(number: $number) {{
+                            id
+                            fields(first: 50) {{
+                                nodes {{
+                                    ... on ProjectV2FieldCommon {{
+                                        id
+                                        name
+                                        dataType
+                                    }}
+                                    ... on ProjectV2SingleSelectField {{
+                                        options {{ id name }}
+                                    }}
+                                    ... on ProjectV2IterationField {{
+                                        configuration {{
+                                            iterations {{ id title startDate duration }}
+                                        }}
+                                    }}
+                                }}
+                            }}
+                        }}
+                    }}
+                }}
+            }}
+        "#
+        );
+
+        #[derive(Deserialize)]
+        struct Response {
+            account: Option<Account>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Account {
+            project_v2: Option<ProjectData>,
+        }
+
+        #[derive(Deserialize)]
+        struct ProjectData {
+            id: String,
+            fields: FieldConnection,
+        }
+
+        #[derive(Deserialize)]
+        struct FieldConnection {
+            nodes: Vec<FieldDef>,
+        }
+
+        let variables = serde_json::json!({ "login": owner, "number": number });
+        let response: Response = self.graphql(&query, Some(variables)).await?;
+        let project = response
+            .account
+            .and_then(|a| a.project_v2)
+            .with_context(|| format!("Project #{} not found", number))?;
+
+        Ok((project.id, project.fields.nodes))
+    }
+
+    /// Get a project's node ID from its owner and number.
+    async fn project_id(&self, owner: Option<&str>, number: i32) -> Result<String> {
+        let (declaration, root) = project_owner(owner);
+        let query = format!(
+            r#"
+            query($number: Int!{declaration}) {{
+                account: {root} {{
+                    ... on ProjectV2Owner {{
+                        projectV2(number: $number) {{
+                            id
+                        }}
+                    }}
+                }}
+            }}
+        "#
+        );
+
+        #[derive(Deserialize)]
+        struct Response {
+            account: Option<Account>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Account {
+            project_v2: Option<IdNode>,
+        }
+
+        let variables = serde_json::json!({ "login": owner, "number": number });
+        let response: Response = self.graphql(&query, Some(variables)).await?;
+        response
+            .account
+            .and_then(|a| a.project_v2)
+            .map(|p| p.id)
+            .with_context(|| format!("Project #{} not found", number))
+    }
+
+    /// Get the node ID of an issue or pull request.
+    async fn issue_or_pr_id(&self, owner: &str, repo: &str, number: i32) -> Result<String> {
+        let query = r#"
+            query($owner: String!, $name: String!, $number: Int!) {
+                repository(owner: $owner, name: $name) {
+                    issueOrPullRequest(number: $number) {
+                        ... on Issue { id }
+                        ... on PullRequest { id }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: Option<RepoData>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoData {
+            issue_or_pull_request: Option<IdNode>,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "number": number,
+        });
+        let response: RepoResponse = self.graphql(query, Some(variables)).await?;
+        response
+            .repository
+            .context("Repository not found")?
+            .issue_or_pull_request
+            .map(|c| c.id)
+            .with_context(|| format!("Issue or pull request #{} not found", number))
+    }
+}
+
+/// Query variable declaration and root field for a project owner, aliased
+/// as `account` in queries.
+fn project_owner(owner: Option<&str>) -> (&'static str, &'static str) {
+    match owner {
+        Some(_) => (", $login: String!", "repositoryOwner(login: $login)"),
+        None => ("", "viewer"),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+impl PageInfo {
+    fn next_cursor(self) -> Option<String> {
+        self.end_cursor.filter(|_| self.has_next_page)
+    }
+}
+
+#[derive(Deserialize)]
+struct IdNode {
+    id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TotalCount {
+    total_count: i32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ItemNode {
+    id: String,
+    #[serde(rename = "type")]
+    item_type: String,
+    is_archived: bool,
+    content: Option<ItemContent>,
+    field_values: FieldValueConnection,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "__typename")]
+enum ItemContent {
+    Issue(LinkedContent),
+    PullRequest(LinkedContent),
+    DraftIssue(DraftContent),
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LinkedContent {
+    number: i32,
+    title: String,
+    state: String,
+    url: String,
+    closed_at: Option<String>,
+    repository: RepoNode,
+    assignees: UserConnection,
+}
+
+#[derive(Deserialize)]
+struct DraftContent {
+    title: String,
+    assignees: UserConnection,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RepoNode {
+    name_with_owner: String,
+}
+
+#[derive(Deserialize)]
+struct UserConnection {
+    nodes: Vec<LoginNode>,
+}
+
+#[derive(Deserialize)]
+struct LoginNode {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct FieldValueConnection {
+    nodes: Vec<FieldValueNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "__typename")]
+enum FieldValueNode {
+    #[serde(rename = "ProjectV2ItemFieldSingleSelectValue")]
+    SingleSelect {
+        name: Option<String>,
+        field: FieldName,
+    },
+    #[serde(rename = "ProjectV2ItemFieldIterationValue")]
+    #[serde(rename_all = "camelCase")]
+    Iteration {
+        title: String,
+        start_date: String,
+        duration: i32,
+        field: FieldName,
+    },
+    #[serde(rename = "ProjectV2ItemFieldTextValue")]
+    Text {
+        text: Option<String>,
+        field: FieldName,
+    },
+    #[serde(rename = "ProjectV2ItemFieldNumberValue")]
+    Number {
+        number: Option<f64>,
+        field: FieldName,
+    },
+    #[serde(rename = "ProjectV2ItemFieldDateValue")]
+    Date {
+        date: Option<String>,
+        field: FieldName,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+struct FieldName {
+    name: String,
+}
+
+/// Project field definition.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FieldDef {
+    id: String,
+    name: String,
+    data_type: String,
+    #[serde(default)]
+    options: Vec<OptionDef>,
+    configuration: Option<IterationConfig>,
+}
+
+#[derive(Deserialize)]
+struct OptionDef {
+    id: String,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct IterationConfig {
+    iterations: Vec<IterationDef>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IterationDef {
+    id: String,
+    title: String,
+    start_date: String,
+    duration: i64,
+}
+
+/// Convert a user-supplied value to a `ProjectV2FieldValue` input for
+/// `field`. `None` means clear the field.
+fn field_value(field: &FieldDef, value: &Value, today: NaiveDate) -> Result<Option<Value>> {
+    if value.is_null() {
+        return Ok(None);
+    }
+
+    let text = || {
+        value
+            .as_str()
+            .with_context(|| format!("Field '{}' takes a string", field.name))
+    };
+
+    let input = match field.data_type.as_str() {
+        "TEXT" => serde_json::json!({ "text": text()? }),
+        "NUMBER" => {
+            let number = value
+                .as_f64()
+                .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+                .with_context(|| format!("Field '{}' takes a number", field.name))?;
+            serde_json::json!({ "number": number })
+        }
+        "DATE" => {
+            let date = text()?;
+            NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .with_context(|| format!("Field '{}' takes a YYYY-MM-DD date", field.name))?;
+            serde_json::json!({ "date": date })
+        }
+        "SINGLE_SELECT" => {
+            let name = text()?;
+            let option = field
+                .options
+                .iter()
+                .find(|o| o.name.eq_ignore_ascii_case(name))
+                .with_context(|| {
+                    let names: Vec<&str> = field.options.iter().map(|o| o.name.as_str()).collect();
+                    format!(
+                        "'{}' is not an option of '{}' (options: {})",
+                        name,
+                        field.name,
+                        names.join(", ")
+                    )
+                })?;
+            serde_json::json!({ "singleSelectOptionId": option.id })
+        }
+        "ITERATION" => {
+            let title = text()?;
+            let iterations = field
+                .configuration
+                .as_ref()
+                .map(|c| c.iterations.as_slice())
+                .unwrap_or_default();
+            let iteration = match title {
+                "@current" => iterations.iter().find(|i| {
+                    iteration_bounds(i).is_some_and(|(start, end)| start <= today && today < end)
+                }),
+                "@next" => iterations
+                    .iter()
+                    .filter(|i| iteration_bounds(i).is_some_and(|(start, _)| start > today))
+                    .min_by_key(|i| i.start_date.clone()),
+                _ => iterations
+                    .iter()
+                    .find(|i| i.title.eq_ignore_ascii_case(title)),
+            }
+            .with_context(|| format!("No iteration '{}' in '{}'", title, field.name))?;
+            serde_json::json!({ "iterationId": iteration.id })
+        }
+        other => bail!("Field '{}' ({}) cannot be set", field.name, other),
+    };
+
+    Ok(Some(input))
+}
+
+/// Group items into columns by a single-select field's value: one column per
+/// option in order, then unknown values, then items without a value.
+fn group_by_field(
+    items: Vec<ProjectItem>,
+    field: &str,
+    options: &[&str],
+    per_column: usize,
+) -> Vec<BoardColumn> {
+    let mut columns: Vec<BoardColumn> = options
+        .iter()
+        .map(|name| BoardColumn {
+            name: name.to_string(),
+            count: 0,
+            items: Vec::new(),
+        })
+        .collect();
+    let mut unset = BoardColumn {
+        name: NO_VALUE_COLUMN.to_string(),
+        count: 0,
+        items: Vec::new(),
+    };
+
+    for item in items {
+        let value = item.fields.get(field).and_then(Value::as_str);
+        let column = match value {
+            Some(value) => match columns.iter().position(|c| c.name == value) {
+                Some(i) => &mut columns[i],
+                // Options renamed or removed since the item was set.
+                None => {
+                    columns.push(BoardColumn {
+                        name: value.to_string(),
+                        count: 0,
+                        items: Vec::new(),
+                    });
+                    columns.last_mut().unwrap()
+                }
+            },
+            None => &mut unset,
+        };
+        column.count += 1;
+        if column.items.len() < per_column {
+            column.items.push(item);
+        }
+    }
+
+    columns.push(unset);
+    columns
+}
+
+/// Aggregate project items into a report. Issues and PRs count as closed
+/// when closed or merged; draft issues are always open.
+fn build_report(items: &[ProjectItem], weeks: u32, today: NaiveDate) -> ProjectReport {
+    let is_closed = |item: &ProjectItem| matches!(item.state.as_deref(), Some("CLOSED" | "MERGED"));
+    let week_start = |date: NaiveDate| {
+        date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64)
+    };
+
+    // Most recent `weeks` weeks, oldest first, ending with the current one.
+    let current_week = week_start(today);
+    let mut completed: Vec<WeekCount> = (0..weeks as i64)
+        .rev()
+        .map(|i| WeekCount {
+            week_start: (current_week - chrono::Duration::weeks(i)).to_string(),
+            completed: 0,
+        })
+        .collect();
+    for item in items {
+        let Some(closed) = item
+            .closed_at
+            .as_deref()
+            .filter(|_| is_closed(item))
+            .and_then(|c| chrono::DateTime::parse_from_rfc3339(c).ok())
+        else {
+            continue;
+        };
+        let week = week_start(closed.date_naive()).to_string();
+        if let Some(bucket) = completed.iter_mut().find(|w| w.week_start == week) {
+            bucket.completed += 1;
+        }
+    }
+
+    let mut iterations: Vec<IterationProgress> = Vec::new();
+    for item in items {
+        let (title, start_date) = match &item.iteration {
+            Some(i) => (i.title.as_str(), Some(i.start_date.clone())),
+            None => (NO_ITERATION, None),
+        };
+        let index = match iterations.iter().position(|p| p.title == title) {
+            Some(index) => index,
+            None => {
+                iterations.push(IterationProgress {
+                    title: title.to_string(),
+                    start_date,
+                    open: 0,
+                    closed: 0,
+                });
+                iterations.len() - 1
+            }
+        };
+        if is_closed(item) {
+            iterations[index].closed += 1;
+        } else {
+            iterations[index].open += 1;
+        }
+    }
+    // Chronological, with unscheduled items last.
+    iterations.sort_by(|a, b| match (&a.start_date, &b.start_date) {
+        (Some(a), Some(b)) => a.cmp(b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+
+    let closed = items.iter().filter(|i| is_closed(i)).count();
+    let unassigned: Vec<&ProjectItem> = items.iter().filter(|i| i.assignees.is_empty()).collect();
+
+    ProjectReport {
+        project: String::new(),
+        number: 0,
+        total: items.len(),
+        open: items.len() - closed,
+        closed,
+        unassigned_open: unassigned.iter().filter(|i| !is_closed(i)).count(),
+        unassigned_total: unassigned.len(),
+        completed_per_week: completed,
+        iterations,
+        truncated: false,
+    }
+}
+
+/// Start date and exclusive end date of an iteration.
+fn iteration_bounds(iteration: &IterationDef) -> Option<(NaiveDate, NaiveDate)> {
+    let start = NaiveDate::parse_from_str(&iteration.start_date, "%Y-%m-%d").ok()?;
+    Some((start, start + chrono::Duration::days(iteration.duration)))
+}
+
+impl From<ItemNode> for ProjectItem {
+    fn from(node: ItemNode) -> Self {
+        let mut item = ProjectItem {
+            id: node.id,
+            item_type: node.item_type,
+            title: None,
+            number: None,
+            repo: None,
+            state: None,
+            url: None,
+            closed_at: None,
+            assignees: Vec::new(),
+            archived: node.is_archived,
+            status: None,
+            iteration: None,
+            fields: BTreeMap::new(),
+        };
+
+        match node.content {
+            Some(ItemContent::Issue(c)) | Some(ItemContent::PullRequest(c)) => {
+                item.title = Some(c.title);
+                item.number = Some(c.number);
+                item.repo = Some(c.repository.name_with_owner);
+                item.state = Some(c.state);
+                item.url = Some(c.url);
+                item.closed_at = c.closed_at;
+                item.assignees = c.assignees.nodes.into_iter().map(|u| u.login).collect();
+            }
+            Some(ItemContent::DraftIssue(c)) => {
+                item.title = Some(c.title);
+                item.assignees = c.assignees.nodes.into_iter().map(|u| u.login).collect();
+            }
+            Some(ItemContent::Other) | None => {}
+        }
+
+        for value in node.field_values.nodes {
+            let (field, value) = match value {
+                FieldValueNode::SingleSelect { name, field } => {
+                    if field.name == STATUS_FIELD {
+                        item.status = name.clone();
+                    }
+                    (field.name, serde_json::json!(name))
+                }
+                FieldValueNode::Iteration {
+                    title,
+                    start_date,
+                    duration,
+                    field,
+                } => {
+                    // The first iteration field is the item's iteration.
+                    if item.iteration.is_none() {
+                        item.iteration = Some(ProjectIteration {
+                            title: title.clone(),
+                            start_date,
+                            duration_days: duration,
+                        });
+                    }
+                    (field.name, serde_json::json!(title))
+                }
+                FieldValueNode::Text { text, field } => (field.name, serde_json::json!(text)),
+                FieldValueNode::Number { number, field } => (field.name, serde_json::json!(number)),
+                FieldValueNode::Date { date, field } => (field.name, serde_json::json!(date)),
+                FieldValueNode::Other => continue,
+            };
+            // Title duplicates the content title.
+            if field != "Title" {
+                item.fields.insert(field, value);
+            }
+        }
+
+        item
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_by_field() {
+        let item = |id: &str, status: Option<&str>| {
+            let mut fields = BTreeMap::new();
+            if let Some(status) = status {
+                fields.insert(STATUS_FIELD.to_string(), serde_json::json!(status));
+            }
+            ProjectItem {
+                id: id.to_string(),
+                item_type: "ISSUE".to_string(),
+                title: None,
+                number: None,
+                repo: None,
+                state: None,
+                url: None,
+                closed_at: None,
+                assignees: vec![],
+                archived: false,
+                status: status.map(|s| s.to_string()),
+                iteration: None,
+                fields,
+            }
+        };
+        let items = vec![
+            item("1", Some("Done")),
+            item("2", Some("Todo")),
+            item("3", None),
+            item("4", Some("Todo")),
+            item("5", Some("Blocked")),
+        ];
+
+        let columns = group_by_field(items, STATUS_FIELD, &["Todo", "In Progress", "Done"], 1);
+        let summary: Vec<(&str, usize, usize)> = columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.count, c.items.len()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Todo", 2, 1),
+                ("In Progress", 0, 0),
+                ("Done", 1, 1),
+                ("Blocked", 1, 1),
+                ("No Status", 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_report() {
+        let item =
+            |state: &str, closed_at: Option<&str>, sprint: Option<(&str, &str)>, assignee: bool| {
+                ProjectItem {
+                    id: "PVTI".to_string(),
+                    item_type: "ISSUE".to_string(),
+                    title: None,
+                    number: None,
+                    repo: None,
+                    state: Some(state.to_string()),
+                    url: None,
+                    closed_at: closed_at.map(|c| c.to_string()),
+                    assignees: if assignee {
+                        vec!["octocat".to_string()]
+                    } else {
+                        vec![]
+                    },
+                    archived: false,
+                    status: None,
+                    iteration: sprint.map(|(title, start)| ProjectIteration {
+                        title: title.to_string(),
+                        start_date: start.to_string(),
+                        duration_days: 14,
+                    }),
+                    fields: BTreeMap::new(),
+                }
+            };
+        let items = vec![
+            item(
+                "CLOSED",
+                Some("2026-10-14T10:00:00Z"),
+                Some(("Sprint 2", "2026-10-12")),
+                true,
+            ),
+            item(
+                "MERGED",
+                Some("2026-10-06T10:00:00Z"),
+                Some(("Sprint 1", "2026-09-28")),
+                true,
+            ),
+            item("OPEN", None, Some(("Sprint 2", "2026-10-12")), false),
+            item("OPEN", None, None, false),
+            item("CLOSED", Some("2026-06-01T10:00:00Z"), None, false),
+        ];
+        let today = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+
+        let report = build_report(&items, 3, today);
+        assert_eq!((report.total, report.open, report.closed), (5, 2, 3));
+        assert_eq!((report.unassigned_open, report.unassigned_total), (2, 3));
+
+        let weeks: Vec<(&str, usize)> = report
+            .completed_per_week
+            .iter()
+            .map(|w| (w.week_start.as_str(), w.completed))
+            .collect();
+        assert_eq!(
+            weeks,
+            vec![("2026-09-28", 0), ("2026-10-05", 1), ("2026-10-12", 1)]
+        );
+
+        let iterations: Vec<(&str, usize, usize)> = report
+            .iterations
+            .iter()
+            .map(|i| (i.title.as_str(), i.open, i.closed))
+            .collect();
+        assert_eq!(
+            iterations,
+            vec![
+                ("Sprint 1", 0, 1),
+                ("Sprint 2", 1, 1),
+                ("No iteration", 1, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_field_value() {
+        let field = |data_type: &str, extra: Value| -> FieldDef {
+            let mut def = serde_json::json!({"id": "F1", "name": "Field", "dataType": data_type});
+            def.as_object_mut()
+                .unwrap()
+                .extend(extra.as_object().unwrap().clone());
+            serde_json::from_value(def).unwrap()
+        };
+        let today = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+
+        let status = field(
+            "SINGLE_SELECT",
+            serde_json::json!({"options": [{"id": "O1", "name": "Todo"}, {"id": "O2", "name": "In Progress"}]}),
+        );
+        assert_eq!(
+            field_value(&status, &serde_json::json!("in progress"), today).unwrap(),
+            Some(serde_json::json!({"singleSelectOptionId": "O2"}))
+        );
+        assert!(field_value(&status, &serde_json::json!("Done"), today).is_err());
+        assert_eq!(field_value(&status, &Value::Null, today).unwrap(), None);
+
+        let sprint = field(
+            "ITERATION",
+            serde_json::json!({"configuration": {"iterations": [
+                {"id": "I1", "title": "Sprint 1", "startDate": "2026-10-12", "duration": 14},
+                {"id": "I2", "title": "Sprint 2", "startDate": "2026-10-26", "duration": 14},
+            ]}}),
+        );
+        assert_eq!(
+            field_value(&sprint, &serde_json::json!("@current"), today).unwrap(),
+            Some(serde_json::json!({"iterationId": "I1"}))
+        );
+        assert_eq!(
+            field_value(&sprint, &serde_json::json!("@next"), today).unwrap(),
+            Some(serde_json::json!({"iterationId": "I2"}))
+        );
+
+        let estimate = field("NUMBER", serde_json::json!({}));
+        assert_eq!(
+            field_value(&estimate, &serde_json::json!("3"), today).unwrap(),
+            Some(serde_json::json!({"number": 3.0}))
+        );
+        assert!(field_value(
+            &field("DATE", serde_json::json!({})),
+            &serde_json::json!("soon"),
+            today
+        )
+        .is_err());
+        assert!(field_value(
+            &field("ASSIGNEES", serde_json::json!({})),
+            &serde_json::json!("x"),
+            today
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_project_item_from_node() {
+        let node: ItemNode = serde_json::from_value(serde_json::json!({
+            "id": "PVTI_1",
+            "type": "ISSUE",
+            "isArchived": false,
+            "content": {
+                "__typename": "Issue",
+                "number": 12,
+                "title": "Fix login",
+                "state": "OPEN",
+                "url": "https://github.com/o/r/issues/12",
+                "closedAt": null,
+                "repository": {"nameWithOwner": "o/r"},
+                "assignees": {"nodes": [{"login": "octocat"}]},
+            },
+            "fieldValues": {"nodes": [
+                {"__typename": "ProjectV2ItemFieldTextValue", "text": "Fix login", "field": {"name": "Title"}},
+                {"__typename": "ProjectV2ItemFieldSingleSelectValue", "name": "In Progress", "field": {"name": "Status"}},
+                {"__typename": "ProjectV2ItemFieldIterationValue", "title": "Sprint 4", "startDate": "2026-10-12", "duration": 14, "field": {"name": "Sprint"}},
+                {"__typename": "ProjectV2ItemFieldNumberValue", "number": 3.0, "field": {"name": "Estimate"}},
+                {"__typename": "ProjectV2ItemFieldRepositoryValue"},
+            ]},
+        }))
+        .unwrap();
+
+        let item = ProjectItem::from(node);
+        assert_eq!(item.number, Some(12));
+        assert_eq!(item.status.as_deref(), Some("In Progress"));
+        assert_eq!(item.iteration.unwrap().title, "Sprint 4");
+        assert_eq!(item.fields["Estimate"], serde_json::json!(3.0));
+        assert!(!item.fields.contains_key("Title"));
+        assert_eq!(item.assignees, vec!["octocat"]);
+    }
+}
@@ -0,0 +1,147 @@
+//! Rate-limit tracking.
+//!
+//! Every response carries `X-RateLimit-*` headers for the resource it was
+//! charged to (core, graphql, search, ...). Each client records the latest
+//! values, so separate accounts keep separate budgets.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Query GET /rate_limit for every resource at once
+//! 10/16/2026 - Initial implementation with per-client header tracking
+
+use anyhow::Result;
+use chrono::DateTime;
+use reqwest::header::HeaderMap;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use super::GitHubClient;
+use crate::models::RateLimitStatus;
+
+/// Latest rate-limit standing per resource, as seen in response headers.
+#[derive(Default)]
+pub(super) struct RateLimits {
+    resources: Mutex<BTreeMap<String, RateLimitStatus>>,
+}
+
+impl RateLimits {
+    /// Record the rate-limit headers of a response, if present.
+    pub(super) fn record(&self, headers: &HeaderMap) {
+        if let Some(status) = parse_headers(headers) {
+            self.insert(status);
+        }
+    }
+
+    fn insert(&self, status: RateLimitStatus) {
+        self.resources
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(status.resource.clone(), status);
+    }
+
+    /// Everything recorded so far, by resource.
+    pub(super) fn snapshot(&self) -> Vec<RateLimitStatus> {
+        self.resources
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .cloned()
+            .collect()
+    }
+}
+
+impl GitHubClient {
+    /// Rate-limit standing per resource from the most recent responses.
+    pub fn rate_limit_status(&self) -> Vec<RateLimitStatus> {
+        self.rate_limits.snapshot()
+    }
+
+    /// Fetch current standing for every resource from GET /rate_limit, which
+    /// does not itself count against the limit.
+    pub async fn get_rate_limit(&self) -> Result<Vec<RateLimitStatus>> {
+        #[derive(Deserialize)]
+        struct Resource {
+            limit: u64,
+            remaining: u64,
+            #[serde(default)]
+            used: u64,
+            reset: i64,
+        }
+
+        #[derive(Deserialize)]
+        struct Response {
+            resources: BTreeMap<String, Resource>,
+        }
+
+        let response: Response = self.rest_get("/rate_limit").await?;
+        let statuses: Vec<RateLimitStatus> = response
+            .resources
+            .into_iter()
+            .map(|(resource, r)| RateLimitStatus {
+                resource,
+                limit: r.limit,
+                remaining: r.remaining,
+                used: r.used,
+                reset_at: reset_time(r.reset),
+            })
+            .collect();
+
+        for status in &statuses {
+            self.rate_limits.insert(status.clone());
+        }
+        Ok(statuses)
+    }
+}
+
+/// RFC 3339 time for an `X-RateLimit-Reset` epoch timestamp.
+fn reset_time(epoch: i64) -> String {
+    DateTime::from_timestamp(epoch, 0)
+        .map(|t| t.to_rfc3339())
+        .unwrap_or_default()
+}
+
+/// Parse `X-RateLimit-*` headers. Responses without them (e.g. redirects to
+/// storage hosts) yield `None`.
+fn parse_headers(headers: &HeaderMap) -> Option<RateLimitStatus> {
+    let get = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+    let number = |name: &str| get(name).and_then(|v| v.parse::<u64>().ok());
+
+    let reset = number("x-ratelimit-reset")?;
+    Some(RateLimitStatus {
+        resource: get("x-ratelimit-resource").unwrap_or("core").to_string(),
+        limit: number("x-ratelimit-limit")?,
+        remaining: number("x-ratelimit-remaining")?,
+        used: number("x-ratelimit-used").unwrap_or_default(),
+        reset_at: reset_time(reset as i64),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_record_keeps_latest_per_resource() {
+        let limits = RateLimits::default();
+        let headers = |resource: &'static str, remaining: &'static str| {
+            let mut headers = HeaderMap::new();
+            headers.insert("x-ratelimit-resource", HeaderValue::from_static(resource));
+            headers.insert("x-ratelimit-limit", HeaderValue::from_static("5000"));
+            headers.insert("x-ratelimit-remaining", HeaderValue::from_static(remaining));
+            headers.insert("x-ratelimit-reset", HeaderValue::from_static("1792108800"));
+            headers
+        };
+
+        limits.record(&headers("core", "4999"));
+        limits.record(&headers("graphql", "4000"));
+        limits.record(&headers("core", "4990"));
+        limits.record(&HeaderMap::new());
+
+        let snapshot = limits.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0].resource, "core");
+        assert_eq!(snapshot[0].remaining, 4990);
+        assert_eq!(snapshot[1].resource, "graphql");
+    }
+}
@@ -0,0 +1,720 @@
+//! Release and tag endpoints.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Initial implementation with release listing
+
+use anyhow::{Context, Result};
+use reqwest::header::{CONTENT_LENGTH, CONTENT_TYPE};
+use reqwest::{Body, Method, Url};
+use serde::Deserialize;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tokio_util::io::ReaderStream;
+
+use super::client::ensure_success;
+use super::GitHubClient;
+use crate::models::{
+    AssetUpload, Download, GeneratedNotes, NewRelease, Release, ReleaseAsset, ReleaseDetail,
+    ReleaseUpdate, Tag,
+};
+
+/// Transfer timeout for asset uploads/downloads (the client default is 30s).
+const TRANSFER_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+impl GitHubClient {
+    /// List releases for a repository, newest first.
+    pub async fn list_releases(
+        &self,
+        owner: &str,
+        repo: &str,
+        limit: i32,
+        page: i32,
+    ) -> Result<Vec<Release>> {
+        let releases: Vec<ReleaseRaw> = self
+            .rest_get(&format!(
+                "/repos/{}/{}/releases?per_page={}&page={}",
+                owner, repo, limit, page
+            ))
+            .await?;
+
+        Ok(releases.into_iter().map(Into::into).collect())
+    }
+
+    /// Get a release by tag name.
+    pub async fn get_release_by_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+    ) -> Result<ReleaseDetail> {
+        let release: ReleaseRaw = self
+            .rest_get(&format!("/repos/{}/{}/releases/tags/{}", owner, repo, tag))
+            .await?;
+        Ok(release.into())
+    }
+
+    /// Get the latest published, non-prerelease release.
+    pub async fn get_latest_release(&self, owner: &str, repo: &str) -> Result<ReleaseDetail> {
+        let release: ReleaseRaw = self
+            .rest_get(&format!("/repos/{}/{}/releases/latest", owner, repo))
+            .await?;
+        Ok(release.into())
+    }
+
+    /// Resolve a release ID from its tag, including draft releases.
+    ///
+    /// The tag endpoint only sees published releases, so drafts are found by
+    /// scanning the most recent page of the release list.
+    pub async fn find_release_id(&self, owner: &str, repo: &str, tag: &str) -> Result<u64> {
+        if let Ok(release) = self.get_release_by_tag(owner, repo, tag).await {
+            return Ok(release.release.id);
+        }
+
+        self.list_releases(owner, repo, 100, 1)
+            .await?
+            .into_iter()
+            .find(|r| r.tag_name == tag)
+            .map(|r| r.id)
+            .with_context(|| format!("No release found for tag {}", tag))
+    }
+
+    /// Update a release (rename, edit notes, publish a draft, ...).
+    pub async fn update_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: u64,
+        update: &ReleaseUpdate,
+    ) -> Result<ReleaseDetail> {
+        let mut payload = serde_json::json!({});
+        if let Some(tag) = &update.tag_name {
+            payload["tag_name"] = serde_json::json!(tag);
+        }
+        if let Some(name) = &update.name {
+            payload["name"] = serde_json::json!(name);
+        }
+        if let Some(body) = &update.body {
+            payload["body"] = serde_json::json!(body);
+        }
+        if let Some(draft) = update.draft {
+            payload["draft"] = serde_json::json!(draft);
+        }
+        if let Some(prerelease) = update.prerelease {
+            payload["prerelease"] = serde_json::json!(prerelease);
+        }
+
+        let updated: ReleaseRaw = self
+            .rest_patch(
+                &format!("/repos/{}/{}/releases/{}", owner, repo, release_id),
+                &payload,
+            )
+            .await?;
+        Ok(updated.into())
+    }
+
+    /// Delete a release, optionally deleting its git tag as well.
+    pub async fn delete_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: u64,
+        delete_tag: Option<&str>,
+    ) -> Result<()> {
+        self.rest_delete(&format!(
+            "/repos/{}/{}/releases/{}",
+            owner, repo, release_id
+        ))
+        .await?;
+
+        if let Some(tag) = delete_tag {
+            self.rest_delete(&format!("/repos/{}/{}/git/refs/tags/{}", owner, repo, tag))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// List tags, most recent commit first, with annotated tags resolved.
+    ///
+    /// Returns the tags and a cursor for the next page, if any.
+    pub async fn list_tags(
+        &self,
+        owner: &str,
+        repo: &str,
+        limit: i32,
+        after: Option<&str>,
+    ) -> Result<(Vec<Tag>, Option<String>)> {
+        let query = r#"
+            query($owner: String!, $name: String!, $first: Int!, $after: String) {
+                repository(owner: $owner, name: $name) {
+                    refs(refPrefix: "refs/tags/", first: $first, after: $after,
+                         orderBy: {field: TAG_COMMIT_DATE, direction: DESC}) {
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                        nodes {
+                            name
+                            target {
+                                __typename
+                                oid
+                                ... on Commit {
+                                    committedDate
+                                }
+                                ... on Tag {
+                                    message
+                                    tagger {
+                                        name
+                                        date
+                                    }
+                                    target {
+                                        oid
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            refs: RefConnection,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RefConnection {
+            page_info: PageInfo,
+            nodes: Vec<RefNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct PageInfo {
+            has_next_page: bool,
+            end_cursor: Option<String>,
+        }
+
+        #[derive(Deserialize)]
+        struct RefNode {
+            name: String,
+            target: TargetNode,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct TargetNode {
+            #[serde(rename = "__typename")]
+            typename: String,
+            oid: String,
+            committed_date: Option<String>,
+            message: Option<String>,
+            tagger: Option<TaggerNode>,
+            target: Option<PeeledNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct TaggerNode {
+            name: Option<String>,
+            date: Option<String>,
+        }
+
+        #[derive(Deserialize)]
+        struct PeeledNode {
+            oid: String,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "first": limit,
+            "after": after,
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        let refs = result.repository.refs;
+
+        let tags = refs
+            .nodes
+            .into_iter()
+            .map(|n| {
+                let t = n.target;
+                if t.typename == "Tag" {
+                    let tagger = t.tagger;
+                    Tag {
+                        name: n.name,
+                        sha: t.target.map(|p| p.oid).unwrap_or_default(),
+                        is_annotated: true,
+                        tag_sha: Some(t.oid),
+                        tagger: tagger.as_ref().and_then(|g| g.name.clone()),
+                        message: t.message,
+                        date: tagger.and_then(|g| g.date),
+                    }
+                } else {
+                    Tag {
+                        name: n.name,
+                        sha: t.oid,
+                        is_annotated: false,
+                        tag_sha: None,
+                        tagger: None,
+                        message: None,
+                        date: t.committed_date,
+                    }
+                }
+            })
+            .collect();
+
+        let next_cursor = if refs.page_info.has_next_page {
+            refs.page_info.end_cursor
+        } else {
+            None
+        };
+
+        Ok((tags, next_cursor))
+    }
+
+    /// Create a tag on `target` (default branch when omitted).
+    ///
+    /// With a `message` an annotated tag object is created first; without
+    /// one the ref points straight at the commit (lightweight tag).
+    pub async fn create_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+        target: Option<&str>,
+        message: Option<&str>,
+    ) -> Result<Tag> {
+        let target = match target {
+            Some(t) => t.to_string(),
+            None => self.get_default_branch(owner, repo).await?,
+        };
+        let commit_sha = self.resolve_commit_sha(owner, repo, &target).await?;
+
+        #[derive(Deserialize)]
+        struct TagObject {
+            sha: String,
+            tagger: Option<TagObjectTagger>,
+        }
+
+        #[derive(Deserialize)]
+        struct TagObjectTagger {
+            name: String,
+            date: String,
+        }
+
+        let (ref_sha, annotated) = match message {
+            Some(message) => {
+                let object: TagObject = self
+                    .rest_post(
+                        &format!("/repos/{}/{}/git/tags", owner, repo),
+                        &serde_json::json!({
+                            "tag": tag,
+                            "message": message,
+                            "object": commit_sha,
+                            "type": "commit",
+                        }),
+                    )
+                    .await?;
+                (object.sha.clone(), Some(object))
+            }
+            None => (commit_sha.clone(), None),
+        };
+
+        let _: serde_json::Value = self
+            .rest_post(
+                &format!("/repos/{}/{}/git/refs", owner, repo),
+                &serde_json::json!({
+                    "ref": format!("refs/tags/{}", tag),
+                    "sha": ref_sha,
+                }),
+            )
+            .await?;
+
+        Ok(match annotated {
+            Some(object) => Tag {
+                name: tag.to_string(),
+                sha: commit_sha,
+                is_annotated: true,
+                tag_sha: Some(object.sha),
+                tagger: object.tagger.as_ref().map(|t| t.name.clone()),
+                message: message.map(|m| m.to_string()),
+                date: object.tagger.map(|t| t.date),
+            },
+            None => Tag {
+                name: tag.to_string(),
+                sha: commit_sha,
+                is_annotated: false,
+                tag_sha: None,
+                tagger: None,
+                message: None,
+                date: None,
+            },
+        })
+    }
+
+    /// Generate release notes for a tag via GitHub's generate-notes endpoint.
+    ///
+    /// `previous_tag` defaults to the last release when omitted; the tag does
+    /// not need to exist yet if `target` is given.
+    pub async fn generate_release_notes(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+        target: Option<&str>,
+        previous_tag: Option<&str>,
+    ) -> Result<GeneratedNotes> {
+        let mut body = serde_json::json!({ "tag_name": tag });
+        if let Some(target) = target {
+            body["target_commitish"] = serde_json::json!(target);
+        }
+        if let Some(previous) = previous_tag {
+            body["previous_tag_name"] = serde_json::json!(previous);
+        }
+
+        self.rest_post(
+            &format!("/repos/{}/{}/releases/generate-notes", owner, repo),
+            &body,
+        )
+        .await
+    }
+
+    /// Create a release, optionally prefixing the body with generated notes.
+    pub async fn create_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release: &NewRelease,
+    ) -> Result<ReleaseDetail> {
+        let mut name = release.name.clone();
+        let mut body = release.body.clone();
+
+        if release.generate_notes {
+            let notes = self
+                .generate_release_notes(
+                    owner,
+                    repo,
+                    &release.tag_name,
+                    release.target_commitish.as_deref(),
+                    None,
+                )
+                .await?;
+
+            body = Some(match body {
+                Some(intro) => format!("{}\n\n{}", intro, notes.body),
+                None => notes.body,
+            });
+            name = name.or(Some(notes.name));
+        }
+
+        let mut payload = serde_json::json!({
+            "tag_name": release.tag_name,
+            "draft": release.draft,
+            "prerelease": release.prerelease,
+        });
+        if let Some(target) = &release.target_commitish {
+            payload["target_commitish"] = serde_json::json!(target);
+        }
+        if let Some(name) = name {
+            payload["name"] = serde_json::json!(name);
+        }
+        if let Some(body) = body {
+            payload["body"] = serde_json::json!(body);
+        }
+
+        let created: ReleaseRaw = self
+            .rest_post(&format!("/repos/{}/{}/releases", owner, repo), &payload)
+            .await?;
+        Ok(created.into())
+    }
+
+    /// Upload a local file as a release asset, streaming it from disk.
+    ///
+    /// `name` defaults to the file name and `content_type` is detected from
+    /// the name's extension when not given.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn upload_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: u64,
+        path: &Path,
+        name: Option<&str>,
+        label: Option<&str>,
+        content_type: Option<&str>,
+    ) -> Result<AssetUpload> {
+        let file = tokio::fs::File::open(path)
+            .await
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        let size = file.metadata().await?.len();
+
+        let name = match name {
+            Some(n) => n.to_string(),
+            None => path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .context("Asset path has no file name")?
+                .to_string(),
+        };
+        let content_type = content_type.unwrap_or_else(|| content_type_for(&name));
+
+        let mut url = Url::parse(&format!(
+            "{}/repos/{}/{}/releases/{}/assets",
+            self.endpoints.uploads, owner, repo, release_id
+        ))?;
+        url.query_pairs_mut().append_pair("name", &name);
+        if let Some(label) = label {
+            url.query_pairs_mut().append_pair("label", label);
+        }
+
+        let start = Instant::now();
+        let response = self
+            .send(
+                self.request(Method::POST, url.as_str())
+                    .header(CONTENT_TYPE, content_type)
+                    .header(CONTENT_LENGTH, size)
+                    .timeout(TRANSFER_TIMEOUT)
+                    .body(Body::wrap_stream(ReaderStream::new(file))),
+                true,
+            )
+            .await
+            .context("Failed to upload release asset")?;
+
+        let asset: AssetRaw = ensure_success(response)
+            .await?
+            .json()
+            .await
+            .context("Failed to parse JSON")?;
+        let elapsed = start.elapsed();
+
+        Ok(AssetUpload {
+            asset: asset.into(),
+            bytes_uploaded: size,
+            elapsed_ms: elapsed.as_millis() as u64,
+            bytes_per_sec: (size as f64 / elapsed.as_secs_f64().max(0.001)) as u64,
+        })
+    }
+
+    /// Download a release asset to `dest`.
+    ///
+    /// When `expected_sha256` is given, the file is removed and an error
+    /// returned if its digest does not match.
+    pub async fn download_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        asset_id: u64,
+        dest: &Path,
+        expected_sha256: Option<&str>,
+    ) -> Result<Download> {
+        let download = self
+            .download_to_file(
+                &format!("/repos/{}/{}/releases/assets/{}", owner, repo, asset_id),
+                "application/octet-stream",
+                dest,
+            )
+            .await?;
+
+        if let Some(expected) = expected_sha256 {
+            if !download.sha256.eq_ignore_ascii_case(expected.trim()) {
+                let _ = tokio::fs::remove_file(dest).await;
+                anyhow::bail!(
+                    "Checksum mismatch for {}: expected {}, got {}",
+                    dest.display(),
+                    expected,
+                    download.sha256
+                );
+            }
+        }
+
+        Ok(download)
+    }
+}
+
+/// Guess an asset's MIME type from its file extension.
+fn content_type_for(name: &str) -> &'static str {
+    let lower = name.to_ascii_lowercase();
+    let ext = lower.rsplit('.').next().unwrap_or("");
+
+    match ext {
+        "gz" | "tgz" => "application/gzip",
+        "zip" => "application/zip",
+        "tar" => "application/x-tar",
+        "xz" => "application/x-xz",
+        "bz2" => "application/x-bzip2",
+        "zst" => "application/zstd",
+        "deb" => "application/vnd.debian.binary-package",
+        "rpm" => "application/x-rpm",
+        "dmg" => "application/x-apple-diskimage",
+        "pkg" => "application/octet-stream",
+        "exe" | "dll" => "application/vnd.microsoft.portable-executable",
+        "msi" => "application/x-msi",
+        "wasm" => "application/wasm",
+        "json" => "application/json",
+        "asc" | "sig" => "application/pgp-signature",
+        "txt" | "sha256" | "sha512" | "md5" => "text/plain",
+        "md" => "text/markdown",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Raw release from REST API.
+#[derive(Deserialize)]
+struct ReleaseRaw {
+    id: u64,
+    tag_name: String,
+    name: Option<String>,
+    draft: bool,
+    prerelease: bool,
+    author: Option<ReleaseAuthor>,
+    html_url: String,
+    created_at: String,
+    published_at: Option<String>,
+    body: Option<String>,
+    target_commitish: String,
+    #[serde(default)]
+    assets: Vec<AssetRaw>,
+}
+
+#[derive(Deserialize)]
+struct ReleaseAuthor {
+    login: String,
+}
+
+/// Raw release asset from REST API.
+#[derive(Deserialize)]
+struct AssetRaw {
+    id: u64,
+    name: String,
+    label: Option<String>,
+    content_type: String,
+    size: u64,
+    download_count: u64,
+    browser_download_url: String,
+    updated_at: String,
+}
+
+impl From<AssetRaw> for ReleaseAsset {
+    fn from(a: AssetRaw) -> Self {
+        ReleaseAsset {
+            id: a.id,
+            name: a.name,
+            label: a.label,
+            content_type: a.content_type,
+            size: a.size,
+            download_count: a.download_count,
+            download_url: a.browser_download_url,
+            updated_at: a.updated_at,
+        }
+    }
+}
+
+impl From<ReleaseRaw> for Release {
+    fn from(r: ReleaseRaw) -> Self {
+        Release {
+            id: r.id,
+            tag_name: r.tag_name,
+            name: r.name,
+            is_draft: r.draft,
+            is_prerelease: r.prerelease,
+            author: r.author.map(|a| a.login),
+            url: r.html_url,
+            created_at: r.created_at,
+            published_at: r.published_at,
+            asset_count: r.assets.len(),
+        }
+    }
+}
+
+impl From<ReleaseRaw> for ReleaseDetail {
+    fn from(mut r: ReleaseRaw) -> Self {
+        let body = r.body.take();
+        let target_commitish = std::mem::take(&mut r.target_commitish);
+        let assets: Vec<ReleaseAsset> = std::mem::take(&mut r.assets)
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        // The assets were taken out of `r` above, so count them here
+        let mut release: Release = r.into();
+        release.asset_count = assets.len();
+
+        ReleaseDetail {
+            release,
+            body,
+            target_commitish,
+            assets,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_type_for() {
+        assert_eq!(content_type_for("app-x86_64.tar.gz"), "application/gzip");
+        assert_eq!(content_type_for("app.ZIP"), "application/zip");
+        assert_eq!(content_type_for("checksums.sha256"), "text/plain");
+        assert_eq!(content_type_for("fgp-github"), "application/octet-stream");
+    }
+
+    #[test]
+    fn test_release_detail_from_raw() {
+        let raw: ReleaseRaw = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "tag_name": "v1.2.0",
+            "name": "1.2.0",
+            "draft": false,
+            "prerelease": false,
+            "author": {"login": "octocat"},
+            "html_url": "https://github.com/o/r/releases/tag/v1.2.0",
+            "created_at": "2026-10-01T00:00:00Z",
+            "published_at": "2026-10-01T00:00:00Z",
+            "body": "Notes",
+            "target_commitish": "main",
+            "assets": [
+                {
+                    "id": 10,
+                    "name": "app.tar.gz",
+                    "label": null,
+                    "content_type": "application/gzip",
+                    "size": 2048,
+                    "download_count": 7,
+                    "browser_download_url": "https://github.com/o/r/releases/download/v1.2.0/app.tar.gz",
+                    "updated_at": "2026-10-01T00:00:00Z"
+                },
+                {
+                    "id": 11,
+                    "name": "checksums.sha256",
+                    "label": "Checksums",
+                    "content_type": "text/plain",
+                    "size": 128,
+                    "download_count": 3,
+                    "browser_download_url": "https://github.com/o/r/releases/download/v1.2.0/checksums.sha256",
+                    "updated_at": "2026-10-01T00:00:00Z"
+                }
+            ]
+        }))
+        .unwrap();
+
+        let detail = ReleaseDetail::from(raw);
+        assert_eq!(detail.release.asset_count, 2);
+        assert_eq!(detail.release.author.as_deref(), Some("octocat"));
+        assert_eq!(detail.target_commitish, "main");
+        assert_eq!(detail.body.as_deref(), Some("Notes"));
+        assert_eq!(detail.assets[0].download_count, 7);
+
+        let json = serde_json::to_value(&detail).unwrap();
+        assert_eq!(json["asset_count"], 2);
+    }
+}
@@ -0,0 +1,828 @@
+//! Search endpoints.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Page-number cursors for REST searches
+//! 10/16/2026 - Added topic search and trending-by-recent-stars ranking
+//! 10/16/2026 - Added commit search
+//! 10/16/2026 - Added user/organization search
+//! 10/16/2026 - Added repository search
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use futures::stream::{self, StreamExt};
+use reqwest::Method;
+use serde::Deserialize;
+use std::cmp::Reverse;
+
+use super::client::{encode_query, ensure_success, link_rel, RepoRaw};
+use super::GitHubClient;
+use crate::models::{
+    CodeSearchHit, CommitSearchHit, Issue, IssueSearchHit, PrSearchHit, PullRequest, Repository,
+    Review, SearchResults, Topic, TrendingRepo, UserSearchHit,
+};
+
+/// Default REST media type.
+const JSON_ACCEPT: &str = "application/vnd.github+json";
+
+/// Media type that adds `text_matches` fragments to search results.
+const TEXT_MATCH_ACCEPT: &str = "application/vnd.github.text-match+json";
+
+/// Media type that adds `starred_at` to stargazer listings.
+const STAR_ACCEPT: &str = "application/vnd.github.star+json";
+
+/// Stargazer pages (of 100) scanned per repository when counting recent stars.
+const STAR_SCAN_PAGES: u32 = 3;
+
+/// Maximum concurrent stargazer scans when ranking trending repositories.
+const TRENDING_CONCURRENCY: usize = 8;
+
+impl GitHubClient {
+    /// Search code. `query` uses GitHub search syntax, including qualifiers
+    /// such as `repo:`, `org:`, `path:`, and `language:`.
+    pub async fn search_code(
+        &self,
+        query: &str,
+        per_page: u32,
+        page: u32,
+    ) -> Result<SearchResults<CodeSearchHit>> {
+        let results: SearchResponse<CodeRaw> = self
+            .search_rest("code", query, None, None, per_page, page, TEXT_MATCH_ACCEPT)
+            .await?;
+
+        Ok(results.map(|c| CodeSearchHit {
+            name: c.name,
+            path: c.path,
+            repo: c.repository.full_name,
+            sha: c.sha,
+            html_url: c.html_url,
+            fragments: c
+                .text_matches
+                .into_iter()
+                .filter_map(|m| m.fragment)
+                .collect(),
+        }))
+    }
+
+    /// Search repositories. `sort` is one of `stars`, `forks`,
+    /// `help-wanted-issues`, or `updated`; best match when omitted.
+    pub async fn search_repos(
+        &self,
+        query: &str,
+        sort: Option<&str>,
+        order: Option<&str>,
+        per_page: u32,
+        page: u32,
+    ) -> Result<SearchResults<Repository>> {
+        let results: SearchResponse<RepoRaw> = self
+            .search_rest(
+                "repositories",
+                query,
+                sort,
+                order,
+                per_page,
+                page,
+                JSON_ACCEPT,
+            )
+            .await?;
+
+        Ok(results.map(Repository::from))
+    }
+
+    /// Search commits on default branches. `sort` is `author-date` or
+    /// `committer-date`; best match when omitted.
+    pub async fn search_commits(
+        &self,
+        query: &str,
+        sort: Option<&str>,
+        order: Option<&str>,
+        per_page: u32,
+        page: u32,
+    ) -> Result<SearchResults<CommitSearchHit>> {
+        let results: SearchResponse<CommitRaw> = self
+            .search_rest("commits", query, sort, order, per_page, page, JSON_ACCEPT)
+            .await?;
+
+        Ok(results.map(|c| CommitSearchHit {
+            sha: c.sha,
+            message: c.commit.message,
+            date: c.commit.author.date,
+            author: c.author.map(|a| a.login).or(Some(c.commit.author.name)),
+            repo: c.repository.full_name,
+            html_url: c.html_url,
+        }))
+    }
+
+    /// Search users and organizations.
+    pub async fn search_users(
+        &self,
+        query: &str,
+        first: i32,
+        after: Option<&str>,
+    ) -> Result<SearchResults<UserSearchHit>> {
+        let gql = r#"
+            query($q: String!, $first: Int!, $after: String) {
+                search(query: $q, type: USER, first: $first, after: $after) {
+                    userCount
+                    pageInfo {
+                        hasNextPage
+                        endCursor
+                    }
+                    nodes {
+                        __typename
+                        ... on User {
+                            login
+                            name
+                            url
+                            followers { totalCount }
+                        }
+                        ... on Organization {
+                            login
+                            name
+                            url
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct SearchResponse {
+            search: UserSearch,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct UserSearch {
+            user_count: i64,
+            page_info: PageInfo,
+            nodes: Vec<AccountNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(tag = "__typename")]
+        enum AccountNode {
+            User {
+                login: String,
+                name: Option<String>,
+                url: String,
+                followers: TotalCount,
+            },
+            Organization {
+                login: String,
+                name: Option<String>,
+                url: String,
+            },
+        }
+
+        let variables = serde_json::json!({
+            "q": query,
+            "first": first.clamp(1, 100),
+            "after": after,
+        });
+
+        let result: SearchResponse = self.graphql(gql, Some(variables)).await?;
+        let search = result.search;
+
+        let items = search
+            .nodes
+            .into_iter()
+            .map(|n| match n {
+                AccountNode::User {
+                    login,
+                    name,
+                    url,
+                    followers,
+                } => UserSearchHit {
+                    login,
+                    account_type: "user".to_string(),
+                    name,
+                    followers: Some(followers.total_count),
+                    url,
+                },
+                AccountNode::Organization { login, name, url } => UserSearchHit {
+                    login,
+                    account_type: "org".to_string(),
+                    name,
+                    followers: None,
+                    url,
+                },
+            })
+            .collect();
+
+        Ok(SearchResults {
+            total_count: search.user_count,
+            incomplete_results: false,
+            items,
+            next_cursor: search.page_info.next_cursor(),
+        })
+    }
+
+    /// Rank the top `candidates` repositories matching `query` (by total
+    /// stars) by stars gained in the last `days` days.
+    pub async fn trending_repos(
+        &self,
+        query: &str,
+        days: u32,
+        candidates: u32,
+    ) -> Result<SearchResults<TrendingRepo>> {
+        let since = Utc::now() - Duration::days(days as i64);
+        let results = self
+            .search_repos(query, Some("stars"), Some("desc"), candidates, 1)
+            .await?;
+
+        let counts: Vec<Option<(u32, bool)>> = stream::iter(results.items.iter())
+            .map(|r| async move { self.recent_stars(&r.full_name, since).await.ok() })
+            .buffered(TRENDING_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut items: Vec<TrendingRepo> = results
+            .items
+            .into_iter()
+            .zip(counts)
+            .map(|(repository, count)| TrendingRepo {
+                repository,
+                recent_stars: count.map(|(n, _)| n),
+                recent_stars_capped: count.is_some_and(|(_, capped)| capped),
+            })
+            .collect();
+        items.sort_by_key(|t| Reverse(t.recent_stars));
+
+        Ok(SearchResults {
+            total_count: results.total_count,
+            incomplete_results: results.incomplete_results,
+            items,
+            next_cursor: None,
+        })
+    }
+
+    /// Count stars on `full_name` since `since`. Stargazers are listed oldest
+    /// first, so this walks back from the last page. Returns the count and
+    /// whether it hit [`STAR_SCAN_PAGES`] before reaching older stars.
+    async fn recent_stars(&self, full_name: &str, since: DateTime<Utc>) -> Result<(u32, bool)> {
+        let url = format!(
+            "{}/repos/{}/stargazers?per_page=100",
+            self.endpoints.rest, full_name
+        );
+        let (first_page, last_page) = self.stargazer_page(&url).await?;
+        let last_page = last_page.unwrap_or(1);
+
+        let mut count = 0;
+        for (scanned, page) in (1..=last_page).rev().enumerate() {
+            if scanned as u32 == STAR_SCAN_PAGES {
+                return Ok((count, true));
+            }
+
+            let stars = if page == 1 {
+                first_page.clone()
+            } else {
+                self.stargazer_page(&format!("{}&page={}", url, page))
+                    .await?
+                    .0
+            };
+
+            let recent = stars.iter().filter(|s| s.starred_at >= since).count();
+            count += recent as u32;
+            if recent < stars.len() {
+                break;
+            }
+        }
+
+        Ok((count, false))
+    }
+
+    /// Fetch one page of stargazers with timestamps, plus the last page number.
+    async fn stargazer_page(&self, url: &str) -> Result<(Vec<StarRaw>, Option<u32>)> {
+        let response = self
+            .send(self.request_accepting(Method::GET, url, STAR_ACCEPT), false)
+            .await
+            .context("Failed to send REST request")?;
+        let response = ensure_success(response).await?;
+
+        let last_page = response
+            .headers()
+            .get(reqwest::header::LINK)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| link_rel(v, "last"))
+            .and_then(|url| page_param(&url));
+
+        let stars = response.json().await.context("Failed to parse JSON")?;
+        Ok((stars, last_page))
+    }
+
+    /// Search topics.
+    pub async fn search_topics(
+        &self,
+        query: &str,
+        per_page: u32,
+        page: u32,
+    ) -> Result<SearchResults<Topic>> {
+        let results: SearchResponse<TopicRaw> = self
+            .search_rest("topics", query, None, None, per_page, page, JSON_ACCEPT)
+            .await?;
+
+        Ok(results.map(|t| Topic {
+            name: t.name,
+            display_name: t.display_name,
+            short_description: t.short_description,
+            featured: t.featured,
+            curated: t.curated,
+        }))
+    }
+
+    /// Search issues. `query` accepts full GitHub search syntax; `is:issue`
+    /// is added unless already present.
+    pub async fn search_issues(
+        &self,
+        query: &str,
+        first: i32,
+        after: Option<&str>,
+    ) -> Result<SearchResults<IssueSearchHit>> {
+        let query = with_qualifier(query, "is:issue");
+        let search = self.search_graphql(&query, first, after).await?;
+
+        let items = search
+            .nodes
+            .into_iter()
+            .filter_map(|n| match n {
+                SearchNode::Issue(i) => Some(IssueSearchHit {
+                    repo: i.repository.name_with_owner,
+                    issue: Issue {
+                        number: i.number,
+                        title: i.title,
+                        state: i.state,
+                        url: i.url,
+                        created_at: i.created_at,
+                        updated_at: i.updated_at,
+                        author: i.author.map(|a| a.login),
+                        labels: i.labels.nodes.into_iter().map(|l| l.name).collect(),
+                        comment_count: i.comments.total_count,
+                    },
+                }),
+                _ => None,
+            })
+            .collect();
+
+        Ok(SearchResults {
+            total_count: search.issue_count,
+            incomplete_results: false,
+            items,
+            next_cursor: search.page_info.next_cursor(),
+        })
+    }
+
+    /// Search pull requests. `query` accepts full GitHub search syntax;
+    /// `is:pr` is added unless already present.
+    pub async fn search_prs(
+        &self,
+        query: &str,
+        first: i32,
+        after: Option<&str>,
+    ) -> Result<SearchResults<PrSearchHit>> {
+        let query = with_qualifier(query, "is:pr");
+        let search = self.search_graphql(&query, first, after).await?;
+
+        let items = search
+            .nodes
+            .into_iter()
+            .filter_map(|n| match n {
+                SearchNode::PullRequest(pr) => Some(PrSearchHit {
+                    repo: pr.repository.name_with_owner,
+                    pull_request: PullRequest {
+                        number: pr.number,
+                        title: pr.title,
+                        state: pr.state,
+                        url: pr.url,
+                        is_draft: pr.is_draft,
+                        mergeable: pr.mergeable,
+                        created_at: pr.created_at,
+                        updated_at: pr.updated_at,
+                        author: pr.author.map(|a| a.login),
+                        head_branch: pr.head_ref_name,
+                        base_branch: pr.base_ref_name,
+                        additions: pr.additions,
+                        deletions: pr.deletions,
+                        changed_files: pr.changed_files,
+                        commit_count: pr.commits.total_count,
+                        comment_count: pr.comments.total_count,
+                        reviews: pr
+                            .reviews
+                            .nodes
+                            .into_iter()
+                            .map(|r| Review {
+                                author: r.author.map(|a| a.login),
+                                state: r.state,
+                                submitted_at: r.submitted_at,
+                            })
+                            .collect(),
+                    },
+                }),
+                _ => None,
+            })
+            .collect();
+
+        Ok(SearchResults {
+            total_count: search.issue_count,
+            incomplete_results: false,
+            items,
+            next_cursor: search.page_info.next_cursor(),
+        })
+    }
+
+    /// Run a GraphQL `search(type: ISSUE)` returning issue and PR nodes.
+    async fn search_graphql(
+        &self,
+        query: &str,
+        first: i32,
+        after: Option<&str>,
+    ) -> Result<IssueSearch> {
+        let gql = r#"
+            query($q: String!, $first: Int!, $after: String) {
+                search(query: $q, type: ISSUE, first: $first, after: $after) {
+                    issueCount
+                    pageInfo {
+                        hasNextPage
+                        endCursor
+                    }
+                    nodes {
+                        __typename
+                        ... on Issue {
+                            number
+                            title
+                            state
+                            url
+                            createdAt
+                            updatedAt
+                            author { login }
+                            repository { nameWithOwner }
+                            labels(first: 10) { nodes { name } }
+                            comments { totalCount }
+                        }
+                        ... on PullRequest {
+                            number
+                            title
+                            state
+                            url
+                            isDraft
+                            mergeable
+                            createdAt
+                            updatedAt
+                            author { login }
+                            repository { nameWithOwner }
+                            headRefName
+                            baseRefName
+                            additions
+                            deletions
+                            changedFiles
+                            commits { totalCount }
+                            comments { totalCount }
+                            reviews(first: 5) {
+                                nodes {
+                                    author { login }
+                                    state
+                                    submittedAt
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct SearchResponse {
+            search: IssueSearch,
+        }
+
+        let variables = serde_json::json!({
+            "q": query,
+            "first": first.clamp(1, 100),
+            "after": after,
+        });
+
+        let result: SearchResponse = self.graphql(gql, Some(variables)).await?;
+        Ok(result.search)
+    }
+
+    /// Run a REST search against `/search/{kind}`.
+    #[allow(clippy::too_many_arguments)]
+    async fn search_rest<R: for<'de> Deserialize<'de>>(
+        &self,
+        kind: &str,
+        query: &str,
+        sort: Option<&str>,
+        order: Option<&str>,
+        per_page: u32,
+        page: u32,
+        accept: &str,
+    ) -> Result<SearchResponse<R>> {
+        let per_page = per_page.clamp(1, 100);
+        let page = page.max(1);
+        let mut url = format!(
+            "{}/search/{}?q={}&per_page={}&page={}",
+            self.endpoints.rest,
+            kind,
+            encode_query(query),
+            per_page,
+            page
+        );
+        if let Some(sort) = sort {
+            url.push_str(&format!("&sort={}", encode_query(sort)));
+        }
+        if let Some(order) = order {
+            url.push_str(&format!("&order={}", encode_query(order)));
+        }
+
+        let response = self
+            .send(self.request_accepting(Method::GET, &url, accept), false)
+            .await
+            .context("Failed to send REST request")?;
+
+        let mut results: SearchResponse<R> = ensure_success(response)
+            .await?
+            .json()
+            .await
+            .context("Failed to parse JSON")?;
+        results.next_cursor = next_page_cursor(results.total_count, per_page, page);
+        Ok(results)
+    }
+}
+
+/// REST search only serves the first 1000 results of any query.
+const REST_SEARCH_RESULT_CAP: i64 = 1000;
+
+/// Raw search response envelope.
+#[derive(Deserialize)]
+struct SearchResponse<R> {
+    total_count: i64,
+    incomplete_results: bool,
+    items: Vec<R>,
+    #[serde(skip)]
+    next_cursor: Option<String>,
+}
+
+impl<R> SearchResponse<R> {
+    fn map<T>(self, f: impl FnMut(R) -> T) -> SearchResults<T> {
+        SearchResults {
+            total_count: self.total_count,
+            incomplete_results: self.incomplete_results,
+            items: self.items.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct CodeRaw {
+    name: String,
+    path: String,
+    sha: String,
+    html_url: String,
+    repository: RepoRef,
+    #[serde(default)]
+    text_matches: Vec<TextMatch>,
+}
+
+#[derive(Deserialize)]
+struct CommitRaw {
+    sha: String,
+    html_url: String,
+    commit: CommitData,
+    author: Option<LoginRef>,
+    repository: RepoRef,
+}
+
+#[derive(Deserialize)]
+struct CommitData {
+    message: String,
+    author: GitActor,
+}
+
+#[derive(Deserialize)]
+struct GitActor {
+    name: String,
+    date: String,
+}
+
+#[derive(Deserialize)]
+struct LoginRef {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct TopicRaw {
+    name: String,
+    display_name: Option<String>,
+    short_description: Option<String>,
+    featured: bool,
+    curated: bool,
+}
+
+#[derive(Clone, Deserialize)]
+struct StarRaw {
+    starred_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+struct RepoRef {
+    full_name: String,
+}
+
+#[derive(Deserialize)]
+struct TextMatch {
+    fragment: Option<String>,
+}
+
+/// Cursor for REST search: the next page number, while more results are reachable.
+fn next_page_cursor(total_count: i64, per_page: u32, page: u32) -> Option<String> {
+    let seen = per_page as i64 * page as i64;
+    (seen < total_count.min(REST_SEARCH_RESULT_CAP)).then(|| (page + 1).to_string())
+}
+
+/// Extract the `page` query parameter from a pagination URL.
+fn page_param(url: &str) -> Option<u32> {
+    let (_, query) = url.split_once('?')?;
+    query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("page="))
+        .and_then(|p| p.parse().ok())
+}
+
+/// Append `qualifier` unless the query already contains it.
+fn with_qualifier(query: &str, qualifier: &str) -> String {
+    if query.split_whitespace().any(|term| term == qualifier) {
+        query.to_string()
+    } else {
+        format!("{} {}", query, qualifier).trim().to_string()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IssueSearch {
+    issue_count: i64,
+    page_info: PageInfo,
+    nodes: Vec<SearchNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+impl PageInfo {
+    fn next_cursor(self) -> Option<String> {
+        self.end_cursor.filter(|_| self.has_next_page)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "__typename")]
+enum SearchNode {
+    Issue(IssueNode),
+    PullRequest(PrNode),
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IssueNode {
+    number: i32,
+    title: String,
+    state: String,
+    url: String,
+    created_at: String,
+    updated_at: String,
+    author: Option<AuthorNode>,
+    repository: RepoNode,
+    labels: LabelNodes,
+    comments: TotalCount,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PrNode {
+    number: i32,
+    title: String,
+    state: String,
+    url: String,
+    is_draft: bool,
+    mergeable: String,
+    created_at: String,
+    updated_at: String,
+    author: Option<AuthorNode>,
+    repository: RepoNode,
+    head_ref_name: String,
+    base_ref_name: String,
+    additions: i32,
+    deletions: i32,
+    changed_files: i32,
+    commits: TotalCount,
+    comments: TotalCount,
+    reviews: ReviewNodes,
+}
+
+#[derive(Deserialize)]
+struct AuthorNode {
+    login: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RepoNode {
+    name_with_owner: String,
+}
+
+#[derive(Deserialize)]
+struct LabelNodes {
+    nodes: Vec<LabelNode>,
+}
+
+#[derive(Deserialize)]
+struct LabelNode {
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TotalCount {
+    total_count: i32,
+}
+
+#[derive(Deserialize)]
+struct ReviewNodes {
+    nodes: Vec<ReviewNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReviewNode {
+    author: Option<AuthorNode>,
+    state: String,
+    submitted_at: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_page_cursor() {
+        assert_eq!(next_page_cursor(250, 100, 2).as_deref(), Some("3"));
+        assert_eq!(next_page_cursor(250, 100, 3), None);
+        // Results past 1000 are unreachable even when total_count is larger.
+        assert_eq!(next_page_cursor(50_000, 100, 10), None);
+    }
+
+    #[test]
+    fn test_page_param() {
+        assert_eq!(
+            page_param("https://api.github.com/repositories/1/stargazers?per_page=100&page=42"),
+            Some(42)
+        );
+        assert_eq!(
+            page_param("https://api.github.com/repos/o/r/stargazers"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_with_qualifier() {
+        assert_eq!(
+            with_qualifier("label:bug", "is:issue"),
+            "label:bug is:issue"
+        );
+        assert_eq!(
+            with_qualifier("is:pr review:required", "is:pr"),
+            "is:pr review:required"
+        );
+        assert_eq!(with_qualifier("", "is:pr"), "is:pr");
+    }
+
+    #[test]
+    fn test_search_node_typename() {
+        let nodes: Vec<SearchNode> = serde_json::from_value(serde_json::json!([
+            {"__typename": "Discussion"},
+            {
+                "__typename": "Issue",
+                "number": 1,
+                "title": "Bug",
+                "state": "OPEN",
+                "url": "https://github.com/o/r/issues/1",
+                "createdAt": "2024-01-14T00:00:00Z",
+                "updatedAt": "2024-01-14T00:00:00Z",
+                "author": null,
+                "repository": {"nameWithOwner": "o/r"},
+                "labels": {"nodes": []},
+                "comments": {"totalCount": 0}
+            }
+        ]))
+        .unwrap();
+
+        assert!(matches!(nodes[0], SearchNode::Other));
+        assert!(matches!(&nodes[1], SearchNode::Issue(i) if i.repository.name_with_owner == "o/r"));
+    }
+}
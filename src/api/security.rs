@@ -0,0 +1,1128 @@
+//! Security alert and advisory endpoints.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Added repository security overview
+//! 10/16/2026 - Added security feature toggles
+//! 10/16/2026 - Added repository security advisories
+//! 10/16/2026 - Added secret scanning alerts
+//! 10/16/2026 - Added code scanning alerts
+
+use anyhow::{Context, Result};
+use futures::stream::{self, StreamExt};
+use reqwest::Method;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use super::client::encode_query;
+use super::GitHubClient;
+use crate::models::{
+    AdvisoryVulnerability, AlertCounts, BranchProtection, CodeScanningAlert, DependabotAlert,
+    NewAdvisory, RepoAdvisory, SecretLocation, SecretScanningAlert, SecurityFeatures,
+    SecurityFeaturesUpdate, SecurityOverview,
+};
+
+/// Maximum concurrent location lookups when listing secret scanning alerts.
+const LOCATION_CONCURRENCY: usize = 8;
+
+/// Open alerts counted per type in a security overview.
+const OVERVIEW_ALERT_LIMIT: usize = 1000;
+
+/// Maximum repositories inspected concurrently for security overviews.
+const OVERVIEW_CONCURRENCY: usize = 4;
+
+/// Where to list alerts from.
+pub enum AlertScope {
+    Repo { owner: String, repo: String },
+    Org(String),
+}
+
+impl AlertScope {
+    fn base_path(&self) -> String {
+        match self {
+            AlertScope::Repo { owner, repo } => format!("/repos/{}/{}", owner, repo),
+            AlertScope::Org(org) => format!("/orgs/{}", org),
+        }
+    }
+}
+
+/// Filters for listing security alerts. Each list field is sent as a
+/// comma-separated query parameter.
+#[derive(Debug, Default)]
+pub struct AlertFilter {
+    pub state: Vec<String>,
+    pub severity: Vec<String>,
+    pub package: Vec<String>,
+    pub ecosystem: Vec<String>,
+}
+
+impl AlertFilter {
+    fn query(&self) -> String {
+        [
+            ("state", &self.state),
+            ("severity", &self.severity),
+            ("package", &self.package),
+            ("ecosystem", &self.ecosystem),
+        ]
+        .into_iter()
+        .filter(|(_, values)| !values.is_empty())
+        .map(|(key, values)| format!("&{}={}", key, encode_query(&values.join(","))))
+        .collect()
+    }
+}
+
+impl GitHubClient {
+    /// List Dependabot alerts for a repository (`owner/repo`) or an
+    /// organization, newest first.
+    pub async fn list_dependabot_alerts(
+        &self,
+        scope: &AlertScope,
+        filter: &AlertFilter,
+        limit: usize,
+    ) -> Result<Vec<DependabotAlert>> {
+        let path = format!(
+            "{}/dependabot/alerts?sort=created&direction=desc{}",
+            scope.base_path(),
+            filter.query()
+        );
+        let alerts: Vec<DependabotAlertRaw> = self.rest_get_paged(&path, limit).await?;
+        Ok(alerts.into_iter().map(Into::into).collect())
+    }
+
+    /// Dismiss a Dependabot alert with one of GitHub's dismissal reasons.
+    pub async fn dismiss_dependabot_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        reason: &str,
+        comment: Option<&str>,
+    ) -> Result<DependabotAlert> {
+        let mut body = serde_json::json!({
+            "state": "dismissed",
+            "dismissed_reason": reason,
+        });
+        if let Some(comment) = comment {
+            body["dismissed_comment"] = serde_json::json!(comment);
+        }
+
+        let alert: DependabotAlertRaw = self
+            .rest_patch(
+                &format!("/repos/{}/{}/dependabot/alerts/{}", owner, repo, number),
+                &body,
+            )
+            .await?;
+        Ok(alert.into())
+    }
+
+    /// List code scanning alerts for a repository or organization. The API
+    /// takes a single `state` and `severity`; `git_ref` limits results to
+    /// one branch or PR ref.
+    pub async fn list_code_scanning_alerts(
+        &self,
+        scope: &AlertScope,
+        state: Option<&str>,
+        severity: Option<&str>,
+        tool: Option<&str>,
+        git_ref: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<CodeScanningAlert>> {
+        let mut path = format!(
+            "{}/code-scanning/alerts?sort=created&direction=desc",
+            scope.base_path()
+        );
+        for (key, value) in [
+            ("state", state),
+            ("severity", severity),
+            ("tool_name", tool),
+            ("ref", git_ref),
+        ] {
+            if let Some(value) = value {
+                path.push_str(&format!("&{}={}", key, encode_query(value)));
+            }
+        }
+
+        let alerts: Vec<CodeScanningAlertRaw> = self.rest_get_paged(&path, limit).await?;
+        Ok(alerts.into_iter().map(Into::into).collect())
+    }
+
+    /// Dismiss (`dismissed`) or reopen (`open`) a code scanning alert.
+    /// Dismissal requires a reason: `false positive`, `won't fix`, or
+    /// `used in tests`.
+    pub async fn update_code_scanning_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        state: &str,
+        reason: Option<&str>,
+        comment: Option<&str>,
+    ) -> Result<CodeScanningAlert> {
+        let mut body = serde_json::json!({ "state": state });
+        if let Some(reason) = reason {
+            body["dismissed_reason"] = serde_json::json!(reason);
+        }
+        if let Some(comment) = comment {
+            body["dismissed_comment"] = serde_json::json!(comment);
+        }
+
+        let alert: CodeScanningAlertRaw = self
+            .rest_patch(
+                &format!("/repos/{}/{}/code-scanning/alerts/{}", owner, repo, number),
+                &body,
+            )
+            .await?;
+        Ok(alert.into())
+    }
+
+    /// List secret scanning alerts for a repository or organization,
+    /// optionally fetching where each secret was found.
+    pub async fn list_secret_scanning_alerts(
+        &self,
+        scope: &AlertScope,
+        state: Option<&str>,
+        secret_types: &[String],
+        limit: usize,
+        include_locations: bool,
+    ) -> Result<Vec<SecretScanningAlert>> {
+        let mut path = format!(
+            "{}/secret-scanning/alerts?sort=created&direction=desc",
+            scope.base_path()
+        );
+        if let Some(state) = state {
+            path.push_str(&format!("&state={}", encode_query(state)));
+        }
+        if !secret_types.is_empty() {
+            path.push_str(&format!(
+                "&secret_type={}",
+                encode_query(&secret_types.join(","))
+            ));
+        }
+
+        let raw: Vec<SecretAlertRaw> = self.rest_get_paged(&path, limit).await?;
+        let mut alerts: Vec<SecretScanningAlert> = raw.into_iter().map(Into::into).collect();
+
+        if include_locations {
+            let locations: Vec<Vec<SecretLocation>> = stream::iter(alerts.iter())
+                .map(|alert| async move {
+                    let repo = match (scope, &alert.repo) {
+                        (AlertScope::Repo { owner, repo }, _) => format!("{}/{}", owner, repo),
+                        (AlertScope::Org(_), Some(repo)) => repo.clone(),
+                        (AlertScope::Org(_), None) => return Vec::new(),
+                    };
+                    self.secret_alert_locations(&repo, alert.number)
+                        .await
+                        .unwrap_or_default()
+                })
+                .buffered(LOCATION_CONCURRENCY)
+                .collect()
+                .await;
+            for (alert, locations) in alerts.iter_mut().zip(locations) {
+                alert.locations = locations;
+            }
+        }
+
+        Ok(alerts)
+    }
+
+    async fn secret_alert_locations(
+        &self,
+        full_name: &str,
+        number: u64,
+    ) -> Result<Vec<SecretLocation>> {
+        let locations: Vec<SecretLocationRaw> = self
+            .rest_get_paged(
+                &format!(
+                    "/repos/{}/secret-scanning/alerts/{}/locations",
+                    full_name, number
+                ),
+                100,
+            )
+            .await?;
+        Ok(locations.into_iter().map(Into::into).collect())
+    }
+
+    /// Resolve (`resolved`) or reopen (`open`) a secret scanning alert.
+    /// Resolving requires a resolution: `false_positive`, `wont_fix`,
+    /// `revoked`, or `used_in_tests`.
+    pub async fn update_secret_scanning_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        state: &str,
+        resolution: Option<&str>,
+        comment: Option<&str>,
+    ) -> Result<SecretScanningAlert> {
+        let mut body = serde_json::json!({ "state": state });
+        if let Some(resolution) = resolution {
+            body["resolution"] = serde_json::json!(resolution);
+        }
+        if let Some(comment) = comment {
+            body["resolution_comment"] = serde_json::json!(comment);
+        }
+
+        let alert: SecretAlertRaw = self
+            .rest_patch(
+                &format!(
+                    "/repos/{}/{}/secret-scanning/alerts/{}",
+                    owner, repo, number
+                ),
+                &body,
+            )
+            .await?;
+        Ok(alert.into())
+    }
+
+    /// List a repository's security advisories, optionally by `state`
+    /// (`triage`, `draft`, `published`, `closed`).
+    pub async fn list_repo_advisories(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<RepoAdvisory>> {
+        let mut path = format!(
+            "/repos/{}/{}/security-advisories?sort=created&direction=desc",
+            owner, repo
+        );
+        if let Some(state) = state {
+            path.push_str(&format!("&state={}", encode_query(state)));
+        }
+
+        let advisories: Vec<AdvisoryRepoRaw> = self.rest_get_paged(&path, limit).await?;
+        Ok(advisories.into_iter().map(Into::into).collect())
+    }
+
+    /// Draft a repository security advisory.
+    pub async fn create_repo_advisory(
+        &self,
+        owner: &str,
+        repo: &str,
+        advisory: &NewAdvisory,
+    ) -> Result<RepoAdvisory> {
+        let vulnerabilities: Vec<Value> = advisory
+            .vulnerabilities
+            .iter()
+            .map(|v| {
+                serde_json::json!({
+                    "package": { "ecosystem": v.ecosystem, "name": v.package },
+                    "vulnerable_version_range": v.vulnerable_version_range,
+                    "patched_versions": v.patched_versions,
+                })
+            })
+            .collect();
+        let mut body = serde_json::json!({
+            "summary": advisory.summary,
+            "description": advisory.description,
+            "vulnerabilities": vulnerabilities,
+            "cwe_ids": advisory.cwe_ids,
+        });
+        if let Some(severity) = &advisory.severity {
+            body["severity"] = serde_json::json!(severity);
+        }
+        if let Some(cvss_vector) = &advisory.cvss_vector {
+            body["cvss_vector_string"] = serde_json::json!(cvss_vector);
+        }
+        if let Some(cve_id) = &advisory.cve_id {
+            body["cve_id"] = serde_json::json!(cve_id);
+        }
+
+        let created: AdvisoryRepoRaw = self
+            .rest_post(
+                &format!("/repos/{}/{}/security-advisories", owner, repo),
+                &body,
+            )
+            .await?;
+        Ok(created.into())
+    }
+
+    /// Read which security features are enabled on a repository. Fields are
+    /// `None` when the token cannot see them (admin access is required).
+    pub async fn get_security_features(&self, owner: &str, repo: &str) -> Result<SecurityFeatures> {
+        let url = format!(
+            "{}/repos/{}/{}/vulnerability-alerts",
+            self.endpoints.rest, owner, repo
+        );
+        let response = self
+            .send(self.request(Method::GET, &url), false)
+            .await
+            .context("Failed to send REST request")?;
+        // 204 means enabled and 404 disabled; anything else (e.g. 403) is unknown.
+        let dependabot_alerts = match response.status() {
+            reqwest::StatusCode::NO_CONTENT => Some(true),
+            reqwest::StatusCode::NOT_FOUND => Some(false),
+            _ => None,
+        };
+
+        #[derive(Deserialize)]
+        struct AutomatedFixes {
+            enabled: bool,
+        }
+
+        let dependabot_security_updates = self
+            .rest_get::<AutomatedFixes>(&format!(
+                "/repos/{}/{}/automated-security-fixes",
+                owner, repo
+            ))
+            .await
+            .ok()
+            .map(|f| f.enabled);
+
+        #[derive(Deserialize)]
+        struct RepoAnalysis {
+            security_and_analysis: Option<Value>,
+        }
+
+        let analysis: RepoAnalysis = self.rest_get(&format!("/repos/{}/{}", owner, repo)).await?;
+        let status = |feature: &str| {
+            analysis
+                .security_and_analysis
+                .as_ref()
+                .and_then(|a| a.pointer(&format!("/{}/status", feature)))
+                .and_then(|s| s.as_str())
+                .map(|s| s == "enabled")
+        };
+
+        Ok(SecurityFeatures {
+            repo: format!("{}/{}", owner, repo),
+            dependabot_alerts,
+            dependabot_security_updates,
+            secret_scanning: status("secret_scanning"),
+            push_protection: status("secret_scanning_push_protection"),
+        })
+    }
+
+    /// Enable or disable security features, leaving `None` fields unchanged,
+    /// and return the resulting state.
+    pub async fn update_security_features(
+        &self,
+        owner: &str,
+        repo: &str,
+        update: &SecurityFeaturesUpdate,
+    ) -> Result<SecurityFeatures> {
+        let base = format!("/repos/{}/{}", owner, repo);
+
+        // Security updates depend on alerts: enable alerts first, and turn
+        // security updates off before disabling alerts.
+        if update.dependabot_alerts == Some(true) {
+            self.set_repo_feature(&format!("{}/vulnerability-alerts", base), true)
+                .await?;
+        }
+        if let Some(enabled) = update.dependabot_security_updates {
+            self.set_repo_feature(&format!("{}/automated-security-fixes", base), enabled)
+                .await?;
+        }
+        if update.dependabot_alerts == Some(false) {
+            self.set_repo_feature(&format!("{}/vulnerability-alerts", base), false)
+                .await?;
+        }
+
+        let mut analysis = serde_json::Map::new();
+        for (feature, enabled) in [
+            ("secret_scanning", update.secret_scanning),
+            ("secret_scanning_push_protection", update.push_protection),
+        ] {
+            if let Some(enabled) = enabled {
+                let status = if enabled { "enabled" } else { "disabled" };
+                analysis.insert(feature.to_string(), serde_json::json!({ "status": status }));
+            }
+        }
+        if !analysis.is_empty() {
+            let body = serde_json::json!({ "security_and_analysis": analysis });
+            let _: Value = self.rest_patch(&base, &body).await?;
+        }
+
+        self.get_security_features(owner, repo).await
+    }
+
+    /// Toggle an endpoint-style feature: PUT enables, DELETE disables.
+    async fn set_repo_feature(&self, path: &str, enabled: bool) -> Result<()> {
+        if enabled {
+            self.rest_send(Method::PUT, path, None).await?;
+            Ok(())
+        } else {
+            self.rest_delete(path).await
+        }
+    }
+
+    /// Security posture of a repository: default branch protection and
+    /// rules, enabled features, and open alert counts by severity. Sections
+    /// the token cannot read are reported as unknown rather than failing.
+    pub async fn get_security_overview(&self, owner: &str, repo: &str) -> Result<SecurityOverview> {
+        #[derive(Deserialize)]
+        struct DefaultBranch {
+            default_branch: String,
+        }
+
+        let branch = self
+            .rest_get::<DefaultBranch>(&format!("/repos/{}/{}", owner, repo))
+            .await?
+            .default_branch;
+        let scope = AlertScope::Repo {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        };
+        let open = AlertFilter {
+            state: vec!["open".to_string()],
+            ..Default::default()
+        };
+
+        let rules_path = format!("/repos/{}/{}/rules/branches/{}", owner, repo, branch);
+
+        let (protection, rules, features, dependabot, code_scanning, secret_scanning) = futures::join!(
+            self.get_branch_protection(owner, repo, &branch),
+            self.rest_get::<Vec<BranchRuleRaw>>(&rules_path),
+            self.get_security_features(owner, repo),
+            self.list_dependabot_alerts(&scope, &open, OVERVIEW_ALERT_LIMIT),
+            self.list_code_scanning_alerts(
+                &scope,
+                Some("open"),
+                None,
+                None,
+                None,
+                OVERVIEW_ALERT_LIMIT
+            ),
+            self.list_secret_scanning_alerts(
+                &scope,
+                Some("open"),
+                &[],
+                OVERVIEW_ALERT_LIMIT,
+                false
+            ),
+        );
+
+        let protection = protection?;
+        let branch_rules: Vec<String> = rules
+            .map(|rules| rules.into_iter().map(|r| r.rule_type).collect())
+            .unwrap_or_default();
+        let signed_commits_required = if branch_rules.iter().any(|r| r == "required_signatures") {
+            Some(true)
+        } else {
+            protection
+                .as_ref()
+                .map(|p| p.as_ref().is_some_and(|p| p.required_signatures))
+        };
+
+        Ok(SecurityOverview {
+            repo: format!("{}/{}", owner, repo),
+            default_branch: branch,
+            protection_readable: protection.is_some(),
+            branch_protection: protection.flatten(),
+            branch_rules,
+            signed_commits_required,
+            features: features?,
+            dependabot_alerts: dependabot
+                .ok()
+                .map(|alerts| count_by_severity(alerts.iter().map(|a| Some(a.severity.as_str())))),
+            code_scanning_alerts: code_scanning
+                .ok()
+                .map(|alerts| count_by_severity(alerts.iter().map(|a| a.severity.as_deref()))),
+            secret_scanning_alerts: secret_scanning.ok().map(|alerts| alerts.len()),
+        })
+    }
+
+    /// Security overviews for several `owner/repo` names, in input order,
+    /// each paired with its repository name.
+    pub async fn get_security_overviews(
+        &self,
+        repos: &[String],
+    ) -> Vec<(String, Result<SecurityOverview>)> {
+        stream::iter(repos)
+            .map(|full_name| async move {
+                let result = match full_name.split_once('/') {
+                    Some((owner, repo)) => self.get_security_overview(owner, repo).await,
+                    None => Err(anyhow::anyhow!("Invalid repo format. Use owner/repo")),
+                };
+                (full_name.clone(), result)
+            })
+            .buffered(OVERVIEW_CONCURRENCY)
+            .collect()
+            .await
+    }
+
+    /// Classic branch protection. `Ok(None)` when the token cannot read it
+    /// (admin only) and `Ok(Some(None))` when the branch is unprotected.
+    async fn get_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Option<Option<BranchProtection>>> {
+        let url = format!(
+            "{}/repos/{}/{}/branches/{}/protection",
+            self.endpoints.rest, owner, repo, branch
+        );
+        let response = self
+            .send(self.request(Method::GET, &url), false)
+            .await
+            .context("Failed to send REST request")?;
+
+        match response.status() {
+            reqwest::StatusCode::NOT_FOUND => Ok(Some(None)),
+            status if status.is_success() => {
+                let raw: BranchProtectionRaw =
+                    response.json().await.context("Failed to parse JSON")?;
+                Ok(Some(Some(raw.into())))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Tally alerts by severity; alerts without one count as `unknown`.
+fn count_by_severity<'a>(severities: impl Iterator<Item = Option<&'a str>>) -> AlertCounts {
+    let mut by_severity = BTreeMap::new();
+    let mut total = 0;
+    for severity in severities {
+        *by_severity
+            .entry(severity.unwrap_or("unknown").to_string())
+            .or_insert(0) += 1;
+        total += 1;
+    }
+    AlertCounts { total, by_severity }
+}
+
+/// Raw Dependabot alert from REST API.
+#[derive(Deserialize)]
+struct DependabotAlertRaw {
+    number: u64,
+    state: String,
+    dependency: DependencyRaw,
+    security_advisory: AdvisoryRaw,
+    security_vulnerability: VulnerabilityRaw,
+    html_url: String,
+    created_at: String,
+    dismissed_reason: Option<String>,
+    /// Present on organization-level listings.
+    repository: Option<AlertRepoRaw>,
+}
+
+#[derive(Deserialize)]
+struct DependencyRaw {
+    package: PackageRaw,
+    manifest_path: Option<String>,
+    scope: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PackageRaw {
+    ecosystem: String,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct AdvisoryRaw {
+    ghsa_id: String,
+    cve_id: Option<String>,
+    summary: String,
+    severity: String,
+}
+
+#[derive(Deserialize)]
+struct VulnerabilityRaw {
+    vulnerable_version_range: String,
+    first_patched_version: Option<VersionRaw>,
+}
+
+#[derive(Deserialize)]
+struct VersionRaw {
+    identifier: String,
+}
+
+#[derive(Deserialize)]
+struct AlertRepoRaw {
+    full_name: String,
+}
+
+impl From<DependabotAlertRaw> for DependabotAlert {
+    fn from(a: DependabotAlertRaw) -> Self {
+        DependabotAlert {
+            number: a.number,
+            repo: a.repository.map(|r| r.full_name),
+            state: a.state,
+            severity: a.security_advisory.severity,
+            package: a.dependency.package.name,
+            ecosystem: a.dependency.package.ecosystem,
+            manifest_path: a.dependency.manifest_path,
+            scope: a.dependency.scope,
+            ghsa_id: a.security_advisory.ghsa_id,
+            cve_id: a.security_advisory.cve_id,
+            summary: a.security_advisory.summary,
+            vulnerable_version_range: a.security_vulnerability.vulnerable_version_range,
+            patched_version: a
+                .security_vulnerability
+                .first_patched_version
+                .map(|v| v.identifier),
+            dismissed_reason: a.dismissed_reason,
+            created_at: a.created_at,
+            url: a.html_url,
+        }
+    }
+}
+
+/// Raw code scanning alert from REST API.
+#[derive(Deserialize)]
+struct CodeScanningAlertRaw {
+    number: u64,
+    state: String,
+    rule: RuleRaw,
+    tool: ToolRaw,
+    most_recent_instance: Option<InstanceRaw>,
+    html_url: String,
+    created_at: String,
+    dismissed_reason: Option<String>,
+    repository: Option<AlertRepoRaw>,
+}
+
+#[derive(Deserialize)]
+struct RuleRaw {
+    id: Option<String>,
+    description: Option<String>,
+    severity: Option<String>,
+    security_severity_level: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ToolRaw {
+    name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct InstanceRaw {
+    #[serde(rename = "ref")]
+    git_ref: Option<String>,
+    location: Option<LocationRaw>,
+    message: Option<MessageRaw>,
+}
+
+#[derive(Deserialize)]
+struct LocationRaw {
+    path: Option<String>,
+    start_line: Option<u32>,
+    end_line: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct MessageRaw {
+    text: Option<String>,
+}
+
+impl From<CodeScanningAlertRaw> for CodeScanningAlert {
+    fn from(a: CodeScanningAlertRaw) -> Self {
+        let (git_ref, location, message) = match a.most_recent_instance {
+            Some(i) => (i.git_ref, i.location, i.message.and_then(|m| m.text)),
+            None => (None, None, None),
+        };
+        let (path, start_line, end_line) = match location {
+            Some(l) => (l.path, l.start_line, l.end_line),
+            None => (None, None, None),
+        };
+
+        CodeScanningAlert {
+            number: a.number,
+            repo: a.repository.map(|r| r.full_name),
+            state: a.state,
+            rule_id: a.rule.id,
+            rule_description: a.rule.description,
+            // Security rules carry a CVSS-style level; fall back to the
+            // rule's own error/warning/note severity.
+            severity: a.rule.security_severity_level.or(a.rule.severity),
+            tool: a.tool.name,
+            path,
+            start_line,
+            end_line,
+            message,
+            git_ref,
+            dismissed_reason: a.dismissed_reason,
+            created_at: a.created_at,
+            url: a.html_url,
+        }
+    }
+}
+
+/// Raw repository security advisory from REST API.
+#[derive(Deserialize)]
+struct AdvisoryRepoRaw {
+    ghsa_id: String,
+    cve_id: Option<String>,
+    summary: String,
+    severity: Option<String>,
+    state: String,
+    #[serde(default)]
+    cwe_ids: Option<Vec<String>>,
+    #[serde(default)]
+    vulnerabilities: Option<Vec<AdvisoryVulnerabilityRaw>>,
+    created_at: String,
+    published_at: Option<String>,
+    html_url: String,
+}
+
+#[derive(Deserialize)]
+struct AdvisoryVulnerabilityRaw {
+    package: Option<PackageRaw>,
+    vulnerable_version_range: Option<String>,
+    patched_versions: Option<String>,
+}
+
+impl From<AdvisoryRepoRaw> for RepoAdvisory {
+    fn from(a: AdvisoryRepoRaw) -> Self {
+        RepoAdvisory {
+            ghsa_id: a.ghsa_id,
+            cve_id: a.cve_id,
+            summary: a.summary,
+            severity: a.severity,
+            state: a.state,
+            cwe_ids: a.cwe_ids.unwrap_or_default(),
+            vulnerabilities: a
+                .vulnerabilities
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|v| {
+                    let package = v.package?;
+                    Some(AdvisoryVulnerability {
+                        ecosystem: package.ecosystem,
+                        package: package.name,
+                        vulnerable_version_range: v.vulnerable_version_range,
+                        patched_versions: v.patched_versions,
+                    })
+                })
+                .collect(),
+            created_at: a.created_at,
+            published_at: a.published_at,
+            url: a.html_url,
+        }
+    }
+}
+
+/// Raw secret scanning alert from REST API. The secret value itself is
+/// deliberately not deserialized so it never leaves the daemon.
+#[derive(Deserialize)]
+struct SecretAlertRaw {
+    number: u64,
+    state: String,
+    secret_type: String,
+    secret_type_display_name: Option<String>,
+    validity: Option<String>,
+    resolution: Option<String>,
+    resolved_by: Option<ResolverRaw>,
+    html_url: String,
+    created_at: String,
+    repository: Option<AlertRepoRaw>,
+}
+
+#[derive(Deserialize)]
+struct ResolverRaw {
+    login: String,
+}
+
+impl From<SecretAlertRaw> for SecretScanningAlert {
+    fn from(a: SecretAlertRaw) -> Self {
+        SecretScanningAlert {
+            number: a.number,
+            repo: a.repository.map(|r| r.full_name),
+            state: a.state,
+            secret_type: a.secret_type,
+            secret_type_display_name: a.secret_type_display_name,
+            validity: a.validity,
+            resolution: a.resolution,
+            resolved_by: a.resolved_by.map(|r| r.login),
+            created_at: a.created_at,
+            url: a.html_url,
+            locations: Vec::new(),
+        }
+    }
+}
+
+/// Raw secret location from REST API. `details` varies by location type.
+#[derive(Deserialize)]
+struct SecretLocationRaw {
+    #[serde(rename = "type")]
+    location_type: String,
+    #[serde(default)]
+    details: Value,
+}
+
+impl From<SecretLocationRaw> for SecretLocation {
+    fn from(l: SecretLocationRaw) -> Self {
+        let field = |key: &str| {
+            l.details
+                .get(key)
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+        };
+        // Commits link to the blob; other locations (issue titles, comments,
+        // wiki pages, ...) carry a single type-specific `*_url`.
+        let url = field("blob_url").or_else(|| {
+            l.details.as_object().and_then(|d| {
+                d.iter()
+                    .find(|(k, v)| k.ends_with("_url") && v.is_string())
+                    .and_then(|(_, v)| v.as_str().map(|s| s.to_string()))
+            })
+        });
+
+        SecretLocation {
+            path: field("path"),
+            start_line: l
+                .details
+                .get("start_line")
+                .and_then(|v| v.as_u64())
+                .map(|n| n as u32),
+            commit_sha: field("commit_sha"),
+            url,
+            location_type: l.location_type,
+        }
+    }
+}
+
+/// Raw branch protection from REST API.
+#[derive(Deserialize)]
+struct BranchProtectionRaw {
+    required_status_checks: Option<StatusChecksRaw>,
+    required_pull_request_reviews: Option<ReviewsRaw>,
+    enforce_admins: Option<EnabledRaw>,
+    required_signatures: Option<EnabledRaw>,
+    required_linear_history: Option<EnabledRaw>,
+    allow_force_pushes: Option<EnabledRaw>,
+    allow_deletions: Option<EnabledRaw>,
+}
+
+#[derive(Deserialize)]
+struct StatusChecksRaw {
+    #[serde(default)]
+    strict: bool,
+    #[serde(default)]
+    contexts: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct ReviewsRaw {
+    #[serde(default)]
+    required_approving_review_count: u32,
+    #[serde(default)]
+    dismiss_stale_reviews: bool,
+    #[serde(default)]
+    require_code_owner_reviews: bool,
+}
+
+#[derive(Deserialize)]
+struct EnabledRaw {
+    enabled: bool,
+}
+
+/// Raw active branch rule from a repository or organization ruleset.
+#[derive(Deserialize)]
+struct BranchRuleRaw {
+    #[serde(rename = "type")]
+    rule_type: String,
+}
+
+impl From<BranchProtectionRaw> for BranchProtection {
+    fn from(p: BranchProtectionRaw) -> Self {
+        let enabled = |flag: &Option<EnabledRaw>| flag.as_ref().is_some_and(|f| f.enabled);
+        let (required_reviews, dismiss_stale_reviews, require_code_owner_reviews) =
+            match &p.required_pull_request_reviews {
+                Some(r) => (
+                    Some(r.required_approving_review_count),
+                    r.dismiss_stale_reviews,
+                    r.require_code_owner_reviews,
+                ),
+                None => (None, false, false),
+            };
+
+        BranchProtection {
+            required_reviews,
+            dismiss_stale_reviews,
+            require_code_owner_reviews,
+            required_status_checks: p
+                .required_status_checks
+                .as_ref()
+                .map(|c| c.contexts.clone())
+                .unwrap_or_default(),
+            strict_status_checks: p.required_status_checks.as_ref().is_some_and(|c| c.strict),
+            enforce_admins: enabled(&p.enforce_admins),
+            required_signatures: enabled(&p.required_signatures),
+            linear_history: enabled(&p.required_linear_history),
+            allow_force_pushes: enabled(&p.allow_force_pushes),
+            allow_deletions: enabled(&p.allow_deletions),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_location_from_raw() {
+        let commit: SecretLocationRaw = serde_json::from_value(serde_json::json!({
+            "type": "commit",
+            "details": {
+                "path": "config/secrets.yml",
+                "start_line": 3,
+                "commit_sha": "f14d7debf9775f957cf4f1e8176da0786431f72b",
+                "commit_url": "https://api.github.com/repos/o/r/git/commits/f14d7de",
+                "blob_url": "https://api.github.com/repos/o/r/git/blobs/af5626b",
+            },
+        }))
+        .unwrap();
+        let location = SecretLocation::from(commit);
+        assert_eq!(location.path.as_deref(), Some("config/secrets.yml"));
+        assert_eq!(location.start_line, Some(3));
+        assert_eq!(
+            location.url.as_deref(),
+            Some("https://api.github.com/repos/o/r/git/blobs/af5626b")
+        );
+
+        let issue: SecretLocationRaw = serde_json::from_value(serde_json::json!({
+            "type": "issue_comment",
+            "details": {"issue_comment_url": "https://api.github.com/repos/o/r/issues/comments/1"},
+        }))
+        .unwrap();
+        let location = SecretLocation::from(issue);
+        assert_eq!(location.location_type, "issue_comment");
+        assert_eq!(
+            location.url.as_deref(),
+            Some("https://api.github.com/repos/o/r/issues/comments/1")
+        );
+    }
+
+    #[test]
+    fn test_dependabot_alert_from_raw() {
+        let raw: DependabotAlertRaw = serde_json::from_value(serde_json::json!({
+            "number": 2,
+            "state": "open",
+            "dependency": {
+                "package": {"ecosystem": "npm", "name": "lodash"},
+                "manifest_path": "package-lock.json",
+                "scope": "runtime"
+            },
+            "security_advisory": {
+                "ghsa_id": "GHSA-jf85-cpcp-j695",
+                "cve_id": "CVE-2019-10744",
+                "summary": "Prototype Pollution in lodash",
+                "severity": "critical"
+            },
+            "security_vulnerability": {
+                "vulnerable_version_range": "< 4.17.12",
+                "first_patched_version": {"identifier": "4.17.12"}
+            },
+            "html_url": "https://github.com/o/r/security/dependabot/2",
+            "created_at": "2024-01-15T00:00:00Z",
+            "dismissed_reason": null,
+            "repository": {"full_name": "o/r"}
+        }))
+        .unwrap();
+
+        let alert = DependabotAlert::from(raw);
+        assert_eq!(alert.package, "lodash");
+        assert_eq!(alert.severity, "critical");
+        assert_eq!(alert.patched_version.as_deref(), Some("4.17.12"));
+        assert_eq!(alert.repo.as_deref(), Some("o/r"));
+    }
+
+    #[test]
+    fn test_code_scanning_alert_from_raw() {
+        let raw = |rule: Value, instance: Value| -> CodeScanningAlertRaw {
+            serde_json::from_value(serde_json::json!({
+                "number": 4,
+                "state": "open",
+                "rule": rule,
+                "tool": {"name": "CodeQL"},
+                "most_recent_instance": instance,
+                "html_url": "https://github.com/o/r/security/code-scanning/4",
+                "created_at": "2024-01-15T00:00:00Z"
+            }))
+            .unwrap()
+        };
+
+        let alert = CodeScanningAlert::from(raw(
+            serde_json::json!({"id": "js/xss", "severity": "error", "security_severity_level": "high"}),
+            serde_json::json!({
+                "ref": "refs/heads/main",
+                "location": {"path": "src/app.js", "start_line": 10, "end_line": 12},
+                "message": {"text": "Cross-site scripting"}
+            }),
+        ));
+        assert_eq!(alert.severity.as_deref(), Some("high"));
+        assert_eq!(alert.path.as_deref(), Some("src/app.js"));
+        assert_eq!(alert.end_line, Some(12));
+        assert_eq!(alert.message.as_deref(), Some("Cross-site scripting"));
+
+        // Non-security rules fall back to their own severity
+        let alert = CodeScanningAlert::from(raw(
+            serde_json::json!({"id": "js/unused", "severity": "note"}),
+            Value::Null,
+        ));
+        assert_eq!(alert.severity.as_deref(), Some("note"));
+        assert!(alert.path.is_none() && alert.git_ref.is_none());
+    }
+
+    #[test]
+    fn test_repo_advisory_skips_vulnerabilities_without_package() {
+        let raw: AdvisoryRepoRaw = serde_json::from_value(serde_json::json!({
+            "ghsa_id": "GHSA-abcd-1234-efgh",
+            "cve_id": null,
+            "summary": "Path traversal",
+            "severity": "high",
+            "state": "draft",
+            "cwe_ids": null,
+            "vulnerabilities": [
+                {"package": {"ecosystem": "npm", "name": "left-pad"}, "vulnerable_version_range": "< 1.3.0"},
+                {"package": null, "vulnerable_version_range": "< 2.0.0"}
+            ],
+            "created_at": "2024-01-15T00:00:00Z",
+            "published_at": null,
+            "html_url": "https://github.com/o/r/security/advisories/GHSA-abcd-1234-efgh"
+        }))
+        .unwrap();
+
+        let advisory = RepoAdvisory::from(raw);
+        assert!(advisory.cwe_ids.is_empty());
+        assert_eq!(advisory.vulnerabilities.len(), 1);
+        assert_eq!(advisory.vulnerabilities[0].package, "left-pad");
+        assert!(advisory.vulnerabilities[0].patched_versions.is_none());
+    }
+
+    #[test]
+    fn test_branch_protection_from_raw() {
+        let raw: BranchProtectionRaw = serde_json::from_value(serde_json::json!({
+            "required_status_checks": {"strict": true, "contexts": ["ci/test"]},
+            "required_pull_request_reviews": {"required_approving_review_count": 2},
+            "enforce_admins": {"enabled": true},
+            "required_signatures": {"enabled": false},
+            "allow_force_pushes": {"enabled": false},
+        }))
+        .unwrap();
+
+        let protection = BranchProtection::from(raw);
+        assert_eq!(protection.required_reviews, Some(2));
+        assert_eq!(protection.required_status_checks, vec!["ci/test"]);
+        assert!(protection.strict_status_checks);
+        assert!(protection.enforce_admins);
+        assert!(!protection.required_signatures);
+        assert!(!protection.linear_history);
+    }
+
+    #[test]
+    fn test_count_by_severity() {
+        let counts =
+            count_by_severity([Some("high"), Some("critical"), Some("high"), None].into_iter());
+        assert_eq!(counts.total, 4);
+        assert_eq!(counts.by_severity["high"], 2);
+        assert_eq!(counts.by_severity["unknown"], 1);
+    }
+
+    #[test]
+    fn test_alert_filter_query() {
+        let filter = AlertFilter {
+            state: vec!["open".to_string()],
+            severity: vec!["high".to_string(), "critical".to_string()],
+            ..Default::default()
+        };
+
+        assert_eq!(filter.query(), "&state=open&severity=high%2Ccritical");
+        assert_eq!(AlertFilter::default().query(), "");
+    }
+}
@@ -0,0 +1,197 @@
+//! SAML single sign-on enforcement.
+//!
+//! Organizations that enforce SAML SSO reject tokens that have not been
+//! authorized for them. GitHub says so in the `X-GitHub-SSO` header, either
+//! `required; url=<authorization URL>` on a rejected request, or
+//! `partial-results; organizations=<ids>` when a listing silently left out
+//! those organizations' resources.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Initial implementation
+
+use anyhow::Result;
+use futures::stream::{self, StreamExt};
+use reqwest::header::HeaderMap;
+use reqwest::Method;
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+
+use super::GitHubClient;
+use crate::models::{SsoOrganization, SsoStatus};
+
+/// Concurrent organization probes in `sso_status`.
+const SSO_PROBE_CONCURRENCY: usize = 8;
+
+/// A request was refused because the token is not authorized for an
+/// organization's SAML SSO.
+#[derive(Debug)]
+pub struct SsoRequired {
+    /// Where the user can authorize the token for the organization.
+    pub url: String,
+}
+
+impl std::fmt::Display for SsoRequired {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SAML SSO authorization required: the organization enforces single sign-on \
+             and this token is not authorized for it. Authorize it at {}",
+            self.url
+        )
+    }
+}
+
+impl std::error::Error for SsoRequired {}
+
+/// A parsed `X-GitHub-SSO` header.
+#[derive(Debug, PartialEq)]
+enum SsoHeader {
+    Required(String),
+    PartialResults(Vec<u64>),
+}
+
+fn parse_header(value: &str) -> Option<SsoHeader> {
+    let (kind, rest) = value.split_once(';').unwrap_or((value, ""));
+    let (key, data) = rest.trim().split_once('=')?;
+    match (kind.trim(), key.trim()) {
+        ("required", "url") => Some(SsoHeader::Required(data.trim().to_string())),
+        ("partial-results", "organizations") => Some(SsoHeader::PartialResults(
+            data.split(',')
+                .filter_map(|id| id.trim().parse().ok())
+                .collect(),
+        )),
+        _ => None,
+    }
+}
+
+fn sso_header(headers: &HeaderMap) -> Option<SsoHeader> {
+    headers
+        .get("x-github-sso")
+        .and_then(|v| v.to_str().ok())
+        .and_then(parse_header)
+}
+
+/// The SSO error a failed response carries, if any.
+pub(super) fn required(headers: &HeaderMap) -> Option<SsoRequired> {
+    match sso_header(headers)? {
+        SsoHeader::Required(url) => Some(SsoRequired { url }),
+        SsoHeader::PartialResults(_) => None,
+    }
+}
+
+/// Organization IDs GitHub has left out of results for lack of SSO
+/// authorization.
+#[derive(Default)]
+pub(super) struct SsoOrgs(Mutex<BTreeSet<u64>>);
+
+impl SsoOrgs {
+    pub(super) fn record(&self, headers: &HeaderMap) {
+        if let Some(SsoHeader::PartialResults(ids)) = sso_header(headers) {
+            self.0.lock().unwrap_or_else(|e| e.into_inner()).extend(ids);
+        }
+    }
+
+    fn take(&self) -> BTreeSet<u64> {
+        std::mem::take(&mut *self.0.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+#[derive(Deserialize)]
+struct OrgRaw {
+    id: u64,
+    login: String,
+}
+
+impl GitHubClient {
+    /// Which of the viewer's organizations the token is authorized for.
+    ///
+    /// Each listed organization is probed with a one-item repository
+    /// listing; organizations GitHub withheld from the listing itself (or
+    /// from earlier partial results) are looked up by ID.
+    pub async fn sso_status(&self) -> Result<SsoStatus> {
+        // Start from a clean slate so organizations authorized since are not
+        // reported again
+        let mut withheld = self.sso_orgs.take();
+        let orgs: Vec<OrgRaw> = self.rest_get_paged("/user/orgs", usize::MAX).await?;
+
+        let probes: Vec<Option<String>> = stream::iter(orgs.iter())
+            .map(|org| self.sso_probe(&org.login))
+            .buffered(SSO_PROBE_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut status = SsoStatus {
+            authorized: Vec::new(),
+            unauthorized: Vec::new(),
+        };
+        for (org, probe) in orgs.into_iter().zip(probes) {
+            withheld.remove(&org.id);
+            match probe {
+                Some(url) => status.unauthorized.push(SsoOrganization {
+                    id: Some(org.id),
+                    login: Some(org.login),
+                    authorize_url: url,
+                }),
+                None => status.authorized.push(org.login),
+            }
+        }
+
+        // Withheld organizations carry no authorization URL; point at the
+        // organization's SSO page instead
+        withheld.extend(self.sso_orgs.take());
+        for id in withheld {
+            let login = self
+                .rest_get::<OrgRaw>(&format!("/user/{}", id))
+                .await
+                .ok()
+                .map(|org| org.login);
+            let authorize_url = match &login {
+                Some(login) => format!("https://{}/orgs/{}/sso", self.endpoints.host, login),
+                None => format!("https://{}/settings/tokens", self.endpoints.host),
+            };
+            status.unauthorized.push(SsoOrganization {
+                id: Some(id),
+                login,
+                authorize_url,
+            });
+        }
+
+        Ok(status)
+    }
+
+    /// The SSO authorization URL if the token is refused by `org`.
+    async fn sso_probe(&self, org: &str) -> Option<String> {
+        let url = format!("{}/orgs/{}/repos?per_page=1", self.endpoints.rest, org);
+        let response = self
+            .send(self.request(Method::GET, &url), false)
+            .await
+            .ok()?;
+        if response.status().is_success() {
+            return None;
+        }
+        required(response.headers()).map(|sso| sso.url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_header() {
+        assert_eq!(
+            parse_header(
+                "required; url=https://github.com/orgs/octo/sso?authorization_request=abc"
+            ),
+            Some(SsoHeader::Required(
+                "https://github.com/orgs/octo/sso?authorization_request=abc".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_header("partial-results; organizations=21955855,20582480"),
+            Some(SsoHeader::PartialResults(vec![21955855, 20582480]))
+        );
+        assert_eq!(parse_header("something-new"), None);
+    }
+}
@@ -0,0 +1,269 @@
+//! Repository statistics endpoints.
+//!
+//! GitHub computes these in the background: the first request for a repo
+//! returns 202 Accepted with no body, and the data appears after a short
+//! wait. Requests are retried with backoff before reporting the stats as
+//! still pending.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Added per-contributor weekly stats
+//! 10/16/2026 - Initial implementation with commit activity
+
+use anyhow::{Context, Result};
+use reqwest::{Method, StatusCode};
+use serde::Deserialize;
+use std::time::Duration;
+
+use super::GitHubClient;
+use crate::models::{CommitActivity, ContributorStats, ContributorWeek, WeekActivity};
+
+/// Attempts made while GitHub is still computing statistics.
+const STATS_ATTEMPTS: u32 = 4;
+
+/// Wait before the first retry; doubles on each attempt.
+const STATS_BACKOFF: Duration = Duration::from_secs(1);
+
+impl GitHubClient {
+    /// Weekly commit counts for the last year, with per-day breakdown and
+    /// the owner's share of each week.
+    pub async fn get_commit_activity(
+        &self,
+        owner: &str,
+        repo: &str,
+        weeks: usize,
+    ) -> Result<CommitActivity> {
+        #[derive(Deserialize)]
+        struct ParticipationRaw {
+            owner: Vec<u32>,
+        }
+
+        let activity_path = format!("/repos/{}/{}/stats/commit_activity", owner, repo);
+        let participation_path = format!("/repos/{}/{}/stats/participation", owner, repo);
+        let (activity, participation) = futures::join!(
+            self.stats_get::<Vec<WeekRaw>>(&activity_path),
+            self.stats_get::<ParticipationRaw>(&participation_path),
+        );
+        let activity = activity?;
+        // Participation only adds the owner's share; don't fail without it
+        let owner_weeks = participation.ok().flatten().map(|p| p.owner);
+
+        Ok(match activity {
+            Some(raw) => {
+                let weeks = weekly_activity(raw, owner_weeks.as_deref(), weeks);
+                CommitActivity {
+                    total: weeks.iter().map(|w| w.total).sum(),
+                    weeks,
+                    pending: false,
+                }
+            }
+            None => CommitActivity {
+                weeks: Vec::new(),
+                total: 0,
+                pending: true,
+            },
+        })
+    }
+
+    /// Per-contributor weekly additions, deletions, and commits over the
+    /// last `weeks` weeks, most active first.
+    ///
+    /// Returns `None` while GitHub is still computing the stats. GitHub
+    /// only reports the top 100 contributors and omits line counts for
+    /// repositories with 10,000+ commits.
+    pub async fn get_contributor_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+        weeks: usize,
+    ) -> Result<Option<Vec<ContributorStats>>> {
+        let raw: Option<Vec<ContributorRaw>> = self
+            .stats_get(&format!("/repos/{}/{}/stats/contributors", owner, repo))
+            .await?;
+        Ok(raw.map(|raw| contributor_stats(raw, weeks)))
+    }
+
+    /// GET a statistics endpoint, retrying while GitHub answers 202.
+    ///
+    /// Returns `None` if the stats are still being computed after the last
+    /// attempt. Empty repositories (204) deserialize from `[]`.
+    async fn stats_get<T: for<'de> Deserialize<'de>>(&self, path: &str) -> Result<Option<T>> {
+        let mut delay = STATS_BACKOFF;
+        for attempt in 1..=STATS_ATTEMPTS {
+            let response = self.rest_send(Method::GET, path, None).await?;
+            match response.status() {
+                StatusCode::ACCEPTED if attempt < STATS_ATTEMPTS => {
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                }
+                StatusCode::ACCEPTED => break,
+                StatusCode::NO_CONTENT => {
+                    return serde_json::from_str("[]")
+                        .map(Some)
+                        .context("Failed to parse JSON");
+                }
+                _ => {
+                    let result = response.json().await.context("Failed to parse JSON")?;
+                    return Ok(Some(result));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[derive(Deserialize)]
+struct WeekRaw {
+    week: i64,
+    total: u32,
+    days: Vec<u32>,
+}
+
+#[derive(Deserialize)]
+struct ContributorRaw {
+    author: Option<AuthorRaw>,
+    weeks: Vec<ContributorWeekRaw>,
+}
+
+#[derive(Deserialize)]
+struct AuthorRaw {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct ContributorWeekRaw {
+    w: i64,
+    a: u32,
+    d: u32,
+    c: u32,
+}
+
+/// Summarize each contributor's last `limit` weeks, dropping those with no
+/// commits in the window and sorting by commits.
+fn contributor_stats(raw: Vec<ContributorRaw>, limit: usize) -> Vec<ContributorStats> {
+    let mut stats: Vec<ContributorStats> = raw
+        .into_iter()
+        .map(|c| {
+            let skip = c.weeks.len().saturating_sub(limit);
+            let weeks: Vec<ContributorWeek> = c
+                .weeks
+                .into_iter()
+                .skip(skip)
+                .map(|w| ContributorWeek {
+                    week_start: week_start(w.w),
+                    additions: w.a,
+                    deletions: w.d,
+                    commits: w.c,
+                })
+                .collect();
+
+            ContributorStats {
+                login: c.author.map(|a| a.login),
+                commits: weeks.iter().map(|w| w.commits).sum(),
+                additions: weeks.iter().map(|w| w.additions).sum(),
+                deletions: weeks.iter().map(|w| w.deletions).sum(),
+                weeks,
+            }
+        })
+        .filter(|c| c.commits > 0)
+        .collect();
+
+    stats.sort_by(|a, b| b.commits.cmp(&a.commits).then(a.login.cmp(&b.login)));
+    stats
+}
+
+/// Date of a stats week's starting Sunday from its Unix timestamp.
+fn week_start(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|d| d.date_naive().to_string())
+        .unwrap_or_default()
+}
+
+/// Convert raw weeks to the model, keeping the last `limit`.
+///
+/// The owner series covers the same weeks, oldest first, so the two are
+/// aligned from the most recent week.
+fn weekly_activity(raw: Vec<WeekRaw>, owner: Option<&[u32]>, limit: usize) -> Vec<WeekActivity> {
+    let owner = owner.unwrap_or_default();
+    let offset = owner.len() as isize - raw.len() as isize;
+    let skip = raw.len().saturating_sub(limit);
+
+    raw.into_iter()
+        .enumerate()
+        .skip(skip)
+        .map(|(i, w)| WeekActivity {
+            week_start: week_start(w.week),
+            total: w.total,
+            owner: usize::try_from(i as isize + offset)
+                .ok()
+                .and_then(|j| owner.get(j).copied()),
+            days: w.days,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn week(week: i64, total: u32) -> WeekRaw {
+        WeekRaw {
+            week,
+            total,
+            days: vec![0, total, 0, 0, 0, 0, 0],
+        }
+    }
+
+    #[test]
+    fn test_contributor_stats_window_and_order() {
+        let raw: Vec<ContributorRaw> = serde_json::from_value(serde_json::json!([
+            {
+                "author": {"login": "old-timer"},
+                "total": 40,
+                "weeks": [
+                    {"w": 1727568000, "a": 900, "d": 100, "c": 40},
+                    {"w": 1728172800, "a": 0, "d": 0, "c": 0}
+                ]
+            },
+            {
+                "author": {"login": "octocat"},
+                "total": 5,
+                "weeks": [
+                    {"w": 1727568000, "a": 0, "d": 0, "c": 0},
+                    {"w": 1728172800, "a": 120, "d": 30, "c": 5}
+                ]
+            },
+            {
+                "author": null,
+                "total": 2,
+                "weeks": [
+                    {"w": 1727568000, "a": 0, "d": 0, "c": 0},
+                    {"w": 1728172800, "a": 4, "d": 1, "c": 2}
+                ]
+            }
+        ]))
+        .unwrap();
+
+        let stats = contributor_stats(raw, 1);
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].login.as_deref(), Some("octocat"));
+        assert_eq!(stats[0].additions, 120);
+        assert_eq!(stats[0].weeks.len(), 1);
+        assert!(stats[1].login.is_none());
+    }
+
+    #[test]
+    fn test_weekly_activity_aligns_owner_from_latest_week() {
+        let raw = vec![
+            week(1727568000, 5),
+            week(1728172800, 3),
+            week(1728777600, 7),
+        ];
+        let weeks = weekly_activity(raw, Some(&[9, 1, 2, 4]), 2);
+
+        assert_eq!(weeks.len(), 2);
+        assert_eq!(weeks[0].week_start, "2024-10-06");
+        assert_eq!(weeks[0].owner, Some(2));
+        assert_eq!(weeks[1].total, 7);
+        assert_eq!(weeks[1].owner, Some(4));
+    }
+}
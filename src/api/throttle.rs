@@ -0,0 +1,252 @@
+//! Rate-limit aware request pacing.
+//!
+//! GitHub answers requests that trip a secondary (abuse) rate limit with 403
+//! or 429, usually with a `Retry-After` header, and exhausted primary limits
+//! with `X-RateLimit-Remaining: 0` and the reset time. Such responses are
+//! retried here after the indicated wait, as long as it is short enough,
+//! instead of failing the call. Secondary limits reported without any of
+//! those headers are recognized by their message and backed off
+//! exponentially from a minute, as GitHub's guidelines ask. Mutations
+//! (non-GET REST requests and GraphQL mutations) are additionally capped in
+//! concurrency, as the guidelines ask for content-creating requests to be
+//! made serially.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Back off secondary limits that come without Retry-After
+//! 10/16/2026 - Initial implementation
+
+use chrono::Utc;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{RequestBuilder, Response, StatusCode};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+use super::GitHubClient;
+use crate::config::ThrottleConfig;
+
+/// Shortest wait after a secondary rate limit without `Retry-After`.
+const SECONDARY_MIN_WAIT: Duration = Duration::from_secs(60);
+
+/// Retry and concurrency limits for one client.
+pub(super) struct Throttle {
+    mutations: Semaphore,
+    max_wait: Duration,
+    max_retries: u32,
+}
+
+impl Throttle {
+    pub(super) fn new(config: &ThrottleConfig) -> Self {
+        Self {
+            mutations: Semaphore::new(config.max_concurrent_mutations.max(1)),
+            max_wait: Duration::from_secs(config.max_wait_secs),
+            max_retries: config.max_retries,
+        }
+    }
+}
+
+impl Default for Throttle {
+    fn default() -> Self {
+        Self::new(&ThrottleConfig::default())
+    }
+}
+
+/// How long to wait before retrying a rate-limited response, or `None` when
+/// the response is not rate limited.
+fn retry_delay(status: StatusCode, headers: &HeaderMap) -> Option<Duration> {
+    if status != StatusCode::FORBIDDEN && status != StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+
+    if let Some(secs) = header(RETRY_AFTER.as_str()).and_then(|v| v.trim().parse::<u64>().ok()) {
+        return Some(Duration::from_secs(secs));
+    }
+    if header("x-ratelimit-remaining") == Some("0") {
+        let reset = header("x-ratelimit-reset")?.parse::<i64>().ok()?;
+        let secs = (reset - Utc::now().timestamp()).max(0) as u64;
+        // A second of slack for clock skew
+        return Some(Duration::from_secs(secs + 1));
+    }
+    None
+}
+
+/// Whether a 403/429 body is GitHub's secondary (abuse) rate limit message.
+fn is_secondary_limit(body: &[u8]) -> bool {
+    let body = String::from_utf8_lossy(body).to_lowercase();
+    body.contains("secondary rate limit") || body.contains("abuse")
+}
+
+/// Wait before retry `attempt` (from 0) of a secondary rate limit that gave
+/// no `Retry-After`: a minute, doubling per attempt, capped at `max_wait`.
+/// `None` when `max_wait` is shorter than the minimum.
+fn secondary_backoff(attempt: u32, max_wait: Duration) -> Option<Duration> {
+    if max_wait < SECONDARY_MIN_WAIT {
+        return None;
+    }
+    let delay = SECONDARY_MIN_WAIT.saturating_mul(2u32.saturating_pow(attempt));
+    Some(delay.min(max_wait))
+}
+
+/// Read a rate-limit status response's body to check for a secondary limit,
+/// returning an equivalent response for the caller.
+async fn check_secondary(response: Response) -> reqwest::Result<(Response, bool)> {
+    let status = response.status();
+    let version = response.version();
+    let headers = response.headers().clone();
+    let body = response.bytes().await?;
+    let secondary = is_secondary_limit(&body);
+
+    let mut rebuilt = http::Response::new(body);
+    *rebuilt.status_mut() = status;
+    *rebuilt.version_mut() = version;
+    *rebuilt.headers_mut() = headers;
+    Ok((Response::from(rebuilt), secondary))
+}
+
+impl GitHubClient {
+    /// Use `config` for retries and mutation concurrency.
+    pub fn with_throttle(mut self, config: &ThrottleConfig) -> Self {
+        self.throttle = Throttle::new(config);
+        self
+    }
+
+    /// Send a request, waiting out and retrying rate-limited responses.
+    ///
+    /// Every response, retried or not, is passed to `observe`.
+    /// Requests whose body cannot be replayed (streams) are sent once.
+    /// `mutation` requests hold a mutation slot for the whole exchange.
+    pub(super) async fn send(
+        &self,
+        request: RequestBuilder,
+        mutation: bool,
+    ) -> reqwest::Result<Response> {
+        let _slot = if mutation {
+            self.throttle.mutations.acquire().await.ok()
+        } else {
+            None
+        };
+
+        let mut request = request;
+        let mut attempt = 0;
+        loop {
+            let retry = request.try_clone();
+            let mut response = request.send().await?;
+            self.observe(response.headers());
+
+            let status = response.status();
+            let mut delay = retry_delay(status, response.headers());
+            if delay.is_none()
+                && (status == StatusCode::FORBIDDEN || status == StatusCode::TOO_MANY_REQUESTS)
+            {
+                // Secondary limits do not always say so in the headers
+                let secondary;
+                (response, secondary) = check_secondary(response).await?;
+                if secondary {
+                    delay = secondary_backoff(attempt, self.throttle.max_wait);
+                }
+            }
+            match (delay, retry) {
+                (Some(delay), Some(retry))
+                    if attempt < self.throttle.max_retries && delay <= self.throttle.max_wait =>
+                {
+                    attempt += 1;
+                    tracing::warn!(
+                        "GitHub rate limit hit ({}), retrying in {}s (attempt {}/{})",
+                        response.status(),
+                        delay.as_secs(),
+                        attempt,
+                        self.throttle.max_retries
+                    );
+                    tokio::time::sleep(delay).await;
+                    request = retry;
+                }
+                _ => return Ok(response),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_retry_delay() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_delay(StatusCode::FORBIDDEN, &headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("30"));
+        assert_eq!(
+            retry_delay(StatusCode::FORBIDDEN, &headers),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            retry_delay(StatusCode::TOO_MANY_REQUESTS, &headers),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(retry_delay(StatusCode::OK, &headers), None);
+
+        let mut primary = HeaderMap::new();
+        primary.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+        primary.insert("x-ratelimit-reset", HeaderValue::from_static("0"));
+        assert_eq!(
+            retry_delay(StatusCode::FORBIDDEN, &primary),
+            Some(Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn test_secondary_limit_without_retry_after() {
+        // Secondary limits can arrive with quota left and no Retry-After
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("4321"));
+        assert_eq!(retry_delay(StatusCode::FORBIDDEN, &headers), None);
+
+        assert!(is_secondary_limit(
+            br#"{"message":"You have exceeded a secondary rate limit. Please wait a few minutes before you try again."}"#
+        ));
+        assert!(is_secondary_limit(
+            br#"{"message":"You have triggered an abuse detection mechanism."}"#
+        ));
+        assert!(!is_secondary_limit(
+            br#"{"message":"Resource not accessible by integration"}"#
+        ));
+
+        let max_wait = Duration::from_secs(300);
+        assert_eq!(
+            secondary_backoff(0, max_wait),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            secondary_backoff(1, max_wait),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            secondary_backoff(2, max_wait),
+            Some(Duration::from_secs(240))
+        );
+        assert_eq!(secondary_backoff(3, max_wait), Some(max_wait));
+        assert_eq!(secondary_backoff(0, Duration::from_secs(30)), None);
+    }
+
+    #[test]
+    fn test_check_secondary_preserves_response() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let mut raw = http::Response::new(r#"{"message":"secondary rate limit"}"#);
+        *raw.status_mut() = StatusCode::FORBIDDEN;
+        raw.headers_mut()
+            .insert("x-ratelimit-remaining", HeaderValue::from_static("10"));
+
+        let (response, secondary) = runtime
+            .block_on(check_secondary(Response::from(raw)))
+            .unwrap();
+        assert!(secondary);
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert_eq!(response.headers()["x-ratelimit-remaining"], "10");
+        assert_eq!(
+            runtime.block_on(response.text()).unwrap(),
+            r#"{"message":"secondary rate limit"}"#
+        );
+    }
+}
@@ -0,0 +1,439 @@
+//! User profile and social endpoints.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Added user and organization blocks
+//! 10/16/2026 - Added contribution calendar and streaks
+//! 10/16/2026 - Added follow and unfollow
+//! 10/16/2026 - Added followers and following
+//! 10/16/2026 - Initial implementation with user profiles
+
+use anyhow::{Context, Result};
+use reqwest::Method;
+use serde::Deserialize;
+
+use super::GitHubClient;
+use crate::models::{
+    ContributionDay, ContributionTotals, Contributions, User, UserProfile, UserSummary,
+};
+
+impl GitHubClient {
+    /// Get any user's public profile and their visible organization memberships.
+    pub async fn get_user_profile(&self, login: &str) -> Result<UserProfile> {
+        let query = r#"
+            query($login: String!) {
+                user(login: $login) {
+                    login
+                    name
+                    email
+                    avatarUrl
+                    bio
+                    company
+                    location
+                    websiteUrl
+                    twitterUsername
+                    repositories {
+                        totalCount
+                    }
+                    followers {
+                        totalCount
+                    }
+                    following {
+                        totalCount
+                    }
+                    createdAt
+                    organizations(first: 100) {
+                        nodes {
+                            login
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct UserResponse {
+            user: Option<UserData>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct UserData {
+            login: String,
+            name: Option<String>,
+            email: String,
+            avatar_url: String,
+            bio: Option<String>,
+            company: Option<String>,
+            location: Option<String>,
+            website_url: Option<String>,
+            twitter_username: Option<String>,
+            repositories: CountWrapper,
+            followers: CountWrapper,
+            following: CountWrapper,
+            created_at: String,
+            organizations: OrgNodes,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CountWrapper {
+            total_count: i32,
+        }
+
+        #[derive(Deserialize)]
+        struct OrgNodes {
+            nodes: Vec<OrgNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct OrgNode {
+            login: String,
+        }
+
+        let variables = serde_json::json!({ "login": login });
+        let result: UserResponse = self.graphql(query, Some(variables)).await?;
+        let u = result
+            .user
+            .with_context(|| format!("User not found: {}", login))?;
+
+        Ok(UserProfile {
+            user: User {
+                login: u.login,
+                name: u.name,
+                // Only the public email is visible; GraphQL returns "" when unset.
+                email: Some(u.email).filter(|e| !e.is_empty()),
+                avatar_url: u.avatar_url,
+                bio: u.bio,
+                company: u.company,
+                location: u.location,
+                website_url: u.website_url,
+                twitter_username: u.twitter_username,
+                public_repos: u.repositories.total_count,
+                followers: u.followers.total_count,
+                following: u.following.total_count,
+                created_at: u.created_at,
+            },
+            organizations: u.organizations.nodes.into_iter().map(|o| o.login).collect(),
+        })
+    }
+
+    /// List followers of `login`, or of the viewer when `None`.
+    pub async fn list_followers(
+        &self,
+        login: Option<&str>,
+        limit: i32,
+        page: i32,
+    ) -> Result<Vec<UserSummary>> {
+        self.list_user_connections(login, "followers", limit, page)
+            .await
+    }
+
+    /// List accounts `login` follows, or the viewer follows when `None`.
+    pub async fn list_following(
+        &self,
+        login: Option<&str>,
+        limit: i32,
+        page: i32,
+    ) -> Result<Vec<UserSummary>> {
+        self.list_user_connections(login, "following", limit, page)
+            .await
+    }
+
+    /// Get the contribution calendar for `login` (or the viewer) between
+    /// `from` and `to`. GitHub defaults to the past year and rejects ranges
+    /// longer than one year.
+    pub async fn get_contributions(
+        &self,
+        login: Option<&str>,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<Contributions> {
+        let (declaration, root) = match login {
+            Some(_) => (", $login: String!", "user(login: $login)"),
+            None => ("", "viewer"),
+        };
+        let query = format!(
+            r#"
+            query($from: DateTime, $to: DateTime{declaration}) {{
+                account: {root} {{
+                    login
+                    contributionsCollection(from: $from, to: $to) {{
+                        startedAt
+                        endedAt
+                        totalCommitContributions
+                        totalIssueContributions
+                        totalPullRequestContributions
+                        totalPullRequestReviewContributions
+                        totalRepositoryContributions
+                        restrictedContributionsCount
+                        contributionCalendar {{
+                            totalContributions
+                            weeks {{
+                                contributionDays {{
+                                    date
+                                    contributionCount
+                                }}
+                            }}
+                        }}
+                    }}
+                }}
+            }}
+        "#
+        );
+
+        #[derive(Deserialize)]
+        struct Response {
+            account: Option<Account>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Account {
+            login: String,
+            contributions_collection: Collection,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Collection {
+            started_at: String,
+            ended_at: String,
+            total_commit_contributions: i32,
+            total_issue_contributions: i32,
+            total_pull_request_contributions: i32,
+            total_pull_request_review_contributions: i32,
+            total_repository_contributions: i32,
+            restricted_contributions_count: i32,
+            contribution_calendar: Calendar,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Calendar {
+            total_contributions: i32,
+            weeks: Vec<Week>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Week {
+            contribution_days: Vec<Day>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Day {
+            date: String,
+            contribution_count: i32,
+        }
+
+        let variables = serde_json::json!({
+            "login": login,
+            "from": from.map(|d| date_bound(d, "T00:00:00Z")),
+            "to": to.map(|d| date_bound(d, "T23:59:59Z")),
+        });
+        let result: Response = self.graphql(&query, Some(variables)).await?;
+        let account = result
+            .account
+            .with_context(|| format!("User not found: {}", login.unwrap_or("viewer")))?;
+        let c = account.contributions_collection;
+
+        let days: Vec<ContributionDay> = c
+            .contribution_calendar
+            .weeks
+            .into_iter()
+            .flat_map(|w| w.contribution_days)
+            .map(|d| ContributionDay {
+                date: d.date,
+                count: d.contribution_count,
+            })
+            .collect();
+        let (current_streak, longest_streak) = streaks(&days);
+
+        Ok(Contributions {
+            login: account.login,
+            from: c.started_at,
+            to: c.ended_at,
+            total: c.contribution_calendar.total_contributions,
+            totals: ContributionTotals {
+                commits: c.total_commit_contributions,
+                issues: c.total_issue_contributions,
+                pull_requests: c.total_pull_request_contributions,
+                reviews: c.total_pull_request_review_contributions,
+                repositories: c.total_repository_contributions,
+                restricted: c.restricted_contributions_count,
+            },
+            current_streak,
+            longest_streak,
+            days,
+        })
+    }
+
+    /// Follow a user as the viewer.
+    pub async fn follow_user(&self, login: &str) -> Result<()> {
+        self.rest_send(Method::PUT, &format!("/user/following/{}", login), None)
+            .await?;
+        Ok(())
+    }
+
+    /// Stop following a user.
+    pub async fn unfollow_user(&self, login: &str) -> Result<()> {
+        self.rest_delete(&format!("/user/following/{}", login))
+            .await
+    }
+
+    /// List accounts blocked by the viewer, or by `org` when set.
+    pub async fn list_blocked_users(
+        &self,
+        org: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<UserSummary>> {
+        let users: Vec<UserSummaryRaw> =
+            self.rest_get_paged(&blocks_path(org, None), limit).await?;
+        Ok(users.into_iter().map(UserSummaryRaw::into_model).collect())
+    }
+
+    /// Block a user for the viewer, or for `org` when set.
+    pub async fn block_user(&self, org: Option<&str>, login: &str) -> Result<()> {
+        self.rest_send(Method::PUT, &blocks_path(org, Some(login)), None)
+            .await?;
+        Ok(())
+    }
+
+    /// Unblock a user for the viewer, or for `org` when set.
+    pub async fn unblock_user(&self, org: Option<&str>, login: &str) -> Result<()> {
+        self.rest_delete(&blocks_path(org, Some(login))).await
+    }
+
+    async fn list_user_connections(
+        &self,
+        login: Option<&str>,
+        connection: &str,
+        limit: i32,
+        page: i32,
+    ) -> Result<Vec<UserSummary>> {
+        let base = match login {
+            Some(login) => format!("/users/{}", login),
+            None => "/user".to_string(),
+        };
+        let users: Vec<UserSummaryRaw> = self
+            .rest_get(&format!(
+                "{}/{}?per_page={}&page={}",
+                base, connection, limit, page
+            ))
+            .await?;
+
+        Ok(users.into_iter().map(UserSummaryRaw::into_model).collect())
+    }
+}
+
+/// Block list path for the viewer or an organization, optionally for one user.
+fn blocks_path(org: Option<&str>, login: Option<&str>) -> String {
+    let base = match org {
+        Some(org) => format!("/orgs/{}/blocks", org),
+        None => "/user/blocks".to_string(),
+    };
+    match login {
+        Some(login) => format!("{}/{}", base, login),
+        None => base,
+    }
+}
+
+/// Expand a bare `YYYY-MM-DD` date to a DateTime; full timestamps pass through.
+pub(super) fn date_bound(date: &str, time: &str) -> String {
+    if date.len() == 10 {
+        format!("{}{}", date, time)
+    } else {
+        date.to_string()
+    }
+}
+
+/// Current and longest runs of days with contributions. The current streak
+/// ends on the last day, or the day before it when the last day (usually
+/// today) has no contributions yet.
+fn streaks(days: &[ContributionDay]) -> (u32, u32) {
+    let mut longest = 0;
+    let mut run = 0;
+    for day in days {
+        if day.count > 0 {
+            run += 1;
+            longest = longest.max(run);
+        } else {
+            run = 0;
+        }
+    }
+
+    let skip_today = days.last().is_some_and(|d| d.count == 0);
+    let current = days
+        .iter()
+        .rev()
+        .skip(usize::from(skip_today))
+        .take_while(|d| d.count > 0)
+        .count() as u32;
+
+    (current, longest)
+}
+
+/// Raw simple user from REST API.
+#[derive(Deserialize)]
+struct UserSummaryRaw {
+    login: String,
+    avatar_url: String,
+    html_url: String,
+}
+
+impl UserSummaryRaw {
+    fn into_model(self) -> UserSummary {
+        UserSummary {
+            login: self.login,
+            avatar_url: self.avatar_url,
+            url: self.html_url,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days(counts: &[i32]) -> Vec<ContributionDay> {
+        counts
+            .iter()
+            .enumerate()
+            .map(|(i, &count)| ContributionDay {
+                date: format!("2024-01-{:02}", i + 1),
+                count,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_streaks() {
+        assert_eq!(streaks(&days(&[1, 2, 3, 0, 1, 1])), (2, 3));
+        // No contributions yet today keeps yesterday's streak alive.
+        assert_eq!(streaks(&days(&[0, 1, 1, 0])), (2, 2));
+        assert_eq!(streaks(&days(&[1, 0, 0])), (0, 1));
+        assert_eq!(streaks(&[]), (0, 0));
+    }
+
+    #[test]
+    fn test_blocks_path() {
+        assert_eq!(blocks_path(None, None), "/user/blocks");
+        assert_eq!(
+            blocks_path(Some("my-org"), Some("spammer")),
+            "/orgs/my-org/blocks/spammer"
+        );
+    }
+
+    #[test]
+    fn test_date_bound() {
+        assert_eq!(
+            date_bound("2024-01-01", "T00:00:00Z"),
+            "2024-01-01T00:00:00Z"
+        );
+        assert_eq!(
+            date_bound("2024-01-01T12:00:00Z", "T00:00:00Z"),
+            "2024-01-01T12:00:00Z"
+        );
+    }
+}
@@ -0,0 +1,240 @@
+//! TTL response cache.
+//!
+//! Results of methods given a TTL under `cache.ttl` in config.yaml are kept
+//! in memory, keyed by account, login, method and parameters, so repeated
+//! dashboard refreshes are answered without calling GitHub. Passing
+//! `fresh: true` skips the lookup (the new result is still cached). Cached
+//! methods report what happened in a `_cache` field on their response.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Key results by account and login
+//! 10/16/2026 - Initial implementation
+
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Entries kept before expired and then oldest entries are evicted.
+const MAX_ENTRIES: usize = 1024;
+
+/// Parameter that bypasses the cache; not part of the key.
+pub const FRESH_PARAM: &str = "fresh";
+
+struct Entry {
+    value: Value,
+    stored_at: Instant,
+    ttl: Duration,
+}
+
+/// Cached method results with hit/miss counters.
+pub struct ResponseCache {
+    /// TTL per method (`github.*` names).
+    ttls: BTreeMap<String, Duration>,
+    entries: Mutex<HashMap<String, Entry>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl ResponseCache {
+    /// Cache for the methods in `ttls` (seconds; 0 disables a method).
+    pub fn new(ttls: &BTreeMap<String, u64>) -> Self {
+        Self {
+            ttls: ttls
+                .iter()
+                .filter(|(_, secs)| **secs > 0)
+                .map(|(method, secs)| (method.clone(), Duration::from_secs(*secs)))
+                .collect(),
+            entries: Mutex::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// TTL configured for a method, if it is cached.
+    pub fn ttl(&self, method: &str) -> Option<Duration> {
+        self.ttls.get(method).copied()
+    }
+
+    /// Key for a call: the account and the login it authenticates as, the
+    /// method, and its parameters in name order, without `fresh`.
+    pub fn key(
+        account: &str,
+        login: &str,
+        method: &str,
+        params: &HashMap<String, Value>,
+    ) -> String {
+        let params: BTreeMap<&String, &Value> = params
+            .iter()
+            .filter(|(name, _)| name.as_str() != FRESH_PARAM)
+            .collect();
+        format!(
+            "{}@{}:{}:{}",
+            account,
+            login,
+            method,
+            serde_json::to_string(&params).unwrap_or_default()
+        )
+    }
+
+    /// A result younger than its TTL, with its age. Counts a hit or miss.
+    pub fn get(&self, key: &str) -> Option<(Value, Duration)> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let found = entries
+            .get(key)
+            .map(|entry| (entry, entry.stored_at.elapsed()))
+            .filter(|(entry, age)| *age < entry.ttl)
+            .map(|(entry, age)| (entry.value.clone(), age));
+
+        let counter = if found.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        found
+    }
+
+    /// Store a result for `ttl`.
+    pub fn put(&self, key: String, value: Value, ttl: Duration) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if entries.len() >= MAX_ENTRIES {
+            entries.retain(|_, entry| entry.stored_at.elapsed() < entry.ttl);
+        }
+        if entries.len() >= MAX_ENTRIES {
+            if let Some(oldest) = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.stored_at)
+                .map(|(key, _)| key.clone())
+            {
+                entries.remove(&oldest);
+            }
+        }
+
+        entries.insert(
+            key,
+            Entry {
+                value,
+                stored_at: Instant::now(),
+                ttl,
+            },
+        );
+    }
+
+    /// Drop every entry, e.g. after credentials change.
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+
+    /// Counters and size, for `health`.
+    pub fn stats(&self) -> Value {
+        json!({
+            "hits": self.hits.load(Ordering::Relaxed),
+            "misses": self.misses.load(Ordering::Relaxed),
+            "entries": self.entries.lock().unwrap_or_else(|e| e.into_inner()).len(),
+        })
+    }
+}
+
+/// Add `_cache` details to an object response; other responses are
+/// returned as they are.
+pub fn annotate(mut value: Value, hit: bool, age: Duration, ttl: Duration) -> Value {
+    if let Some(object) = value.as_object_mut() {
+        object.insert(
+            "_cache".to_string(),
+            json!({
+                "hit": hit,
+                "age_ms": age.as_millis() as u64,
+                "ttl_secs": ttl.as_secs(),
+            }),
+        );
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(value: Value) -> HashMap<String, Value> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_key_ignores_fresh_and_order() {
+        let a = ResponseCache::key(
+            "default",
+            "octocat",
+            "github.prs",
+            &params(json!({"repo": "o/r", "state": "open"})),
+        );
+        let b = ResponseCache::key(
+            "default",
+            "octocat",
+            "github.prs",
+            &params(json!({"state": "open", "repo": "o/r", "fresh": true})),
+        );
+        assert_eq!(a, b);
+        assert_ne!(
+            a,
+            ResponseCache::key(
+                "default",
+                "octocat",
+                "github.issues",
+                &params(json!({"repo": "o/r", "state": "open"}))
+            )
+        );
+    }
+
+    #[test]
+    fn test_key_separates_accounts_and_logins() {
+        let params = params(json!({"repo": "o/r"}));
+        let key = |account, login| ResponseCache::key(account, login, "github.prs", &params);
+
+        assert_ne!(key("default", "octocat"), key("work", "octocat"));
+        assert_ne!(key("default", "octocat"), key("default", "hubot"));
+    }
+
+    #[test]
+    fn test_get_respects_ttl() {
+        let ttls = BTreeMap::from([
+            ("github.repos".to_string(), 60),
+            ("github.user".to_string(), 0),
+        ]);
+        let cache = ResponseCache::new(&ttls);
+        assert_eq!(cache.ttl("github.repos"), Some(Duration::from_secs(60)));
+        assert_eq!(cache.ttl("github.user"), None);
+
+        assert!(cache.get("k").is_none());
+        cache.put("k".to_string(), json!({"n": 1}), Duration::from_secs(60));
+        let (value, _) = cache.get("k").unwrap();
+        assert_eq!(value["n"], 1);
+
+        cache.put("expired".to_string(), json!({}), Duration::ZERO);
+        assert!(cache.get("expired").is_none());
+
+        let stats = cache.stats();
+        assert_eq!(stats["hits"], 1);
+        assert_eq!(stats["misses"], 2);
+    }
+
+    #[test]
+    fn test_annotate() {
+        let value = annotate(
+            json!({"count": 2}),
+            true,
+            Duration::from_millis(1500),
+            Duration::from_secs(60),
+        );
+        assert_eq!(value["_cache"]["hit"], true);
+        assert_eq!(value["_cache"]["age_ms"], 1500);
+        assert_eq!(
+            annotate(json!([1]), false, Duration::ZERO, Duration::ZERO),
+            json!([1])
+        );
+    }
+}
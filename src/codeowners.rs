@@ -0,0 +1,186 @@
+//! CODEOWNERS parsing and path matching.
+//!
+//! Follows GitHub's rules: patterns use gitignore syntax without `!`
+//! negation or `[...]` ranges, and the last matching line wins. A matching
+//! line with no owners leaves the path unowned.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Initial implementation
+
+/// Locations GitHub reads CODEOWNERS from, in order.
+pub const CODEOWNERS_PATHS: &[&str] = &[".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// One pattern line from a CODEOWNERS file.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pub pattern: String,
+    /// `@user`, `@org/team`, or email owners.
+    pub owners: Vec<String>,
+    /// 1-based line number in the file.
+    pub line: usize,
+}
+
+/// Parsed CODEOWNERS file.
+#[derive(Debug, Clone, Default)]
+pub struct CodeOwners {
+    pub rules: Vec<Rule>,
+}
+
+impl CodeOwners {
+    /// Parse a CODEOWNERS file, skipping blank lines and comments.
+    pub fn parse(content: &str) -> Self {
+        let rules = content
+            .lines()
+            .enumerate()
+            .filter_map(|(i, line)| {
+                let line = strip_comment(line).trim();
+                let mut parts = line.split_whitespace();
+                let pattern = parts.next()?;
+                Some(Rule {
+                    pattern: pattern.replace("\\#", "#"),
+                    owners: parts.map(|o| o.to_string()).collect(),
+                    line: i + 1,
+                })
+            })
+            .collect();
+
+        CodeOwners { rules }
+    }
+
+    /// The rule that decides ownership of `path`: the last one matching.
+    pub fn rule_for(&self, path: &str) -> Option<&Rule> {
+        let path = path.trim_start_matches('/');
+        self.rules
+            .iter()
+            .rev()
+            .find(|r| pattern_matches(&r.pattern, path))
+    }
+}
+
+/// Drop a trailing `# comment`, keeping escaped `\#`.
+fn strip_comment(line: &str) -> &str {
+    let bytes = line.as_bytes();
+    for (i, &b) in bytes.iter().enumerate() {
+        if b == b'#' && (i == 0 || bytes[i - 1] != b'\\') {
+            return &line[..i];
+        }
+    }
+    line
+}
+
+/// Whether a CODEOWNERS pattern matches a file path.
+///
+/// Patterns containing a non-trailing `/` are anchored to the repository
+/// root; others match at any depth. A pattern ending in a plain name also
+/// matches every file beneath a directory of that name, but one ending in a
+/// wildcard (`docs/*`) only matches direct children. A trailing `/` only
+/// matches directories.
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let dir_only = pattern.ends_with('/');
+    let trimmed = pattern.trim_end_matches('/');
+    let anchored = trimmed.contains('/');
+    let pattern: Vec<&str> = trimmed.trim_start_matches('/').split('/').collect();
+    let segments: Vec<&str> = path.split('/').collect();
+    let last = pattern.last().copied().unwrap_or_default();
+    let mode = DirMatch {
+        allowed: !last.contains(['*', '?']),
+        required: dir_only,
+    };
+
+    if anchored {
+        match_segments(&pattern, &segments, mode)
+    } else {
+        (0..segments.len()).any(|i| match_segments(&pattern, &segments[i..], mode))
+    }
+}
+
+/// Whether a pattern may (or must) match a parent directory of the path.
+#[derive(Clone, Copy)]
+struct DirMatch {
+    allowed: bool,
+    required: bool,
+}
+
+/// Match pattern segments against a prefix of the path segments. Leftover
+/// path segments mean the pattern matched a parent directory.
+fn match_segments(pattern: &[&str], path: &[&str], mode: DirMatch) -> bool {
+    match pattern.split_first() {
+        None if path.is_empty() => !mode.required,
+        None => mode.allowed,
+        Some((&"**", rest)) => (0..=path.len()).any(|k| match_segments(rest, &path[k..], mode)),
+        Some((first, rest)) => match path.split_first() {
+            Some((segment, path_rest)) => {
+                glob_segment(first.as_bytes(), segment.as_bytes())
+                    && match_segments(rest, path_rest, mode)
+            }
+            None => false,
+        },
+    }
+}
+
+/// Match one path segment against a pattern with `*` and `?` wildcards.
+fn glob_segment(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => (0..=text.len()).any(|k| glob_segment(rest, &text[k..])),
+        Some((b'?', rest)) => !text.is_empty() && glob_segment(rest, &text[1..]),
+        Some((c, rest)) => text.first() == Some(c) && glob_segment(rest, &text[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILE: &str = "\
+# Default owners
+*       @org/core
+
+*.js    @js-owner # frontend
+/docs/  @org/docs
+apps/   @app-owner
+/build/logs/
+**/fixtures/** @qa
+src/**/generated.rs @codegen
+";
+
+    fn owners(co: &CodeOwners, path: &str) -> Vec<String> {
+        co.rule_for(path)
+            .map(|r| r.owners.clone())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_last_matching_rule_wins() {
+        let co = CodeOwners::parse(FILE);
+        assert_eq!(co.rules.len(), 7);
+        assert_eq!(owners(&co, "README.md"), ["@org/core"]);
+        assert_eq!(owners(&co, "web/app.js"), ["@js-owner"]);
+        assert_eq!(owners(&co, "docs/guide/intro.md"), ["@org/docs"]);
+        assert_eq!(owners(&co, "nested/apps/main.go"), ["@app-owner"]);
+        assert_eq!(owners(&co, "tests/fixtures/a/b.json"), ["@qa"]);
+        assert_eq!(owners(&co, "src/api/v1/generated.rs"), ["@codegen"]);
+    }
+
+    #[test]
+    fn test_rule_without_owners_unowns_path() {
+        let co = CodeOwners::parse(FILE);
+        let rule = co.rule_for("build/logs/out.txt").unwrap();
+        assert_eq!(rule.line, 7);
+        assert!(rule.owners.is_empty());
+    }
+
+    #[test]
+    fn test_anchoring_and_directory_patterns() {
+        assert!(pattern_matches("/docs/", "docs/a.md"));
+        assert!(!pattern_matches("/docs/", "src/docs/a.md"));
+        assert!(pattern_matches("docs/", "src/docs/a.md"));
+        assert!(!pattern_matches("docs/", "docs"));
+        assert!(pattern_matches("docs", "docs"));
+        assert!(pattern_matches("docs/*", "docs/a.md"));
+        assert!(!pattern_matches("docs/*", "docs/sub/a.md"));
+        assert!(pattern_matches("**/logs", "deep/logs/today.txt"));
+        assert!(!pattern_matches("docs/*.md", "docs/sub/a.md"));
+        assert!(pattern_matches("*.md", "deep/path/file.md"));
+    }
+}
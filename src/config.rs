@@ -0,0 +1,307 @@
+//! Daemon configuration file.
+//!
+//! Optional YAML next to the daemon socket
+//! (`~/.fgp/services/github/config.yaml`). A missing file means defaults:
+//!
+//! ```yaml
+//! host: github.example.com
+//! hosts:
+//!   github.example.com:
+//!     api_url: https://github.example.com/api/v3
+//!     graphql_url: https://github.example.com/api/graphql
+//! repo_accounts:
+//!   - repo: myorg/*
+//!     account: work
+//! throttle:
+//!   max_concurrent_mutations: 1
+//!   max_wait_secs: 60
+//! cache:
+//!   persist: true
+//!   ttl:
+//!     github.repos: 300
+//!     github.ci_summary: 60
+//! ```
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Rate-limit retry and mutation concurrency settings
+//! 10/16/2026 - Opt-in persistent cache
+//! 10/16/2026 - Per-method response cache TTLs
+//! 10/16/2026 - Map repository patterns to accounts
+//! 10/16/2026 - Initial implementation with per-host API endpoints
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Default config file location.
+const DEFAULT_CONFIG_PATH: &str = "~/.fgp/services/github/config.yaml";
+
+/// The public GitHub host.
+pub const GITHUB_HOST: &str = "github.com";
+
+/// Parsed config file.
+#[derive(Debug, Default, Deserialize)]
+pub struct DaemonConfig {
+    /// Host to talk to when neither `--host` nor `GH_HOST` is given.
+    pub host: Option<String>,
+    /// Endpoint overrides per host.
+    #[serde(default)]
+    pub hosts: BTreeMap<String, HostConfig>,
+    /// Account to use for calls on matching repositories; first match wins.
+    #[serde(default)]
+    pub repo_accounts: Vec<RepoAccount>,
+    #[serde(default)]
+    pub cache: CacheConfig,
+    #[serde(default)]
+    pub throttle: ThrottleConfig,
+}
+
+/// How rate-limited requests are retried and mutations paced.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ThrottleConfig {
+    /// Mutating requests allowed in flight at once per account.
+    pub max_concurrent_mutations: usize,
+    /// Longest `Retry-After` (or primary reset) wait to sit out; longer
+    /// waits fail the call instead. Also caps the backoff for secondary
+    /// limits reported without `Retry-After`.
+    pub max_wait_secs: u64,
+    /// Retries per request after rate-limited responses.
+    pub max_retries: u32,
+}
+
+impl Default for ThrottleConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent_mutations: 1,
+            max_wait_secs: 60,
+            max_retries: 3,
+        }
+    }
+}
+
+/// Response cache settings.
+#[derive(Debug, Default, Deserialize)]
+pub struct CacheConfig {
+    /// Seconds to reuse each method's results, by `github.*` name. Methods
+    /// not listed are never cached.
+    #[serde(default)]
+    pub ttl: BTreeMap<String, u64>,
+    /// Keep repository, issue, PR, and notification results on disk and
+    /// serve them (marked stale) when GitHub is unreachable.
+    #[serde(default)]
+    pub persist: bool,
+}
+
+/// Route calls on repositories matching `repo` to `account`.
+///
+/// `repo` is `owner/name`, where either part may use `*` wildcards
+/// (`myorg/*`, `*/dotfiles`). A bare owner means every repository of that
+/// owner. Matching is case-insensitive.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RepoAccount {
+    pub repo: String,
+    pub account: String,
+}
+
+impl RepoAccount {
+    /// Whether the rule covers `owner/name`, or every repository of `owner`
+    /// when `name` is `None` (organization-level calls).
+    pub fn matches(&self, owner: &str, name: Option<&str>) -> bool {
+        let (owner_pattern, name_pattern) = self.repo.split_once('/').unwrap_or((&self.repo, "*"));
+        wildcard_match(owner_pattern, owner)
+            && match name {
+                Some(name) => wildcard_match(name_pattern, name),
+                None => name_pattern == "*",
+            }
+    }
+}
+
+/// Endpoint overrides for one host. Unset URLs are derived from the host.
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct HostConfig {
+    pub api_url: Option<String>,
+    pub graphql_url: Option<String>,
+    pub uploads_url: Option<String>,
+}
+
+/// API base URLs for one GitHub host.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Endpoints {
+    /// Hostname, as used for gh's hosts.yml and web URLs.
+    pub host: String,
+    /// REST base, e.g. `https://api.github.com` or `https://ghe/api/v3`.
+    pub rest: String,
+    pub graphql: String,
+    pub uploads: String,
+}
+
+impl Endpoints {
+    /// Default endpoints for a host: github.com's API subdomains, or the
+    /// GitHub Enterprise Server `/api/v3`, `/api/graphql`, and `/api/uploads`
+    /// paths for any other host.
+    pub fn for_host(host: &str) -> Self {
+        let host = host
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .trim_end_matches('/')
+            .to_string();
+
+        if host == GITHUB_HOST {
+            return Self {
+                host,
+                rest: "https://api.github.com".to_string(),
+                graphql: "https://api.github.com/graphql".to_string(),
+                uploads: "https://uploads.github.com".to_string(),
+            };
+        }
+
+        Self {
+            rest: format!("https://{}/api/v3", host),
+            graphql: format!("https://{}/api/graphql", host),
+            uploads: format!("https://{}/api/uploads", host),
+            host,
+        }
+    }
+
+    /// Apply any overrides configured for this host.
+    fn with_overrides(mut self, overrides: &HostConfig) -> Self {
+        let trim = |url: &String| url.trim_end_matches('/').to_string();
+        if let Some(url) = &overrides.api_url {
+            self.rest = trim(url);
+        }
+        if let Some(url) = &overrides.graphql_url {
+            self.graphql = trim(url);
+        }
+        if let Some(url) = &overrides.uploads_url {
+            self.uploads = trim(url);
+        }
+        self
+    }
+
+    /// Whether these are the public github.com endpoints.
+    pub fn is_github_com(&self) -> bool {
+        self.host == GITHUB_HOST
+    }
+}
+
+impl DaemonConfig {
+    /// Load the config file, treating a missing file as defaults.
+    pub fn load() -> Result<Self> {
+        let path = PathBuf::from(shellexpand::tilde(DEFAULT_CONFIG_PATH).to_string());
+        match std::fs::read_to_string(&path) {
+            Ok(content) => serde_yaml::from_str(&content)
+                .with_context(|| format!("Failed to parse {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        }
+    }
+
+    /// Endpoints for the daemon's host.
+    ///
+    /// The host comes from `cli_host`, then `GH_HOST`, then the config file,
+    /// defaulting to github.com. `GITHUB_API_URL` and `GITHUB_GRAPHQL_URL`
+    /// (as set in GitHub Actions) override the derived URLs.
+    pub fn endpoints(&self, cli_host: Option<&str>) -> Endpoints {
+        let host = cli_host
+            .map(|h| h.to_string())
+            .or_else(|| env_var("GH_HOST"))
+            .or_else(|| self.host.clone())
+            .unwrap_or_else(|| GITHUB_HOST.to_string());
+
+        let env_overrides = HostConfig {
+            api_url: env_var("GITHUB_API_URL"),
+            graphql_url: env_var("GITHUB_GRAPHQL_URL"),
+            uploads_url: None,
+        };
+        self.endpoints_for(&host).with_overrides(&env_overrides)
+    }
+
+    /// Endpoints for a specific host, with config-file overrides applied.
+    pub fn endpoints_for(&self, host: &str) -> Endpoints {
+        let endpoints = Endpoints::for_host(host);
+        match self.hosts.get(&endpoints.host) {
+            Some(overrides) => endpoints.with_overrides(overrides),
+            None => endpoints,
+        }
+    }
+}
+
+/// Case-insensitive match of `text` against a pattern where `*` matches any
+/// run of characters.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.to_lowercase();
+    let text = text.to_lowercase();
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No `*` at all: the whole text must be consumed
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(i) => rest = &rest[i + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+/// A non-empty environment variable.
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoints_for_host() {
+        let public = Endpoints::for_host("github.com");
+        assert_eq!(public.rest, "https://api.github.com");
+        assert!(public.is_github_com());
+
+        let ghes = Endpoints::for_host("https://ghe.example.com/");
+        assert_eq!(ghes.host, "ghe.example.com");
+        assert_eq!(ghes.rest, "https://ghe.example.com/api/v3");
+        assert_eq!(ghes.graphql, "https://ghe.example.com/api/graphql");
+    }
+
+    #[test]
+    fn test_config_overrides() {
+        let config: DaemonConfig = serde_yaml::from_str(
+            "host: ghe.example.com\n\
+             hosts:\n  ghe.example.com:\n    api_url: https://api.ghe.example.com/\n",
+        )
+        .unwrap();
+
+        let endpoints = config.endpoints_for("ghe.example.com");
+        assert_eq!(endpoints.rest, "https://api.ghe.example.com");
+        assert_eq!(endpoints.graphql, "https://ghe.example.com/api/graphql");
+    }
+
+    #[test]
+    fn test_repo_account_matches() {
+        let rule = |repo: &str| RepoAccount {
+            repo: repo.to_string(),
+            account: "work".to_string(),
+        };
+
+        assert!(rule("myorg/*").matches("MyOrg", Some("api")));
+        assert!(rule("myorg/*").matches("myorg", None));
+        assert!(!rule("myorg/*").matches("other", Some("api")));
+        assert!(rule("myorg").matches("myorg", Some("api")));
+        assert!(rule("*/dotfiles").matches("anyone", Some("dotfiles")));
+        assert!(rule("myorg/svc-*").matches("myorg", Some("svc-auth")));
+        assert!(!rule("myorg/svc-*").matches("myorg", Some("web")));
+        assert!(!rule("myorg/svc-*").matches("myorg", None));
+        assert!(!rule("my*org/a").matches("myorg", Some("ab")));
+    }
+}
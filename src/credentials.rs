@@ -0,0 +1,283 @@
+//! Daemon-owned credential store.
+//!
+//! Tokens obtained by `fgp-github login` or `fgp-github auth set-token` are
+//! indexed in a JSON file next to the daemon socket, readable only by the
+//! owning user. The secret itself goes to the OS keychain. Without a usable
+//! keychain, storing fails unless the plaintext file store was explicitly
+//! allowed (`--insecure-file-store`), in which case the secret goes into the
+//! file. Like the state file, it is rewritten through a temp file and rename.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Require opting in to plaintext storage when no keychain exists
+//! 10/16/2026 - Keep token secrets in the OS keychain when available
+//! 10/16/2026 - Initial implementation for device-flow tokens
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+
+use crate::config::GITHUB_HOST;
+use crate::keychain;
+
+/// Default credentials file location.
+const DEFAULT_CREDENTIALS_PATH: &str = "~/.fgp/services/github/credentials.json";
+
+/// Account name used when none is given.
+pub const DEFAULT_ACCOUNT: &str = "default";
+
+/// Everything in the credentials file, keyed by account name.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Credentials {
+    #[serde(default)]
+    pub accounts: BTreeMap<String, StoredToken>,
+}
+
+/// A token the daemon obtained and owns.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredToken {
+    /// Empty in the file when the secret lives in the OS keychain.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub token: String,
+    /// Whether the secret is stored in the OS keychain.
+    #[serde(default)]
+    pub keychain: bool,
+    /// Host the token is for; github.com when unset.
+    #[serde(default)]
+    pub host: Option<String>,
+    /// Login the token belongs to, when known.
+    pub login: Option<String>,
+    /// Scopes granted at login.
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    /// How the token was obtained: `device_flow` or `set_token`.
+    pub source: String,
+    pub created_at: String,
+}
+
+/// Handle to the credentials file on disk.
+pub struct CredentialStore {
+    path: PathBuf,
+    /// Whether secrets go to the OS keychain.
+    keychain: bool,
+    /// Whether secrets may be written to the file when the keychain cannot
+    /// take them.
+    insecure_file_store: bool,
+}
+
+impl StoredToken {
+    fn host(&self) -> &str {
+        self.host.as_deref().unwrap_or(GITHUB_HOST)
+    }
+}
+
+impl CredentialStore {
+    /// Store at the default location
+    /// (`~/.fgp/services/github/credentials.json`), keeping secrets in the OS
+    /// keychain when available.
+    pub fn new() -> Self {
+        Self {
+            path: PathBuf::from(shellexpand::tilde(DEFAULT_CREDENTIALS_PATH).to_string()),
+            keychain: true,
+            insecure_file_store: false,
+        }
+    }
+
+    /// File-only store at an explicit path; the keychain is never touched.
+    #[cfg(test)]
+    pub fn at(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            keychain: false,
+            insecure_file_store: true,
+        }
+    }
+
+    /// Allow (or forbid) keeping secrets in plaintext in the file when no
+    /// keychain is available.
+    pub fn with_insecure_file_store(mut self, allow: bool) -> Self {
+        self.insecure_file_store = allow;
+        self
+    }
+
+    /// Fail early, before obtaining a token, when it could not be stored:
+    /// the keychain is unusable and the file store was not allowed.
+    pub fn check_backend(&self, account: &str, host: &str) -> Result<()> {
+        if self.insecure_file_store {
+            return Ok(());
+        }
+        if !self.keychain {
+            anyhow::bail!(Self::no_keychain_message());
+        }
+        keychain::get_token(account, host)
+            .map(|_| ())
+            .context(Self::no_keychain_message())
+    }
+
+    fn no_keychain_message() -> &'static str {
+        "No usable OS keychain; refusing to store the token in plaintext. \
+         Pass --insecure-file-store to keep it in the owner-only credentials file instead"
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Load credentials, treating a missing file as empty.
+    pub fn load(&self) -> Result<Credentials> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(content) => serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse {}", self.path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Credentials::default()),
+            Err(e) => Err(e).with_context(|| format!("Failed to read {}", self.path.display())),
+        }
+    }
+
+    /// The token stored for an account, if any, with its secret read from
+    /// the keychain when it lives there.
+    pub fn token(&self, account: &str) -> Result<Option<StoredToken>> {
+        let Some(mut stored) = self.load()?.accounts.remove(account) else {
+            return Ok(None);
+        };
+        if stored.keychain {
+            match keychain::get_token(account, stored.host())? {
+                Some(token) => stored.token = token,
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(stored))
+    }
+
+    /// Write credentials atomically with owner-only permissions.
+    pub fn save(&self, credentials: &Credentials) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create credentials directory")?;
+        }
+
+        let tmp = self.path.with_extension("json.tmp");
+        let content = serde_json::to_string_pretty(credentials)?;
+        let mut file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(&tmp)
+            .with_context(|| format!("Failed to write {}", tmp.display()))?;
+        file.write_all(content.as_bytes())
+            .with_context(|| format!("Failed to write {}", tmp.display()))?;
+        std::fs::rename(&tmp, &self.path)
+            .with_context(|| format!("Failed to replace {}", self.path.display()))
+    }
+
+    /// Store (or replace) the token for an account. The secret goes to the
+    /// keychain, or into the file only when the file store is allowed;
+    /// returns whether the keychain was used.
+    pub fn set_token(&self, account: &str, mut token: StoredToken) -> Result<bool> {
+        token.keychain = false;
+        if self.keychain {
+            match keychain::set_token(account, token.host(), &token.token) {
+                Ok(()) => {
+                    token.keychain = true;
+                    token.token.clear();
+                }
+                Err(e) if self.insecure_file_store => {
+                    tracing::warn!("Keychain unavailable, storing token in file: {:#}", e)
+                }
+                Err(e) => return Err(e).context(Self::no_keychain_message()),
+            }
+        } else if !self.insecure_file_store {
+            anyhow::bail!(Self::no_keychain_message());
+        }
+        let in_keychain = token.keychain;
+
+        let mut credentials = self.load()?;
+        credentials.accounts.insert(account.to_string(), token);
+        self.save(&credentials)?;
+        Ok(in_keychain)
+    }
+
+    /// Forget an account, deleting its keychain entry too. Returns whether
+    /// the account existed.
+    pub fn remove(&self, account: &str) -> Result<bool> {
+        let mut credentials = self.load()?;
+        let Some(stored) = credentials.accounts.remove(account) else {
+            return Ok(false);
+        };
+        if stored.keychain {
+            keychain::delete_token(account, stored.host())?;
+        }
+        self.save(&credentials)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    #[test]
+    fn test_credentials_round_trip_owner_only() {
+        let dir = std::env::temp_dir().join(format!("fgp-github-creds-{}", std::process::id()));
+        let store = CredentialStore::at(dir.join("credentials.json"));
+
+        assert!(store.token(DEFAULT_ACCOUNT).unwrap().is_none());
+
+        store
+            .set_token(
+                DEFAULT_ACCOUNT,
+                StoredToken {
+                    token: "gho_abc".to_string(),
+                    keychain: false,
+                    host: None,
+                    login: Some("octocat".to_string()),
+                    scopes: vec!["repo".to_string()],
+                    source: "device_flow".to_string(),
+                    created_at: "2026-10-16T00:00:00Z".to_string(),
+                },
+            )
+            .unwrap();
+        let file = std::fs::read_to_string(store.path()).unwrap();
+        assert!(file.contains("gho_abc"));
+
+        let stored = store.token(DEFAULT_ACCOUNT).unwrap().unwrap();
+        assert_eq!(stored.token, "gho_abc");
+        let mode = std::fs::metadata(store.path())
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        assert!(store.remove(DEFAULT_ACCOUNT).unwrap());
+        assert!(store.token(DEFAULT_ACCOUNT).unwrap().is_none());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_plaintext_needs_opt_in() {
+        let dir = std::env::temp_dir().join(format!("fgp-github-nokey-{}", std::process::id()));
+        let store =
+            CredentialStore::at(dir.join("credentials.json")).with_insecure_file_store(false);
+
+        assert!(store.check_backend(DEFAULT_ACCOUNT, GITHUB_HOST).is_err());
+        let err = store
+            .set_token(
+                DEFAULT_ACCOUNT,
+                StoredToken {
+                    token: "gho_abc".to_string(),
+                    keychain: false,
+                    host: None,
+                    login: None,
+                    scopes: Vec::new(),
+                    source: "set_token".to_string(),
+                    created_at: "2026-10-16T00:00:00Z".to_string(),
+                },
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("--insecure-file-store"));
+        assert!(!store.path().exists());
+    }
+}
@@ -0,0 +1,206 @@
+//! Persistent response store for offline reads.
+//!
+//! When `cache.persist` is set in config.yaml, the latest results of the
+//! repository, issue, pull request, and notification methods are written to
+//! a SQLite database next to the daemon socket. They survive restarts, and
+//! when GitHub cannot be reached the stored result is returned instead of
+//! the error, marked `stale: true`. Results are keyed by account and login,
+//! and the last login seen per account is kept so offline lookups still
+//! find them after a restart.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Key results by account and login; remember logins per account
+//! 10/16/2026 - Initial implementation
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde_json::Value;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Default database location.
+pub const DEFAULT_CACHE_PATH: &str = "~/.fgp/services/github/cache.db";
+
+/// Methods whose results are persisted.
+const PERSISTED_METHODS: &[&str] = &[
+    "github.repos",
+    "github.issues",
+    "github.prs",
+    "github.pr",
+    "github.notifications",
+];
+
+/// Stored results older than this are pruned when the database is opened.
+const MAX_AGE_DAYS: i64 = 30;
+
+/// Whether results of `method` (a `github.*` name) are persisted.
+pub fn is_persisted(method: &str) -> bool {
+    PERSISTED_METHODS.contains(&method)
+}
+
+/// Handle to the cache database.
+pub struct DiskCache {
+    conn: Mutex<Connection>,
+}
+
+impl DiskCache {
+    /// Open (creating if needed) the database at `path`.
+    pub fn open(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create cache directory")?;
+        }
+        let conn =
+            Connection::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS responses (
+                 key TEXT PRIMARY KEY,
+                 method TEXT NOT NULL,
+                 value TEXT NOT NULL,
+                 stored_at INTEGER NOT NULL
+             );
+             CREATE TABLE IF NOT EXISTS logins (
+                 account TEXT PRIMARY KEY,
+                 login TEXT NOT NULL
+             );",
+        )
+        .context("Failed to create cache schema")?;
+
+        let cutoff = Utc::now().timestamp() - MAX_AGE_DAYS * 24 * 60 * 60;
+        conn.execute(
+            "DELETE FROM responses WHERE stored_at < ?1",
+            params![cutoff],
+        )
+        .context("Failed to prune cache")?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Store the latest result for a call.
+    pub fn put(&self, key: &str, method: &str, value: &Value) -> Result<()> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        conn.execute(
+            "INSERT OR REPLACE INTO responses (key, method, value, stored_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![
+                key,
+                method,
+                serde_json::to_string(value)?,
+                Utc::now().timestamp()
+            ],
+        )
+        .context("Failed to write cache entry")?;
+        Ok(())
+    }
+
+    /// Remember the login `account` authenticates as.
+    pub fn set_login(&self, account: &str, login: &str) -> Result<()> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        conn.execute(
+            "INSERT OR REPLACE INTO logins (account, login) VALUES (?1, ?2)",
+            params![account, login],
+        )
+        .context("Failed to write cache login")?;
+        Ok(())
+    }
+
+    /// The login last seen for `account`.
+    pub fn login(&self, account: &str) -> Result<Option<String>> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        conn.query_row(
+            "SELECT login FROM logins WHERE account = ?1",
+            params![account],
+            |row| row.get(0),
+        )
+        .optional()
+        .context("Failed to read cache login")
+    }
+
+    /// Forget remembered logins, e.g. after tokens were reloaded and may
+    /// belong to someone else; `None` forgets every account's.
+    pub fn forget_logins(&self, account: Option<&str>) -> Result<()> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        match account {
+            Some(account) => {
+                conn.execute("DELETE FROM logins WHERE account = ?1", params![account])
+            }
+            None => conn.execute("DELETE FROM logins", []),
+        }
+        .context("Failed to clear cache logins")?;
+        Ok(())
+    }
+
+    /// The stored result for a call and when it was stored.
+    pub fn get(&self, key: &str) -> Result<Option<(Value, DateTime<Utc>)>> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let row: Option<(String, i64)> = conn
+            .query_row(
+                "SELECT value, stored_at FROM responses WHERE key = ?1",
+                params![key],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+            .context("Failed to read cache entry")?;
+
+        let Some((value, stored_at)) = row else {
+            return Ok(None);
+        };
+        let value = serde_json::from_str(&value).context("Corrupt cache entry")?;
+        Ok(Some((
+            value,
+            DateTime::from_timestamp(stored_at, 0).unwrap_or_default(),
+        )))
+    }
+}
+
+/// Mark an object result as served from the store while offline.
+pub fn mark_stale(mut value: Value, stored_at: DateTime<Utc>) -> Value {
+    if let Some(object) = value.as_object_mut() {
+        object.insert("stale".to_string(), Value::Bool(true));
+        object.insert(
+            "stale_since".to_string(),
+            Value::String(stored_at.to_rfc3339()),
+        );
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_round_trip_and_stale_marker() {
+        let dir = std::env::temp_dir().join(format!("fgp-github-cache-{}", std::process::id()));
+        let cache = DiskCache::open(&dir.join("cache.db")).unwrap();
+
+        assert!(cache.get("github.prs:{}").unwrap().is_none());
+        cache
+            .put("github.prs:{}", "github.prs", &json!({"count": 3}))
+            .unwrap();
+        cache
+            .put("github.prs:{}", "github.prs", &json!({"count": 4}))
+            .unwrap();
+
+        let (value, stored_at) = cache.get("github.prs:{}").unwrap().unwrap();
+        assert_eq!(value["count"], 4);
+
+        let stale = mark_stale(value, stored_at);
+        assert_eq!(stale["stale"], true);
+        assert!(stale["stale_since"].is_string());
+
+        assert!(cache.login("work").unwrap().is_none());
+        cache.set_login("work", "octocat").unwrap();
+        assert_eq!(cache.login("work").unwrap().as_deref(), Some("octocat"));
+        cache.forget_logins(Some("work")).unwrap();
+        assert!(cache.login("work").unwrap().is_none());
+
+        assert!(is_persisted("github.issues"));
+        assert!(!is_persisted("github.create_issue"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
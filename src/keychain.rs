@@ -0,0 +1,71 @@
+//! OS keychain storage for tokens.
+//!
+//! Uses the macOS Keychain or the Secret Service (GNOME Keyring, KWallet) on
+//! Linux. Entries are stored under the `fgp-github` service with the account
+//! name as the user, suffixed with the host for non-github.com hosts.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Initial implementation
+
+use anyhow::{Context, Result};
+use keyring::Entry;
+
+use crate::config::GITHUB_HOST;
+
+/// Keychain service name for all entries.
+const SERVICE: &str = "fgp-github";
+
+/// Keychain user for an account on a host.
+fn entry_user(account: &str, host: &str) -> String {
+    if host == GITHUB_HOST {
+        account.to_string()
+    } else {
+        format!("{}@{}", account, host)
+    }
+}
+
+fn entry(account: &str, host: &str) -> Result<Entry> {
+    Entry::new(SERVICE, &entry_user(account, host)).context("Failed to open keychain entry")
+}
+
+/// The token stored for an account, or `None` when there is no entry.
+///
+/// A missing or locked keychain (e.g. no Secret Service on a headless
+/// machine) is an error; callers resolving tokens treat it as absent.
+pub fn get_token(account: &str, host: &str) -> Result<Option<String>> {
+    match entry(account, host)?.get_password() {
+        Ok(token) => Ok(Some(token)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(e).context("Failed to read from the OS keychain"),
+    }
+}
+
+/// Store (or replace) the token for an account.
+pub fn set_token(account: &str, host: &str, token: &str) -> Result<()> {
+    entry(account, host)?
+        .set_password(token)
+        .context("Failed to write to the OS keychain")
+}
+
+/// Remove an account's token. Returns whether an entry existed.
+pub fn delete_token(account: &str, host: &str) -> Result<bool> {
+    match entry(account, host)?.delete_credential() {
+        Ok(()) => Ok(true),
+        Err(keyring::Error::NoEntry) => Ok(false),
+        Err(e) => Err(e).context("Failed to delete from the OS keychain"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entry_user() {
+        assert_eq!(entry_user("default", "github.com"), "default");
+        assert_eq!(
+            entry_user("work", "ghe.example.com"),
+            "work@ghe.example.com"
+        );
+    }
+}
@@ -0,0 +1,211 @@
+//! OAuth device authorization flow for `fgp-github login`.
+//!
+//! Requests a device code, asks the user to enter it at GitHub, then polls
+//! until the user approves (or the code expires).
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Initial implementation
+
+use anyhow::{bail, Context, Result};
+use reqwest::Client;
+use serde::Deserialize;
+use std::time::Duration;
+
+use crate::config::Endpoints;
+
+const DEVICE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// Device and user codes returned when the flow starts.
+#[derive(Debug, Deserialize)]
+pub struct DeviceCode {
+    pub device_code: String,
+    pub user_code: String,
+    pub verification_uri: String,
+    pub expires_in: u64,
+    pub interval: u64,
+}
+
+/// A token granted by the flow.
+#[derive(Debug)]
+pub struct GrantedToken {
+    pub access_token: String,
+    pub scopes: Vec<String>,
+}
+
+/// Result of one poll of the token endpoint.
+#[derive(Debug, PartialEq)]
+enum Poll {
+    Granted(String, Vec<String>),
+    Pending,
+    /// Poll less often; GitHub returns the new interval.
+    SlowDown(Option<u64>),
+}
+
+/// Interpret a token endpoint response. GitHub answers 200 with an `error`
+/// field while the user has not yet approved.
+fn parse_poll(body: &serde_json::Value) -> Result<Poll> {
+    if let Some(token) = body.get("access_token").and_then(|t| t.as_str()) {
+        let scopes = body
+            .get("scope")
+            .and_then(|s| s.as_str())
+            .unwrap_or_default()
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        return Ok(Poll::Granted(token.to_string(), scopes));
+    }
+
+    match body.get("error").and_then(|e| e.as_str()) {
+        Some("authorization_pending") => Ok(Poll::Pending),
+        Some("slow_down") => Ok(Poll::SlowDown(
+            body.get("interval").and_then(|i| i.as_u64()),
+        )),
+        Some("expired_token") => bail!("The device code expired. Run `fgp-github login` again."),
+        Some("access_denied") => bail!("Authorization was denied."),
+        Some(error) => {
+            let description = body
+                .get("error_description")
+                .and_then(|d| d.as_str())
+                .unwrap_or_default();
+            bail!("Device flow failed: {} {}", error, description)
+        }
+        None => bail!("Unexpected token endpoint response: {}", body),
+    }
+}
+
+/// Client for the device flow endpoints, which are unauthenticated and live
+/// on the web host (e.g. github.com) rather than the API host.
+pub struct DeviceFlow {
+    client: Client,
+    client_id: String,
+    endpoints: Endpoints,
+}
+
+impl DeviceFlow {
+    pub fn new(client_id: &str, endpoints: Endpoints) -> Result<Self> {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(30))
+            .user_agent("fgp-github/0.2.0")
+            .build()
+            .context("Failed to build HTTP client")?;
+
+        Ok(Self {
+            client,
+            client_id: client_id.to_string(),
+            endpoints,
+        })
+    }
+
+    /// Start the flow, requesting `scopes`.
+    pub async fn request_code(&self, scopes: &[String]) -> Result<DeviceCode> {
+        let scope = scopes.join(" ");
+        let response = self
+            .client
+            .post(format!("https://{}/login/device/code", self.endpoints.host))
+            .header("Accept", "application/json")
+            .form(&[("client_id", self.client_id.as_str()), ("scope", &scope)])
+            .send()
+            .await
+            .context("Failed to request device code")?;
+
+        let status = response.status();
+        let body: serde_json::Value = response.json().await.context("Failed to parse JSON")?;
+        if !status.is_success() || body.get("error").is_some() {
+            bail!("Failed to start device flow: {} - {}", status, body);
+        }
+        serde_json::from_value(body).context("Unexpected device code response")
+    }
+
+    /// Poll until the user approves the code, honouring `slow_down`.
+    pub async fn wait_for_token(&self, code: &DeviceCode) -> Result<GrantedToken> {
+        let mut interval = code.interval.max(1);
+        let deadline = std::time::Instant::now() + Duration::from_secs(code.expires_in);
+
+        loop {
+            tokio::time::sleep(Duration::from_secs(interval)).await;
+            if std::time::Instant::now() > deadline {
+                bail!("The device code expired. Run `fgp-github login` again.");
+            }
+
+            let response = self
+                .client
+                .post(format!(
+                    "https://{}/login/oauth/access_token",
+                    self.endpoints.host
+                ))
+                .header("Accept", "application/json")
+                .form(&[
+                    ("client_id", self.client_id.as_str()),
+                    ("device_code", code.device_code.as_str()),
+                    ("grant_type", DEVICE_GRANT_TYPE),
+                ])
+                .send()
+                .await
+                .context("Failed to poll for access token")?;
+            let body: serde_json::Value = response.json().await.context("Failed to parse JSON")?;
+
+            match parse_poll(&body)? {
+                Poll::Granted(access_token, scopes) => {
+                    return Ok(GrantedToken {
+                        access_token,
+                        scopes,
+                    })
+                }
+                Poll::Pending => {}
+                Poll::SlowDown(next) => interval = next.unwrap_or(interval + 5),
+            }
+        }
+    }
+
+    /// Look up the login a token belongs to.
+    pub async fn login_for(&self, token: &str) -> Result<String> {
+        #[derive(Deserialize)]
+        struct Viewer {
+            login: String,
+        }
+
+        let response = self
+            .client
+            .get(format!("{}/user", self.endpoints.rest))
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Accept", "application/vnd.github+json")
+            .send()
+            .await
+            .context("Failed to look up the authenticated user")?;
+        if !response.status().is_success() {
+            bail!("Token was rejected: {}", response.status());
+        }
+        let viewer: Viewer = response.json().await.context("Failed to parse JSON")?;
+        Ok(viewer.login)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_poll() {
+        assert_eq!(
+            parse_poll(&json!({"error": "authorization_pending"})).unwrap(),
+            Poll::Pending
+        );
+        assert_eq!(
+            parse_poll(&json!({"error": "slow_down", "interval": 10})).unwrap(),
+            Poll::SlowDown(Some(10))
+        );
+        assert_eq!(
+            parse_poll(
+                &json!({"access_token": "gho_x", "token_type": "bearer", "scope": "repo,gist"})
+            )
+            .unwrap(),
+            Poll::Granted(
+                "gho_x".to_string(),
+                vec!["repo".to_string(), "gist".to_string()]
+            )
+        );
+        assert!(parse_poll(&json!({"error": "access_denied"})).is_err());
+    }
+}
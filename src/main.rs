@@ -9,13 +9,41 @@
 //! fgp-github start -f        # Start in foreground
 //! fgp-github stop            # Stop daemon
 //! fgp-github status          # Check daemon status
+//! fgp-github login --client-id <id>  # Authenticate via OAuth device flow
+//! fgp-github auth set-token  # Store a token (read from stdin) in the OS keychain
+//! fgp-github auth status     # Show token source, login, scopes, expiry, rate limits
 //! ```
 //!
 //! # Authentication
 //! Token resolution order:
-//! 1. GITHUB_TOKEN environment variable
-//! 2. GH_TOKEN environment variable
-//! 3. gh CLI config (~/.config/gh/hosts.yml)
+//! 1. OS keychain (stored with `fgp-github auth set-token`)
+//! 2. GITHUB_TOKEN environment variable
+//! 3. GH_TOKEN environment variable
+//! 4. GitHub App: GITHUB_APP_ID with GITHUB_APP_PRIVATE_KEY or
+//!    GITHUB_APP_PRIVATE_KEY_PATH (and optionally GITHUB_APP_INSTALLATION_ID)
+//! 5. Token stored by `fgp-github login` (~/.fgp/services/github/credentials.json)
+//! 6. gh CLI: hosts.yml (either layout), then `gh auth token` for keyring-stored tokens
+//!
+//! For GitHub Enterprise Server, pass `--host` (or set GH_HOST, or `host:` in
+//! ~/.fgp/services/github/config.yaml). Enterprise hosts read
+//! GH_ENTERPRISE_TOKEN / GITHUB_ENTERPRISE_TOKEN instead of GITHUB_TOKEN.
+//!
+//! Additional named accounts can be added with `fgp-github login --account
+//! <name>` and selected per call with the `account` parameter, or by
+//! repository with `repo_accounts` rules (e.g. `myorg/*` → `work`) in
+//! config.yaml.
+//!
+//! `login` and `auth set-token` refuse to store a token when no OS keychain
+//! is usable, unless `--insecure-file-store` allows the plaintext
+//! credentials file.
+//!
+//! # Caching
+//! Methods listed under `cache.ttl` in config.yaml (seconds per method) reuse
+//! results for identical calls and report `_cache` details; pass
+//! `fresh: true` to skip the cache for one call. With `cache.persist: true`,
+//! repository, issue, PR, and notification results are also kept in
+//! ~/.fgp/services/github/cache.db and returned with `stale: true` when
+//! GitHub is unreachable.
 //!
 //! # Methods
 //! - `github.user` - Get current authenticated user
@@ -25,6 +53,118 @@
 //! - `github.pr` - Get PR details with reviews and status checks
 //! - `github.notifications` - Get unread notifications
 //! - `github.create_issue` - Create a new issue
+//! - `github.run_annotations` - Get check-run annotations for a workflow run
+//! - `github.ci_summary` - Latest run per workflow on the default branch
+//! - `github.actions_settings` - Get or update repository Actions permissions
+//! - `github.releases` - List releases for a repository
+//! - `github.release` - Get a release by tag or the latest release
+//! - `github.create_release` - Create a release, optionally with generated notes
+//! - `github.upload_asset` - Upload a local file as a release asset
+//! - `github.download_asset` - Download a release asset to a local path
+//! - `github.update_release` - Edit a release or publish a draft
+//! - `github.delete_release` - Delete a release, optionally with its tag
+//! - `github.tags` - List tags with their commits
+//! - `github.create_tag` - Create an annotated or lightweight tag
+//! - `github.release_notes` - Generate release notes between two tags
+//! - `github.gists` - List your gists
+//! - `github.gist` - Get a gist including file contents
+//! - `github.create_gist` - Create a gist from a map of files
+//! - `github.update_gist` - Update a gist's files or description
+//! - `github.compare_releases` - Changelog of merged PRs between two tags
+//! - `github.thread_subscription` - Get, subscribe, ignore, or unsubscribe a thread
+//! - `github.notification_events` - Long-poll new notification events (starts poller)
+//! - `github.set_repo_subscription` - Watch, participate-only, or ignore a repo (no custom modes)
+//! - `github.notification_digest` - Unread notifications grouped by repo and reason
+//! - `github.search_code` - Search code with text match fragments
+//! - `github.search` - Search issues or PRs with GitHub query syntax
+//! - `github.search_repos` - Search repositories with qualifiers and sort
+//! - `github.search_users` - Search users and organizations
+//! - `github.search_commits` - Search commits by message, author, and repo
+//! - `github.search_topics` - Search repository topics
+//! - `github.search_all` - Unified search with a consistent cursor envelope
+//! - `github.save_search` - Save a named search
+//! - `github.saved_searches` - List saved searches
+//! - `github.run_saved_search` - Run a saved search by name
+//! - `github.get_user_profile` - Get any user's profile with org memberships
+//! - `github.org_repos` - List an organization's repositories
+//! - `github.org_members` - List org members with role and 2FA status
+//! - `github.orgs` - List your org memberships with role and plan
+//! - `github.teams` - List org teams with privacy and member count
+//! - `github.team_members` - List a team's members
+//! - `github.team_repos` - List a team's repos and permission levels
+//! - `github.add_team_repo` - Grant a team access to a repo
+//! - `github.remove_team_repo` - Remove a team's access to a repo
+//! - `github.org_invitations` - List pending org invitations
+//! - `github.invite_to_org` - Invite a user to an org
+//! - `github.cancel_org_invitation` - Cancel an org invitation
+//! - `github.followers` - List followers
+//! - `github.following` - List followed accounts
+//! - `github.follow` - Follow a user
+//! - `github.unfollow` - Unfollow a user
+//! - `github.contributions` - Contribution calendar and stats
+//! - `github.events` - User and repo event feeds
+//! - `github.audit_log` - Org or enterprise audit log
+//! - `github.keys` - Manage SSH keys
+//! - `github.gpg_keys` - Manage GPG keys
+//! - `github.blocked_users` - List blocked users
+//! - `github.block` - Block a user
+//! - `github.unblock` - Unblock a user
+//! - `github.org_dashboard` - Multi-repo issue/PR/CI dashboard
+//! - `github.dependabot_alerts` - List Dependabot alerts
+//! - `github.dismiss_dependabot_alert` - Dismiss a Dependabot alert
+//! - `github.code_scanning_alerts` - List code scanning alerts
+//! - `github.update_code_scanning_alert` - Dismiss or reopen a code scanning alert
+//! - `github.secret_scanning_alerts` - List secret scanning alerts
+//! - `github.update_secret_scanning_alert` - Resolve or reopen a secret scanning alert
+//! - `github.advisories` - List repo security advisories
+//! - `github.create_advisory` - Draft a security advisory
+//! - `github.sbom` - Export SPDX SBOM
+//! - `github.dependencies` - List manifests and dependencies
+//! - `github.dependency_diff` - Dependency review between refs
+//! - `github.security_features` - Toggle repo security features
+//! - `github.token_info` - Inspect token scopes and which methods are usable
+//! - `github.security_overview` - Repo security posture report
+//! - `github.dependabot_config` - Validate Dependabot config coverage
+//! - `github.discussions` - List discussions
+//! - `github.discussion` - Get a discussion with comments
+//! - `github.create_discussion` - Start a discussion
+//! - `github.discussion_comment` - Comment or reply on a discussion
+//! - `github.discussion_categories` - List discussion categories
+//! - `github.mark_answer` - Mark a discussion answer
+//! - `github.unmark_answer` - Unmark a discussion answer
+//! - `github.projects` - List Projects (v2)
+//! - `github.project_items` - List project items with fields
+//! - `github.project_add` - Add an issue or PR to a project
+//! - `github.project_update_item` - Set project item fields
+//! - `github.project_board` - Project board grouped by status
+//! - `github.project_report` - Project progress report
+//! - `github.commits` - List commit history
+//! - `github.commit` - Get commit detail with file changes
+//! - `github.compare` - Compare two refs
+//! - `github.file` - Read file contents
+//! - `github.put_file` - Create or update a file
+//! - `github.delete_file` - Delete a file
+//! - `github.tree` - List repository tree
+//! - `github.blame` - Blame a file
+//! - `github.refs` - List git refs
+//! - `github.resolve_ref` - Resolve a ref to a commit SHA
+//! - `github.create_blob` - Create a git blob
+//! - `github.create_tree` - Create a git tree
+//! - `github.create_commit` - Create a git commit object
+//! - `github.update_ref` - Move or create a git ref
+//! - `github.commit_files` - Commit multiple files
+//! - `github.branch_status` - Branch ahead/behind status
+//! - `github.verify_commits` - Check commit signatures
+//! - `github.archive` - Download a repository archive
+//! - `github.file_history` - List commits touching a file
+//! - `github.owners_for_path` - Look up CODEOWNERS for paths
+//! - `github.commit_activity` - Weekly commit activity
+//! - `github.contributor_stats` - Weekly contributor statistics
+//! - `github.installations` - List GitHub App installations
+//! - `github.accounts` - List configured accounts
+//! - `github.reload_auth` - Re-read tokens without restarting
+//! - `github.sso_status` - Organizations the token lacks SSO authorization for
+//! - `github.rate_limit` - Rate-limit standing per resource
 //!
 //! # Test
 //! ```bash
@@ -38,9 +178,20 @@
 //! 01/14/2026 - Upgraded to GraphQL/REST API, removed gh CLI dependency (Claude)
 //! 01/12/2026 - Initial implementation with gh CLI wrapper (Claude)
 
+mod accounts;
 mod api;
+mod cache;
+mod codeowners;
+mod config;
+mod credentials;
+mod disk_cache;
+mod keychain;
+mod login;
 mod models;
+mod poller;
+mod scopes;
 mod service;
+mod state;
 
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
@@ -52,6 +203,9 @@ use crate::service::GitHubService;
 
 const DEFAULT_SOCKET: &str = "~/.fgp/services/github/daemon.sock";
 
+/// Scopes requested by `fgp-github login` unless overridden.
+const DEFAULT_LOGIN_SCOPES: &str = "repo,read:org,gist,notifications,workflow";
+
 #[derive(Parser)]
 #[command(name = "fgp-github")]
 #[command(about = "FGP daemon for GitHub operations via GraphQL/REST API")]
@@ -72,6 +226,11 @@ enum Commands {
         /// Run in foreground (don't daemonize)
         #[arg(short, long)]
         foreground: bool,
+
+        /// GitHub host, e.g. a GitHub Enterprise Server hostname
+        /// (default: GH_HOST, then config.yaml, then github.com)
+        #[arg(long)]
+        host: Option<String>,
     },
 
     /// Stop the running daemon
@@ -87,19 +246,108 @@ enum Commands {
         #[arg(short, long, default_value = DEFAULT_SOCKET)]
         socket: String,
     },
+
+    /// Authenticate with GitHub via the OAuth device flow and store the token
+    Login {
+        /// Client ID of an OAuth App with device flow enabled
+        #[arg(long, env = "FGP_GITHUB_CLIENT_ID")]
+        client_id: String,
+
+        /// Comma-separated OAuth scopes to request
+        #[arg(long, default_value = DEFAULT_LOGIN_SCOPES)]
+        scopes: String,
+
+        /// Account name to store the token under
+        #[arg(long, default_value = credentials::DEFAULT_ACCOUNT)]
+        account: String,
+
+        /// GitHub host to log in to (default: GH_HOST, then config.yaml,
+        /// then github.com)
+        #[arg(long)]
+        host: Option<String>,
+
+        /// Without an OS keychain, keep the token in plaintext in the
+        /// owner-only credentials file instead of failing
+        #[arg(long)]
+        insecure_file_store: bool,
+    },
+
+    /// Manage stored credentials
+    Auth {
+        #[command(subcommand)]
+        command: AuthCommands,
+    },
+}
+
+#[derive(Subcommand)]
+enum AuthCommands {
+    /// Store a token read from stdin in the OS keychain
+    SetToken {
+        /// Account name to store the token under
+        #[arg(long, default_value = credentials::DEFAULT_ACCOUNT)]
+        account: String,
+
+        /// GitHub host the token is for
+        #[arg(long)]
+        host: Option<String>,
+
+        /// Without an OS keychain, keep the token in plaintext in the
+        /// owner-only credentials file instead of failing
+        #[arg(long)]
+        insecure_file_store: bool,
+    },
+
+    /// Delete a stored token
+    DeleteToken {
+        /// Account name whose token to delete
+        #[arg(long, default_value = credentials::DEFAULT_ACCOUNT)]
+        account: String,
+    },
+
+    /// Show where each account's token comes from and what it can do,
+    /// without starting the daemon
+    Status {
+        /// Only show this account
+        #[arg(long)]
+        account: Option<String>,
+
+        /// GitHub host for the default account
+        #[arg(long)]
+        host: Option<String>,
+    },
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Start { socket, foreground } => cmd_start(socket, foreground),
+        Commands::Start {
+            socket,
+            foreground,
+            host,
+        } => cmd_start(socket, foreground, host),
         Commands::Stop { socket } => cmd_stop(socket),
         Commands::Status { socket } => cmd_status(socket),
+        Commands::Login {
+            client_id,
+            scopes,
+            account,
+            host,
+            insecure_file_store,
+        } => cmd_login(client_id, scopes, account, host, insecure_file_store),
+        Commands::Auth { command } => match command {
+            AuthCommands::SetToken {
+                account,
+                host,
+                insecure_file_store,
+            } => cmd_set_token(account, host, insecure_file_store),
+            AuthCommands::DeleteToken { account } => cmd_delete_token(account),
+            AuthCommands::Status { account, host } => cmd_auth_status(account, host),
+        },
     }
 }
 
-fn cmd_start(socket: String, foreground: bool) -> Result<()> {
+fn cmd_start(socket: String, foreground: bool, host: Option<String>) -> Result<()> {
     let socket_path = shellexpand::tilde(&socket).to_string();
 
     // Create parent directory
@@ -120,6 +368,118 @@ fn cmd_start(socket: String, foreground: bool) -> Result<()> {
     println!("  github.pr             - Get PR details with reviews/checks");
     println!("  github.notifications  - Get unread notifications");
     println!("  github.create_issue   - Create a new issue");
+    println!("  github.run_annotations - Get check-run annotations for a workflow run");
+    println!("  github.ci_summary     - Latest run per workflow on the default branch");
+    println!("  github.actions_settings - Get or update repository Actions permissions");
+    println!("  github.releases       - List releases for a repository");
+    println!("  github.release        - Get a release by tag or the latest release");
+    println!("  github.create_release - Create a release, optionally with generated notes");
+    println!("  github.upload_asset   - Upload a local file as a release asset");
+    println!("  github.download_asset - Download a release asset to a local path");
+    println!("  github.update_release - Edit a release or publish a draft");
+    println!("  github.delete_release - Delete a release, optionally with its tag");
+    println!("  github.tags           - List tags with their commits");
+    println!("  github.create_tag     - Create an annotated or lightweight tag");
+    println!("  github.release_notes  - Generate release notes between two tags");
+    println!("  github.gists          - List your gists");
+    println!("  github.gist           - Get a gist including file contents");
+    println!("  github.create_gist    - Create a gist from a map of files");
+    println!("  github.update_gist    - Update a gist's files or description");
+    println!("  github.compare_releases - Changelog of merged PRs between two tags");
+    println!("  github.thread_subscription - Get, subscribe, ignore, or unsubscribe a thread");
+    println!("  github.notification_events - Long-poll new notification events (starts poller)");
+    println!("  github.set_repo_subscription - Watch, participate-only, or ignore a repo (no custom modes)");
+    println!("  github.notification_digest - Unread notifications grouped by repo and reason");
+    println!("  github.search_code    - Search code with text match fragments");
+    println!("  github.search         - Search issues or PRs with GitHub query syntax");
+    println!("  github.search_repos   - Search repositories with qualifiers and sort");
+    println!("  github.search_users   - Search users and organizations");
+    println!("  github.search_commits - Search commits by message, author, and repo");
+    println!("  github.search_topics  - Search repository topics");
+    println!("  github.search_all     - Unified search with a consistent cursor envelope");
+    println!("  github.save_search    - Save a named search");
+    println!("  github.saved_searches - List saved searches");
+    println!("  github.run_saved_search - Run a saved search by name");
+    println!("  github.get_user_profile - Get any user's profile with org memberships");
+    println!("  github.org_repos      - List an organization's repositories");
+    println!("  github.org_members    - List org members with role and 2FA status");
+    println!("  github.orgs           - List your org memberships with role and plan");
+    println!("  github.teams          - List org teams with privacy and member count");
+    println!("  github.team_members   - List a team's members");
+    println!("  github.team_repos     - List a team's repos and permission levels");
+    println!("  github.add_team_repo  - Grant a team access to a repo");
+    println!("  github.remove_team_repo - Remove a team's access to a repo");
+    println!("  github.org_invitations - List pending org invitations");
+    println!("  github.invite_to_org  - Invite a user to an org");
+    println!("  github.cancel_org_invitation - Cancel an org invitation");
+    println!("  github.followers      - List followers");
+    println!("  github.following      - List followed accounts");
+    println!("  github.follow         - Follow a user");
+    println!("  github.unfollow       - Unfollow a user");
+    println!("  github.contributions  - Contribution calendar and stats");
+    println!("  github.events         - User and repo event feeds");
+    println!("  github.audit_log      - Org or enterprise audit log");
+    println!("  github.keys           - Manage SSH keys");
+    println!("  github.gpg_keys       - Manage GPG keys");
+    println!("  github.blocked_users  - List blocked users");
+    println!("  github.block          - Block a user");
+    println!("  github.unblock        - Unblock a user");
+    println!("  github.org_dashboard  - Multi-repo issue/PR/CI dashboard");
+    println!("  github.dependabot_alerts - List Dependabot alerts");
+    println!("  github.dismiss_dependabot_alert - Dismiss a Dependabot alert");
+    println!("  github.code_scanning_alerts - List code scanning alerts");
+    println!("  github.update_code_scanning_alert - Dismiss or reopen a code scanning alert");
+    println!("  github.secret_scanning_alerts - List secret scanning alerts");
+    println!("  github.update_secret_scanning_alert - Resolve or reopen a secret scanning alert");
+    println!("  github.advisories     - List repo security advisories");
+    println!("  github.create_advisory - Draft a security advisory");
+    println!("  github.sbom           - Export SPDX SBOM");
+    println!("  github.dependencies   - List manifests and dependencies");
+    println!("  github.dependency_diff - Dependency review between refs");
+    println!("  github.security_features - Toggle repo security features");
+    println!("  github.token_info     - Inspect token scopes and which methods are usable");
+    println!("  github.security_overview - Repo security posture report");
+    println!("  github.dependabot_config - Validate Dependabot config coverage");
+    println!("  github.discussions    - List discussions");
+    println!("  github.discussion     - Get a discussion with comments");
+    println!("  github.create_discussion - Start a discussion");
+    println!("  github.discussion_comment - Comment or reply on a discussion");
+    println!("  github.discussion_categories - List discussion categories");
+    println!("  github.mark_answer    - Mark a discussion answer");
+    println!("  github.unmark_answer  - Unmark a discussion answer");
+    println!("  github.projects       - List Projects (v2)");
+    println!("  github.project_items  - List project items with fields");
+    println!("  github.project_add    - Add an issue or PR to a project");
+    println!("  github.project_update_item - Set project item fields");
+    println!("  github.project_board  - Project board grouped by status");
+    println!("  github.project_report - Project progress report");
+    println!("  github.commits        - List commit history");
+    println!("  github.commit         - Get commit detail with file changes");
+    println!("  github.compare        - Compare two refs");
+    println!("  github.file           - Read file contents");
+    println!("  github.put_file       - Create or update a file");
+    println!("  github.delete_file    - Delete a file");
+    println!("  github.tree           - List repository tree");
+    println!("  github.blame          - Blame a file");
+    println!("  github.refs           - List git refs");
+    println!("  github.resolve_ref    - Resolve a ref to a commit SHA");
+    println!("  github.create_blob    - Create a git blob");
+    println!("  github.create_tree    - Create a git tree");
+    println!("  github.create_commit  - Create a git commit object");
+    println!("  github.update_ref     - Move or create a git ref");
+    println!("  github.commit_files   - Commit multiple files");
+    println!("  github.branch_status  - Branch ahead/behind status");
+    println!("  github.verify_commits - Check commit signatures");
+    println!("  github.archive        - Download a repository archive");
+    println!("  github.file_history   - List commits touching a file");
+    println!("  github.owners_for_path - Look up CODEOWNERS for paths");
+    println!("  github.commit_activity - Weekly commit activity");
+    println!("  github.contributor_stats - Weekly contributor statistics");
+    println!("  github.installations  - List GitHub App installations");
+    println!("  github.accounts       - List configured accounts");
+    println!("  github.reload_auth    - Re-read tokens without restarting");
+    println!("  github.sso_status     - Organizations the token lacks SSO authorization for");
+    println!("  github.rate_limit     - Rate-limit standing per resource");
     println!();
     println!("Test with:");
     println!("  fgp call github.user");
@@ -133,7 +493,8 @@ fn cmd_start(socket: String, foreground: bool) -> Result<()> {
             .init();
 
         // Token is resolved inside GitHubService::new
-        let service = GitHubService::new(None).context("Failed to create GitHubService")?;
+        let service =
+            GitHubService::new(None, host.as_deref()).context("Failed to create GitHubService")?;
         let server =
             FgpServer::new(service, &socket_path).context("Failed to create FGP server")?;
         server.serve().context("Server error")?;
@@ -153,7 +514,8 @@ fn cmd_start(socket: String, foreground: bool) -> Result<()> {
                     .with_env_filter("fgp_github=debug,fgp_daemon=debug")
                     .init();
 
-                let service = GitHubService::new(None).context("Failed to create GitHubService")?;
+                let service = GitHubService::new(None, host.as_deref())
+                    .context("Failed to create GitHubService")?;
                 let server =
                     FgpServer::new(service, &socket_path).context("Failed to create FGP server")?;
                 server.serve().context("Server error")?;
@@ -263,3 +625,234 @@ fn cmd_status(socket: String) -> Result<()> {
 
     Ok(())
 }
+
+fn cmd_login(
+    client_id: String,
+    scopes: String,
+    account: String,
+    host: Option<String>,
+    insecure_file_store: bool,
+) -> Result<()> {
+    let scopes: Vec<String> = scopes
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    let endpoints = config::DaemonConfig::load()?.endpoints(host.as_deref());
+    let store = credentials::CredentialStore::new().with_insecure_file_store(insecure_file_store);
+    store.check_backend(&account, &endpoints.host)?;
+    let flow = login::DeviceFlow::new(&client_id, endpoints.clone())?;
+
+    let (granted, login) = runtime.block_on(async {
+        let code = flow.request_code(&scopes).await?;
+        println!("First copy your one-time code: {}", code.user_code);
+        println!("Then open {} and enter it.", code.verification_uri);
+        println!();
+        println!("Waiting for authorization...");
+
+        let granted = flow.wait_for_token(&code).await?;
+        let login = flow.login_for(&granted.access_token).await?;
+        anyhow::Ok((granted, login))
+    })?;
+
+    let in_keychain = store.set_token(
+        &account,
+        credentials::StoredToken {
+            token: granted.access_token,
+            keychain: false,
+            host: Some(endpoints.host.clone()),
+            login: Some(login.clone()),
+            scopes: granted.scopes,
+            source: "device_flow".to_string(),
+            created_at: chrono::Utc::now().to_rfc3339(),
+        },
+    )?;
+
+    println!("Logged in as {} (account '{}').", login, account);
+    print_token_location(&store, in_keychain);
+    print_reload_hint();
+
+    Ok(())
+}
+
+fn cmd_set_token(account: String, host: Option<String>, insecure_file_store: bool) -> Result<()> {
+    use std::io::{BufRead, IsTerminal, Write};
+
+    let endpoints = config::DaemonConfig::load()?.endpoints(host.as_deref());
+    let store = credentials::CredentialStore::new().with_insecure_file_store(insecure_file_store);
+    store.check_backend(&account, &endpoints.host)?;
+
+    if std::io::stdin().is_terminal() {
+        eprint!("Paste your GitHub token: ");
+        std::io::stderr().flush()?;
+    }
+    let mut token = String::new();
+    std::io::stdin()
+        .lock()
+        .read_line(&mut token)
+        .context("Failed to read token from stdin")?;
+    let token = token.trim().to_string();
+    if token.is_empty() {
+        anyhow::bail!("No token given on stdin");
+    }
+
+    // Check the token works before storing it
+    let client = api::GitHubClient::new(Some(token.clone()), endpoints.clone())?;
+    let info = tokio::runtime::Runtime::new()?
+        .block_on(client.get_token_info())
+        .context("Token was rejected by GitHub")?;
+
+    let in_keychain = store.set_token(
+        &account,
+        credentials::StoredToken {
+            token,
+            keychain: false,
+            host: Some(endpoints.host.clone()),
+            login: Some(info.login.clone()),
+            scopes: info.scopes.unwrap_or_default(),
+            source: "set_token".to_string(),
+            created_at: chrono::Utc::now().to_rfc3339(),
+        },
+    )?;
+
+    println!(
+        "Stored token for {} on {} (account '{}').",
+        info.login, endpoints.host, account
+    );
+    print_token_location(&store, in_keychain);
+    print_reload_hint();
+
+    Ok(())
+}
+
+fn cmd_delete_token(account: String) -> Result<()> {
+    if credentials::CredentialStore::new().remove(&account)? {
+        println!("Deleted token for account '{}'.", account);
+    } else {
+        println!("No stored token for account '{}'.", account);
+    }
+    Ok(())
+}
+
+fn cmd_auth_status(account: Option<String>, host: Option<String>) -> Result<()> {
+    let pool = accounts::AccountPool::load(None, host.as_deref())?;
+    let runtime = tokio::runtime::Runtime::new()?;
+
+    let mut first = true;
+    for (name, entry) in pool.iter() {
+        if account.as_deref().is_some_and(|a| a != name) {
+            continue;
+        }
+        if !first {
+            println!();
+        }
+        first = false;
+        runtime.block_on(print_account_status(name, &entry.client));
+    }
+
+    if first {
+        // Reuse the pool's error listing the configured accounts
+        pool.get(account.as_deref())?;
+    }
+    Ok(())
+}
+
+/// Print one account's token details. Lookup failures are reported inline
+/// so the remaining accounts are still shown.
+async fn print_account_status(name: &str, client: &api::GitHubClient) {
+    println!("{} (account '{}')", client.host(), name);
+    println!("  Token source: {}", client.token_source());
+
+    if client.is_app() {
+        match client.refresh_installation_token().await {
+            Ok(_) => {
+                println!("  Token type:   app_installation");
+                if let Some(expiry) = client.installation_token_expiry() {
+                    println!("  Expires:      {}", format_expiry(expiry));
+                }
+            }
+            Err(e) => {
+                println!("  Error:        {:#}", e);
+                return;
+            }
+        }
+    } else {
+        match client.get_token_info().await {
+            Ok(info) => {
+                println!("  Logged in as: {}", info.login);
+                println!("  Token type:   {}", info.token_type);
+                match info.scopes {
+                    Some(scopes) if scopes.is_empty() => println!("  Scopes:       (none)"),
+                    Some(scopes) => println!("  Scopes:       {}", scopes.join(", ")),
+                    None => println!("  Scopes:       (not reported for this token type)"),
+                }
+                match info.expires_at.as_deref() {
+                    Some(raw) => match api::parse_expiration(raw) {
+                        Some(expiry) => {
+                            println!("  Expires:      {}", format_expiry(expiry));
+                            if api::expires_soon(expiry) {
+                                println!(
+                                    "  Warning:      expires within {} days; rotate the token",
+                                    api::EXPIRY_WARNING_DAYS
+                                );
+                            }
+                        }
+                        None => println!("  Expires:      {}", raw),
+                    },
+                    None => println!("  Expires:      never"),
+                }
+            }
+            Err(e) => {
+                println!("  Error:        {:#}", e);
+                return;
+            }
+        }
+    }
+
+    match client.get_rate_limit().await {
+        Ok(limits) => {
+            let mut label = "Rate limit:";
+            for limit in limits
+                .iter()
+                .filter(|l| matches!(l.resource.as_str(), "core" | "graphql" | "search"))
+            {
+                println!(
+                    "  {:<13} {} {}/{} remaining, resets {}",
+                    label, limit.resource, limit.remaining, limit.limit, limit.reset_at
+                );
+                label = "";
+            }
+        }
+        Err(e) => println!("  Rate limit:   unavailable ({:#})", e),
+    }
+}
+
+/// An expiry time with the days remaining.
+fn format_expiry(expiry: chrono::DateTime<chrono::Utc>) -> String {
+    let days = (expiry - chrono::Utc::now()).num_days();
+    let remaining = if expiry <= chrono::Utc::now() {
+        "expired".to_string()
+    } else if days == 0 {
+        "less than a day left".to_string()
+    } else {
+        format!("{} days left", days)
+    };
+    format!("{} ({})", expiry.format("%Y-%m-%d %H:%M UTC"), remaining)
+}
+
+fn print_token_location(store: &credentials::CredentialStore, in_keychain: bool) {
+    if in_keychain {
+        println!("Token saved to the OS keychain.");
+    } else {
+        println!(
+            "OS keychain unavailable; token saved in plaintext to {}",
+            store.path().display()
+        );
+    }
+}
+
+fn print_reload_hint() {
+    println!("A running daemon picks it up on `fgp call github.reload_auth` (or a restart).");
+}
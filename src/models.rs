@@ -1,9 +1,15 @@
 //! Data models for GitHub API responses.
 //!
 //! # CHANGELOG (recent first, max 5 entries)
-//! 01/14/2026 - Initial implementation (Claude)
+//! 10/16/2026 - Surface SAML SSO authorization errors and add github.sso_status
+//! 10/16/2026 - Add named accounts with per-call selection and github.accounts
+//! 10/16/2026 - Add GitHub App authentication with auto-refreshed installation tokens
+//! 10/16/2026 - Add github.contributor_stats with per-week additions, deletions, and commits
+//! 10/16/2026 - Add github.commit_activity with 202 retry handling
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
 
 /// GitHub user.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +29,627 @@ pub struct User {
     pub created_at: String,
 }
 
+/// Details about the token the daemon authenticates with.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenInfo {
+    pub login: String,
+    /// `classic`, `fine_grained`, `oauth`, `app_user`, `app_installation`,
+    /// or `unknown`.
+    pub token_type: String,
+    /// Granted OAuth scopes; `None` for token types that do not report them.
+    pub scopes: Option<Vec<String>>,
+    pub expires_at: Option<String>,
+}
+
+/// Rate-limit standing for one API resource (core, graphql, search, ...).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitStatus {
+    pub resource: String,
+    pub limit: u64,
+    pub remaining: u64,
+    pub used: u64,
+    pub reset_at: String,
+}
+
+/// SAML SSO authorization of the token across the viewer's organizations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SsoStatus {
+    /// Organizations the token can access.
+    pub authorized: Vec<String>,
+    /// Organizations that enforce SSO and have not authorized the token.
+    pub unauthorized: Vec<SsoOrganization>,
+}
+
+/// An organization the token still needs SSO authorization for.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SsoOrganization {
+    pub id: Option<u64>,
+    /// `None` when the organization could not be looked up.
+    pub login: Option<String>,
+    pub authorize_url: String,
+}
+
+/// An installation of the authenticated GitHub App.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Installation {
+    pub id: u64,
+    /// Login of the user or organization the app is installed on.
+    pub account: String,
+    /// `User` or `Organization`.
+    pub target_type: String,
+    /// `all` or `selected`.
+    pub repository_selection: String,
+    pub permissions: BTreeMap<String, String>,
+    pub events: Vec<String>,
+    pub created_at: String,
+    pub suspended: bool,
+    pub url: String,
+}
+
+/// A user's profile with their visible organization memberships.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserProfile {
+    #[serde(flatten)]
+    pub user: User,
+    /// Logins of organizations the user publicly belongs to.
+    pub organizations: Vec<String>,
+}
+
+/// Minimal account reference returned by list endpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserSummary {
+    pub login: String,
+    pub avatar_url: String,
+    pub url: String,
+}
+
+/// Contribution calendar and totals for a user over a date range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contributions {
+    pub login: String,
+    pub from: String,
+    pub to: String,
+    /// Calendar total, including private contributions when visible.
+    pub total: i32,
+    pub totals: ContributionTotals,
+    /// Consecutive days with contributions, ending today (or yesterday).
+    pub current_streak: u32,
+    pub longest_streak: u32,
+    pub days: Vec<ContributionDay>,
+}
+
+/// Contribution counts by type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContributionTotals {
+    pub commits: i32,
+    pub issues: i32,
+    pub pull_requests: i32,
+    pub reviews: i32,
+    pub repositories: i32,
+    /// Contributions to private repositories the viewer cannot see.
+    pub restricted: i32,
+}
+
+/// Contributions on a single day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContributionDay {
+    pub date: String,
+    pub count: i32,
+}
+
+/// Organization team.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Team {
+    pub slug: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// `visible` or `secret`.
+    pub privacy: String,
+    pub member_count: i32,
+    /// Slug of the parent team, if nested.
+    pub parent: Option<String>,
+    pub url: String,
+}
+
+/// Member of a team.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamMember {
+    pub login: String,
+    pub name: Option<String>,
+    /// `maintainer` or `member`.
+    pub role: String,
+}
+
+/// Repository a team can access.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamRepo {
+    pub full_name: String,
+    pub is_private: bool,
+    /// `pull`, `triage`, `push`, `maintain`, `admin`, or a custom role name.
+    pub permission: String,
+    pub url: String,
+}
+
+/// The viewer's membership in an organization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgMembership {
+    pub org: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// `admin` (owner) or `member`.
+    pub role: String,
+    /// `active` or `pending`.
+    pub state: String,
+    /// Billing plan; only visible to organization owners.
+    pub plan: Option<OrgPlan>,
+}
+
+/// Pending invitation to join an organization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgInvitation {
+    pub id: u64,
+    /// Invitee login, when invited by account rather than email.
+    pub login: Option<String>,
+    pub email: Option<String>,
+    /// `admin`, `direct_member`, `billing_manager`, or `hiring_manager`.
+    pub role: String,
+    pub inviter: Option<String>,
+    pub team_count: i32,
+    pub created_at: String,
+}
+
+/// Audit log entry from an organization or enterprise.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLogEntry {
+    pub id: Option<String>,
+    /// Action name, e.g. `repo.create` or `org.add_member`.
+    pub action: String,
+    pub actor: Option<String>,
+    /// User affected by the action, if any.
+    pub user: Option<String>,
+    pub repo: Option<String>,
+    pub org: Option<String>,
+    pub country_code: Option<String>,
+    pub created_at: Option<String>,
+    /// Full entry as returned by the API.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<Value>,
+}
+
+/// Consolidated open-work and CI report across repositories.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgDashboard {
+    pub org: Option<String>,
+    pub open_issues: i32,
+    pub open_prs: i32,
+    /// Repositories whose default-branch head is failing CI.
+    pub failing_ci: Vec<String>,
+    pub repos: Vec<RepoDashboard>,
+    /// Requested repositories that could not be read.
+    pub missing: Vec<String>,
+}
+
+/// One repository's row in an [`OrgDashboard`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoDashboard {
+    pub repo: String,
+    pub url: String,
+    pub open_issues: i32,
+    pub open_prs: i32,
+    pub oldest_prs: Vec<DashboardPr>,
+    pub default_branch: Option<String>,
+    /// Combined check state of the default-branch head: `success`, `failure`,
+    /// `error`, `pending`, or `expected`; `None` when no checks ran.
+    pub ci_state: Option<String>,
+}
+
+/// Open pull request listed on a dashboard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DashboardPr {
+    pub number: i32,
+    pub title: String,
+    pub author: Option<String>,
+    pub is_draft: bool,
+    pub created_at: String,
+    pub url: String,
+}
+
+/// Organization billing plan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgPlan {
+    pub name: String,
+    pub seats: Option<i64>,
+    pub filled_seats: Option<i64>,
+}
+
+/// Organization member with role.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgMember {
+    pub login: String,
+    pub name: Option<String>,
+    /// `admin` (owner) or `member`.
+    pub role: String,
+    /// Two-factor status; only visible to organization owners.
+    pub two_factor_enabled: Option<bool>,
+}
+
+/// Activity event from a user or repository feed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Event {
+    pub id: String,
+    /// API event name, e.g. `PushEvent`.
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub actor: String,
+    pub repo: String,
+    /// Payload `action` (e.g. `opened`), for event types that have one.
+    pub action: Option<String>,
+    pub public: bool,
+    pub created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload: Option<Value>,
+}
+
+/// SSH public key registered to the authenticated user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SshKey {
+    pub id: u64,
+    pub title: Option<String>,
+    pub key: String,
+    pub created_at: Option<String>,
+    pub verified: bool,
+    pub read_only: bool,
+}
+
+/// GPG public key registered to the authenticated user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GpgKey {
+    pub id: u64,
+    pub name: Option<String>,
+    /// Long key id, e.g. `3262EFF25BA0D270`.
+    pub key_id: String,
+    pub emails: Vec<String>,
+    pub can_sign: bool,
+    pub created_at: Option<String>,
+    pub expires_at: Option<String>,
+    pub revoked: bool,
+}
+
+/// Dependabot vulnerability alert.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependabotAlert {
+    pub number: u64,
+    /// Repository, for organization-level listings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo: Option<String>,
+    /// `open`, `dismissed`, `fixed`, or `auto_dismissed`.
+    pub state: String,
+    /// `low`, `medium`, `high`, or `critical`.
+    pub severity: String,
+    pub package: String,
+    pub ecosystem: String,
+    pub manifest_path: Option<String>,
+    /// `runtime` or `development`.
+    pub scope: Option<String>,
+    pub ghsa_id: String,
+    pub cve_id: Option<String>,
+    pub summary: String,
+    pub vulnerable_version_range: String,
+    pub patched_version: Option<String>,
+    pub dismissed_reason: Option<String>,
+    pub created_at: String,
+    pub url: String,
+}
+
+/// Code scanning alert.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeScanningAlert {
+    pub number: u64,
+    /// Repository, for organization-level listings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo: Option<String>,
+    /// `open`, `dismissed`, or `fixed`.
+    pub state: String,
+    pub rule_id: Option<String>,
+    pub rule_description: Option<String>,
+    /// `critical`/`high`/`medium`/`low` for security rules, otherwise
+    /// `error`/`warning`/`note`.
+    pub severity: Option<String>,
+    pub tool: Option<String>,
+    pub path: Option<String>,
+    pub start_line: Option<u32>,
+    pub end_line: Option<u32>,
+    pub message: Option<String>,
+    #[serde(rename = "ref")]
+    pub git_ref: Option<String>,
+    pub dismissed_reason: Option<String>,
+    pub created_at: String,
+    pub url: String,
+}
+
+/// Secret scanning alert. The detected secret value is never included.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretScanningAlert {
+    pub number: u64,
+    /// Repository, for organization-level listings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo: Option<String>,
+    /// `open` or `resolved`.
+    pub state: String,
+    /// Provider pattern id, e.g. `github_personal_access_token`.
+    pub secret_type: String,
+    pub secret_type_display_name: Option<String>,
+    /// `active`, `inactive`, or `unknown`.
+    pub validity: Option<String>,
+    /// `false_positive`, `wont_fix`, `revoked`, or `used_in_tests`.
+    pub resolution: Option<String>,
+    pub resolved_by: Option<String>,
+    pub created_at: String,
+    pub url: String,
+    #[serde(default)]
+    pub locations: Vec<SecretLocation>,
+}
+
+/// Where a secret was found.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretLocation {
+    /// `commit`, `issue_title`, `issue_comment`, `pull_request_body`, ...
+    #[serde(rename = "type")]
+    pub location_type: String,
+    pub path: Option<String>,
+    pub start_line: Option<u32>,
+    pub commit_sha: Option<String>,
+    pub url: Option<String>,
+}
+
+/// Security features enabled on a repository. `None` means the token
+/// cannot see the setting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecurityFeatures {
+    pub repo: String,
+    pub dependabot_alerts: Option<bool>,
+    pub dependabot_security_updates: Option<bool>,
+    pub secret_scanning: Option<bool>,
+    pub push_protection: Option<bool>,
+}
+
+/// Dependabot configuration check for a repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependabotConfigReport {
+    pub repo: String,
+    /// Branch the config and manifests were read from (the default branch).
+    pub branch: String,
+    /// Config file path; `None` when the repository has no config.
+    pub path: Option<String>,
+    /// True when a config exists and has no errors.
+    pub valid: bool,
+    pub issues: Vec<ConfigIssue>,
+    pub updates: Vec<DependabotUpdate>,
+    /// Manifest directories per detected ecosystem and which are covered.
+    pub coverage: Vec<EcosystemCoverage>,
+    /// Configured `ecosystem directory` entries that match no manifest.
+    pub unmatched_updates: Vec<String>,
+    /// True when the repository tree was too large to list completely.
+    pub tree_truncated: bool,
+}
+
+/// Problem found while validating a configuration file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigIssue {
+    /// `error` or `warning`.
+    pub severity: String,
+    /// Location in the document, e.g. `updates[0].schedule.interval`.
+    pub path: String,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    pub fn error(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: "error".to_string(),
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn warning(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: "warning".to_string(),
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Update entry from a Dependabot configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependabotUpdate {
+    pub ecosystem: String,
+    pub directories: Vec<String>,
+    pub interval: Option<String>,
+    pub target_branch: Option<String>,
+}
+
+/// Dependabot coverage of one detected package ecosystem.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EcosystemCoverage {
+    pub ecosystem: String,
+    /// Whether any update entry targets this ecosystem.
+    pub configured: bool,
+    /// Directories containing manifests for this ecosystem.
+    pub directories: Vec<String>,
+    /// Manifest directories no update entry covers.
+    pub uncovered: Vec<String>,
+}
+
+/// Security posture of a repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecurityOverview {
+    pub repo: String,
+    pub default_branch: String,
+    /// Whether the token could read classic branch protection (admin only).
+    pub protection_readable: bool,
+    /// Classic protection on the default branch; `None` when unprotected or
+    /// unreadable.
+    pub branch_protection: Option<BranchProtection>,
+    /// Rule types active on the default branch from rulesets.
+    pub branch_rules: Vec<String>,
+    /// Whether signed commits are enforced by protection or a ruleset;
+    /// `None` when protection is unreadable and no ruleset requires them.
+    pub signed_commits_required: Option<bool>,
+    pub features: SecurityFeatures,
+    /// Open alert counts; `None` when the feature is off or unreadable.
+    pub dependabot_alerts: Option<AlertCounts>,
+    pub code_scanning_alerts: Option<AlertCounts>,
+    pub secret_scanning_alerts: Option<usize>,
+}
+
+/// Classic branch protection settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchProtection {
+    /// Required approving reviews; `None` when reviews are not required.
+    pub required_reviews: Option<u32>,
+    pub dismiss_stale_reviews: bool,
+    pub require_code_owner_reviews: bool,
+    pub required_status_checks: Vec<String>,
+    pub strict_status_checks: bool,
+    pub enforce_admins: bool,
+    pub required_signatures: bool,
+    pub linear_history: bool,
+    pub allow_force_pushes: bool,
+    pub allow_deletions: bool,
+}
+
+/// Alert totals keyed by severity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertCounts {
+    pub total: usize,
+    pub by_severity: BTreeMap<String, usize>,
+}
+
+/// Security feature changes; `None` leaves a feature unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct SecurityFeaturesUpdate {
+    pub dependabot_alerts: Option<bool>,
+    pub dependabot_security_updates: Option<bool>,
+    pub secret_scanning: Option<bool>,
+    pub push_protection: Option<bool>,
+}
+
+/// Repository security advisory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoAdvisory {
+    pub ghsa_id: String,
+    pub cve_id: Option<String>,
+    pub summary: String,
+    /// `low`, `medium`, `high`, or `critical`.
+    pub severity: Option<String>,
+    /// `triage`, `draft`, `published`, or `closed`.
+    pub state: String,
+    pub cwe_ids: Vec<String>,
+    pub vulnerabilities: Vec<AdvisoryVulnerability>,
+    pub created_at: String,
+    pub published_at: Option<String>,
+    pub url: String,
+}
+
+/// Package affected by an advisory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdvisoryVulnerability {
+    pub ecosystem: String,
+    pub package: String,
+    /// e.g. `< 1.2.3`.
+    #[serde(default)]
+    pub vulnerable_version_range: Option<String>,
+    /// e.g. `1.2.3`.
+    #[serde(default)]
+    pub patched_versions: Option<String>,
+}
+
+/// Parameters for drafting a repository security advisory.
+#[derive(Debug, Clone, Default)]
+pub struct NewAdvisory {
+    pub summary: String,
+    pub description: String,
+    /// Either `severity` or `cvss_vector` may be set, not both.
+    pub severity: Option<String>,
+    pub cvss_vector: Option<String>,
+    pub cve_id: Option<String>,
+    pub cwe_ids: Vec<String>,
+    pub vulnerabilities: Vec<AdvisoryVulnerability>,
+}
+
+/// Dependency manifest detected by the dependency graph.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyManifest {
+    pub filename: String,
+    /// Path within the repository.
+    pub path: String,
+    pub dependencies_count: i32,
+    /// False when GitHub could not parse the manifest or it was too large.
+    pub parseable: bool,
+    pub dependencies: Vec<ManifestDependency>,
+}
+
+/// Dependency declared in a manifest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestDependency {
+    pub package: String,
+    /// e.g. `npm`, `pip`, `rust`.
+    pub package_manager: Option<String>,
+    /// Version requirement, e.g. `^1.2.0` or `= 1.2.3`.
+    pub requirements: Option<String>,
+    /// Whether the package has dependencies of its own.
+    pub has_dependencies: bool,
+}
+
+/// Dependency added or removed between two refs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyChange {
+    /// `added` or `removed`.
+    pub change_type: String,
+    pub manifest: String,
+    pub ecosystem: String,
+    pub name: String,
+    pub version: String,
+    pub license: Option<String>,
+    /// `runtime`, `development`, or `unknown`.
+    pub scope: Option<String>,
+    pub vulnerabilities: Vec<DependencyVulnerability>,
+}
+
+impl DependencyChange {
+    /// Whether this change adds a package with a known vulnerability at or
+    /// above `min_severity` (`low`, `moderate`, `high`, `critical`).
+    pub fn introduces_vulnerability(&self, min_severity: &str) -> bool {
+        let threshold = severity_rank(min_severity);
+        self.change_type == "added"
+            && self
+                .vulnerabilities
+                .iter()
+                .any(|v| severity_rank(&v.severity) >= threshold)
+    }
+}
+
+/// Order advisory severities; `medium` is accepted as an alias for `moderate`.
+fn severity_rank(severity: &str) -> u8 {
+    match severity.to_ascii_lowercase().as_str() {
+        "critical" => 4,
+        "high" => 3,
+        "moderate" | "medium" => 2,
+        _ => 1,
+    }
+}
+
+/// Known vulnerability affecting a dependency version.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyVulnerability {
+    pub severity: String,
+    pub ghsa_id: String,
+    pub summary: String,
+    pub url: String,
+}
+
 /// GitHub repository.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Repository {
@@ -83,6 +710,487 @@ pub struct Review {
     pub submitted_at: Option<String>,
 }
 
+/// Commit in a branch's history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Commit {
+    pub sha: String,
+    /// First line of the commit message.
+    pub message: String,
+    /// Author login, or the git author name when not linked to an account.
+    pub author: Option<String>,
+    pub author_email: Option<String>,
+    /// Commit date.
+    pub date: String,
+    /// Combined status and check state (`SUCCESS`, `FAILURE`, `PENDING`, ...);
+    /// `None` when no CI ran.
+    pub ci_state: Option<String>,
+    pub verification: Option<CommitVerification>,
+    pub url: String,
+}
+
+/// Commit in a file's history, with the file's path at that commit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileHistoryEntry {
+    pub path: String,
+    #[serde(flatten)]
+    pub commit: Commit,
+}
+
+/// Rename found while following a file's history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileRename {
+    /// Commit that performed the rename.
+    pub sha: String,
+    pub from: String,
+    pub to: String,
+}
+
+/// Commit signature verification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitVerification {
+    pub verified: bool,
+    /// `valid`, `unsigned`, `unknown_key`, `bad_email`, `expired_key`, ...
+    pub reason: String,
+    /// Login of the account whose key made the signature.
+    pub signer: Option<String>,
+}
+
+/// Single commit with its file-level changes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitDetail {
+    pub sha: String,
+    /// Full commit message.
+    pub message: String,
+    /// Author login, or the git author name when not linked to an account.
+    pub author: Option<String>,
+    pub author_email: Option<String>,
+    /// Committer login, or the git committer name.
+    pub committer: Option<String>,
+    /// Author date.
+    pub date: Option<String>,
+    pub parents: Vec<String>,
+    pub additions: u32,
+    pub deletions: u32,
+    pub files: Vec<FileChange>,
+    pub verification: Option<CommitVerification>,
+    pub url: String,
+}
+
+/// Per-file change in a commit or comparison.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileChange {
+    pub filename: String,
+    /// `added`, `removed`, `modified`, `renamed`, `copied`, `changed`, or `unchanged`.
+    pub status: String,
+    pub additions: u32,
+    pub deletions: u32,
+    /// Path before a rename.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_filename: Option<String>,
+    /// Unified diff hunk; omitted unless requested, and absent for binary files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patch: Option<String>,
+}
+
+/// File contents at a ref.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileContent {
+    pub path: String,
+    /// Blob SHA, needed to update or delete the file.
+    pub sha: String,
+    pub size: u64,
+    /// Decoded text; `None` for binary files or when written to disk.
+    pub content: Option<String>,
+    /// True when `content` was cut at the byte limit.
+    pub truncated: bool,
+    pub binary: bool,
+    pub url: Option<String>,
+}
+
+/// Commit produced by a contents API write.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileCommit {
+    pub path: String,
+    /// True when the file did not exist before.
+    pub created: bool,
+    /// New blob SHA; `None` after a delete.
+    pub sha: Option<String>,
+    pub commit_sha: String,
+    pub commit_url: String,
+}
+
+/// Repository tree at a ref.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoTree {
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    /// Tree SHA.
+    pub sha: String,
+    /// Subdirectory the listing is limited to.
+    pub path: Option<String>,
+    pub entries: Vec<TreeEntry>,
+    /// True when GitHub truncated the recursive listing (very large repos).
+    pub truncated: bool,
+    /// True when entries were cut at the requested limit.
+    pub capped: bool,
+}
+
+/// Entry in a repository tree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TreeEntry {
+    /// Path from the repository root.
+    pub path: String,
+    /// `blob`, `tree`, or `commit` (submodule).
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    /// Blob size in bytes; `None` for trees and submodules.
+    pub size: Option<u64>,
+    pub sha: String,
+}
+
+/// Contiguous lines last changed by the same commit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlameRange {
+    pub start_line: u32,
+    pub end_line: u32,
+    /// Recency bucket from 1 (newest) to 10 (oldest).
+    pub age: u8,
+    pub sha: String,
+    /// Commit message headline.
+    pub message: String,
+    /// Author login, or the git author name when not linked to an account.
+    pub author: Option<String>,
+    pub author_email: Option<String>,
+    /// Commit date.
+    pub date: String,
+    pub url: String,
+}
+
+/// Git reference.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitRef {
+    /// Fully qualified name (`refs/heads/main`).
+    pub name: String,
+    /// SHA the ref points at (a tag object for annotated tags).
+    pub sha: String,
+    /// `commit` or `tag`.
+    pub object_type: String,
+}
+
+/// Commit object created through the git database API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitCommit {
+    pub sha: String,
+    pub message: String,
+    /// Root tree SHA.
+    pub tree: String,
+    pub parents: Vec<String>,
+    pub url: String,
+}
+
+/// Entry for a new git tree.
+///
+/// With neither `sha` nor `content` set, the path is removed from the base
+/// tree.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct NewTreeEntry {
+    pub path: String,
+    /// File mode (`100644`, `100755`, `040000`, `160000`, `120000`);
+    /// defaults to `100644`.
+    #[serde(default)]
+    pub mode: String,
+    /// `blob`, `tree`, or `commit`; inferred from `mode` when empty.
+    #[serde(default, rename = "type")]
+    pub entry_type: String,
+    /// Existing object SHA.
+    #[serde(default)]
+    pub sha: Option<String>,
+    /// Inline UTF-8 content, stored as a new blob.
+    #[serde(default)]
+    pub content: Option<String>,
+}
+
+/// Branch position relative to a base branch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchStatus {
+    pub name: String,
+    pub base: String,
+    /// Commits on the branch not on the base.
+    pub ahead_by: u32,
+    /// Commits on the base not on the branch.
+    pub behind_by: u32,
+    /// `ahead`, `behind`, `diverged`, or `identical`.
+    pub status: String,
+    pub last_commit_sha: Option<String>,
+    pub last_commit_date: Option<String>,
+    /// Author login, or the git author name when not linked to an account.
+    pub last_author: Option<String>,
+    /// Most recently updated open pull request from this branch.
+    pub pull_request: Option<BranchPr>,
+}
+
+/// Open pull request associated with a branch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchPr {
+    pub number: i32,
+    pub title: String,
+    pub is_draft: bool,
+    pub url: String,
+}
+
+/// Symbolic name resolved to a commit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolvedRef {
+    pub input: String,
+    /// `tag`, `branch`, or `commit` (SHA or other expression).
+    pub kind: String,
+    pub qualified_name: Option<String>,
+    /// Commit SHA, with annotated tags peeled.
+    pub sha: String,
+}
+
+/// CODEOWNERS result for a single path.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathOwners {
+    pub path: String,
+    /// Users, teams, or emails; empty when the path is unowned.
+    pub owners: Vec<String>,
+    /// Pattern of the deciding CODEOWNERS line.
+    pub pattern: Option<String>,
+    /// 1-based line number of the deciding rule.
+    pub line: Option<usize>,
+}
+
+/// Comparison between two refs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Comparison {
+    pub base: String,
+    pub head: String,
+    /// `ahead`, `behind`, `diverged`, or `identical`.
+    pub status: String,
+    pub ahead_by: u32,
+    pub behind_by: u32,
+    /// SHA of the merge base.
+    pub merge_base: String,
+    /// Commits on `head` not on `base`, oldest first (at most 250).
+    pub commits: Vec<Commit>,
+    pub total_commits: u32,
+    pub files: Vec<FileChange>,
+    pub url: String,
+}
+
+/// Weekly commit activity for the last year.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitActivity {
+    /// Oldest first.
+    pub weeks: Vec<WeekActivity>,
+    pub total: u32,
+    /// True when GitHub was still computing the stats; retry shortly.
+    pub pending: bool,
+}
+
+/// Commits in one week.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeekActivity {
+    /// Sunday the week starts on (`YYYY-MM-DD`).
+    pub week_start: String,
+    pub total: u32,
+    /// Commits by the repository owner, when participation stats are ready.
+    pub owner: Option<u32>,
+    /// Commits per day, Sunday first.
+    pub days: Vec<u32>,
+}
+
+/// One contributor's activity over a window of weeks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContributorStats {
+    /// `None` for commits not linked to a GitHub account.
+    pub login: Option<String>,
+    pub commits: u32,
+    pub additions: u32,
+    pub deletions: u32,
+    /// Oldest first.
+    pub weeks: Vec<ContributorWeek>,
+}
+
+/// A contributor's activity in one week.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContributorWeek {
+    /// Sunday the week starts on (`YYYY-MM-DD`).
+    pub week_start: String,
+    pub additions: u32,
+    pub deletions: u32,
+    pub commits: u32,
+}
+
+/// GitHub Project (v2).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Project {
+    /// Node ID, used by project mutations.
+    pub id: String,
+    pub number: i32,
+    pub title: String,
+    /// Login of the owning user or organization.
+    pub owner: String,
+    pub description: Option<String>,
+    pub url: String,
+    pub closed: bool,
+    pub public: bool,
+    pub item_count: i32,
+    pub updated_at: String,
+}
+
+/// Item on a Project (v2): an issue, pull request, or draft issue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectItem {
+    /// Item node ID (not the issue or PR ID).
+    pub id: String,
+    /// `ISSUE`, `PULL_REQUEST`, `DRAFT_ISSUE`, or `REDACTED`.
+    #[serde(rename = "type")]
+    pub item_type: String,
+    /// `None` for items the token cannot see.
+    pub title: Option<String>,
+    pub number: Option<i32>,
+    pub repo: Option<String>,
+    pub state: Option<String>,
+    pub url: Option<String>,
+    pub closed_at: Option<String>,
+    pub assignees: Vec<String>,
+    pub archived: bool,
+    /// Value of the `Status` field.
+    pub status: Option<String>,
+    pub iteration: Option<ProjectIteration>,
+    /// All set field values keyed by field name.
+    pub fields: BTreeMap<String, Value>,
+}
+
+/// Project items grouped into columns by a single-select field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectBoard {
+    pub project: String,
+    pub number: i32,
+    /// Field the columns are grouped by.
+    pub field: String,
+    /// Unarchived items on the board.
+    pub total: usize,
+    pub columns: Vec<BoardColumn>,
+    /// True when the project had more items than were read.
+    pub truncated: bool,
+}
+
+/// Board column; `items` may hold fewer than `count`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardColumn {
+    pub name: String,
+    pub count: usize,
+    pub items: Vec<ProjectItem>,
+}
+
+/// Progress report for a project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectReport {
+    pub project: String,
+    pub number: i32,
+    pub total: usize,
+    pub open: usize,
+    pub closed: usize,
+    /// Open items nobody is assigned to.
+    pub unassigned_open: usize,
+    pub unassigned_total: usize,
+    /// Items closed per week (weeks start on Monday), oldest first.
+    pub completed_per_week: Vec<WeekCount>,
+    /// Open vs. closed items per iteration, chronologically.
+    pub iterations: Vec<IterationProgress>,
+    /// True when the project had more items than were read.
+    pub truncated: bool,
+}
+
+/// Items completed in the week starting `week_start` (YYYY-MM-DD).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeekCount {
+    pub week_start: String,
+    pub completed: usize,
+}
+
+/// Item counts for one iteration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IterationProgress {
+    pub title: String,
+    /// `None` for items not scheduled in an iteration.
+    pub start_date: Option<String>,
+    pub open: usize,
+    pub closed: usize,
+}
+
+/// Iteration an item is scheduled in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectIteration {
+    pub title: String,
+    pub start_date: String,
+    pub duration_days: i32,
+}
+
+/// GitHub discussion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Discussion {
+    /// Node ID, used by discussion mutations.
+    pub id: String,
+    pub number: i32,
+    pub title: String,
+    pub category: String,
+    pub author: Option<String>,
+    /// Always false outside answerable (Q&A) categories.
+    pub is_answered: bool,
+    pub locked: bool,
+    pub upvote_count: i32,
+    pub comment_count: i32,
+    pub url: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Discussion category.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscussionCategory {
+    /// Node ID.
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    /// Emoji shortcode, e.g. `:speech_balloon:`.
+    pub emoji: String,
+    pub description: Option<String>,
+    /// Whether comments can be marked as the answer (Q&A format).
+    pub is_answerable: bool,
+}
+
+/// Discussion with its body and comment tree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscussionDetail {
+    #[serde(flatten)]
+    pub discussion: Discussion,
+    pub body: String,
+    /// Node ID of the comment marked as the answer.
+    pub answer_id: Option<String>,
+    pub comments: Vec<DiscussionComment>,
+}
+
+/// Discussion comment. Top-level comments carry their replies; replies
+/// have none.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscussionComment {
+    /// Node ID, used to reply to or mark the comment as the answer.
+    pub id: String,
+    pub author: Option<String>,
+    pub body: String,
+    pub is_answer: bool,
+    pub upvote_count: i32,
+    pub url: String,
+    pub created_at: String,
+    /// Total replies; `replies` may hold fewer.
+    pub reply_count: i32,
+    pub replies: Vec<DiscussionComment>,
+}
+
 /// GitHub notification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Notification {
@@ -94,6 +1202,425 @@ pub struct Notification {
     pub subject_url: Option<String>,
     pub repo_full_name: String,
     pub updated_at: String,
+    /// Resolved subject details, present when hydration was requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_detail: Option<SubjectDetail>,
+}
+
+/// Human-facing details of a notification subject (issue, PR, release, commit).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubjectDetail {
+    pub html_url: Option<String>,
+    pub number: Option<i64>,
+    /// `open`/`closed`, `merged` for merged PRs, `draft`/`published` for releases.
+    pub state: Option<String>,
+}
+
+/// A change observed by the background notification poller.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationEvent {
+    /// Monotonic sequence number; pass the last one seen as `cursor`.
+    pub seq: u64,
+    /// `new` for a thread not seen before, `updated` for new activity on a known thread.
+    pub kind: String,
+    pub notification: Notification,
+    pub observed_at: String,
+}
+
+/// Notification query: server-side parameters plus client-side filters.
+#[derive(Debug, Clone, Default)]
+pub struct NotificationFilter {
+    /// Include notifications already marked as read.
+    pub all: bool,
+    /// Only notifications where the viewer is directly participating or mentioned.
+    pub participating: bool,
+    /// Only notifications updated after this ISO 8601 timestamp.
+    pub since: Option<String>,
+    /// Only notifications updated before this ISO 8601 timestamp.
+    pub before: Option<String>,
+    /// Keep only these reasons (e.g. `review_requested`, `mention`).
+    pub reasons: Vec<String>,
+    /// Keep only this repo (`owner/repo`) or owner (`owner/*`).
+    pub repo: Option<String>,
+}
+
+impl NotificationFilter {
+    /// Whether a notification passes the client-side reason/repo filters.
+    pub fn matches(&self, notification: &Notification) -> bool {
+        if !self.reasons.is_empty() && !self.reasons.iter().any(|r| r == &notification.reason) {
+            return false;
+        }
+
+        match self.repo.as_deref() {
+            Some(repo) => match repo.strip_suffix("/*") {
+                Some(owner) => notification
+                    .repo_full_name
+                    .split('/')
+                    .next()
+                    .is_some_and(|o| o.eq_ignore_ascii_case(owner)),
+                None => notification.repo_full_name.eq_ignore_ascii_case(repo),
+            },
+            None => true,
+        }
+    }
+}
+
+/// Subscription state for a notification thread.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadSubscription {
+    pub thread_id: String,
+    pub subscribed: bool,
+    pub ignored: bool,
+    pub reason: Option<String>,
+    pub created_at: Option<String>,
+}
+
+/// One page of search results.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResults<T> {
+    pub total_count: i64,
+    /// True when the search timed out and `items` may be missing matches.
+    pub incomplete_results: bool,
+    pub items: Vec<T>,
+    /// Cursor for the next page, if there is one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+/// GitHub topic.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Topic {
+    pub name: String,
+    pub display_name: Option<String>,
+    pub short_description: Option<String>,
+    pub featured: bool,
+    pub curated: bool,
+}
+
+/// Repository ranked by stars gained in a recent window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrendingRepo {
+    #[serde(flatten)]
+    pub repository: Repository,
+    /// Stars gained in the window, or `None` if stargazers could not be read.
+    pub recent_stars: Option<u32>,
+    /// True when the count stopped at the scan limit and is a lower bound.
+    pub recent_stars_capped: bool,
+}
+
+/// Commit search match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitSearchHit {
+    pub sha: String,
+    pub message: String,
+    /// Author date.
+    pub date: String,
+    /// Author login, or the git author name when not linked to an account.
+    pub author: Option<String>,
+    pub repo: String,
+    pub html_url: String,
+}
+
+/// User or organization search match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserSearchHit {
+    pub login: String,
+    /// `user` or `org`.
+    #[serde(rename = "type")]
+    pub account_type: String,
+    pub name: Option<String>,
+    /// Follower count (users only; organizations have no followers).
+    pub followers: Option<i32>,
+    pub url: String,
+}
+
+/// Issue search match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueSearchHit {
+    pub repo: String,
+    #[serde(flatten)]
+    pub issue: Issue,
+}
+
+/// Pull request search match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrSearchHit {
+    pub repo: String,
+    #[serde(flatten)]
+    pub pull_request: PullRequest,
+}
+
+/// Code search match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeSearchHit {
+    pub name: String,
+    pub path: String,
+    pub repo: String,
+    pub sha: String,
+    pub html_url: String,
+    /// Text fragments surrounding each match.
+    pub fragments: Vec<String>,
+}
+
+/// Unread notifications grouped by repository and reason.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationDigest {
+    pub total: usize,
+    pub repo_count: usize,
+    /// Groups, largest first.
+    pub groups: Vec<NotificationGroup>,
+}
+
+/// One repository/reason bucket of a notification digest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationGroup {
+    pub repo: String,
+    pub reason: String,
+    pub count: usize,
+    /// Most recently updated notifications in the group.
+    pub items: Vec<Notification>,
+}
+
+/// Watch setting for a repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoSubscription {
+    pub repo: String,
+    /// `all` (watching), `participating` (not watching), or `ignore`.
+    pub mode: String,
+    pub subscribed: bool,
+    pub ignored: bool,
+    pub created_at: Option<String>,
+}
+
+/// Check-run annotation attached to a workflow job.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Annotation {
+    pub job_id: u64,
+    pub job_name: String,
+    pub path: String,
+    pub start_line: i32,
+    pub end_line: i32,
+    pub level: String,
+    pub title: Option<String>,
+    pub message: String,
+}
+
+/// GitHub Actions workflow run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowRun {
+    pub id: u64,
+    pub name: Option<String>,
+    pub status: Option<String>,
+    pub conclusion: Option<String>,
+    pub branch: Option<String>,
+    pub head_sha: String,
+    pub event: String,
+    pub url: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub duration_secs: Option<i64>,
+}
+
+/// Workflow with its most recent run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowStatus {
+    pub workflow_id: u64,
+    pub name: String,
+    pub path: String,
+    pub state: String,
+    pub latest_run: Option<WorkflowRun>,
+}
+
+/// Repository GitHub Actions permission settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionsSettings {
+    pub enabled: bool,
+    /// One of `all`, `local_only`, or `selected`.
+    pub allowed_actions: Option<String>,
+    /// Allow-list, present only when `allowed_actions` is `selected`.
+    pub selected_actions: Option<SelectedActions>,
+    /// `read` or `write`.
+    pub default_workflow_permissions: String,
+    pub can_approve_pull_request_reviews: bool,
+    /// Fork PR approval policy (public repositories only).
+    pub fork_pr_approval_policy: Option<String>,
+}
+
+/// Allow-list for `selected` Actions permissions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelectedActions {
+    pub github_owned_allowed: bool,
+    pub verified_allowed: bool,
+    pub patterns_allowed: Vec<String>,
+}
+
+/// Partial update for repository Actions settings; `None` fields are unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsSettingsUpdate {
+    pub enabled: Option<bool>,
+    pub allowed_actions: Option<String>,
+    pub selected_actions: Option<SelectedActions>,
+    pub default_workflow_permissions: Option<String>,
+    pub can_approve_pull_request_reviews: Option<bool>,
+    pub fork_pr_approval_policy: Option<String>,
+}
+
+/// GitHub release.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Release {
+    pub id: u64,
+    pub tag_name: String,
+    pub name: Option<String>,
+    pub is_draft: bool,
+    pub is_prerelease: bool,
+    pub author: Option<String>,
+    pub url: String,
+    pub created_at: String,
+    pub published_at: Option<String>,
+    pub asset_count: usize,
+}
+
+/// Release with body, target, and assets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseDetail {
+    #[serde(flatten)]
+    pub release: Release,
+    pub body: Option<String>,
+    pub target_commitish: String,
+    pub assets: Vec<ReleaseAsset>,
+}
+
+/// Binary asset attached to a release.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseAsset {
+    pub id: u64,
+    pub name: String,
+    pub label: Option<String>,
+    pub content_type: String,
+    pub size: u64,
+    pub download_count: u64,
+    pub download_url: String,
+    pub updated_at: String,
+}
+
+/// Release notes generated by GitHub.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedNotes {
+    pub name: String,
+    pub body: String,
+}
+
+/// Result of a release asset upload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetUpload {
+    pub asset: ReleaseAsset,
+    pub bytes_uploaded: u64,
+    pub elapsed_ms: u64,
+    pub bytes_per_sec: u64,
+}
+
+/// Partial update for a release; `None` fields are unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseUpdate {
+    pub tag_name: Option<String>,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub draft: Option<bool>,
+    pub prerelease: Option<bool>,
+}
+
+/// File downloaded to local disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Download {
+    pub path: String,
+    pub bytes: u64,
+    pub sha256: String,
+    pub elapsed_ms: u64,
+}
+
+/// Parameters for creating a release.
+#[derive(Debug, Clone, Default)]
+pub struct NewRelease {
+    pub tag_name: String,
+    pub target_commitish: Option<String>,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub generate_notes: bool,
+}
+
+/// Git tag with the commit it points to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tag {
+    pub name: String,
+    /// SHA of the tagged commit (annotated tags are peeled).
+    pub sha: String,
+    pub is_annotated: bool,
+    /// SHA of the tag object itself, for annotated tags.
+    pub tag_sha: Option<String>,
+    pub tagger: Option<String>,
+    pub message: Option<String>,
+    /// Tagger date for annotated tags, otherwise the commit date.
+    pub date: Option<String>,
+}
+
+/// GitHub gist.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Gist {
+    pub id: String,
+    pub description: Option<String>,
+    pub is_public: bool,
+    pub url: String,
+    pub owner: Option<String>,
+    pub files: Vec<GistFile>,
+    pub comment_count: i32,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// File within a gist. `content` is only populated when fetching a single gist.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GistFile {
+    pub filename: String,
+    pub language: Option<String>,
+    pub size: u64,
+    pub raw_url: String,
+    pub content: Option<String>,
+    pub truncated: bool,
+}
+
+/// Structured changelog between two refs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Changelog {
+    pub base: String,
+    pub head: String,
+    pub ahead_by: i32,
+    pub commit_count: usize,
+    pub pull_request_count: usize,
+    /// Merged PRs keyed by label; unlabeled PRs are under `unlabeled`.
+    pub groups: BTreeMap<String, Vec<ChangelogPr>>,
+    /// Commits that did not arrive through a merged pull request.
+    pub direct_commits: Vec<ChangelogCommit>,
+}
+
+/// Merged pull request in a changelog.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangelogPr {
+    pub number: i32,
+    pub title: String,
+    pub url: String,
+    pub author: Option<String>,
+    pub labels: Vec<String>,
+    pub merged_at: Option<String>,
+}
+
+/// Commit in a changelog.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangelogCommit {
+    pub sha: String,
+    pub message: String,
+    pub author: Option<String>,
 }
 
 /// GraphQL response wrapper.
@@ -142,6 +1669,29 @@ mod tests {
         assert_eq!(parsed.public_repos, 42);
     }
 
+    #[test]
+    fn test_dependency_change_introduces_vulnerability() {
+        let change = |change_type: &str, severity: &str| DependencyChange {
+            change_type: change_type.to_string(),
+            manifest: "package-lock.json".to_string(),
+            ecosystem: "npm".to_string(),
+            name: "lodash".to_string(),
+            version: "4.17.11".to_string(),
+            license: Some("MIT".to_string()),
+            scope: Some("runtime".to_string()),
+            vulnerabilities: vec![DependencyVulnerability {
+                severity: severity.to_string(),
+                ghsa_id: "GHSA-jf85-cpcp-j695".to_string(),
+                summary: "Prototype Pollution in lodash".to_string(),
+                url: "https://github.com/advisories/GHSA-jf85-cpcp-j695".to_string(),
+            }],
+        };
+
+        assert!(change("added", "high").introduces_vulnerability("moderate"));
+        assert!(!change("added", "moderate").introduces_vulnerability("high"));
+        assert!(!change("removed", "critical").introduces_vulnerability("low"));
+    }
+
     #[test]
     fn test_repository_serialization() {
         let repo = Repository {
@@ -231,6 +1781,11 @@ mod tests {
             subject_url: Some("https://api.github.com/repos/octocat/repo/issues/42".to_string()),
             repo_full_name: "octocat/repo".to_string(),
             updated_at: "2024-01-14T00:00:00Z".to_string(),
+            subject_detail: Some(SubjectDetail {
+                html_url: Some("https://github.com/octocat/repo/issues/42".to_string()),
+                number: Some(42),
+                state: Some("open".to_string()),
+            }),
         };
 
         let json = serde_json::to_string(&notification).unwrap();
@@ -238,5 +1793,50 @@ mod tests {
 
         assert_eq!(parsed.id, "12345");
         assert!(parsed.unread);
+        assert_eq!(parsed.subject_detail.unwrap().number, Some(42));
+    }
+
+    #[test]
+    fn test_new_tree_entry_deserialization() {
+        let entries: Vec<NewTreeEntry> = serde_json::from_value(serde_json::json!([
+            {"path": "README.md", "content": "# Hello"},
+            {"path": "bin/run", "mode": "100755", "type": "blob", "sha": "abc123"},
+            {"path": "old.txt"}
+        ]))
+        .unwrap();
+
+        assert_eq!(entries[0].content.as_deref(), Some("# Hello"));
+        assert!(entries[0].mode.is_empty());
+        assert_eq!(entries[1].entry_type, "blob");
+        assert!(entries[2].sha.is_none() && entries[2].content.is_none());
+    }
+
+    #[test]
+    fn test_notification_filter_matches() {
+        let notification = Notification {
+            id: "1".to_string(),
+            unread: true,
+            reason: "review_requested".to_string(),
+            subject_title: "Add feature".to_string(),
+            subject_type: "PullRequest".to_string(),
+            subject_url: None,
+            repo_full_name: "octo-org/repo".to_string(),
+            updated_at: "2024-01-14T00:00:00Z".to_string(),
+            subject_detail: None,
+        };
+
+        let mut filter = NotificationFilter {
+            reasons: vec!["review_requested".to_string()],
+            repo: Some("octo-org/*".to_string()),
+            ..Default::default()
+        };
+        assert!(filter.matches(&notification));
+
+        filter.repo = Some("octo-org/other".to_string());
+        assert!(!filter.matches(&notification));
+
+        filter.repo = None;
+        filter.reasons = vec!["mention".to_string()];
+        assert!(!filter.matches(&notification));
     }
 }
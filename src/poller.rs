@@ -0,0 +1,282 @@
+//! Background notification poller.
+//!
+//! Polls `/notifications` at the interval GitHub advertises (`X-Poll-Interval`),
+//! using `If-Modified-Since` so unchanged polls are free, and records new or
+//! updated threads as sequenced events. FGP calls are request/response, so
+//! subscribers receive events by long-polling `github.notification_events`
+//! with the last cursor they saw. Each account gets its own poller.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - One poller per account
+//! 10/16/2026 - Initial implementation
+
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::runtime::Runtime;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+use crate::api::{GitHubClient, NotificationPoll};
+use crate::models::{Notification, NotificationEvent};
+
+/// Maximum number of events kept for subscribers to catch up on.
+const MAX_EVENTS: usize = 500;
+
+/// Minimum delay before retrying after a failed poll.
+const ERROR_BACKOFF_SECS: u64 = 60;
+
+/// Background poller that keeps notification state warm.
+#[derive(Default)]
+pub struct NotificationPoller {
+    state: Mutex<PollerState>,
+    notify: Notify,
+    task: Mutex<Option<JoinHandle<()>>>,
+}
+
+#[derive(Default)]
+struct PollerState {
+    last_modified: Option<String>,
+    poll_interval_secs: u64,
+    /// Thread id -> `updated_at` from the latest poll.
+    known: HashMap<String, String>,
+    unread_count: usize,
+    events: VecDeque<NotificationEvent>,
+    last_seq: u64,
+    seeded: bool,
+    last_poll_at: Option<String>,
+    last_error: Option<String>,
+}
+
+/// Poller state reported alongside events.
+#[derive(Debug, Serialize)]
+pub struct PollerStatus {
+    pub running: bool,
+    pub seeded: bool,
+    pub unread_count: usize,
+    pub poll_interval_secs: u64,
+    pub last_poll_at: Option<String>,
+    pub last_error: Option<String>,
+}
+
+impl NotificationPoller {
+    /// Start polling on `runtime` unless a poll loop is already running.
+    pub fn ensure_started(self: &Arc<Self>, runtime: &Runtime, client: Arc<GitHubClient>) {
+        let mut task = self.task.lock().unwrap_or_else(|e| e.into_inner());
+        if task.as_ref().is_some_and(|t| !t.is_finished()) {
+            return;
+        }
+
+        tracing::info!("Starting notification poller");
+        *task = Some(runtime.spawn(self.clone().run(client)));
+    }
+
+    /// Stop the poll loop, if running.
+    pub fn stop(&self) {
+        if let Some(task) = self.task.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            task.abort();
+        }
+    }
+
+    async fn run(self: Arc<Self>, client: Arc<GitHubClient>) {
+        loop {
+            let last_modified = self
+                .state
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .last_modified
+                .clone();
+            let interval = match client.poll_notifications(last_modified.as_deref()).await {
+                Ok(poll) => self.apply(poll),
+                Err(e) => {
+                    tracing::warn!("Notification poll failed: {}", e);
+                    let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+                    state.last_error = Some(e.to_string());
+                    state.poll_interval_secs.max(ERROR_BACKOFF_SECS)
+                }
+            };
+            tokio::time::sleep(Duration::from_secs(interval.max(1))).await;
+        }
+    }
+
+    /// Record a poll result, emitting events for new and updated threads.
+    /// Returns the number of seconds to wait before the next poll.
+    fn apply(&self, poll: NotificationPoll) -> u64 {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.poll_interval_secs = poll.poll_interval_secs;
+        state.last_modified = poll.last_modified;
+        state.last_poll_at = Some(chrono::Utc::now().to_rfc3339());
+        state.last_error = None;
+
+        let Some(notifications) = poll.notifications else {
+            return state.poll_interval_secs;
+        };
+
+        // The first poll establishes a baseline rather than replaying the inbox.
+        let changes = if state.seeded {
+            diff_notifications(&state.known, notifications.clone())
+        } else {
+            Vec::new()
+        };
+
+        state.known = notifications
+            .iter()
+            .map(|n| (n.id.clone(), n.updated_at.clone()))
+            .collect();
+        state.unread_count = notifications.iter().filter(|n| n.unread).count();
+        state.seeded = true;
+
+        if !changes.is_empty() {
+            let observed_at = chrono::Utc::now().to_rfc3339();
+            for (kind, notification) in changes {
+                state.last_seq += 1;
+                let seq = state.last_seq;
+                state.events.push_back(NotificationEvent {
+                    seq,
+                    kind: kind.to_string(),
+                    notification,
+                    observed_at: observed_at.clone(),
+                });
+            }
+            while state.events.len() > MAX_EVENTS {
+                state.events.pop_front();
+            }
+            self.notify.notify_waiters();
+        }
+
+        state.poll_interval_secs
+    }
+
+    /// Events after `cursor`, waiting up to `wait` for one to arrive.
+    pub async fn events_after(
+        &self,
+        cursor: u64,
+        limit: usize,
+        wait: Duration,
+    ) -> Vec<NotificationEvent> {
+        let deadline = tokio::time::Instant::now() + wait;
+        loop {
+            // Register interest before checking so an event pushed in between is not missed.
+            let notified = self.notify.notified();
+
+            let events: Vec<NotificationEvent> = self
+                .state
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .events
+                .iter()
+                .filter(|e| e.seq > cursor)
+                .take(limit)
+                .cloned()
+                .collect();
+
+            if !events.is_empty() || tokio::time::timeout_at(deadline, notified).await.is_err() {
+                return events;
+            }
+        }
+    }
+
+    /// Sequence number of the most recent event.
+    pub fn last_seq(&self) -> u64 {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .last_seq
+    }
+
+    pub fn status(&self) -> PollerStatus {
+        let running = self
+            .task
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .is_some_and(|t| !t.is_finished());
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        PollerStatus {
+            running,
+            seeded: state.seeded,
+            unread_count: state.unread_count,
+            poll_interval_secs: state.poll_interval_secs,
+            last_poll_at: state.last_poll_at.clone(),
+            last_error: state.last_error.clone(),
+        }
+    }
+}
+
+/// Classify notifications against the previous poll as `new` or `updated`;
+/// unchanged threads are dropped.
+fn diff_notifications(
+    known: &HashMap<String, String>,
+    current: Vec<Notification>,
+) -> Vec<(&'static str, Notification)> {
+    current
+        .into_iter()
+        .filter_map(|n| match known.get(&n.id) {
+            None => Some(("new", n)),
+            Some(updated_at) if *updated_at != n.updated_at => Some(("updated", n)),
+            Some(_) => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification(id: &str, updated_at: &str) -> Notification {
+        Notification {
+            id: id.to_string(),
+            unread: true,
+            reason: "mention".to_string(),
+            subject_title: "Title".to_string(),
+            subject_type: "Issue".to_string(),
+            subject_url: None,
+            repo_full_name: "octocat/repo".to_string(),
+            updated_at: updated_at.to_string(),
+            subject_detail: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_notifications() {
+        let known = HashMap::from([
+            ("1".to_string(), "2024-01-14T00:00:00Z".to_string()),
+            ("2".to_string(), "2024-01-14T00:00:00Z".to_string()),
+        ]);
+        let current = vec![
+            notification("1", "2024-01-14T00:00:00Z"),
+            notification("2", "2024-01-15T00:00:00Z"),
+            notification("3", "2024-01-15T00:00:00Z"),
+        ];
+
+        let changes: Vec<(&str, String)> = diff_notifications(&known, current)
+            .into_iter()
+            .map(|(kind, n)| (kind, n.id))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![("updated", "2".to_string()), ("new", "3".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_first_poll_seeds_without_events() {
+        let poller = NotificationPoller::default();
+        let poll = |notifications| NotificationPoll {
+            notifications: Some(notifications),
+            last_modified: Some("Sun, 14 Jan 2024 00:00:00 GMT".to_string()),
+            poll_interval_secs: 60,
+        };
+
+        poller.apply(poll(vec![notification("1", "2024-01-14T00:00:00Z")]));
+        assert_eq!(poller.last_seq(), 0);
+
+        poller.apply(poll(vec![
+            notification("1", "2024-01-14T00:00:00Z"),
+            notification("2", "2024-01-15T00:00:00Z"),
+        ]));
+        assert_eq!(poller.last_seq(), 1);
+        assert_eq!(poller.status().unread_count, 2);
+    }
+}
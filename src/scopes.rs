@@ -0,0 +1,287 @@
+//! OAuth scope requirements for daemon methods.
+//!
+//! Classic PATs and OAuth tokens report their scopes in `X-OAuth-Scopes`.
+//! Each gated method lists the scopes that let it work at all (public
+//! repositories); any one of them is enough, as GitHub accepts either on
+//! those endpoints. Private repositories generally also need `repo`. Methods
+//! not listed here need no scopes. Actions of multi-action methods that need
+//! more than listing does have their own entries.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Gate the add and delete actions of github.keys/gpg_keys
+//! 10/16/2026 - Accept any one of a method's scopes; drop over-strict entries
+//! 10/16/2026 - Added MissingScopes error for gating calls
+//! 10/16/2026 - Initial implementation
+
+/// Scopes accepted by each gated method; a token needs one of them.
+pub const METHOD_SCOPES: &[(&str, &[&str])] = &[
+    ("github.notifications", &["notifications", "repo"]),
+    ("github.thread_subscription", &["notifications", "repo"]),
+    ("github.notification_events", &["notifications", "repo"]),
+    ("github.notification_digest", &["notifications", "repo"]),
+    ("github.set_repo_subscription", &["notifications", "repo"]),
+    ("github.create_issue", &["public_repo"]),
+    ("github.put_file", &["public_repo"]),
+    ("github.delete_file", &["public_repo"]),
+    ("github.create_blob", &["public_repo"]),
+    ("github.create_tree", &["public_repo"]),
+    ("github.create_commit", &["public_repo"]),
+    ("github.update_ref", &["public_repo"]),
+    ("github.commit_files", &["public_repo"]),
+    ("github.actions_settings", &["repo"]),
+    ("github.create_release", &["public_repo"]),
+    ("github.upload_asset", &["public_repo"]),
+    ("github.update_release", &["public_repo"]),
+    ("github.delete_release", &["public_repo"]),
+    ("github.create_tag", &["public_repo"]),
+    ("github.release_notes", &["public_repo"]),
+    ("github.create_discussion", &["public_repo"]),
+    ("github.discussion_comment", &["public_repo"]),
+    ("github.mark_answer", &["public_repo"]),
+    ("github.unmark_answer", &["public_repo"]),
+    ("github.projects", &["read:project"]),
+    ("github.project_items", &["read:project"]),
+    ("github.project_board", &["read:project"]),
+    ("github.project_report", &["read:project"]),
+    ("github.project_add", &["project"]),
+    ("github.project_update_item", &["project"]),
+    ("github.create_gist", &["gist"]),
+    ("github.update_gist", &["gist"]),
+    ("github.teams", &["read:org"]),
+    ("github.team_members", &["read:org"]),
+    ("github.team_repos", &["read:org"]),
+    ("github.add_team_repo", &["admin:org"]),
+    ("github.remove_team_repo", &["admin:org"]),
+    ("github.org_invitations", &["admin:org"]),
+    ("github.invite_to_org", &["admin:org"]),
+    ("github.cancel_org_invitation", &["admin:org"]),
+    ("github.follow", &["user:follow"]),
+    ("github.unfollow", &["user:follow"]),
+    ("github.audit_log", &["read:audit_log"]),
+    ("github.keys", &["read:public_key"]),
+    ("github.gpg_keys", &["read:gpg_key"]),
+    ("github.blocked_users", &["user"]),
+    ("github.block", &["user"]),
+    ("github.unblock", &["user"]),
+    (
+        "github.dependabot_alerts",
+        &["security_events", "public_repo"],
+    ),
+    (
+        "github.dismiss_dependabot_alert",
+        &["security_events", "public_repo"],
+    ),
+    (
+        "github.code_scanning_alerts",
+        &["security_events", "public_repo"],
+    ),
+    (
+        "github.update_code_scanning_alert",
+        &["security_events", "public_repo"],
+    ),
+    (
+        "github.secret_scanning_alerts",
+        &["security_events", "public_repo"],
+    ),
+    (
+        "github.update_secret_scanning_alert",
+        &["security_events", "public_repo"],
+    ),
+    ("github.create_advisory", &["repo"]),
+    ("github.security_features", &["repo"]),
+    ("github.security_overview", &["repo"]),
+];
+
+/// Scopes accepted by individual actions of a method, overriding its
+/// `METHOD_SCOPES` entry for that action.
+pub const ACTION_SCOPES: &[(&str, &str, &[&str])] = &[
+    ("github.keys", "add", &["write:public_key"]),
+    ("github.keys", "delete", &["admin:public_key"]),
+    ("github.gpg_keys", "add", &["write:gpg_key"]),
+    ("github.gpg_keys", "delete", &["admin:gpg_key"]),
+];
+
+/// Scopes that include narrower ones.
+const IMPLIED_SCOPES: &[(&str, &[&str])] = &[
+    (
+        "repo",
+        &[
+            "repo:status",
+            "repo_deployment",
+            "public_repo",
+            "repo:invite",
+            "security_events",
+        ],
+    ),
+    ("admin:org", &["write:org", "read:org"]),
+    ("write:org", &["read:org"]),
+    ("admin:public_key", &["write:public_key", "read:public_key"]),
+    ("write:public_key", &["read:public_key"]),
+    ("admin:gpg_key", &["write:gpg_key", "read:gpg_key"]),
+    ("write:gpg_key", &["read:gpg_key"]),
+    ("admin:repo_hook", &["write:repo_hook", "read:repo_hook"]),
+    ("write:repo_hook", &["read:repo_hook"]),
+    ("user", &["read:user", "user:email", "user:follow"]),
+    ("project", &["read:project"]),
+    ("write:packages", &["read:packages"]),
+];
+
+/// Scopes a method (or one of its actions) accepts; empty when it is not
+/// gated.
+pub fn required_scopes(method: &str, action: Option<&str>) -> &'static [&'static str] {
+    let by_action = action.and_then(|action| {
+        ACTION_SCOPES
+            .iter()
+            .find(|(name, a, _)| *name == method && *a == action)
+    });
+    if let Some((_, _, scopes)) = by_action {
+        return scopes;
+    }
+    METHOD_SCOPES
+        .iter()
+        .find(|(name, _)| *name == method)
+        .map_or(&[], |(_, scopes)| scopes)
+}
+
+/// Whether `granted` includes `scope`, directly or through a broader scope.
+pub fn has_scope(granted: &[String], scope: &str) -> bool {
+    granted.iter().any(|g| implies(g, scope))
+}
+
+fn implies(granted: &str, scope: &str) -> bool {
+    granted == scope
+        || IMPLIED_SCOPES
+            .iter()
+            .find(|(broad, _)| *broad == granted)
+            .is_some_and(|(_, narrower)| narrower.iter().any(|n| implies(n, scope)))
+}
+
+/// The scopes `method` (or its `action`) accepts when `granted` covers none
+/// of them, else nothing.
+pub fn missing_scopes(method: &str, action: Option<&str>, granted: &[String]) -> Vec<&'static str> {
+    let accepted = required_scopes(method, action);
+    if accepted.iter().any(|scope| has_scope(granted, scope)) {
+        return Vec::new();
+    }
+    accepted.to_vec()
+}
+
+/// A call rejected before reaching GitHub because the token has none of the
+/// scopes the method accepts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MissingScopes {
+    pub method: String,
+    /// Accepted scopes; any one would do.
+    pub missing: Vec<&'static str>,
+    pub granted: Vec<String>,
+}
+
+impl std::fmt::Display for MissingScopes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let quoted: Vec<String> = self.missing.iter().map(|s| format!("'{}'", s)).collect();
+        write!(
+            f,
+            "Missing permission: {} requires the {} scope; the token has [{}]. \
+             Grant it with `gh auth refresh -s {}` or create a token that includes it.",
+            self.method,
+            quoted.join(" or "),
+            self.granted.join(", "),
+            self.missing.first().copied().unwrap_or_default()
+        )
+    }
+}
+
+impl std::error::Error for MissingScopes {}
+
+/// Fail with [`MissingScopes`] when `granted` does not cover `method` called
+/// with `action`.
+pub fn check(method: &str, action: Option<&str>, granted: &[String]) -> Result<(), MissingScopes> {
+    let missing = missing_scopes(method, action, granted);
+    if missing.is_empty() {
+        return Ok(());
+    }
+    Err(MissingScopes {
+        method: method.to_string(),
+        missing,
+        granted: granted.to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scopes(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_has_scope_follows_implications() {
+        let granted = scopes(&["repo", "admin:org"]);
+
+        assert!(has_scope(&granted, "security_events"));
+        assert!(has_scope(&granted, "read:org"));
+        assert!(!has_scope(&granted, "gist"));
+    }
+
+    #[test]
+    fn test_missing_scopes() {
+        let granted = scopes(&["public_repo", "read:org"]);
+
+        assert!(missing_scopes("github.create_issue", None, &granted).is_empty());
+        assert!(missing_scopes("github.teams", None, &granted).is_empty());
+        assert_eq!(
+            missing_scopes("github.invite_to_org", None, &granted),
+            vec!["admin:org"]
+        );
+        assert!(missing_scopes("github.search_repos", None, &granted).is_empty());
+        assert!(missing_scopes("github.orgs", None, &scopes(&[])).is_empty());
+    }
+
+    #[test]
+    fn test_any_accepted_scope_is_enough() {
+        assert!(check("github.notifications", None, &scopes(&["repo"])).is_ok());
+        assert!(check("github.notifications", None, &scopes(&["notifications"])).is_ok());
+        assert!(check("github.dependabot_alerts", None, &scopes(&["public_repo"])).is_ok());
+
+        let err = check("github.notifications", None, &scopes(&["gist"])).unwrap_err();
+        assert_eq!(err.missing, vec!["notifications", "repo"]);
+        assert!(err
+            .to_string()
+            .contains("requires the 'notifications' or 'repo' scope"));
+    }
+
+    #[test]
+    fn test_check_reports_missing_scopes() {
+        let granted = scopes(&["repo"]);
+
+        assert!(check("github.create_issue", None, &granted).is_ok());
+        let err = check("github.create_gist", None, &granted).unwrap_err();
+        assert_eq!(err.missing, vec!["gist"]);
+        assert!(err
+            .to_string()
+            .starts_with("Missing permission: github.create_gist requires the 'gist' scope"));
+    }
+
+    #[test]
+    fn test_action_scopes() {
+        let granted = scopes(&["read:public_key", "write:gpg_key"]);
+
+        assert!(check("github.keys", None, &granted).is_ok());
+        assert!(check("github.keys", Some("list"), &granted).is_ok());
+        let err = check("github.keys", Some("add"), &granted).unwrap_err();
+        assert_eq!(err.missing, vec!["write:public_key"]);
+
+        assert!(check("github.gpg_keys", Some("add"), &granted).is_ok());
+        assert_eq!(
+            missing_scopes("github.gpg_keys", Some("delete"), &granted),
+            vec!["admin:gpg_key"]
+        );
+        assert!(check(
+            "github.keys",
+            Some("delete"),
+            &scopes(&["admin:public_key"])
+        )
+        .is_ok());
+    }
+}
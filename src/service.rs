@@ -1,40 +1,392 @@
 //! FGP service implementation for GitHub.
 //!
 //! # CHANGELOG (recent first, max 5 entries)
-//! 01/15/2026 - Added rich JSON Schema definitions for all methods (Claude)
-//! 01/14/2026 - Initial implementation with GraphQL/REST (Claude)
+//! 10/16/2026 - Add github.rate_limit and report tracked quota in health
+//! 10/16/2026 - Persist read results in SQLite and serve them stale when offline
+//! 10/16/2026 - Add per-method TTL response cache with fresh bypass and _cache meta
+//! 10/16/2026 - Revalidate REST GETs with ETags and serve 304s from memory
+//! 10/16/2026 - Track token expiry, report it in health, and warn when near
 
 use anyhow::Result;
 use fgp_daemon::schema::SchemaBuilder;
 use fgp_daemon::service::{HealthStatus, MethodInfo};
 use fgp_daemon::FgpService;
 use serde_json::{json, Value};
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
 use tokio::runtime::Runtime;
+use tokio::task::JoinHandle;
 
-use crate::api::GitHubClient;
+use crate::accounts::AccountPool;
+use crate::api::{
+    is_unauthorized, is_unreachable, AlertFilter, AlertScope, CommitFilter, EventFeed,
+    GitHubClient, STATUS_FIELD,
+};
+use crate::cache::{self, ResponseCache};
+use crate::credentials::DEFAULT_ACCOUNT;
+use crate::disk_cache::{self, DiskCache};
+use crate::models::{
+    ActionsSettingsUpdate, AdvisoryVulnerability, NewAdvisory, NewRelease, NewTreeEntry,
+    NotificationFilter, PathOwners, ReleaseUpdate, SearchResults, SecurityFeaturesUpdate,
+    SelectedActions,
+};
+use crate::poller::NotificationPoller;
+use crate::scopes;
+use crate::state::{SavedSearch, StateStore};
+
+thread_local! {
+    /// Account name and client chosen for the call being dispatched on this
+    /// thread. Handlers run synchronously within `dispatch`, so this selects
+    /// the account without threading it through every handler.
+    static ACTIVE_CLIENT: RefCell<Option<(String, Arc<GitHubClient>)>> = const { RefCell::new(None) };
+}
+
+/// Makes an account the active one for the current thread until dropped,
+/// then restores whatever was active before, so nothing outside the
+/// dispatched call (health checks, later calls, a reloaded pool) sees it.
+struct ActiveClientGuard {
+    previous: Option<(String, Arc<GitHubClient>)>,
+}
+
+impl ActiveClientGuard {
+    fn set(account: &str, client: Arc<GitHubClient>) -> Self {
+        let previous =
+            ACTIVE_CLIENT.with(|active| active.borrow_mut().replace((account.to_string(), client)));
+        Self { previous }
+    }
+}
+
+impl Drop for ActiveClientGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        ACTIVE_CLIENT.with(|active| *active.borrow_mut() = previous);
+    }
+}
+
+/// Method metadata whose schemas are still to be given. Every method accepts
+/// the parameters handled in `dispatch`, so `schema` declares them alongside
+/// the method's own; methods with a cache TTL also take `fresh` and return
+/// `_cache`.
+struct MethodDoc {
+    info: MethodInfo,
+    cached: bool,
+}
+
+impl MethodDoc {
+    fn schema(self, schema: Value) -> Self {
+        Self {
+            info: self.info.schema(with_common_params(schema, self.cached)),
+            ..self
+        }
+    }
+
+    fn returns(self, returns: Value) -> MethodInfo {
+        if !self.cached {
+            return self.info.returns(returns);
+        }
+        self.info.returns(with_property(
+            returns,
+            "_cache",
+            SchemaBuilder::object()
+                .property(
+                    "hit",
+                    SchemaBuilder::boolean().description("Served from the cache"),
+                )
+                .property(
+                    "age_ms",
+                    SchemaBuilder::integer().description("Age of the cached result"),
+                )
+                .property("ttl_secs", SchemaBuilder::integer())
+                .description("Response cache details")
+                .build(),
+        ))
+    }
+}
+
+/// Add the parameters every method accepts to an object schema, plus
+/// `fresh` for `cached` methods.
+fn with_common_params(schema: Value, cached: bool) -> Value {
+    let schema = with_property(
+        schema,
+        "account",
+        SchemaBuilder::string()
+            .description("Account to call as (see github.accounts); defaults to the repo_accounts rule for the repository, then the default account")
+            .build(),
+    );
+    if !cached {
+        return schema;
+    }
+    with_property(
+        schema,
+        cache::FRESH_PARAM,
+        SchemaBuilder::boolean()
+            .default_value(json!(false))
+            .description("Skip the response cache and fetch from GitHub")
+            .build(),
+    )
+}
+
+/// Add a property to an object schema unless it declares one of that name
+/// itself.
+fn with_property(mut schema: Value, name: &str, property: Value) -> Value {
+    if let Some(object) = schema.as_object_mut() {
+        if let Some(properties) = object
+            .entry("properties")
+            .or_insert_with(|| json!({}))
+            .as_object_mut()
+        {
+            properties.entry(name).or_insert(property);
+        }
+    }
+    schema
+}
+
+/// Minimum time between credential reloads for one account prompted by a
+/// 401, so a revoked token does not re-read every source on every call.
+const AUTH_RELOAD_INTERVAL: Duration = Duration::from_secs(60);
 
 /// FGP service for GitHub operations.
 pub struct GitHubService {
-    client: Arc<GitHubClient>,
+    /// Replaced wholesale by `github.reload_auth`.
+    accounts: RwLock<Arc<AccountPool>>,
+    /// Explicit token and host the service was started with, reapplied on
+    /// reload.
+    token: Option<String>,
+    host: Option<String>,
+    /// Installation-token refresher for a GitHub App default account.
+    refresher: Mutex<Option<JoinHandle<()>>>,
+    /// When each account's credentials were last reloaded after a 401.
+    auth_reloads: Mutex<HashMap<String, Instant>>,
     runtime: Runtime,
+    /// Notification pollers by account name, started on first use.
+    pollers: Mutex<HashMap<String, Arc<NotificationPoller>>>,
+    state: StateStore,
+    /// Results of methods with a configured TTL.
+    cache: ResponseCache,
+    /// Persisted results for offline reads, when enabled.
+    disk_cache: Option<DiskCache>,
 }
 
 impl GitHubService {
     /// Create a new GitHubService.
     ///
     /// Token is resolved from:
-    /// 1. GITHUB_TOKEN environment variable
-    /// 2. gh CLI config (~/.config/gh/hosts.yml)
-    pub fn new(token: Option<String>) -> Result<Self> {
-        let client = GitHubClient::new(token)?;
-        let runtime = Runtime::new()?;
-
-        Ok(Self {
-            client: Arc::new(client),
-            runtime,
-        })
+    /// 1. OS keychain (`fgp-github auth set-token`)
+    /// 2. GITHUB_TOKEN environment variable
+    /// 3. GitHub App credentials (GITHUB_APP_ID + private key)
+    /// 4. Token stored by `fgp-github login`
+    /// 5. gh CLI config (~/.config/gh/hosts.yml)
+    ///
+    /// `host` selects a GitHub Enterprise Server instance (see
+    /// [`DaemonConfig::endpoints`](crate::config::DaemonConfig::endpoints)).
+    /// App installation tokens are minted here and refreshed in the
+    /// background before they expire.
+    pub fn new(token: Option<String>, host: Option<&str>) -> Result<Self> {
+        let config = crate::config::DaemonConfig::load()?;
+        let service = Self {
+            accounts: RwLock::new(Arc::new(AccountPool::load(token.clone(), host)?)),
+            token,
+            host: host.map(|h| h.to_string()),
+            refresher: Mutex::new(None),
+            auth_reloads: Mutex::new(HashMap::new()),
+            runtime: Runtime::new()?,
+            pollers: Mutex::new(HashMap::new()),
+            state: StateStore::new(),
+            cache: ResponseCache::new(&config.cache.ttl),
+            disk_cache: Self::open_disk_cache(config.cache.persist),
+        };
+        service.start_refresher()?;
+
+        Ok(service)
+    }
+
+    /// The current account pool.
+    fn pool(&self) -> Arc<AccountPool> {
+        self.accounts
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Mint the first installation token and keep it fresh, when the
+    /// default account is a GitHub App.
+    fn start_refresher(&self) -> Result<()> {
+        let client = self.pool().default_client();
+        if !client.is_app() {
+            return Ok(());
+        }
+
+        self.runtime.block_on(client.refresh_installation_token())?;
+        let task = self.runtime.spawn(client.keep_installation_token_fresh());
+        *self.refresher.lock().unwrap_or_else(|e| e.into_inner()) = Some(task);
+        Ok(())
+    }
+
+    /// Open the persistent cache if enabled. A database that cannot be opened
+    /// disables it rather than stopping the daemon.
+    fn open_disk_cache(enabled: bool) -> Option<DiskCache> {
+        if !enabled {
+            return None;
+        }
+        let path = PathBuf::from(shellexpand::tilde(disk_cache::DEFAULT_CACHE_PATH).to_string());
+        match DiskCache::open(&path) {
+            Ok(cache) => Some(cache),
+            Err(e) => {
+                tracing::warn!("Persistent cache disabled: {:#}", e);
+                None
+            }
+        }
+    }
+
+    /// Re-read every token source and swap in fresh clients. Background work
+    /// holding the old clients is stopped; notification pollers restart on
+    /// their next use.
+    fn reload_accounts(&self) -> Result<Arc<AccountPool>> {
+        let pool = Arc::new(AccountPool::load(self.token.clone(), self.host.as_deref())?);
+
+        *self.accounts.write().unwrap_or_else(|e| e.into_inner()) = pool.clone();
+        self.restart_refresher()?;
+        self.stop_pollers();
+        self.cache.clear();
+        self.forget_logins(None);
+
+        Ok(pool)
+    }
+
+    /// Re-read one account's token after GitHub rejected it, at most once
+    /// per [`AUTH_RELOAD_INTERVAL`]. Returns whether a different token was
+    /// found and swapped in.
+    fn reload_account(&self, account: &str) -> Result<bool> {
+        {
+            let mut reloads = self.auth_reloads.lock().unwrap_or_else(|e| e.into_inner());
+            let now = Instant::now();
+            if reloads
+                .get(account)
+                .is_some_and(|at| now.duration_since(*at) < AUTH_RELOAD_INTERVAL)
+            {
+                return Ok(false);
+            }
+            reloads.insert(account.to_string(), now);
+        }
+
+        let Some(pool) = self
+            .pool()
+            .reload(account, self.token.clone(), self.host.as_deref())?
+        else {
+            return Ok(false);
+        };
+        *self.accounts.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(pool);
+        if account == DEFAULT_ACCOUNT {
+            self.restart_refresher()?;
+        }
+        if let Some(poller) = self
+            .pollers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(account)
+        {
+            poller.stop();
+        }
+        self.forget_logins(Some(account));
+
+        Ok(true)
+    }
+
+    /// Drop the logins remembered for offline cache lookups, as reloaded
+    /// tokens may authenticate as someone else. Results cached under the
+    /// old login are no longer found.
+    fn forget_logins(&self, account: Option<&str>) {
+        if let Some(disk) = &self.disk_cache {
+            if let Err(e) = disk.forget_logins(account) {
+                tracing::warn!("Failed to clear cached logins: {:#}", e);
+            }
+        }
+    }
+
+    /// Stop the installation-token refresher of the previous default client
+    /// and start one for the current pool's.
+    fn restart_refresher(&self) -> Result<()> {
+        if let Some(task) = self
+            .refresher
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+        {
+            task.abort();
+        }
+        self.start_refresher()
+    }
+
+    /// Client for the account selected by the call being dispatched, or the
+    /// default account outside of a call.
+    fn client(&self) -> Arc<GitHubClient> {
+        ACTIVE_CLIENT
+            .with(|active| active.borrow().as_ref().map(|(_, client)| client.clone()))
+            .unwrap_or_else(|| self.pool().default_client())
+    }
+
+    /// Name of the account selected by the call being dispatched, or the
+    /// default account outside of a call.
+    fn account_name(&self) -> String {
+        ACTIVE_CLIENT
+            .with(|active| active.borrow().as_ref().map(|(name, _)| name.clone()))
+            .unwrap_or_else(|| DEFAULT_ACCOUNT.to_string())
+    }
+
+    /// The notification poller for the active account.
+    fn poller(&self) -> Arc<NotificationPoller> {
+        self.pollers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(self.account_name())
+            .or_default()
+            .clone()
+    }
+
+    /// Stop every account's notification poller.
+    fn stop_pollers(&self) {
+        for poller in self
+            .pollers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+        {
+            poller.stop();
+        }
+    }
+
+    /// Method metadata, noting in the description when the default
+    /// account's token lacks scopes the method needs. The common parameters
+    /// are added when the schema is given.
+    fn method_info(&self, name: &str, description: &str) -> MethodDoc {
+        let missing = self
+            .pool()
+            .default_client()
+            .granted_scopes()
+            .map(|granted| scopes::missing_scopes(name, None, &granted))
+            .unwrap_or_default();
+
+        let cached = self.cache.ttl(name).is_some();
+        if missing.is_empty() {
+            return MethodDoc {
+                info: MethodInfo::new(name, description),
+                cached,
+            };
+        }
+        MethodDoc {
+            info: MethodInfo::new(
+                name,
+                &format!(
+                    "{} [unavailable: token lacks scope {}]",
+                    description,
+                    missing.join(" or ")
+                ),
+            ),
+            cached,
+        }
     }
 
     /// Helper to get a string parameter.
@@ -51,6 +403,68 @@ impl GitHubService {
             .unwrap_or(default)
     }
 
+    /// Helper to get a bool parameter.
+    fn get_bool(params: &HashMap<String, Value>, key: &str) -> Option<bool> {
+        params.get(key).and_then(|v| v.as_bool())
+    }
+
+    /// Helper to get a u64 parameter (run/job IDs exceed i32).
+    fn get_u64(params: &HashMap<String, Value>, key: &str) -> Option<u64> {
+        params.get(key).and_then(|v| v.as_u64())
+    }
+
+    /// Read a parameter given as either a single string or an array of strings.
+    fn get_str_list(params: &HashMap<String, Value>, key: &str) -> Vec<String> {
+        match params.get(key) {
+            Some(Value::String(s)) => vec![s.clone()],
+            Some(Value::Array(values)) => values
+                .iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Build a search query from the `query` param plus any qualifier params
+    /// present (e.g. `language` -> `language:rust`).
+    fn search_query(params: &HashMap<String, Value>, qualifiers: &[&str]) -> Result<String> {
+        let mut terms: Vec<String> = Self::get_str(params, "query")
+            .filter(|q| !q.trim().is_empty())
+            .map(|q| vec![q.trim().to_string()])
+            .unwrap_or_default();
+
+        for key in qualifiers {
+            let value = match params.get(*key) {
+                Some(Value::String(s)) => s.clone(),
+                Some(Value::Number(n)) => n.to_string(),
+                Some(Value::Bool(b)) => b.to_string(),
+                _ => continue,
+            };
+            if value.contains(char::is_whitespace) {
+                terms.push(format!("{}:\"{}\"", key, value));
+            } else {
+                terms.push(format!("{}:{}", key, value));
+            }
+        }
+
+        if terms.is_empty() {
+            anyhow::bail!("Missing required parameter: query");
+        }
+        Ok(terms.join(" "))
+    }
+
+    /// Common response shape for search methods.
+    fn search_envelope<T: serde::Serialize>(query: &str, results: SearchResults<T>) -> Value {
+        serde_json::json!({
+            "query": query,
+            "total_count": results.total_count,
+            "incomplete_results": results.incomplete_results,
+            "results": results.items,
+            "count": results.items.len(),
+            "next_cursor": results.next_cursor,
+        })
+    }
+
     /// Parse owner/repo from "owner/repo" format.
     fn parse_repo(repo_str: &str) -> Result<(&str, &str)> {
         let parts: Vec<&str> = repo_str.split('/').collect();
@@ -67,19 +481,323 @@ impl GitHubService {
     // Method implementations
     // ========================================================================
 
+    /// The account a call runs as: its `account` parameter, else the
+    /// `repo_accounts` rule matching its repository, else the default.
+    fn select_account(&self, params: &HashMap<String, Value>) -> String {
+        let pool = self.pool();
+        let account = Self::get_str(params, "account").or_else(|| {
+            match Self::get_str(params, "repo").and_then(|r| r.split_once('/')) {
+                Some((owner, name)) => pool.account_for_repo(owner, Some(name)),
+                None => Self::get_str(params, "org")
+                    .or_else(|| Self::get_str(params, "owner"))
+                    .and_then(|owner| pool.account_for_repo(owner, None)),
+            }
+        });
+        account.unwrap_or(DEFAULT_ACCOUNT).to_string()
+    }
+
+    /// Select the client for `account`, check the token's scopes, and route
+    /// the call through the response caches.
+    fn dispatch_as_account(
+        &self,
+        account: &str,
+        method: &str,
+        params: HashMap<String, Value>,
+    ) -> Result<Value> {
+        let client = self.pool().get(Some(account))?;
+
+        let name = match method.strip_prefix("github.") {
+            Some(_) => method.to_string(),
+            None => format!("github.{}", method),
+        };
+
+        // Reject calls the token's scopes cannot satisfy before GitHub does
+        if let Some(granted) = client.granted_scopes() {
+            scopes::check(&name, Self::get_str(&params, "action"), &granted)?;
+        }
+
+        let _active = ActiveClientGuard::set(account, client);
+        self.route_cached(&name, method, params)
+    }
+
+    /// Route a call, answering from the TTL cache for methods with a TTL and
+    /// recording persisted methods' results on disk. When GitHub is
+    /// unreachable, a persisted result is returned marked stale.
+    fn route_cached(
+        &self,
+        name: &str,
+        method: &str,
+        params: HashMap<String, Value>,
+    ) -> Result<Value> {
+        let ttl = self.cache.ttl(name);
+        let disk = self
+            .disk_cache
+            .as_ref()
+            .filter(|_| disk_cache::is_persisted(name));
+        if ttl.is_none() && disk.is_none() {
+            return self.route(method, params);
+        }
+
+        let account = self.account_name();
+        let Some(login) = self.cache_login(&account) else {
+            return self.route(method, params);
+        };
+        let key = ResponseCache::key(&account, &login, name, &params);
+        if let Some(ttl) = ttl {
+            if !Self::get_bool(&params, cache::FRESH_PARAM).unwrap_or(false) {
+                if let Some((value, age)) = self.cache.get(&key) {
+                    return Ok(cache::annotate(value, true, age, ttl));
+                }
+            }
+        }
+
+        let value = match self.route(method, params) {
+            Ok(value) => value,
+            Err(e) if is_unreachable(&e) => {
+                let stored = disk.and_then(|disk| disk.get(&key).ok().flatten());
+                let Some((value, stored_at)) = stored else {
+                    return Err(e);
+                };
+                tracing::warn!(
+                    "GitHub unreachable, serving stored {} result: {:#}",
+                    name,
+                    e
+                );
+                return Ok(disk_cache::mark_stale(value, stored_at));
+            }
+            Err(e) => return Err(e),
+        };
+
+        if let Some(disk) = disk {
+            if let Err(e) = disk.put(&key, name, &value) {
+                tracing::warn!("Failed to persist {} result: {:#}", name, e);
+            }
+        }
+        match ttl {
+            Some(ttl) => {
+                self.cache.put(key, value.clone(), ttl);
+                Ok(cache::annotate(
+                    value,
+                    false,
+                    std::time::Duration::ZERO,
+                    ttl,
+                ))
+            }
+            None => Ok(value),
+        }
+    }
+
+    /// Login the active account authenticates as, for cache keys: looked up
+    /// once per client, or the one last remembered on disk while GitHub is
+    /// unreachable. `None` leaves the call uncached.
+    fn cache_login(&self, account: &str) -> Option<String> {
+        let client = self.client();
+        if client.is_app() {
+            return Some("installation".to_string());
+        }
+        if let Some(login) = client.known_login() {
+            return Some(login);
+        }
+
+        match self.runtime.block_on(client.login()) {
+            Ok(login) => {
+                if let Some(disk) = &self.disk_cache {
+                    if let Err(e) = disk.set_login(account, &login) {
+                        tracing::warn!("Failed to remember the '{}' login: {:#}", account, e);
+                    }
+                }
+                Some(login)
+            }
+            Err(e) => {
+                tracing::debug!("Could not look up the '{}' login: {:#}", account, e);
+                self.disk_cache
+                    .as_ref()
+                    .and_then(|disk| disk.login(account).ok().flatten())
+            }
+        }
+    }
+
+    /// Route a method call to its handler.
+    fn route(&self, method: &str, params: HashMap<String, Value>) -> Result<Value> {
+        match method {
+            "health" => self.health(),
+            "user" | "github.user" => self.get_user(),
+            "repos" | "github.repos" => self.list_repos(params),
+            "issues" | "github.issues" => self.list_issues(params),
+            "prs" | "github.prs" => self.list_prs(params),
+            "pr" | "github.pr" => self.get_pr(params),
+            "notifications" | "github.notifications" => self.get_notifications(params),
+            "create_issue" | "github.create_issue" => self.create_issue(params),
+            "run_annotations" | "github.run_annotations" => self.run_annotations(params),
+            "ci_summary" | "github.ci_summary" => self.ci_summary(params),
+            "actions_settings" | "github.actions_settings" => self.actions_settings(params),
+            "releases" | "github.releases" => self.list_releases(params),
+            "release" | "github.release" => self.get_release(params),
+            "create_release" | "github.create_release" => self.create_release(params),
+            "upload_asset" | "github.upload_asset" => self.upload_asset(params),
+            "download_asset" | "github.download_asset" => self.download_asset(params),
+            "update_release" | "github.update_release" => self.update_release(params),
+            "delete_release" | "github.delete_release" => self.delete_release(params),
+            "tags" | "github.tags" => self.list_tags(params),
+            "create_tag" | "github.create_tag" => self.create_tag(params),
+            "release_notes" | "github.release_notes" => self.release_notes(params),
+            "gists" | "github.gists" => self.list_gists(params),
+            "gist" | "github.gist" => self.get_gist(params),
+            "create_gist" | "github.create_gist" => self.create_gist(params),
+            "update_gist" | "github.update_gist" => self.update_gist(params),
+            "compare_releases" | "github.compare_releases" => self.compare_releases(params),
+            "thread_subscription" | "github.thread_subscription" => {
+                self.thread_subscription(params)
+            }
+            "notification_events" | "github.notification_events" => {
+                self.notification_events(params)
+            }
+            "set_repo_subscription" | "github.set_repo_subscription" => {
+                self.set_repo_subscription(params)
+            }
+            "notification_digest" | "github.notification_digest" => {
+                self.notification_digest(params)
+            }
+            "search_code" | "github.search_code" => self.search_code(params),
+            "search" | "github.search" => self.search(params),
+            "search_repos" | "github.search_repos" => self.search_repos(params),
+            "search_users" | "github.search_users" => self.search_users(params),
+            "search_commits" | "github.search_commits" => self.search_commits(params),
+            "search_topics" | "github.search_topics" => self.search_topics(params),
+            "search_all" | "github.search_all" => self.search_all(params),
+            "save_search" | "github.save_search" => self.save_search(params),
+            "saved_searches" | "github.saved_searches" => self.saved_searches(params),
+            "run_saved_search" | "github.run_saved_search" => self.run_saved_search(params),
+            "get_user_profile" | "github.get_user_profile" => self.get_user_profile(params),
+            "org_repos" | "github.org_repos" => self.org_repos(params),
+            "org_members" | "github.org_members" => self.org_members(params),
+            "orgs" | "github.orgs" => self.list_orgs(params),
+            "teams" | "github.teams" => self.list_teams(params),
+            "team_members" | "github.team_members" => self.team_members(params),
+            "team_repos" | "github.team_repos" => self.team_repos(params),
+            "add_team_repo" | "github.add_team_repo" => self.add_team_repo(params),
+            "remove_team_repo" | "github.remove_team_repo" => self.remove_team_repo(params),
+            "org_invitations" | "github.org_invitations" => self.org_invitations(params),
+            "invite_to_org" | "github.invite_to_org" => self.invite_to_org(params),
+            "cancel_org_invitation" | "github.cancel_org_invitation" => {
+                self.cancel_org_invitation(params)
+            }
+            "followers" | "github.followers" => self.followers(params),
+            "following" | "github.following" => self.following(params),
+            "follow" | "github.follow" => self.follow(params),
+            "unfollow" | "github.unfollow" => self.unfollow(params),
+            "contributions" | "github.contributions" => self.contributions(params),
+            "events" | "github.events" => self.events(params),
+            "audit_log" | "github.audit_log" => self.audit_log(params),
+            "keys" | "github.keys" => self.ssh_keys(params),
+            "gpg_keys" | "github.gpg_keys" => self.gpg_keys(params),
+            "blocked_users" | "github.blocked_users" => self.blocked_users(params),
+            "block" | "github.block" => self.block(params),
+            "unblock" | "github.unblock" => self.unblock(params),
+            "org_dashboard" | "github.org_dashboard" => self.org_dashboard(params),
+            "dependabot_alerts" | "github.dependabot_alerts" => self.dependabot_alerts(params),
+            "dismiss_dependabot_alert" | "github.dismiss_dependabot_alert" => {
+                self.dismiss_dependabot_alert(params)
+            }
+            "code_scanning_alerts" | "github.code_scanning_alerts" => {
+                self.code_scanning_alerts(params)
+            }
+            "update_code_scanning_alert" | "github.update_code_scanning_alert" => {
+                self.update_code_scanning_alert(params)
+            }
+            "secret_scanning_alerts" | "github.secret_scanning_alerts" => {
+                self.secret_scanning_alerts(params)
+            }
+            "update_secret_scanning_alert" | "github.update_secret_scanning_alert" => {
+                self.update_secret_scanning_alert(params)
+            }
+            "advisories" | "github.advisories" => self.advisories(params),
+            "create_advisory" | "github.create_advisory" => self.create_advisory(params),
+            "sbom" | "github.sbom" => self.sbom(params),
+            "dependencies" | "github.dependencies" => self.dependencies(params),
+            "dependency_diff" | "github.dependency_diff" => self.dependency_diff(params),
+            "security_features" | "github.security_features" => self.security_features(params),
+            "token_info" | "github.token_info" => self.token_info(),
+            "security_overview" | "github.security_overview" => self.security_overview(params),
+            "dependabot_config" | "github.dependabot_config" => self.dependabot_config(params),
+            "discussions" | "github.discussions" => self.list_discussions(params),
+            "discussion" | "github.discussion" => self.get_discussion(params),
+            "create_discussion" | "github.create_discussion" => self.create_discussion(params),
+            "discussion_comment" | "github.discussion_comment" => self.discussion_comment(params),
+            "discussion_categories" | "github.discussion_categories" => {
+                self.discussion_categories(params)
+            }
+            "mark_answer" | "github.mark_answer" => self.mark_answer(params),
+            "unmark_answer" | "github.unmark_answer" => self.unmark_answer(params),
+            "projects" | "github.projects" => self.list_projects(params),
+            "project_items" | "github.project_items" => self.project_items(params),
+            "project_add" | "github.project_add" => self.project_add(params),
+            "project_update_item" | "github.project_update_item" => {
+                self.project_update_item(params)
+            }
+            "project_board" | "github.project_board" => self.project_board(params),
+            "project_report" | "github.project_report" => self.project_report(params),
+            "commits" | "github.commits" => self.list_commits(params),
+            "commit" | "github.commit" => self.get_commit(params),
+            "compare" | "github.compare" => self.compare(params),
+            "file" | "github.file" => self.get_file(params),
+            "put_file" | "github.put_file" => self.put_file(params),
+            "delete_file" | "github.delete_file" => self.delete_file(params),
+            "tree" | "github.tree" => self.get_tree(params),
+            "blame" | "github.blame" => self.get_blame(params),
+            "refs" | "github.refs" => self.list_refs(params),
+            "resolve_ref" | "github.resolve_ref" => self.resolve_ref(params),
+            "create_blob" | "github.create_blob" => self.create_blob(params),
+            "create_tree" | "github.create_tree" => self.create_tree(params),
+            "create_commit" | "github.create_commit" => self.create_commit(params),
+            "update_ref" | "github.update_ref" => self.update_ref(params),
+            "commit_files" | "github.commit_files" => self.commit_files(params),
+            "branch_status" | "github.branch_status" => self.branch_status(params),
+            "verify_commits" | "github.verify_commits" => self.verify_commits(params),
+            "archive" | "github.archive" => self.archive(params),
+            "file_history" | "github.file_history" => self.file_history(params),
+            "owners_for_path" | "github.owners_for_path" => self.owners_for_path(params),
+            "commit_activity" | "github.commit_activity" => self.commit_activity(params),
+            "contributor_stats" | "github.contributor_stats" => self.contributor_stats(params),
+            "installations" | "github.installations" => self.installations(params),
+            "accounts" | "github.accounts" => self.accounts(params),
+            "reload_auth" | "github.reload_auth" => self.reload_auth(),
+            "sso_status" | "github.sso_status" => self.sso_status(),
+            "rate_limit" | "github.rate_limit" => self.rate_limit(params),
+            _ => anyhow::bail!("Unknown method: {}", method),
+        }
+    }
+
     fn health(&self) -> Result<Value> {
-        let client = self.client.clone();
+        let client = self.client();
         let ok = self.runtime.block_on(async move { client.ping().await })?;
 
+        // Installation tokens are re-minted before they expire, so only
+        // user tokens warrant a warning
+        let client = self.client();
+        let expiry = client.token_expiry();
+        let expiring_soon = !client.is_app() && expiry.is_some_and(crate::api::expires_soon);
+
         Ok(serde_json::json!({
             "status": if ok { "healthy" } else { "unhealthy" },
             "api_connected": ok,
             "version": env!("CARGO_PKG_VERSION"),
+            "token_expires_at": expiry.map(|t| t.to_rfc3339()),
+            "token_days_remaining": expiry.map(|t| (t - chrono::Utc::now()).num_days()),
+            "token_expiring_soon": expiring_soon,
+            "rate_limit": client
+                .rate_limit_status()
+                .into_iter()
+                .filter(|r| matches!(r.resource.as_str(), "core" | "graphql" | "search"))
+                .map(|r| (r.resource.clone(), r))
+                .collect::<BTreeMap<_, _>>(),
+            "etag_cache_hits": client.etag_cache_hits(),
+            "cache": self.cache.stats(),
         }))
     }
 
     fn get_user(&self) -> Result<Value> {
-        let client = self.client.clone();
+        let client = self.client();
         let user = self
             .runtime
             .block_on(async move { client.get_user().await })?;
@@ -89,7 +807,7 @@ impl GitHubService {
 
     fn list_repos(&self, params: HashMap<String, Value>) -> Result<Value> {
         let limit = Self::get_i32(&params, "limit", 10);
-        let client = self.client.clone();
+        let client = self.client();
 
         let repos = self
             .runtime
@@ -108,7 +826,7 @@ impl GitHubService {
         let state = Self::get_str(&params, "state").unwrap_or("open");
         let limit = Self::get_i32(&params, "limit", 10);
 
-        let client = self.client.clone();
+        let client = self.client();
         let owner = owner.to_string();
         let repo = repo.to_string();
         let state = state.to_string();
@@ -133,7 +851,7 @@ impl GitHubService {
         let state = Self::get_str(&params, "state").unwrap_or("open");
         let limit = Self::get_i32(&params, "limit", 10);
 
-        let client = self.client.clone();
+        let client = self.client();
         let owner = owner.to_string();
         let repo = repo.to_string();
         let state = state.to_string();
@@ -160,7 +878,7 @@ impl GitHubService {
             anyhow::bail!("Missing required parameter: number");
         }
 
-        let client = self.client.clone();
+        let client = self.client();
         let owner = owner.to_string();
         let repo = repo.to_string();
 
@@ -171,15 +889,32 @@ impl GitHubService {
         Ok(serde_json::json!(pr))
     }
 
-    fn get_notifications(&self, _params: HashMap<String, Value>) -> Result<Value> {
-        let client = self.client.clone();
+    fn get_notifications(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let reasons = Self::get_str_list(&params, "reason");
+        let filter = NotificationFilter {
+            all: Self::get_bool(&params, "all").unwrap_or(false),
+            participating: Self::get_bool(&params, "participating").unwrap_or(false),
+            since: Self::get_str(&params, "since").map(|s| s.to_string()),
+            before: Self::get_str(&params, "before").map(|s| s.to_string()),
+            reasons,
+            repo: Self::get_str(&params, "repo").map(|s| s.to_string()),
+        };
+        let limit = Self::get_i32(&params, "limit", 50).max(1) as usize;
+        let page = Self::get_i32(&params, "page", 1).max(1) as u32;
+        let hydrate = Self::get_bool(&params, "hydrate").unwrap_or(false);
+        let client = self.client();
 
-        let notifications = self
-            .runtime
-            .block_on(async move { client.get_notifications().await })?;
+        let notifications = self.runtime.block_on(async move {
+            let mut notifications = client.get_notifications(&filter, limit, page).await?;
+            if hydrate {
+                client.hydrate_notifications(&mut notifications).await;
+            }
+            Ok::<_, anyhow::Error>(notifications)
+        })?;
 
         Ok(serde_json::json!({
             "notifications": notifications,
+            "count": notifications.len(),
             "unread_count": notifications.iter().filter(|n| n.unread).count(),
         }))
     }
@@ -192,7 +927,7 @@ impl GitHubService {
             .ok_or_else(|| anyhow::anyhow!("Missing required parameter: title"))?;
         let body = Self::get_str(&params, "body");
 
-        let client = self.client.clone();
+        let client = self.client();
         let owner = owner.to_string();
         let repo = repo.to_string();
         let title = title.to_string();
@@ -209,138 +944,8638 @@ impl GitHubService {
             "issue": issue,
         }))
     }
-}
 
-impl FgpService for GitHubService {
-    fn name(&self) -> &str {
-        "github"
-    }
+    fn run_annotations(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let run_id = Self::get_u64(&params, "run_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: run_id"))?;
+        let level = Self::get_str(&params, "level").map(|s| s.to_string());
 
-    fn version(&self) -> &str {
-        env!("CARGO_PKG_VERSION")
-    }
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
 
-    fn dispatch(&self, method: &str, params: HashMap<String, Value>) -> Result<Value> {
-        match method {
-            "health" => self.health(),
-            "user" | "github.user" => self.get_user(),
-            "repos" | "github.repos" => self.list_repos(params),
-            "issues" | "github.issues" => self.list_issues(params),
-            "prs" | "github.prs" => self.list_prs(params),
-            "pr" | "github.pr" => self.get_pr(params),
-            "notifications" | "github.notifications" => self.get_notifications(params),
-            "create_issue" | "github.create_issue" => self.create_issue(params),
-            _ => anyhow::bail!("Unknown method: {}", method),
+        let mut annotations = self
+            .runtime
+            .block_on(async move { client.get_run_annotations(&owner, &repo, run_id).await })?;
+
+        if let Some(level) = &level {
+            annotations.retain(|a| a.level.eq_ignore_ascii_case(level));
         }
+
+        let failure_count = annotations.iter().filter(|a| a.level == "failure").count();
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "run_id": run_id,
+            "annotations": annotations,
+            "count": annotations.len(),
+            "failure_count": failure_count,
+        }))
     }
 
-    fn method_list(&self) -> Vec<MethodInfo> {
-        vec![
-            // github.user - Get current authenticated user
-            MethodInfo::new("github.user", "Get current authenticated user info")
-                .schema(SchemaBuilder::object().build())
-                .returns(
-                    SchemaBuilder::object()
-                        .property("login", SchemaBuilder::string().description("GitHub username"))
-                        .property("name", SchemaBuilder::string().description("Display name"))
-                        .property("email", SchemaBuilder::string().format("email"))
-                        .property("avatar_url", SchemaBuilder::string().format("uri"))
-                        .build(),
-                )
-                .example("Get current user", json!({})),
+    fn ci_summary(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let branch = Self::get_str(&params, "branch").map(|s| s.to_string());
 
-            // github.repos - List repositories
-            MethodInfo::new("github.repos", "List your repositories")
-                .schema(
-                    SchemaBuilder::object()
-                        .property(
-                            "limit",
-                            SchemaBuilder::integer()
-                                .minimum(1)
-                                .maximum(100)
-                                .default_value(json!(10))
-                                .description("Maximum number of repos to return"),
-                        )
-                        .build(),
-                )
-                .returns(
-                    SchemaBuilder::object()
-                        .property(
-                            "repos",
-                            SchemaBuilder::array()
-                                .items(
-                                    SchemaBuilder::object()
-                                        .property("name", SchemaBuilder::string())
-                                        .property("full_name", SchemaBuilder::string())
-                                        .property("url", SchemaBuilder::string().format("uri"))
-                                        .property("description", SchemaBuilder::string())
-                                        .property("stargazers_count", SchemaBuilder::integer()),
-                                )
-                                .description("List of repositories"),
-                        )
-                        .property("count", SchemaBuilder::integer())
-                        .build(),
-                )
-                .example("List top 5 repos", json!({"limit": 5})),
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
 
-            // github.issues - List issues
-            MethodInfo::new("github.issues", "List issues for a repository")
-                .schema(
-                    SchemaBuilder::object()
-                        .property(
-                            "repo",
-                            SchemaBuilder::string()
-                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
-                                .description("Repository in 'owner/repo' format"),
-                        )
-                        .property(
-                            "state",
-                            SchemaBuilder::string()
-                                .enum_values(&["open", "closed", "all"])
-                                .default_value(json!("open"))
-                                .description("Issue state filter"),
-                        )
-                        .property(
-                            "limit",
+        let (branch, workflows) = self.runtime.block_on(async move {
+            client
+                .get_ci_summary(&owner, &repo, branch.as_deref())
+                .await
+        })?;
+
+        let failing = workflows
+            .iter()
+            .filter(|w| {
+                w.latest_run
+                    .as_ref()
+                    .is_some_and(|r| r.conclusion.as_deref() == Some("failure"))
+            })
+            .count();
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "branch": branch,
+            "workflows": workflows,
+            "count": workflows.len(),
+            "failing": failing,
+        }))
+    }
+
+    fn actions_settings(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+
+        let selected_actions = params
+            .get("selected_actions")
+            .map(|v| serde_json::from_value::<SelectedActions>(v.clone()))
+            .transpose()
+            .map_err(|e| anyhow::anyhow!("Invalid selected_actions: {}", e))?;
+
+        let update = ActionsSettingsUpdate {
+            enabled: Self::get_bool(&params, "enabled"),
+            allowed_actions: Self::get_str(&params, "allowed_actions").map(|s| s.to_string()),
+            selected_actions,
+            default_workflow_permissions: Self::get_str(&params, "default_workflow_permissions")
+                .map(|s| s.to_string()),
+            can_approve_pull_request_reviews: Self::get_bool(
+                &params,
+                "can_approve_pull_request_reviews",
+            ),
+            fork_pr_approval_policy: Self::get_str(&params, "fork_pr_approval_policy")
+                .map(|s| s.to_string()),
+        };
+        let updated = update.enabled.is_some()
+            || update.allowed_actions.is_some()
+            || update.selected_actions.is_some()
+            || update.default_workflow_permissions.is_some()
+            || update.can_approve_pull_request_reviews.is_some()
+            || update.fork_pr_approval_policy.is_some();
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let settings = self.runtime.block_on(async move {
+            if updated {
+                client.update_actions_settings(&owner, &repo, &update).await
+            } else {
+                client.get_actions_settings(&owner, &repo).await
+            }
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "updated": updated,
+            "settings": settings,
+        }))
+    }
+
+    fn list_releases(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let limit = Self::get_i32(&params, "limit", 10);
+        let page = Self::get_i32(&params, "page", 1);
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let releases = self
+            .runtime
+            .block_on(async move { client.list_releases(&owner, &repo, limit, page).await })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "page": page,
+            "releases": releases,
+            "count": releases.len(),
+        }))
+    }
+
+    fn get_release(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let tag = Self::get_str(&params, "tag").map(|s| s.to_string());
+        let latest = Self::get_bool(&params, "latest").unwrap_or(false);
+        if tag.is_none() && !latest {
+            anyhow::bail!("Missing required parameter: tag (or latest: true)");
+        }
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let release = self.runtime.block_on(async move {
+            match tag {
+                Some(tag) => client.get_release_by_tag(&owner, &repo, &tag).await,
+                None => client.get_latest_release(&owner, &repo).await,
+            }
+        })?;
+
+        Ok(serde_json::json!(release))
+    }
+
+    fn create_release(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let tag = Self::get_str(&params, "tag")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: tag"))?;
+
+        let release = NewRelease {
+            tag_name: tag.to_string(),
+            target_commitish: Self::get_str(&params, "target").map(|s| s.to_string()),
+            name: Self::get_str(&params, "name").map(|s| s.to_string()),
+            body: Self::get_str(&params, "body").map(|s| s.to_string()),
+            draft: Self::get_bool(&params, "draft").unwrap_or(false),
+            prerelease: Self::get_bool(&params, "prerelease").unwrap_or(false),
+            generate_notes: Self::get_bool(&params, "generate_notes").unwrap_or(false),
+        };
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let release = self
+            .runtime
+            .block_on(async move { client.create_release(&owner, &repo, &release).await })?;
+
+        Ok(serde_json::json!({
+            "created": true,
+            "release": release,
+        }))
+    }
+
+    fn upload_asset(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let path = Self::get_str(&params, "path")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: path"))?;
+        let path = PathBuf::from(shellexpand::tilde(path).to_string());
+        let release_id = Self::get_u64(&params, "release_id");
+        let tag = Self::get_str(&params, "tag").map(|s| s.to_string());
+        if release_id.is_none() && tag.is_none() {
+            anyhow::bail!("Missing required parameter: release_id (or tag)");
+        }
+        let name = Self::get_str(&params, "name").map(|s| s.to_string());
+        let label = Self::get_str(&params, "label").map(|s| s.to_string());
+        let content_type = Self::get_str(&params, "content_type").map(|s| s.to_string());
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let upload = self.runtime.block_on(async move {
+            let release_id = match release_id {
+                Some(id) => id,
+                None => {
+                    let tag = tag.unwrap_or_default();
+                    client
+                        .get_release_by_tag(&owner, &repo, &tag)
+                        .await?
+                        .release
+                        .id
+                }
+            };
+
+            client
+                .upload_release_asset(
+                    &owner,
+                    &repo,
+                    release_id,
+                    &path,
+                    name.as_deref(),
+                    label.as_deref(),
+                    content_type.as_deref(),
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "uploaded": true,
+            "asset": upload.asset,
+            "progress": {
+                "bytes_uploaded": upload.bytes_uploaded,
+                "elapsed_ms": upload.elapsed_ms,
+                "bytes_per_sec": upload.bytes_per_sec,
+            },
+        }))
+    }
+
+    fn download_asset(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let name = Self::get_str(&params, "name")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: name"))?;
+        let path = Self::get_str(&params, "path")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: path"))?;
+        let tag = Self::get_str(&params, "tag").map(|s| s.to_string());
+        let latest = Self::get_bool(&params, "latest").unwrap_or(false);
+        if tag.is_none() && !latest {
+            anyhow::bail!("Missing required parameter: tag (or latest: true)");
+        }
+        let sha256 = Self::get_str(&params, "sha256").map(|s| s.to_string());
+
+        // A directory destination receives the asset under its own name
+        let mut dest = PathBuf::from(shellexpand::tilde(path).to_string());
+        if dest.is_dir() {
+            dest.push(name);
+        }
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let name = name.to_string();
+        let verified = sha256.is_some();
+
+        let (tag, download) = self.runtime.block_on(async move {
+            let release = match tag {
+                Some(tag) => client.get_release_by_tag(&owner, &repo, &tag).await?,
+                None => client.get_latest_release(&owner, &repo).await?,
+            };
+            let asset = release
+                .assets
+                .iter()
+                .find(|a| a.name == name)
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Asset '{}' not found in release {}",
+                        name,
+                        release.release.tag_name
+                    )
+                })?;
+
+            let download = client
+                .download_release_asset(&owner, &repo, asset.id, &dest, sha256.as_deref())
+                .await?;
+            Ok::<_, anyhow::Error>((release.release.tag_name, download))
+        })?;
+
+        Ok(serde_json::json!({
+            "downloaded": true,
+            "repo": repo_str,
+            "tag": tag,
+            "path": download.path,
+            "bytes": download.bytes,
+            "sha256": download.sha256,
+            "verified": verified,
+            "elapsed_ms": download.elapsed_ms,
+        }))
+    }
+
+    fn update_release(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let release_id = Self::get_u64(&params, "release_id");
+        let tag = Self::get_str(&params, "tag").map(|s| s.to_string());
+        if release_id.is_none() && tag.is_none() {
+            anyhow::bail!("Missing required parameter: release_id (or tag)");
+        }
+
+        let update = ReleaseUpdate {
+            tag_name: Self::get_str(&params, "new_tag").map(|s| s.to_string()),
+            name: Self::get_str(&params, "name").map(|s| s.to_string()),
+            body: Self::get_str(&params, "body").map(|s| s.to_string()),
+            draft: Self::get_bool(&params, "draft"),
+            prerelease: Self::get_bool(&params, "prerelease"),
+        };
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let release = self.runtime.block_on(async move {
+            let release_id = match release_id {
+                Some(id) => id,
+                None => {
+                    let tag = tag.unwrap_or_default();
+                    client.find_release_id(&owner, &repo, &tag).await?
+                }
+            };
+            client
+                .update_release(&owner, &repo, release_id, &update)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "updated": true,
+            "release": release,
+        }))
+    }
+
+    fn delete_release(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let release_id = Self::get_u64(&params, "release_id");
+        let tag = Self::get_str(&params, "tag").map(|s| s.to_string());
+        if release_id.is_none() && tag.is_none() {
+            anyhow::bail!("Missing required parameter: release_id (or tag)");
+        }
+        let delete_tag = Self::get_bool(&params, "delete_tag").unwrap_or(false);
+        if delete_tag && tag.is_none() {
+            anyhow::bail!("delete_tag requires the tag parameter");
+        }
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let release_id = self.runtime.block_on(async move {
+            let release_id = match release_id {
+                Some(id) => id,
+                None => {
+                    let tag = tag.as_deref().unwrap_or_default();
+                    client.find_release_id(&owner, &repo, tag).await?
+                }
+            };
+            let tag_to_delete = if delete_tag { tag.as_deref() } else { None };
+            client
+                .delete_release(&owner, &repo, release_id, tag_to_delete)
+                .await?;
+            Ok::<_, anyhow::Error>(release_id)
+        })?;
+
+        Ok(serde_json::json!({
+            "deleted": true,
+            "repo": repo_str,
+            "release_id": release_id,
+            "tag_deleted": delete_tag,
+        }))
+    }
+
+    fn list_tags(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let limit = Self::get_i32(&params, "limit", 20);
+        let after = Self::get_str(&params, "after").map(|s| s.to_string());
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let (tags, next_cursor) = self.runtime.block_on(async move {
+            client
+                .list_tags(&owner, &repo, limit, after.as_deref())
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "tags": tags,
+            "count": tags.len(),
+            "next_cursor": next_cursor,
+        }))
+    }
+
+    fn create_tag(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let tag = Self::get_str(&params, "tag")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: tag"))?;
+        let target = Self::get_str(&params, "target").map(|s| s.to_string());
+        let lightweight = Self::get_bool(&params, "lightweight").unwrap_or(false);
+        let message = match (Self::get_str(&params, "message"), lightweight) {
+            (Some(_), true) => anyhow::bail!("Lightweight tags cannot have a message"),
+            (Some(m), false) => Some(m.to_string()),
+            (None, true) => None,
+            (None, false) => Some(tag.to_string()),
+        };
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let tag = tag.to_string();
+
+        let tag = self.runtime.block_on(async move {
+            client
+                .create_tag(&owner, &repo, &tag, target.as_deref(), message.as_deref())
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "created": true,
+            "tag": tag,
+        }))
+    }
+
+    fn release_notes(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let tag = Self::get_str(&params, "tag")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: tag"))?;
+        let previous_tag = Self::get_str(&params, "previous_tag").map(|s| s.to_string());
+        let target = Self::get_str(&params, "target").map(|s| s.to_string());
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let tag = tag.to_string();
+        let tag_for_response = tag.clone();
+        let previous_for_response = previous_tag.clone();
+
+        let notes = self.runtime.block_on(async move {
+            client
+                .generate_release_notes(
+                    &owner,
+                    &repo,
+                    &tag,
+                    target.as_deref(),
+                    previous_tag.as_deref(),
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "tag": tag_for_response,
+            "previous_tag": previous_for_response,
+            "name": notes.name,
+            "notes": notes.body,
+        }))
+    }
+
+    fn list_gists(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let limit = Self::get_i32(&params, "limit", 10);
+        let page = Self::get_i32(&params, "page", 1);
+        let client = self.client();
+
+        let gists = self
+            .runtime
+            .block_on(async move { client.list_gists(limit, page).await })?;
+
+        Ok(serde_json::json!({
+            "page": page,
+            "gists": gists,
+            "count": gists.len(),
+        }))
+    }
+
+    fn get_gist(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let id = Self::get_str(&params, "id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: id"))?
+            .to_string();
+        let client = self.client();
+
+        let gist = self
+            .runtime
+            .block_on(async move { client.get_gist(&id).await })?;
+
+        Ok(serde_json::json!(gist))
+    }
+
+    /// Normalize a gist `files` parameter into the REST shape.
+    ///
+    /// Accepts `{"name": "content"}` shorthand alongside full
+    /// `{"name": {"content": ..., "filename": ...}}` entries; `null`
+    /// (delete) entries are only allowed when `allow_delete` is set.
+    fn gist_files(files: &Value, allow_delete: bool) -> Result<Value> {
+        let files = files
+            .as_object()
+            .ok_or_else(|| anyhow::anyhow!("files must be an object of filename -> content"))?;
+
+        let mut normalized = serde_json::Map::new();
+        for (name, value) in files {
+            let entry = match value {
+                Value::String(content) => json!({ "content": content }),
+                Value::Object(_) => value.clone(),
+                Value::Null if allow_delete => Value::Null,
+                _ => anyhow::bail!("Invalid content for gist file '{}'", name),
+            };
+            normalized.insert(name.clone(), entry);
+        }
+        Ok(Value::Object(normalized))
+    }
+
+    fn create_gist(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let files = params
+            .get("files")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: files"))?;
+        let files = Self::gist_files(files, false)?;
+        let description = Self::get_str(&params, "description").map(|s| s.to_string());
+        let public = Self::get_bool(&params, "public").unwrap_or(false);
+
+        let client = self.client();
+        let gist = self.runtime.block_on(async move {
+            client
+                .create_gist(&files, description.as_deref(), public)
+                .await
+        })?;
+
+        let raw_urls: serde_json::Map<String, Value> = gist
+            .files
+            .iter()
+            .map(|f| (f.filename.clone(), json!(f.raw_url)))
+            .collect();
+
+        Ok(serde_json::json!({
+            "created": true,
+            "id": gist.id,
+            "url": gist.url,
+            "raw_urls": raw_urls,
+        }))
+    }
+
+    fn update_gist(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let id = Self::get_str(&params, "id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: id"))?
+            .to_string();
+        let files = params
+            .get("files")
+            .map(|f| Self::gist_files(f, true))
+            .transpose()?;
+        let description = Self::get_str(&params, "description").map(|s| s.to_string());
+        if files.is_none() && description.is_none() {
+            anyhow::bail!("Nothing to update: pass files and/or description");
+        }
+
+        let client = self.client();
+        let gist = self.runtime.block_on(async move {
+            client
+                .update_gist(&id, files.as_ref(), description.as_deref())
+                .await
+        })?;
+
+        let raw_urls: serde_json::Map<String, Value> = gist
+            .files
+            .iter()
+            .map(|f| (f.filename.clone(), json!(f.raw_url)))
+            .collect();
+
+        Ok(serde_json::json!({
+            "updated": true,
+            "id": gist.id,
+            "url": gist.url,
+            "raw_urls": raw_urls,
+        }))
+    }
+
+    fn compare_releases(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let base = Self::get_str(&params, "base")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: base"))?;
+        let head = Self::get_str(&params, "head")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: head"))?;
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let base = base.to_string();
+        let head = head.to_string();
+
+        let changelog = self
+            .runtime
+            .block_on(async move { client.compare_releases(&owner, &repo, &base, &head).await })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "changelog": changelog,
+        }))
+    }
+
+    fn thread_subscription(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let thread_id = match params.get("thread_id") {
+            Some(Value::String(s)) => s.clone(),
+            Some(Value::Number(n)) => n.to_string(),
+            _ => anyhow::bail!("Missing required parameter: thread_id"),
+        };
+        let action = Self::get_str(&params, "action")
+            .unwrap_or("get")
+            .to_string();
+
+        let client = self.client();
+        let thread_id_for_response = thread_id.clone();
+
+        let subscription = self.runtime.block_on(async move {
+            match action.as_str() {
+                "get" => client.get_thread_subscription(&thread_id).await.map(Some),
+                "subscribe" => client
+                    .set_thread_subscription(&thread_id, false)
+                    .await
+                    .map(Some),
+                "ignore" => client
+                    .set_thread_subscription(&thread_id, true)
+                    .await
+                    .map(Some),
+                "unsubscribe" => client
+                    .delete_thread_subscription(&thread_id)
+                    .await
+                    .map(|_| None),
+                other => anyhow::bail!(
+                    "Invalid action '{}'. Expected get, subscribe, ignore, or unsubscribe",
+                    other
+                ),
+            }
+        })?;
+
+        Ok(match subscription {
+            Some(subscription) => serde_json::json!(subscription),
+            None => serde_json::json!({
+                "thread_id": thread_id_for_response,
+                "subscribed": false,
+                "ignored": false,
+            }),
+        })
+    }
+
+    fn notification_events(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let cursor = Self::get_u64(&params, "cursor").unwrap_or(0);
+        let limit = Self::get_i32(&params, "limit", 100).max(1) as usize;
+        let wait_ms = Self::get_u64(&params, "wait_ms").unwrap_or(0).min(30_000);
+
+        // Subscribing starts the account's poller; it keeps running until the
+        // daemon stops.
+        let poller = self.poller();
+        poller.ensure_started(&self.runtime, self.client());
+
+        let events = self.runtime.block_on({
+            let poller = poller.clone();
+            async move {
+                poller
+                    .events_after(cursor, limit, std::time::Duration::from_millis(wait_ms))
+                    .await
+            }
+        });
+        let next_cursor = events
+            .last()
+            .map(|e| e.seq)
+            .unwrap_or_else(|| cursor.max(poller.last_seq()));
+
+        Ok(serde_json::json!({
+            "events": events,
+            "count": events.len(),
+            "cursor": next_cursor,
+            "poller": poller.status(),
+        }))
+    }
+
+    fn set_repo_subscription(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let mode = Self::get_str(&params, "mode")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: mode"))?
+            .to_string();
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let ignored = match mode.as_str() {
+            "all" | "participating" => false,
+            "ignore" => true,
+            // GitHub's REST and GraphQL APIs only expose subscribed/ignored;
+            // the web UI's custom event types have no public endpoint.
+            "releases" | "security_alerts" => anyhow::bail!(
+                "Custom watch mode '{}' is not exposed by the GitHub API; set it at https://github.com/{}/{} (Watch > Custom)",
+                mode,
+                owner,
+                repo
+            ),
+            other => anyhow::bail!(
+                "Invalid mode '{}'. Expected all, participating, or ignore",
+                other
+            ),
+        };
+
+        let client = self.client();
+
+        let subscription = self.runtime.block_on(async move {
+            if mode == "participating" {
+                client.delete_repo_subscription(&owner, &repo).await?;
+                client.get_repo_subscription(&owner, &repo).await
+            } else {
+                client.set_repo_subscription(&owner, &repo, ignored).await
+            }
+        })?;
+
+        Ok(serde_json::json!(subscription))
+    }
+
+    fn notification_digest(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let limit = Self::get_i32(&params, "limit", 100).max(1) as usize;
+        let top = Self::get_i32(&params, "top", 3).max(0) as usize;
+        let client = self.client();
+
+        let digest = self
+            .runtime
+            .block_on(async move { client.get_notification_digest(limit, top).await })?;
+
+        Ok(serde_json::json!(digest))
+    }
+
+    fn search_code(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let query = Self::search_query(
+            &params,
+            &[
+                "repo",
+                "org",
+                "user",
+                "path",
+                "language",
+                "filename",
+                "extension",
+            ],
+        )?;
+        let limit = Self::get_i32(&params, "limit", 30).clamp(1, 100) as u32;
+        let page = Self::get_i32(&params, "page", 1).max(1) as u32;
+        let client = self.client();
+
+        let results = self.runtime.block_on({
+            let query = query.clone();
+            async move { client.search_code(&query, limit, page).await }
+        })?;
+
+        Ok(Self::search_envelope(&query, results))
+    }
+
+    fn search(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let kind = Self::get_str(&params, "type")
+            .unwrap_or("issues")
+            .to_string();
+        let query = Self::search_query(
+            &params,
+            &["repo", "org", "author", "assignee", "label", "state"],
+        )?;
+        let limit = Self::get_i32(&params, "limit", 30);
+        let after = Self::get_str(&params, "after").map(|s| s.to_string());
+        let client = self.client();
+
+        self.runtime.block_on(async move {
+            match kind.as_str() {
+                "issues" => {
+                    let results = client
+                        .search_issues(&query, limit, after.as_deref())
+                        .await?;
+                    Ok(Self::search_envelope(&query, results))
+                }
+                "prs" => {
+                    let results = client.search_prs(&query, limit, after.as_deref()).await?;
+                    Ok(Self::search_envelope(&query, results))
+                }
+                other => anyhow::bail!("Invalid type '{}'. Expected issues or prs", other),
+            }
+        })
+    }
+
+    fn search_repos(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let query = Self::search_query(
+            &params,
+            &[
+                "language", "stars", "topic", "pushed", "user", "org", "archived",
+            ],
+        )?;
+        let sort = Self::get_str(&params, "sort").map(|s| s.to_string());
+        let order = Self::get_str(&params, "order").map(|s| s.to_string());
+        let limit = Self::get_i32(&params, "limit", 30).clamp(1, 100) as u32;
+        let page = Self::get_i32(&params, "page", 1).max(1) as u32;
+        let client = self.client();
+
+        if Self::get_str(&params, "rank") == Some("recent_stars") {
+            // Each candidate costs a few stargazer requests, so keep the pool small.
+            let days = Self::get_i32(&params, "days", 7).clamp(1, 365) as u32;
+            let candidates = limit.min(30);
+            let results = self.runtime.block_on({
+                let query = query.clone();
+                async move { client.trending_repos(&query, days, candidates).await }
+            })?;
+            return Ok(Self::search_envelope(&query, results));
+        }
+
+        let results = self.runtime.block_on({
+            let query = query.clone();
+            async move {
+                client
+                    .search_repos(&query, sort.as_deref(), order.as_deref(), limit, page)
+                    .await
+            }
+        })?;
+
+        Ok(Self::search_envelope(&query, results))
+    }
+
+    fn search_users(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let mut query =
+            Self::search_query(&params, &["type", "location", "language", "followers"])?;
+        // Bare handles like "@octocat" are the common free-text case.
+        if let Some(stripped) = query.strip_prefix('@') {
+            query = stripped.to_string();
+        }
+        let limit = Self::get_i32(&params, "limit", 30);
+        let after = Self::get_str(&params, "after").map(|s| s.to_string());
+        let client = self.client();
+
+        let results = self.runtime.block_on({
+            let query = query.clone();
+            async move { client.search_users(&query, limit, after.as_deref()).await }
+        })?;
+
+        Ok(Self::search_envelope(&query, results))
+    }
+
+    fn search_commits(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let query = Self::search_query(
+            &params,
+            &[
+                "repo",
+                "org",
+                "user",
+                "author",
+                "committer",
+                "author-date",
+                "committer-date",
+                "hash",
+                "merge",
+            ],
+        )?;
+        let sort = Self::get_str(&params, "sort").map(|s| s.to_string());
+        let order = Self::get_str(&params, "order").map(|s| s.to_string());
+        let limit = Self::get_i32(&params, "limit", 30).clamp(1, 100) as u32;
+        let page = Self::get_i32(&params, "page", 1).max(1) as u32;
+        let client = self.client();
+
+        let results = self.runtime.block_on({
+            let query = query.clone();
+            async move {
+                client
+                    .search_commits(&query, sort.as_deref(), order.as_deref(), limit, page)
+                    .await
+            }
+        })?;
+
+        Ok(Self::search_envelope(&query, results))
+    }
+
+    fn search_topics(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let query = Self::search_query(&params, &["is", "repositories"])?;
+        let limit = Self::get_i32(&params, "limit", 30).clamp(1, 100) as u32;
+        let page = Self::get_i32(&params, "page", 1).max(1) as u32;
+        let client = self.client();
+
+        let results = self.runtime.block_on({
+            let query = query.clone();
+            async move { client.search_topics(&query, limit, page).await }
+        })?;
+
+        Ok(Self::search_envelope(&query, results))
+    }
+
+    fn search_all(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let kind = Self::get_str(&params, "type")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: type"))?
+            .to_string();
+        let cursor = Self::get_str(&params, "cursor").map(|s| s.to_string());
+
+        // Backend-specific params are forwarded as-is; `type` and `cursor` are
+        // translated here because each backend paginates differently.
+        let mut forwarded = params;
+        forwarded.remove("type");
+        forwarded.remove("cursor");
+
+        match (kind.as_str(), cursor) {
+            (_, None) => {}
+            ("issues" | "prs" | "users", Some(after)) => {
+                forwarded.insert("after".into(), json!(after));
+            }
+            (_, Some(page)) => {
+                let page: u64 = page
+                    .parse()
+                    .map_err(|_| anyhow::anyhow!("Invalid cursor '{}' for type {}", page, kind))?;
+                forwarded.insert("page".into(), json!(page));
+            }
+        }
+
+        let mut envelope = match kind.as_str() {
+            "code" => self.search_code(forwarded)?,
+            "issues" | "prs" => {
+                forwarded.insert("type".into(), json!(kind));
+                self.search(forwarded)?
+            }
+            "repos" => self.search_repos(forwarded)?,
+            "users" => self.search_users(forwarded)?,
+            "commits" => self.search_commits(forwarded)?,
+            "topics" => self.search_topics(forwarded)?,
+            other => anyhow::bail!(
+                "Invalid type '{}'. Expected code, issues, prs, repos, users, commits, or topics",
+                other
+            ),
+        };
+
+        envelope["type"] = json!(kind);
+        Ok(envelope)
+    }
+
+    fn save_search(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let name = Self::get_str(&params, "name")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: name"))?
+            .to_string();
+        let search_type = Self::get_str(&params, "type")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: type"))?
+            .to_string();
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        {
+            anyhow::bail!(
+                "Invalid name '{}'. Use letters, digits, '-', '_', or '.'",
+                name
+            );
+        }
+        if !matches!(
+            search_type.as_str(),
+            "code" | "issues" | "prs" | "repos" | "users" | "commits" | "topics"
+        ) {
+            anyhow::bail!(
+                "Invalid type '{}'. Expected code, issues, prs, repos, users, commits, or topics",
+                search_type
+            );
+        }
+
+        // Everything except the bookkeeping fields is replayed into search_all.
+        let mut search_params = match params.get("params") {
+            Some(Value::Object(map)) => map.clone(),
+            Some(_) => anyhow::bail!("params must be an object"),
+            None => serde_json::Map::new(),
+        };
+        if let Some(query) = Self::get_str(&params, "query") {
+            search_params.insert("query".into(), json!(query));
+        }
+        if search_params.is_empty() {
+            anyhow::bail!("Missing required parameter: query");
+        }
+
+        let saved = SavedSearch {
+            name: name.clone(),
+            search_type,
+            params: search_params,
+            description: Self::get_str(&params, "description").map(|s| s.to_string()),
+            created_at: chrono::Utc::now().to_rfc3339(),
+        };
+
+        let replaced = self
+            .state
+            .update(|state| Ok(state.saved_searches.insert(name, saved.clone()).is_some()))?;
+
+        Ok(serde_json::json!({
+            "saved": saved,
+            "replaced": replaced,
+        }))
+    }
+
+    fn saved_searches(&self, _params: HashMap<String, Value>) -> Result<Value> {
+        let state = self.state.load()?;
+        let searches: Vec<&SavedSearch> = state.saved_searches.values().collect();
+
+        Ok(serde_json::json!({
+            "searches": searches,
+            "count": searches.len(),
+            "path": self.state.path().display().to_string(),
+        }))
+    }
+
+    fn run_saved_search(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let name = Self::get_str(&params, "name")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: name"))?;
+
+        let saved = self
+            .state
+            .load()?
+            .saved_searches
+            .remove(name)
+            .ok_or_else(|| anyhow::anyhow!("No saved search named '{}'", name))?;
+
+        let mut search_params: HashMap<String, Value> = saved.params.into_iter().collect();
+        search_params.insert("type".into(), json!(saved.search_type));
+        for key in ["cursor", "limit"] {
+            if let Some(value) = params.get(key) {
+                search_params.insert(key.into(), value.clone());
+            }
+        }
+
+        let mut envelope = self.search_all(search_params)?;
+        envelope["name"] = json!(saved.name);
+        Ok(envelope)
+    }
+
+    fn get_user_profile(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let login = Self::get_str(&params, "login")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: login"))?
+            .trim_start_matches('@')
+            .to_string();
+        let client = self.client();
+
+        let profile = self
+            .runtime
+            .block_on(async move { client.get_user_profile(&login).await })?;
+
+        Ok(serde_json::json!(profile))
+    }
+
+    fn org_repos(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let org = Self::get_str(&params, "org")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: org"))?
+            .to_string();
+        let repo_type = Self::get_str(&params, "type").unwrap_or("all").to_string();
+        let sort = Self::get_str(&params, "sort")
+            .unwrap_or("updated")
+            .to_string();
+        let direction = Self::get_str(&params, "direction").map(|s| s.to_string());
+        let limit = Self::get_i32(&params, "limit", 30).clamp(1, 100);
+        let page = Self::get_i32(&params, "page", 1).max(1);
+
+        let client = self.client();
+        let org_for_request = org.clone();
+
+        let repos = self.runtime.block_on(async move {
+            client
+                .list_org_repos(
+                    &org_for_request,
+                    &repo_type,
+                    &sort,
+                    direction.as_deref(),
+                    limit,
+                    page,
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "org": org,
+            "page": page,
+            "repos": repos,
+            "count": repos.len(),
+        }))
+    }
+
+    fn org_members(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let org = Self::get_str(&params, "org")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: org"))?
+            .to_string();
+        let role = Self::get_str(&params, "role").map(|s| s.to_string());
+        let limit = Self::get_i32(&params, "limit", 50);
+        let after = Self::get_str(&params, "after").map(|s| s.to_string());
+
+        let client = self.client();
+        let org_for_request = org.clone();
+
+        let (mut members, next_cursor) = self.runtime.block_on(async move {
+            client
+                .list_org_members(&org_for_request, limit, after.as_deref())
+                .await
+        })?;
+        if let Some(role) = role {
+            members.retain(|m| m.role == role);
+        }
+
+        Ok(serde_json::json!({
+            "org": org,
+            "members": members,
+            "count": members.len(),
+            "next_cursor": next_cursor,
+        }))
+    }
+
+    fn list_orgs(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let state = Self::get_str(&params, "state").map(|s| s.to_string());
+        let client = self.client();
+
+        let orgs = self
+            .runtime
+            .block_on(async move { client.list_org_memberships(state.as_deref()).await })?;
+
+        Ok(serde_json::json!({
+            "orgs": orgs,
+            "count": orgs.len(),
+        }))
+    }
+
+    fn list_teams(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let org = Self::get_str(&params, "org")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: org"))?
+            .to_string();
+        let limit = Self::get_i32(&params, "limit", 50);
+        let after = Self::get_str(&params, "after").map(|s| s.to_string());
+
+        let client = self.client();
+        let org_for_request = org.clone();
+
+        let (teams, next_cursor) = self.runtime.block_on(async move {
+            client
+                .list_teams(&org_for_request, limit, after.as_deref())
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "org": org,
+            "teams": teams,
+            "count": teams.len(),
+            "next_cursor": next_cursor,
+        }))
+    }
+
+    fn team_members(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let org = Self::get_str(&params, "org")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: org"))?
+            .to_string();
+        let team = Self::get_str(&params, "team")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: team"))?
+            .to_string();
+        let immediate_only = Self::get_bool(&params, "immediate_only").unwrap_or(false);
+        let limit = Self::get_i32(&params, "limit", 100);
+        let after = Self::get_str(&params, "after").map(|s| s.to_string());
+
+        let client = self.client();
+        let (org_for_request, team_for_request) = (org.clone(), team.clone());
+
+        let (members, next_cursor) = self.runtime.block_on(async move {
+            client
+                .list_team_members(
+                    &org_for_request,
+                    &team_for_request,
+                    immediate_only,
+                    limit,
+                    after.as_deref(),
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "org": org,
+            "team": team,
+            "members": members,
+            "count": members.len(),
+            "next_cursor": next_cursor,
+        }))
+    }
+
+    fn team_repos(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let org = Self::get_str(&params, "org")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: org"))?
+            .to_string();
+        let team = Self::get_str(&params, "team")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: team"))?
+            .to_string();
+        let limit = Self::get_i32(&params, "limit", 30).clamp(1, 100);
+        let page = Self::get_i32(&params, "page", 1).max(1);
+
+        let client = self.client();
+        let (org_for_request, team_for_request) = (org.clone(), team.clone());
+
+        let repos = self.runtime.block_on(async move {
+            client
+                .list_team_repos(&org_for_request, &team_for_request, limit, page)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "org": org,
+            "team": team,
+            "page": page,
+            "repos": repos,
+            "count": repos.len(),
+        }))
+    }
+
+    fn add_team_repo(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let org = Self::get_str(&params, "org")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: org"))?
+            .to_string();
+        let team = Self::get_str(&params, "team")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: team"))?
+            .to_string();
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let permission = Self::get_str(&params, "permission")
+            .unwrap_or("pull")
+            .to_string();
+
+        let client = self.client();
+        let (org_c, team_c, owner, repo, permission_c) = (
+            org.clone(),
+            team.clone(),
+            owner.to_string(),
+            repo.to_string(),
+            permission.clone(),
+        );
+
+        self.runtime.block_on(async move {
+            client
+                .add_team_repo(&org_c, &team_c, &owner, &repo, &permission_c)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "org": org,
+            "team": team,
+            "repo": repo_str,
+            "permission": permission,
+            "added": true,
+        }))
+    }
+
+    fn remove_team_repo(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let org = Self::get_str(&params, "org")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: org"))?
+            .to_string();
+        let team = Self::get_str(&params, "team")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: team"))?
+            .to_string();
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+
+        let client = self.client();
+        let (org_c, team_c, owner, repo) = (
+            org.clone(),
+            team.clone(),
+            owner.to_string(),
+            repo.to_string(),
+        );
+
+        self.runtime.block_on(async move {
+            client
+                .remove_team_repo(&org_c, &team_c, &owner, &repo)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "org": org,
+            "team": team,
+            "repo": repo_str,
+            "removed": true,
+        }))
+    }
+
+    fn org_invitations(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let org = Self::get_str(&params, "org")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: org"))?
+            .to_string();
+        let limit = Self::get_i32(&params, "limit", 100).max(1) as usize;
+
+        let client = self.client();
+        let org_for_request = org.clone();
+
+        let invitations = self
+            .runtime
+            .block_on(async move { client.list_org_invitations(&org_for_request, limit).await })?;
+
+        Ok(serde_json::json!({
+            "org": org,
+            "invitations": invitations,
+            "count": invitations.len(),
+        }))
+    }
+
+    fn invite_to_org(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let org = Self::get_str(&params, "org")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: org"))?
+            .to_string();
+        let login = Self::get_str(&params, "login").map(|s| s.to_string());
+        let email = Self::get_str(&params, "email").map(|s| s.to_string());
+        if login.is_none() && email.is_none() {
+            anyhow::bail!("Missing required parameter: login or email");
+        }
+        let role = Self::get_str(&params, "role")
+            .unwrap_or("direct_member")
+            .to_string();
+        let team_ids: Vec<u64> = match params.get("team_ids") {
+            Some(Value::Array(ids)) => ids.iter().filter_map(|id| id.as_u64()).collect(),
+            _ => vec![],
+        };
+
+        let client = self.client();
+        let org_for_request = org.clone();
+
+        let invitation = self.runtime.block_on(async move {
+            client
+                .invite_to_org(
+                    &org_for_request,
+                    login.as_deref(),
+                    email.as_deref(),
+                    &role,
+                    &team_ids,
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "org": org,
+            "invitation": invitation,
+        }))
+    }
+
+    fn cancel_org_invitation(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let org = Self::get_str(&params, "org")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: org"))?
+            .to_string();
+        let invitation_id = Self::get_u64(&params, "invitation_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: invitation_id"))?;
+
+        let client = self.client();
+        let org_for_request = org.clone();
+
+        self.runtime.block_on(async move {
+            client
+                .cancel_org_invitation(&org_for_request, invitation_id)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "org": org,
+            "invitation_id": invitation_id,
+            "cancelled": true,
+        }))
+    }
+
+    fn followers(&self, params: HashMap<String, Value>) -> Result<Value> {
+        self.user_connections(params, false)
+    }
+
+    fn following(&self, params: HashMap<String, Value>) -> Result<Value> {
+        self.user_connections(params, true)
+    }
+
+    /// Shared handler for `github.followers` and `github.following`.
+    fn user_connections(&self, params: HashMap<String, Value>, following: bool) -> Result<Value> {
+        let login = Self::get_str(&params, "login").map(|s| s.trim_start_matches('@').to_string());
+        let limit = Self::get_i32(&params, "limit", 30).clamp(1, 100);
+        let page = Self::get_i32(&params, "page", 1).max(1);
+
+        let client = self.client();
+        let login_for_request = login.clone();
+
+        let users = self.runtime.block_on(async move {
+            let login = login_for_request.as_deref();
+            if following {
+                client.list_following(login, limit, page).await
+            } else {
+                client.list_followers(login, limit, page).await
+            }
+        })?;
+
+        let key = if following { "following" } else { "followers" };
+        Ok(serde_json::json!({
+            "login": login,
+            "page": page,
+            key: users,
+            "count": users.len(),
+        }))
+    }
+
+    fn follow(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let login = Self::get_str(&params, "login")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: login"))?
+            .trim_start_matches('@')
+            .to_string();
+        let client = self.client();
+        let login_for_request = login.clone();
+
+        self.runtime
+            .block_on(async move { client.follow_user(&login_for_request).await })?;
+
+        Ok(serde_json::json!({
+            "login": login,
+            "following": true,
+        }))
+    }
+
+    fn unfollow(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let login = Self::get_str(&params, "login")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: login"))?
+            .trim_start_matches('@')
+            .to_string();
+        let client = self.client();
+        let login_for_request = login.clone();
+
+        self.runtime
+            .block_on(async move { client.unfollow_user(&login_for_request).await })?;
+
+        Ok(serde_json::json!({
+            "login": login,
+            "following": false,
+        }))
+    }
+
+    fn contributions(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let login = Self::get_str(&params, "login").map(|s| s.trim_start_matches('@').to_string());
+        let from = Self::get_str(&params, "from").map(|s| s.to_string());
+        let to = Self::get_str(&params, "to").map(|s| s.to_string());
+        let include_days = Self::get_bool(&params, "include_days").unwrap_or(true);
+        let client = self.client();
+
+        let mut contributions = self.runtime.block_on(async move {
+            client
+                .get_contributions(login.as_deref(), from.as_deref(), to.as_deref())
+                .await
+        })?;
+
+        if !include_days {
+            contributions.days.clear();
+        }
+
+        Ok(serde_json::json!(contributions))
+    }
+
+    fn events(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let login = Self::get_str(&params, "login").map(|s| s.trim_start_matches('@').to_string());
+        let repo_str = Self::get_str(&params, "repo");
+        let feed_name = Self::get_str(&params, "feed").unwrap_or(if repo_str.is_some() {
+            "repo"
+        } else {
+            "received"
+        });
+        let feed = match feed_name {
+            "received" => EventFeed::Received { login },
+            "public" => EventFeed::Public { login },
+            "repo" => {
+                let repo_str =
+                    repo_str.ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+                let (owner, repo) = Self::parse_repo(repo_str)?;
+                EventFeed::Repo {
+                    owner: owner.to_string(),
+                    repo: repo.to_string(),
+                }
+            }
+            other => anyhow::bail!(
+                "Invalid feed: {}. Must be 'received', 'public', or 'repo'",
+                other
+            ),
+        };
+        let types = Self::get_str_list(&params, "type");
+        let limit = Self::get_i32(&params, "limit", 30).clamp(1, 300) as usize;
+        let include_payload = Self::get_bool(&params, "include_payload").unwrap_or(false);
+        let feed_name = feed_name.to_string();
+        let client = self.client();
+
+        let events = self.runtime.block_on(async move {
+            client
+                .list_events(&feed, &types, limit, include_payload)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "feed": feed_name,
+            "events": events,
+            "count": events.len(),
+        }))
+    }
+
+    fn audit_log(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let (owner, enterprise) = match (
+            Self::get_str(&params, "org"),
+            Self::get_str(&params, "enterprise"),
+        ) {
+            (Some(org), _) => (org.to_string(), false),
+            (None, Some(enterprise)) => (enterprise.to_string(), true),
+            (None, None) => anyhow::bail!("Missing required parameter: org or enterprise"),
+        };
+        let phrase = Self::get_str(&params, "phrase").map(|s| s.to_string());
+        let since = Self::get_str(&params, "since").map(|s| s.to_string());
+        let until = Self::get_str(&params, "until").map(|s| s.to_string());
+        let include = Self::get_str(&params, "include")
+            .unwrap_or("web")
+            .to_string();
+        let order = Self::get_str(&params, "order")
+            .unwrap_or("desc")
+            .to_string();
+        let limit = Self::get_i32(&params, "limit", 30).clamp(1, 100);
+        let after = Self::get_str(&params, "after").map(|s| s.to_string());
+        let include_raw = Self::get_bool(&params, "include_raw").unwrap_or(false);
+
+        let client = self.client();
+        let owner_for_request = owner.clone();
+
+        let (entries, next_cursor) = self.runtime.block_on(async move {
+            client
+                .get_audit_log(
+                    &owner_for_request,
+                    enterprise,
+                    phrase.as_deref(),
+                    since.as_deref(),
+                    until.as_deref(),
+                    &include,
+                    &order,
+                    limit,
+                    after.as_deref(),
+                    include_raw,
+                )
+                .await
+        })?;
+
+        let scope = if enterprise { "enterprise" } else { "org" };
+        Ok(serde_json::json!({
+            scope: owner,
+            "entries": entries,
+            "count": entries.len(),
+            "next_cursor": next_cursor,
+        }))
+    }
+
+    fn ssh_keys(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let action = Self::get_str(&params, "action")
+            .unwrap_or("list")
+            .to_string();
+        let client = self.client();
+
+        match action.as_str() {
+            "list" => {
+                let limit = Self::get_i32(&params, "limit", 100).max(1) as usize;
+                let keys = self
+                    .runtime
+                    .block_on(async move { client.list_ssh_keys(limit).await })?;
+                Ok(serde_json::json!({
+                    "keys": keys,
+                    "count": keys.len(),
+                }))
+            }
+            "add" => {
+                let key = Self::get_str(&params, "key")
+                    .ok_or_else(|| anyhow::anyhow!("Missing required parameter: key"))?
+                    .trim()
+                    .to_string();
+                let title = Self::get_str(&params, "title")
+                    .ok_or_else(|| anyhow::anyhow!("Missing required parameter: title"))?
+                    .to_string();
+                let key = self
+                    .runtime
+                    .block_on(async move { client.add_ssh_key(&title, &key).await })?;
+                Ok(serde_json::json!(key))
+            }
+            "delete" => {
+                let id = Self::get_u64(&params, "id")
+                    .ok_or_else(|| anyhow::anyhow!("Missing required parameter: id"))?;
+                self.runtime
+                    .block_on(async move { client.delete_ssh_key(id).await })?;
+                Ok(serde_json::json!({
+                    "id": id,
+                    "deleted": true,
+                }))
+            }
+            other => anyhow::bail!("Invalid action '{}'. Expected list, add, or delete", other),
+        }
+    }
+
+    fn gpg_keys(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let action = Self::get_str(&params, "action")
+            .unwrap_or("list")
+            .to_string();
+        let client = self.client();
+
+        match action.as_str() {
+            "list" => {
+                let limit = Self::get_i32(&params, "limit", 100).max(1) as usize;
+                let keys = self
+                    .runtime
+                    .block_on(async move { client.list_gpg_keys(limit).await })?;
+                Ok(serde_json::json!({
+                    "keys": keys,
+                    "count": keys.len(),
+                }))
+            }
+            "add" => {
+                let armored = Self::get_str(&params, "armored_public_key")
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Missing required parameter: armored_public_key")
+                    })?
+                    .to_string();
+                let name = Self::get_str(&params, "name").map(|s| s.to_string());
+                let key = self
+                    .runtime
+                    .block_on(async move { client.add_gpg_key(&armored, name.as_deref()).await })?;
+                Ok(serde_json::json!(key))
+            }
+            "delete" => {
+                let id = Self::get_u64(&params, "id")
+                    .ok_or_else(|| anyhow::anyhow!("Missing required parameter: id"))?;
+                self.runtime
+                    .block_on(async move { client.delete_gpg_key(id).await })?;
+                Ok(serde_json::json!({
+                    "id": id,
+                    "deleted": true,
+                }))
+            }
+            other => anyhow::bail!("Invalid action '{}'. Expected list, add, or delete", other),
+        }
+    }
+
+    fn blocked_users(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let org = Self::get_str(&params, "org").map(|s| s.to_string());
+        let limit = Self::get_i32(&params, "limit", 100).max(1) as usize;
+        let client = self.client();
+        let org_for_request = org.clone();
+
+        let users = self.runtime.block_on(async move {
+            client
+                .list_blocked_users(org_for_request.as_deref(), limit)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "org": org,
+            "blocked": users,
+            "count": users.len(),
+        }))
+    }
+
+    fn block(&self, params: HashMap<String, Value>) -> Result<Value> {
+        self.set_blocked(params, true)
+    }
+
+    fn unblock(&self, params: HashMap<String, Value>) -> Result<Value> {
+        self.set_blocked(params, false)
+    }
+
+    /// Shared handler for `github.block` and `github.unblock`.
+    fn set_blocked(&self, params: HashMap<String, Value>, blocked: bool) -> Result<Value> {
+        let login = Self::get_str(&params, "login")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: login"))?
+            .trim_start_matches('@')
+            .to_string();
+        let org = Self::get_str(&params, "org").map(|s| s.to_string());
+        let client = self.client();
+        let (login_for_request, org_for_request) = (login.clone(), org.clone());
+
+        self.runtime.block_on(async move {
+            let org = org_for_request.as_deref();
+            if blocked {
+                client.block_user(org, &login_for_request).await
+            } else {
+                client.unblock_user(org, &login_for_request).await
+            }
+        })?;
+
+        Ok(serde_json::json!({
+            "login": login,
+            "org": org,
+            "blocked": blocked,
+        }))
+    }
+
+    fn org_dashboard(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let org = Self::get_str(&params, "org").map(|s| s.to_string());
+        let repos = Self::get_str_list(&params, "repos");
+        if org.is_none() && repos.is_empty() {
+            anyhow::bail!("Missing required parameter: org or repos");
+        }
+        for repo in &repos {
+            Self::parse_repo(repo)?;
+        }
+        let limit = Self::get_i32(&params, "limit", 50).clamp(1, 500) as usize;
+        let oldest = Self::get_i32(&params, "oldest_prs", 3).clamp(0, 20);
+        let client = self.client();
+
+        let dashboard = self.runtime.block_on(async move {
+            client
+                .get_org_dashboard(org.as_deref(), &repos, limit, oldest)
+                .await
+        })?;
+
+        Ok(serde_json::json!(dashboard))
+    }
+
+    fn dependabot_alerts(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let scope = match (
+            Self::get_str(&params, "repo"),
+            Self::get_str(&params, "org"),
+        ) {
+            (Some(repo_str), _) => {
+                let (owner, repo) = Self::parse_repo(repo_str)?;
+                AlertScope::Repo {
+                    owner: owner.to_string(),
+                    repo: repo.to_string(),
+                }
+            }
+            (None, Some(org)) => AlertScope::Org(org.to_string()),
+            (None, None) => anyhow::bail!("Missing required parameter: repo or org"),
+        };
+        let mut filter = AlertFilter {
+            state: Self::get_str_list(&params, "state"),
+            severity: Self::get_str_list(&params, "severity"),
+            package: Self::get_str_list(&params, "package"),
+            ecosystem: Self::get_str_list(&params, "ecosystem"),
+        };
+        if filter.state.is_empty() {
+            filter.state.push("open".to_string());
+        }
+        let limit = Self::get_i32(&params, "limit", 100).max(1) as usize;
+        let client = self.client();
+
+        let alerts = self
+            .runtime
+            .block_on(async move { client.list_dependabot_alerts(&scope, &filter, limit).await })?;
+
+        Ok(serde_json::json!({
+            "alerts": alerts,
+            "count": alerts.len(),
+        }))
+    }
+
+    fn dismiss_dependabot_alert(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_u64(&params, "number")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: number"))?;
+        let reason = Self::get_str(&params, "reason")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: reason"))?
+            .to_string();
+        let comment = Self::get_str(&params, "comment").map(|s| s.to_string());
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let alert = self.runtime.block_on(async move {
+            client
+                .dismiss_dependabot_alert(&owner, &repo, number, &reason, comment.as_deref())
+                .await
+        })?;
+
+        Ok(serde_json::json!(alert))
+    }
+
+    fn code_scanning_alerts(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let scope = match (
+            Self::get_str(&params, "repo"),
+            Self::get_str(&params, "org"),
+        ) {
+            (Some(repo_str), _) => {
+                let (owner, repo) = Self::parse_repo(repo_str)?;
+                AlertScope::Repo {
+                    owner: owner.to_string(),
+                    repo: repo.to_string(),
+                }
+            }
+            (None, Some(org)) => AlertScope::Org(org.to_string()),
+            (None, None) => anyhow::bail!("Missing required parameter: repo or org"),
+        };
+        let state = Self::get_str(&params, "state")
+            .unwrap_or("open")
+            .to_string();
+        let severity = Self::get_str(&params, "severity").map(|s| s.to_string());
+        let tool = Self::get_str(&params, "tool").map(|s| s.to_string());
+        let git_ref = Self::get_str(&params, "ref").map(|s| s.to_string());
+        let limit = Self::get_i32(&params, "limit", 100).max(1) as usize;
+        let client = self.client();
+
+        let alerts = self.runtime.block_on(async move {
+            client
+                .list_code_scanning_alerts(
+                    &scope,
+                    Some(&state),
+                    severity.as_deref(),
+                    tool.as_deref(),
+                    git_ref.as_deref(),
+                    limit,
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "alerts": alerts,
+            "count": alerts.len(),
+        }))
+    }
+
+    fn update_code_scanning_alert(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_u64(&params, "number")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: number"))?;
+        let state = Self::get_str(&params, "state")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: state"))?
+            .to_string();
+        let reason = Self::get_str(&params, "reason").map(|s| s.to_string());
+        if state == "dismissed" && reason.is_none() {
+            anyhow::bail!("Missing required parameter: reason (required when dismissing)");
+        }
+        let comment = Self::get_str(&params, "comment").map(|s| s.to_string());
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let alert = self.runtime.block_on(async move {
+            client
+                .update_code_scanning_alert(
+                    &owner,
+                    &repo,
+                    number,
+                    &state,
+                    reason.as_deref(),
+                    comment.as_deref(),
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!(alert))
+    }
+
+    fn secret_scanning_alerts(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let scope = match (
+            Self::get_str(&params, "repo"),
+            Self::get_str(&params, "org"),
+        ) {
+            (Some(repo_str), _) => {
+                let (owner, repo) = Self::parse_repo(repo_str)?;
+                AlertScope::Repo {
+                    owner: owner.to_string(),
+                    repo: repo.to_string(),
+                }
+            }
+            (None, Some(org)) => AlertScope::Org(org.to_string()),
+            (None, None) => anyhow::bail!("Missing required parameter: repo or org"),
+        };
+        let state = Self::get_str(&params, "state")
+            .unwrap_or("open")
+            .to_string();
+        let secret_types = Self::get_str_list(&params, "secret_type");
+        let limit = Self::get_i32(&params, "limit", 100).max(1) as usize;
+        let include_locations = Self::get_bool(&params, "include_locations").unwrap_or(true);
+        let client = self.client();
+
+        let alerts = self.runtime.block_on(async move {
+            client
+                .list_secret_scanning_alerts(
+                    &scope,
+                    Some(&state),
+                    &secret_types,
+                    limit,
+                    include_locations,
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "alerts": alerts,
+            "count": alerts.len(),
+        }))
+    }
+
+    fn update_secret_scanning_alert(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_u64(&params, "number")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: number"))?;
+        let state = Self::get_str(&params, "state")
+            .unwrap_or("resolved")
+            .to_string();
+        let resolution = Self::get_str(&params, "resolution").map(|s| s.to_string());
+        if state == "resolved" && resolution.is_none() {
+            anyhow::bail!("Missing required parameter: resolution (required when resolving)");
+        }
+        let comment = Self::get_str(&params, "comment").map(|s| s.to_string());
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let alert = self.runtime.block_on(async move {
+            client
+                .update_secret_scanning_alert(
+                    &owner,
+                    &repo,
+                    number,
+                    &state,
+                    resolution.as_deref(),
+                    comment.as_deref(),
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!(alert))
+    }
+
+    fn advisories(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let state = Self::get_str(&params, "state").map(|s| s.to_string());
+        let limit = Self::get_i32(&params, "limit", 100).max(1) as usize;
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let advisories = self.runtime.block_on(async move {
+            client
+                .list_repo_advisories(&owner, &repo, state.as_deref(), limit)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "advisories": advisories,
+            "count": advisories.len(),
+        }))
+    }
+
+    fn create_advisory(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let summary = Self::get_str(&params, "summary")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: summary"))?;
+        let description = Self::get_str(&params, "description")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: description"))?;
+        let vulnerabilities: Vec<AdvisoryVulnerability> = match params.get("vulnerabilities") {
+            Some(v) => serde_json::from_value(v.clone())
+                .map_err(|e| anyhow::anyhow!("Invalid vulnerabilities: {}", e))?,
+            None => anyhow::bail!("Missing required parameter: vulnerabilities"),
+        };
+        let severity = Self::get_str(&params, "severity").map(|s| s.to_string());
+        let cvss_vector = Self::get_str(&params, "cvss_vector").map(|s| s.to_string());
+        if severity.is_some() && cvss_vector.is_some() {
+            anyhow::bail!("Provide either severity or cvss_vector, not both");
+        }
+
+        let advisory = NewAdvisory {
+            summary: summary.to_string(),
+            description: description.to_string(),
+            severity,
+            cvss_vector,
+            cve_id: Self::get_str(&params, "cve_id").map(|s| s.to_string()),
+            cwe_ids: Self::get_str_list(&params, "cwe_ids"),
+            vulnerabilities,
+        };
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let created = self
+            .runtime
+            .block_on(async move { client.create_repo_advisory(&owner, &repo, &advisory).await })?;
+
+        Ok(serde_json::json!(created))
+    }
+
+    fn sbom(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let path = Self::get_str(&params, "path").map(|p| shellexpand::tilde(p).to_string());
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let sbom = self
+            .runtime
+            .block_on(async move { client.get_sbom(&owner, &repo).await })?;
+        let package_count = sbom
+            .get("packages")
+            .and_then(|p| p.as_array())
+            .map_or(0, |p| p.len());
+
+        // Large SBOMs can be written straight to disk instead of returned inline
+        match path {
+            Some(path) => {
+                std::fs::write(&path, serde_json::to_string_pretty(&sbom)?)
+                    .map_err(|e| anyhow::anyhow!("Failed to write {}: {}", path, e))?;
+                Ok(serde_json::json!({
+                    "repo": repo_str,
+                    "path": path,
+                    "package_count": package_count,
+                }))
+            }
+            None => Ok(serde_json::json!({
+                "repo": repo_str,
+                "package_count": package_count,
+                "sbom": sbom,
+            })),
+        }
+    }
+
+    fn dependencies(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let per_manifest = Self::get_i32(&params, "limit", 100).clamp(1, 100);
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let manifests = self.runtime.block_on(async move {
+            client
+                .list_dependency_manifests(&owner, &repo, per_manifest)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "manifests": manifests,
+            "count": manifests.len(),
+        }))
+    }
+
+    fn dependency_diff(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let base = Self::get_str(&params, "base")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: base"))?
+            .to_string();
+        let head = Self::get_str(&params, "head")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: head"))?
+            .to_string();
+        let manifest = Self::get_str(&params, "manifest").map(|s| s.to_string());
+        let fail_on_severity = Self::get_str(&params, "fail_on_severity")
+            .unwrap_or("low")
+            .to_string();
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let (base_for_request, head_for_request) = (base.clone(), head.clone());
+
+        let changes = self.runtime.block_on(async move {
+            client
+                .compare_dependencies(
+                    &owner,
+                    &repo,
+                    &base_for_request,
+                    &head_for_request,
+                    manifest.as_deref(),
+                )
+                .await
+        })?;
+
+        let (added, removed): (Vec<_>, Vec<_>) =
+            changes.into_iter().partition(|c| c.change_type == "added");
+        let vulnerable: Vec<_> = added
+            .iter()
+            .filter(|c| c.introduces_vulnerability(&fail_on_severity))
+            .cloned()
+            .collect();
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "base": base,
+            "head": head,
+            "fail_on_severity": fail_on_severity,
+            "passed": vulnerable.is_empty(),
+            "vulnerable": vulnerable,
+            "added": added,
+            "removed": removed,
+        }))
+    }
+
+    fn security_features(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+
+        let update = SecurityFeaturesUpdate {
+            dependabot_alerts: Self::get_bool(&params, "dependabot_alerts"),
+            dependabot_security_updates: Self::get_bool(&params, "dependabot_security_updates"),
+            secret_scanning: Self::get_bool(&params, "secret_scanning"),
+            push_protection: Self::get_bool(&params, "push_protection"),
+        };
+        let updated = update.dependabot_alerts.is_some()
+            || update.dependabot_security_updates.is_some()
+            || update.secret_scanning.is_some()
+            || update.push_protection.is_some();
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let features = self.runtime.block_on(async move {
+            if updated {
+                client
+                    .update_security_features(&owner, &repo, &update)
+                    .await
+            } else {
+                client.get_security_features(&owner, &repo).await
+            }
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "updated": updated,
+            "features": features,
+        }))
+    }
+
+    /// Token type, scopes, and which scope-gated methods the token can use.
+    fn token_info(&self) -> Result<Value> {
+        let client = self.client();
+        let info = self
+            .runtime
+            .block_on(async move { client.get_token_info().await })?;
+
+        // Without X-OAuth-Scopes, GitHub checks permissions per request.
+        let Some(granted) = info.scopes.as_deref() else {
+            let gated: Vec<&str> = scopes::METHOD_SCOPES.iter().map(|(m, _)| *m).collect();
+            return Ok(serde_json::json!({
+                "token": info,
+                "usable": Value::Null,
+                "unavailable": Value::Null,
+                "unknown": gated,
+                "note": "This token type does not report OAuth scopes; permissions are checked per request.",
+            }));
+        };
+
+        let mut usable = Vec::new();
+        let mut unavailable = Vec::new();
+        for (method, _) in scopes::METHOD_SCOPES {
+            let missing = scopes::missing_scopes(method, None, granted);
+            if missing.is_empty() {
+                usable.push(*method);
+            } else {
+                unavailable.push(serde_json::json!({
+                    "method": method,
+                    "missing_scopes": missing,
+                }));
+            }
+        }
+
+        Ok(serde_json::json!({
+            "token": info,
+            "usable": usable,
+            "unavailable": unavailable,
+            "unavailable_count": unavailable.len(),
+        }))
+    }
+
+    fn security_overview(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repos = Self::get_str_list(&params, "repos");
+        if repos.is_empty() {
+            anyhow::bail!("Missing required parameter: repos");
+        }
+        for repo in &repos {
+            Self::parse_repo(repo)?;
+        }
+        let client = self.client();
+
+        let results = self
+            .runtime
+            .block_on(async move { client.get_security_overviews(&repos).await });
+
+        let mut reports = Vec::new();
+        let mut errors = Vec::new();
+        for (repo, result) in results {
+            match result {
+                Ok(overview) => reports.push(overview),
+                Err(e) => errors.push(serde_json::json!({"repo": repo, "error": e.to_string()})),
+            }
+        }
+
+        Ok(serde_json::json!({
+            "reports": reports,
+            "count": reports.len(),
+            "errors": errors,
+        }))
+    }
+
+    fn dependabot_config(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let report = self
+            .runtime
+            .block_on(async move { client.get_dependabot_config(&owner, &repo).await })?;
+
+        Ok(serde_json::json!(report))
+    }
+
+    fn list_discussions(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let category = Self::get_str(&params, "category").map(|s| s.to_string());
+        let answered = Self::get_bool(&params, "answered");
+        let limit = Self::get_i32(&params, "limit", 20);
+        let after = Self::get_str(&params, "after").map(|s| s.to_string());
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let (discussions, next_cursor) = self.runtime.block_on(async move {
+            client
+                .list_discussions(
+                    &owner,
+                    &repo,
+                    category.as_deref(),
+                    answered,
+                    limit,
+                    after.as_deref(),
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "discussions": discussions,
+            "count": discussions.len(),
+            "next_cursor": next_cursor,
+        }))
+    }
+
+    fn get_discussion(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+        let comments = Self::get_i32(&params, "comments", 50);
+        let replies = Self::get_i32(&params, "replies", 20);
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let discussion = self.runtime.block_on(async move {
+            client
+                .get_discussion(&owner, &repo, number, comments, replies)
+                .await
+        })?;
+
+        Ok(serde_json::json!(discussion))
+    }
+
+    fn create_discussion(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let category = Self::get_str(&params, "category")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: category"))?
+            .to_string();
+        let title = Self::get_str(&params, "title")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: title"))?
+            .to_string();
+        let body = Self::get_str(&params, "body")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: body"))?
+            .to_string();
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let discussion = self.runtime.block_on(async move {
+            client
+                .create_discussion(&owner, &repo, &category, &title, &body)
+                .await
+        })?;
+
+        Ok(serde_json::json!(discussion))
+    }
+
+    fn discussion_comment(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+        let body = Self::get_str(&params, "body")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: body"))?
+            .to_string();
+        let reply_to = Self::get_str(&params, "reply_to").map(|s| s.to_string());
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let comment = self.runtime.block_on(async move {
+            client
+                .add_discussion_comment(&owner, &repo, number, &body, reply_to.as_deref())
+                .await
+        })?;
+
+        Ok(serde_json::json!(comment))
+    }
+
+    fn discussion_categories(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let categories = self
+            .runtime
+            .block_on(async move { client.list_discussion_categories(&owner, &repo).await })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "categories": categories,
+            "count": categories.len(),
+        }))
+    }
+
+    fn mark_answer(&self, params: HashMap<String, Value>) -> Result<Value> {
+        self.set_answer(params, true)
+    }
+
+    fn unmark_answer(&self, params: HashMap<String, Value>) -> Result<Value> {
+        self.set_answer(params, false)
+    }
+
+    /// Shared handler for `github.mark_answer` and `github.unmark_answer`.
+    fn set_answer(&self, params: HashMap<String, Value>, answer: bool) -> Result<Value> {
+        let comment_id = Self::get_str(&params, "comment_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: comment_id"))?
+            .to_string();
+        let client = self.client();
+        let id_for_request = comment_id.clone();
+
+        let discussion = self
+            .runtime
+            .block_on(async move { client.set_discussion_answer(&id_for_request, answer).await })?;
+
+        Ok(serde_json::json!({
+            "comment_id": comment_id,
+            "is_answer": answer,
+            "discussion": discussion,
+        }))
+    }
+
+    fn list_projects(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let owner = Self::get_str(&params, "owner").map(|s| s.to_string());
+        let include_closed = Self::get_bool(&params, "include_closed").unwrap_or(false);
+        let limit = Self::get_i32(&params, "limit", 20);
+        let after = Self::get_str(&params, "after").map(|s| s.to_string());
+        let client = self.client();
+
+        let (projects, next_cursor) = self.runtime.block_on(async move {
+            client
+                .list_projects(owner.as_deref(), include_closed, limit, after.as_deref())
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "projects": projects,
+            "count": projects.len(),
+            "next_cursor": next_cursor,
+        }))
+    }
+
+    fn project_items(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let owner = Self::get_str(&params, "owner").map(|s| s.to_string());
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+        let limit = Self::get_i32(&params, "limit", 50);
+        let after = Self::get_str(&params, "after").map(|s| s.to_string());
+        let client = self.client();
+
+        let (title, items, next_cursor) = self.runtime.block_on(async move {
+            client
+                .list_project_items(owner.as_deref(), number, limit, after.as_deref())
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "project": title,
+            "number": number,
+            "items": items,
+            "count": items.len(),
+            "next_cursor": next_cursor,
+        }))
+    }
+
+    fn project_add(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let owner = Self::get_str(&params, "owner").map(|s| s.to_string());
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (repo_owner, repo) = Self::parse_repo(repo_str)?;
+        let issue = Self::get_i32(&params, "issue", 0);
+        if issue == 0 {
+            anyhow::bail!("Missing required parameter: issue");
+        }
+
+        let client = self.client();
+        let repo_owner = repo_owner.to_string();
+        let repo = repo.to_string();
+
+        let item_id = self.runtime.block_on(async move {
+            client
+                .add_project_item(owner.as_deref(), number, &repo_owner, &repo, issue)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "item_id": item_id,
+            "project": number,
+            "repo": repo_str,
+            "issue": issue,
+        }))
+    }
+
+    fn project_update_item(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let owner = Self::get_str(&params, "owner").map(|s| s.to_string());
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+        let item_id = Self::get_str(&params, "item_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: item_id"))?
+            .to_string();
+        let updates: Vec<(String, Value)> = match params.get("fields") {
+            Some(Value::Object(fields)) if !fields.is_empty() => fields
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+            _ => anyhow::bail!("Missing required parameter: fields"),
+        };
+
+        let client = self.client();
+        let item_for_request = item_id.clone();
+
+        let updated = self.runtime.block_on(async move {
+            client
+                .update_project_item(owner.as_deref(), number, &item_for_request, &updates)
+                .await
+        })?;
+
+        let updated: Vec<Value> = updated
+            .into_iter()
+            .map(|(field, value)| json!({"field": field, "value": value}))
+            .collect();
+
+        Ok(serde_json::json!({
+            "item_id": item_id,
+            "updated": updated,
+            "count": updated.len(),
+        }))
+    }
+
+    fn project_board(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let owner = Self::get_str(&params, "owner").map(|s| s.to_string());
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+        let field = Self::get_str(&params, "field")
+            .unwrap_or(STATUS_FIELD)
+            .to_string();
+        let per_column = Self::get_i32(&params, "per_column", 25).clamp(0, 500) as usize;
+        let client = self.client();
+
+        let board = self.runtime.block_on(async move {
+            client
+                .get_project_board(owner.as_deref(), number, &field, per_column)
+                .await
+        })?;
+
+        Ok(serde_json::json!(board))
+    }
+
+    fn project_report(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let owner = Self::get_str(&params, "owner").map(|s| s.to_string());
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+        let weeks = Self::get_i32(&params, "weeks", 8).clamp(1, 52) as u32;
+        let client = self.client();
+
+        let report = self.runtime.block_on(async move {
+            client
+                .get_project_report(owner.as_deref(), number, weeks)
+                .await
+        })?;
+
+        Ok(serde_json::json!(report))
+    }
+
+    fn list_commits(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let git_ref = Self::get_str(&params, "ref").map(|s| s.to_string());
+        let filter = CommitFilter {
+            path: Self::get_str(&params, "path").map(|s| s.to_string()),
+            author: Self::get_str(&params, "author").map(|s| s.trim_start_matches('@').to_string()),
+            since: Self::get_str(&params, "since").map(|s| s.to_string()),
+            until: Self::get_str(&params, "until").map(|s| s.to_string()),
+        };
+        let limit = Self::get_i32(&params, "limit", 30);
+        let after = Self::get_str(&params, "after").map(|s| s.to_string());
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let (commits, next_cursor) = self.runtime.block_on(async move {
+            client
+                .list_commits(
+                    &owner,
+                    &repo,
+                    git_ref.as_deref(),
+                    &filter,
+                    limit,
+                    after.as_deref(),
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "commits": commits,
+            "count": commits.len(),
+            "next_cursor": next_cursor,
+        }))
+    }
+
+    fn get_commit(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let sha = Self::get_str(&params, "sha")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: sha"))?
+            .to_string();
+        let patches = Self::get_bool(&params, "patches").unwrap_or(false);
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let commit = self
+            .runtime
+            .block_on(async move { client.get_commit(&owner, &repo, &sha, patches).await })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "file_count": commit.files.len(),
+            "commit": commit,
+        }))
+    }
+
+    fn compare(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let base = Self::get_str(&params, "base")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: base"))?
+            .to_string();
+        let head = Self::get_str(&params, "head")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: head"))?
+            .to_string();
+        let patches = Self::get_bool(&params, "patches").unwrap_or(false);
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let comparison = self.runtime.block_on(async move {
+            client
+                .compare_refs(&owner, &repo, &base, &head, patches)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "comparison": comparison,
+        }))
+    }
+
+    fn get_file(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let path = Self::get_str(&params, "path")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: path"))?
+            .to_string();
+        let git_ref = Self::get_str(&params, "ref").map(|s| s.to_string());
+        let max_bytes = Self::get_u64(&params, "max_bytes").unwrap_or(100_000) as usize;
+        let output = Self::get_str(&params, "output")
+            .map(|p| PathBuf::from(shellexpand::tilde(p).to_string()));
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        // Large or binary files can be written straight to disk
+        if let Some(dest) = output {
+            let (file, download) = self.runtime.block_on(async move {
+                client
+                    .download_file(&owner, &repo, &path, git_ref.as_deref(), &dest)
+                    .await
+            })?;
+
+            return Ok(serde_json::json!({
+                "repo": repo_str,
+                "file": file,
+                "written_to": download.path,
+                "bytes": download.bytes,
+                "sha256": download.sha256,
+            }));
+        }
+
+        let file = self.runtime.block_on(async move {
+            client
+                .get_file(&owner, &repo, &path, git_ref.as_deref(), max_bytes)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "file": file,
+        }))
+    }
+
+    fn put_file(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let path = Self::get_str(&params, "path")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: path"))?
+            .to_string();
+        let content = Self::get_str(&params, "content")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: content"))?
+            .to_string();
+        let message = Self::get_str(&params, "message")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: message"))?
+            .to_string();
+        let branch = Self::get_str(&params, "branch").map(|s| s.to_string());
+        let sha = Self::get_str(&params, "sha").map(|s| s.to_string());
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let result = self.runtime.block_on(async move {
+            client
+                .put_file(
+                    &owner,
+                    &repo,
+                    &path,
+                    content.as_bytes(),
+                    &message,
+                    branch.as_deref(),
+                    sha.as_deref(),
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "file": result,
+        }))
+    }
+
+    fn delete_file(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let path = Self::get_str(&params, "path")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: path"))?
+            .to_string();
+        let message = Self::get_str(&params, "message")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: message"))?
+            .to_string();
+        let sha = Self::get_str(&params, "sha")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: sha"))?
+            .to_string();
+        let branch = Self::get_str(&params, "branch").map(|s| s.to_string());
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let result = self.runtime.block_on(async move {
+            client
+                .delete_file(&owner, &repo, &path, &message, branch.as_deref(), &sha)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "deleted": true,
+            "repo": repo_str,
+            "file": result,
+        }))
+    }
+
+    fn get_tree(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let git_ref = Self::get_str(&params, "ref").map(|s| s.to_string());
+        let path = Self::get_str(&params, "path").map(|s| s.to_string());
+        let recursive = Self::get_bool(&params, "recursive").unwrap_or(false);
+        let limit = Self::get_i32(&params, "limit", 1000).max(1) as usize;
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let tree = self.runtime.block_on(async move {
+            client
+                .get_tree(
+                    &owner,
+                    &repo,
+                    git_ref.as_deref(),
+                    path.as_deref(),
+                    recursive,
+                    limit,
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "count": tree.entries.len(),
+            "tree": tree,
+        }))
+    }
+
+    fn get_blame(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let path = Self::get_str(&params, "path")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: path"))?
+            .to_string();
+        let git_ref = Self::get_str(&params, "ref").map(|s| s.to_string());
+        let start = Self::get_u64(&params, "start_line").map(|n| n as u32);
+        let end = Self::get_u64(&params, "end_line").map(|n| n as u32);
+        let lines = match (start, end) {
+            (None, None) => None,
+            (start, end) => Some((start.unwrap_or(1), end.unwrap_or(u32::MAX))),
+        };
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let ranges = self.runtime.block_on(async move {
+            client
+                .get_blame(&owner, &repo, &path, git_ref.as_deref(), lines)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "path": Self::get_str(&params, "path"),
+            "ranges": ranges,
+            "count": ranges.len(),
+        }))
+    }
+
+    fn list_refs(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let prefix = Self::get_str(&params, "prefix")
+            .unwrap_or("heads/")
+            .to_string();
+        let limit = Self::get_i32(&params, "limit", 100).max(1) as usize;
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let refs = self
+            .runtime
+            .block_on(async move { client.list_refs(&owner, &repo, &prefix, limit).await })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "refs": refs,
+            "count": refs.len(),
+        }))
+    }
+
+    fn resolve_ref(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let name = Self::get_str(&params, "ref")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: ref"))?
+            .to_string();
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let resolved = self
+            .runtime
+            .block_on(async move { client.resolve_ref(&owner, &repo, &name).await })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "ref": resolved,
+        }))
+    }
+
+    fn create_blob(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let content = Self::get_str(&params, "content")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: content"))?
+            .to_string();
+        let encoding = Self::get_str(&params, "encoding")
+            .unwrap_or("utf-8")
+            .to_string();
+        if encoding != "utf-8" && encoding != "base64" {
+            anyhow::bail!("encoding must be 'utf-8' or 'base64'");
+        }
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let sha = self.runtime.block_on(async move {
+            client.create_blob(&owner, &repo, &content, &encoding).await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "sha": sha,
+        }))
+    }
+
+    fn create_tree(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let entries: Vec<NewTreeEntry> = match params.get("entries") {
+            Some(v) => serde_json::from_value(v.clone())
+                .map_err(|e| anyhow::anyhow!("Invalid entries: {}", e))?,
+            None => anyhow::bail!("Missing required parameter: entries"),
+        };
+        if entries.is_empty() {
+            anyhow::bail!("entries must not be empty");
+        }
+        let base_tree = Self::get_str(&params, "base_tree").map(|s| s.to_string());
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let count = entries.len();
+
+        let sha = self.runtime.block_on(async move {
+            client
+                .create_tree(&owner, &repo, base_tree.as_deref(), &entries)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "sha": sha,
+            "count": count,
+        }))
+    }
+
+    fn create_commit(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let message = Self::get_str(&params, "message")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: message"))?
+            .to_string();
+        let tree = Self::get_str(&params, "tree")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: tree"))?
+            .to_string();
+        let parents = Self::get_str_list(&params, "parents");
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let commit = self.runtime.block_on(async move {
+            client
+                .create_git_commit(&owner, &repo, &message, &tree, &parents)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "commit": commit,
+        }))
+    }
+
+    fn update_ref(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let name = Self::get_str(&params, "ref")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: ref"))?
+            .to_string();
+        let sha = Self::get_str(&params, "sha")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: sha"))?
+            .to_string();
+        let force = Self::get_bool(&params, "force").unwrap_or(false);
+        let create = Self::get_bool(&params, "create").unwrap_or(false);
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let updated = self.runtime.block_on(async move {
+            client
+                .update_ref(&owner, &repo, &name, &sha, force, create)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "created": create,
+            "ref": updated,
+        }))
+    }
+
+    fn commit_files(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let branch = Self::get_str(&params, "branch")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: branch"))?
+            .to_string();
+        let message = Self::get_str(&params, "message")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: message"))?
+            .to_string();
+        let files: BTreeMap<String, Option<String>> = match params.get("files") {
+            Some(v) => serde_json::from_value(v.clone())
+                .map_err(|e| anyhow::anyhow!("Invalid files: {}", e))?,
+            None => anyhow::bail!("Missing required parameter: files"),
+        };
+        if files.is_empty() {
+            anyhow::bail!("files must not be empty");
+        }
+        let expected_head = Self::get_str(&params, "expected_head").map(|s| s.to_string());
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let changed = files.values().filter(|c| c.is_some()).count();
+        let deleted = files.len() - changed;
+
+        let commit = self.runtime.block_on(async move {
+            client
+                .commit_files(
+                    &owner,
+                    &repo,
+                    &branch,
+                    &message,
+                    &files,
+                    expected_head.as_deref(),
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "commit": commit,
+            "changed": changed,
+            "deleted": deleted,
+        }))
+    }
+
+    fn branch_status(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let branch = Self::get_str(&params, "branch").map(|s| s.to_string());
+        let base = Self::get_str(&params, "base").map(|s| s.to_string());
+        let limit = Self::get_i32(&params, "limit", 50);
+        let after = Self::get_str(&params, "after").map(|s| s.to_string());
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        // A single branch, or every branch when none is named
+        let (branches, next_cursor) = self.runtime.block_on(async move {
+            match branch {
+                Some(branch) => client
+                    .get_branch_status(&owner, &repo, &branch, base.as_deref())
+                    .await
+                    .map(|s| (vec![s], None)),
+                None => {
+                    client
+                        .list_branch_statuses(
+                            &owner,
+                            &repo,
+                            base.as_deref(),
+                            limit,
+                            after.as_deref(),
+                        )
+                        .await
+                }
+            }
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "branches": branches,
+            "count": branches.len(),
+            "next_cursor": next_cursor,
+        }))
+    }
+
+    fn verify_commits(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let base = Self::get_str(&params, "base")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: base"))?
+            .to_string();
+        let head = Self::get_str(&params, "head")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: head"))?
+            .to_string();
+        let limit = Self::get_i32(&params, "limit", 1000).max(1) as usize;
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let (base_ref, head_ref) = (base.clone(), head.clone());
+
+        let (commits, total) = self.runtime.block_on(async move {
+            client
+                .compare_commits(&owner, &repo, &base_ref, &head_ref, limit)
+                .await
+        })?;
+
+        let unverified: Vec<Value> = commits
+            .iter()
+            .filter(|c| !c.verification.as_ref().is_some_and(|v| v.verified))
+            .map(|c| {
+                serde_json::json!({
+                    "sha": c.sha,
+                    "message": c.message,
+                    "author": c.author,
+                    "reason": c.verification.as_ref().map(|v| v.reason.as_str()),
+                })
+            })
+            .collect();
+        let checked = commits.len();
+        // A range cut short by `limit` cannot vouch for the unchecked rest
+        let complete = checked as u32 == total;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "base": base,
+            "head": head,
+            "total_commits": total,
+            "checked": checked,
+            "verified": checked - unverified.len(),
+            "all_verified": unverified.is_empty() && complete,
+            "unverified": unverified,
+            "complete": complete,
+        }))
+    }
+
+    fn archive(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let path = Self::get_str(&params, "path")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: path"))?;
+        let git_ref = Self::get_str(&params, "ref").map(|s| s.to_string());
+        let format = Self::get_str(&params, "format").unwrap_or("tarball");
+        let extension = match format {
+            "tarball" => "tar.gz",
+            "zipball" => "zip",
+            _ => anyhow::bail!("format must be 'tarball' or 'zipball'"),
+        };
+
+        // A directory destination receives `<repo>-<ref>.<ext>`
+        let mut dest = PathBuf::from(shellexpand::tilde(path).to_string());
+        if dest.is_dir() {
+            let label = git_ref.as_deref().unwrap_or("HEAD").replace('/', "-");
+            dest.push(format!("{}-{}.{}", repo, label, extension));
+        }
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let format = format.to_string();
+
+        let download = self.runtime.block_on(async move {
+            client
+                .download_archive(&owner, &repo, git_ref.as_deref(), &format, &dest)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "downloaded": true,
+            "repo": repo_str,
+            "path": download.path,
+            "bytes": download.bytes,
+            "sha256": download.sha256,
+            "elapsed_ms": download.elapsed_ms,
+            "bytes_per_sec": download.bytes * 1000 / download.elapsed_ms.max(1),
+        }))
+    }
+
+    fn file_history(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let path = Self::get_str(&params, "path")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: path"))?
+            .to_string();
+        let git_ref = Self::get_str(&params, "ref").map(|s| s.to_string());
+        let limit = Self::get_i32(&params, "limit", 50).clamp(1, 500) as usize;
+        let follow = Self::get_bool(&params, "follow").unwrap_or(true);
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let requested = path.clone();
+
+        let (commits, renames) = self.runtime.block_on(async move {
+            client
+                .get_file_history(&owner, &repo, &path, git_ref.as_deref(), limit, follow)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "path": requested,
+            "commits": commits,
+            "count": commits.len(),
+            "renames": renames,
+        }))
+    }
+
+    fn owners_for_path(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let paths = Self::get_str_list(&params, "paths");
+        if paths.is_empty() {
+            anyhow::bail!("Missing required parameter: paths");
+        }
+        let git_ref = Self::get_str(&params, "ref").map(|s| s.to_string());
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let codeowners = self.runtime.block_on(async move {
+            client
+                .get_codeowners(&owner, &repo, git_ref.as_deref())
+                .await
+        })?;
+
+        let Some((file, codeowners)) = codeowners else {
+            return Ok(serde_json::json!({
+                "repo": repo_str,
+                "codeowners_path": null,
+                "owners": [],
+                "unowned": paths.len(),
+            }));
+        };
+
+        let owners: Vec<PathOwners> = paths
+            .into_iter()
+            .map(|path| {
+                let rule = codeowners.rule_for(&path);
+                PathOwners {
+                    owners: rule.map(|r| r.owners.clone()).unwrap_or_default(),
+                    pattern: rule.map(|r| r.pattern.clone()),
+                    line: rule.map(|r| r.line),
+                    path,
+                }
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "codeowners_path": file,
+            "unowned": owners.iter().filter(|o| o.owners.is_empty()).count(),
+            "owners": owners,
+        }))
+    }
+
+    fn commit_activity(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let weeks = Self::get_i32(&params, "weeks", 52).clamp(1, 52) as usize;
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let activity = self
+            .runtime
+            .block_on(async move { client.get_commit_activity(&owner, &repo, weeks).await })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "activity": activity,
+        }))
+    }
+
+    fn contributor_stats(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let weeks = Self::get_i32(&params, "weeks", 12).clamp(1, 520) as usize;
+        let limit = Self::get_i32(&params, "limit", 25).max(1) as usize;
+
+        let client = self.client();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let stats = self
+            .runtime
+            .block_on(async move { client.get_contributor_stats(&owner, &repo, weeks).await })?;
+
+        let Some(mut contributors) = stats else {
+            return Ok(serde_json::json!({
+                "repo": repo_str,
+                "pending": true,
+                "contributors": [],
+                "count": 0,
+            }));
+        };
+        let active = contributors.len();
+        contributors.truncate(limit);
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "pending": false,
+            "weeks": weeks,
+            "contributors": contributors,
+            "count": contributors.len(),
+            "active_contributors": active,
+        }))
+    }
+
+    /// Installations of the authenticated GitHub App.
+    fn installations(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let limit = Self::get_i32(&params, "limit", 30);
+        let client = self.client();
+
+        let installations = self
+            .runtime
+            .block_on(async move { client.list_installations(limit).await })?;
+
+        Ok(serde_json::json!({
+            "installations": installations,
+            "count": installations.len(),
+            "token_expires_at": self.client().installation_token_expiry(),
+        }))
+    }
+
+    /// Configured accounts with their rate-limit standing.
+    fn accounts(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let verify = Self::get_bool(&params, "verify").unwrap_or(false);
+
+        let mut accounts = Vec::new();
+        let pool = self.pool();
+        for (name, account) in pool.iter() {
+            let mut login = account.login.clone();
+            let mut error = None;
+            if verify {
+                let client = account.client.clone();
+                match self
+                    .runtime
+                    .block_on(async move { client.get_user().await })
+                {
+                    Ok(user) => login = Some(user.login),
+                    Err(e) => error = Some(e.to_string()),
+                }
+            }
+
+            accounts.push(serde_json::json!({
+                "name": name,
+                "default": name == crate::credentials::DEFAULT_ACCOUNT,
+                "login": login,
+                "source": account.client.token_source(),
+                "app": account.client.is_app(),
+                "rate_limits": account.client.rate_limit_status(),
+                "error": error,
+            }));
+        }
+
+        Ok(serde_json::json!({
+            "accounts": accounts,
+            "count": accounts.len(),
+        }))
+    }
+
+    /// Re-read token sources and rebuild every account's client.
+    fn reload_auth(&self) -> Result<Value> {
+        let pool = self.reload_accounts()?;
+
+        let mut accounts = Vec::new();
+        for (name, account) in pool.iter() {
+            let client = account.client.clone();
+            let (login, error) = if client.is_app() {
+                (None, None)
+            } else {
+                match self
+                    .runtime
+                    .block_on(async move { client.get_user().await })
+                {
+                    Ok(user) => (Some(user.login), None),
+                    Err(e) => (None, Some(e.to_string())),
+                }
+            };
+
+            accounts.push(serde_json::json!({
+                "name": name,
+                "app": account.client.is_app(),
+                "login": login,
+                "error": error,
+            }));
+        }
+
+        Ok(serde_json::json!({
+            "reloaded": true,
+            "accounts": accounts,
+            "count": accounts.len(),
+        }))
+    }
+
+    fn sso_status(&self) -> Result<Value> {
+        let client = self.client();
+        let status = self
+            .runtime
+            .block_on(async move { client.sso_status().await })?;
+
+        Ok(serde_json::json!({
+            "authorized": status.authorized,
+            "unauthorized": status.unauthorized,
+            "all_authorized": status.unauthorized.is_empty(),
+        }))
+    }
+
+    fn rate_limit(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let live = Self::get_bool(&params, "live").unwrap_or(true);
+        let client = self.client();
+
+        // Snapshot before the live call, which refreshes the tracked values
+        let tracked = client.rate_limit_status();
+        let resources = if live {
+            Some(
+                self.runtime
+                    .block_on(async move { client.get_rate_limit().await })?,
+            )
+        } else {
+            None
+        };
+
+        Ok(serde_json::json!({
+            "resources": resources,
+            "tracked": tracked,
+        }))
+    }
+}
+
+impl FgpService for GitHubService {
+    fn name(&self) -> &str {
+        "github"
+    }
+
+    fn version(&self) -> &str {
+        env!("CARGO_PKG_VERSION")
+    }
+
+    fn dispatch(&self, method: &str, params: HashMap<String, Value>) -> Result<Value> {
+        let account = self.select_account(&params);
+        let retry = params.clone();
+        match self.dispatch_as_account(&account, method, params) {
+            // A rotated or revoked token: re-read the account's credentials
+            // and, if they changed, try once more
+            Err(e) if is_unauthorized(&e) && !method.ends_with("reload_auth") => {
+                match self.reload_account(&account) {
+                    Ok(true) => {
+                        tracing::warn!(
+                            "GitHub rejected the '{}' token; retrying with the reloaded one",
+                            account
+                        );
+                        self.dispatch_as_account(&account, method, retry)
+                    }
+                    Ok(false) => Err(e),
+                    Err(reload) => {
+                        tracing::warn!(
+                            "Failed to reload the '{}' credentials: {:#}",
+                            account,
+                            reload
+                        );
+                        Err(e)
+                    }
+                }
+            }
+            result => result,
+        }
+    }
+
+    fn method_list(&self) -> Vec<MethodInfo> {
+        vec![
+            // github.user - Get current authenticated user
+            self.method_info("github.user", "Get current authenticated user info")
+                .schema(SchemaBuilder::object().build())
+                .returns(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string().description("GitHub username"))
+                        .property("name", SchemaBuilder::string().description("Display name"))
+                        .property("email", SchemaBuilder::string().format("email"))
+                        .property("avatar_url", SchemaBuilder::string().format("uri"))
+                        .build(),
+                )
+                .example("Get current user", json!({})),
+
+            // github.repos - List repositories
+            self.method_info("github.repos", "List your repositories")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(10))
+                                .description("Maximum number of repos to return"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "repos",
+                            SchemaBuilder::array()
+                                .items(
+                                    SchemaBuilder::object()
+                                        .property("name", SchemaBuilder::string())
+                                        .property("full_name", SchemaBuilder::string())
+                                        .property("url", SchemaBuilder::string().format("uri"))
+                                        .property("description", SchemaBuilder::string())
+                                        .property("stargazers_count", SchemaBuilder::integer()),
+                                )
+                                .description("List of repositories"),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("List top 5 repos", json!({"limit": 5})),
+
+            // github.issues - List issues
+            self.method_info("github.issues", "List issues for a repository")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "state",
+                            SchemaBuilder::string()
+                                .enum_values(&["open", "closed", "all"])
+                                .default_value(json!("open"))
+                                .description("Issue state filter"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(10))
+                                .description("Maximum issues to return"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("state", SchemaBuilder::string())
+                        .property(
+                            "issues",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("number", SchemaBuilder::integer())
+                                    .property("title", SchemaBuilder::string())
+                                    .property("state", SchemaBuilder::string())
+                                    .property("created_at", SchemaBuilder::string().format("date-time"))
+                                    .property("url", SchemaBuilder::string().format("uri")),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example(
+                    "List open issues",
+                    json!({"repo": "fast-gateway-protocol/daemon"}),
+                )
+                .example(
+                    "List closed issues",
+                    json!({"repo": "fast-gateway-protocol/daemon", "state": "closed", "limit": 5}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.prs - List pull requests
+            self.method_info("github.prs", "List pull requests for a repository")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "state",
+                            SchemaBuilder::string()
+                                .enum_values(&["open", "closed", "all"])
+                                .default_value(json!("open"))
+                                .description("PR state filter"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(10))
+                                .description("Maximum PRs to return"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("state", SchemaBuilder::string())
+                        .property(
+                            "prs",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("number", SchemaBuilder::integer())
+                                    .property("title", SchemaBuilder::string())
+                                    .property("state", SchemaBuilder::string())
+                                    .property("head_ref", SchemaBuilder::string())
+                                    .property("base_ref", SchemaBuilder::string())
+                                    .property("url", SchemaBuilder::string().format("uri")),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example(
+                    "List open PRs",
+                    json!({"repo": "fast-gateway-protocol/daemon"}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.pr - Get PR details
+            self.method_info("github.pr", "Get pull request details with reviews and status checks")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "number",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .description("Pull request number"),
+                        )
+                        .required(&["repo", "number"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("number", SchemaBuilder::integer())
+                        .property("title", SchemaBuilder::string())
+                        .property("body", SchemaBuilder::string())
+                        .property("state", SchemaBuilder::string())
+                        .property("mergeable", SchemaBuilder::boolean())
+                        .property("head_ref", SchemaBuilder::string())
+                        .property("base_ref", SchemaBuilder::string())
+                        .property(
+                            "reviews",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("author", SchemaBuilder::string())
+                                    .property("state", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property(
+                            "status_checks",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("context", SchemaBuilder::string())
+                                    .property("state", SchemaBuilder::string()),
+                            ),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Get PR #42",
+                    json!({"repo": "fast-gateway-protocol/daemon", "number": 42}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.notifications - Get notifications
+            self.method_info("github.notifications", "Get GitHub notifications (unread by default)")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "all",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Include notifications already marked as read"),
+                        )
+                        .property(
+                            "participating",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Only threads you participate in or are mentioned in"),
+                        )
+                        .property(
+                            "since",
+                            SchemaBuilder::string()
+                                .format("date-time")
+                                .description("Only notifications updated after this time"),
+                        )
+                        .property(
+                            "before",
+                            SchemaBuilder::string()
+                                .format("date-time")
+                                .description("Only notifications updated before this time"),
+                        )
+                        .property(
+                            "reason",
+                            SchemaBuilder::string()
+                                .description("Reason filter, e.g. review_requested, mention (string or array)"),
+                        )
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .description("Repository filter: 'owner/repo' or 'owner/*'"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(50))
+                                .description("Maximum notifications to return; pages are fetched until this many match the reason/repo filters"),
+                        )
+                        .property(
+                            "page",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(1))
+                                .description("Page of results in units of limit, counted after the reason/repo filters (filtered pages are found by scanning from the newest notification)"),
+                        )
+                        .property(
+                            "hydrate",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Resolve subjects into html_url, number, and state"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "notifications",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::string())
+                                    .property("reason", SchemaBuilder::string())
+                                    .property("unread", SchemaBuilder::boolean())
+                                    .property("subject_title", SchemaBuilder::string())
+                                    .property("subject_type", SchemaBuilder::string())
+                                    .property("repo_full_name", SchemaBuilder::string())
+                                    .property(
+                                        "subject_detail",
+                                        SchemaBuilder::object()
+                                            .property("html_url", SchemaBuilder::string())
+                                            .property("number", SchemaBuilder::integer())
+                                            .property("state", SchemaBuilder::string()),
+                                    ),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("unread_count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Get notifications", json!({}))
+                .example(
+                    "Review requests in an org since yesterday",
+                    json!({"reason": "review_requested", "repo": "fast-gateway-protocol/*", "since": "2026-01-14T00:00:00Z"}),
+                ),
+
+            // github.create_issue - Create issue
+            self.method_info("github.create_issue", "Create a new issue in a repository")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "title",
+                            SchemaBuilder::string()
+                                .min_length(1)
+                                .max_length(256)
+                                .description("Issue title"),
+                        )
+                        .property(
+                            "body",
+                            SchemaBuilder::string()
+                                .description("Issue body (Markdown supported)"),
+                        )
+                        .required(&["repo", "title"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("created", SchemaBuilder::boolean())
+                        .property(
+                            "issue",
+                            SchemaBuilder::object()
+                                .property("number", SchemaBuilder::integer())
+                                .property("title", SchemaBuilder::string())
+                                .property("url", SchemaBuilder::string().format("uri")),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Create a bug report",
+                    json!({
+                        "repo": "fast-gateway-protocol/daemon",
+                        "title": "Bug: Socket timeout after 30s",
+                        "body": "## Description\nThe socket times out unexpectedly..."
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.run_annotations - Check-run annotations for a workflow run
+            self.method_info("github.run_annotations", "Collect check-run annotations across a workflow run's jobs")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "run_id",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .description("Workflow run ID"),
+                        )
+                        .property(
+                            "level",
+                            SchemaBuilder::string()
+                                .enum_values(&["failure", "warning", "notice"])
+                                .description("Only return annotations of this level"),
+                        )
+                        .required(&["repo", "run_id"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("run_id", SchemaBuilder::integer())
+                        .property(
+                            "annotations",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("job_name", SchemaBuilder::string())
+                                    .property("path", SchemaBuilder::string())
+                                    .property("start_line", SchemaBuilder::integer())
+                                    .property("end_line", SchemaBuilder::integer())
+                                    .property("level", SchemaBuilder::string())
+                                    .property("title", SchemaBuilder::string())
+                                    .property("message", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("failure_count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example(
+                    "Get failures for a run",
+                    json!({"repo": "fast-gateway-protocol/daemon", "run_id": 7654321098u64, "level": "failure"}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.ci_summary - Latest run per workflow
+            self.method_info("github.ci_summary", "Latest run per workflow on the default branch")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "branch",
+                            SchemaBuilder::string()
+                                .description("Branch to inspect (default: repository default branch)"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("branch", SchemaBuilder::string())
+                        .property(
+                            "workflows",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("workflow_id", SchemaBuilder::integer())
+                                    .property("name", SchemaBuilder::string())
+                                    .property("path", SchemaBuilder::string())
+                                    .property("state", SchemaBuilder::string())
+                                    .property(
+                                        "latest_run",
+                                        SchemaBuilder::object()
+                                            .property("id", SchemaBuilder::integer())
+                                            .property("status", SchemaBuilder::string())
+                                            .property("conclusion", SchemaBuilder::string())
+                                            .property("duration_secs", SchemaBuilder::integer())
+                                            .property("url", SchemaBuilder::string().format("uri")),
+                                    ),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("failing", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("CI status board", json!({"repo": "fast-gateway-protocol/daemon"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.actions_settings - Get/set repository Actions permissions
+            self.method_info("github.actions_settings", "Get or update repository Actions permissions")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "enabled",
+                            SchemaBuilder::boolean().description("Enable or disable Actions"),
+                        )
+                        .property(
+                            "allowed_actions",
+                            SchemaBuilder::string()
+                                .enum_values(&["all", "local_only", "selected"])
+                                .description("Which actions may run"),
+                        )
+                        .property(
+                            "selected_actions",
+                            SchemaBuilder::object()
+                                .property("github_owned_allowed", SchemaBuilder::boolean())
+                                .property("verified_allowed", SchemaBuilder::boolean())
+                                .property(
+                                    "patterns_allowed",
+                                    SchemaBuilder::array().items(SchemaBuilder::string()),
+                                )
+                                .required(&["github_owned_allowed", "verified_allowed", "patterns_allowed"])
+                                .description("Allow-list used when allowed_actions is 'selected'"),
+                        )
+                        .property(
+                            "default_workflow_permissions",
+                            SchemaBuilder::string()
+                                .enum_values(&["read", "write"])
+                                .description("Default GITHUB_TOKEN permissions"),
+                        )
+                        .property(
+                            "can_approve_pull_request_reviews",
+                            SchemaBuilder::boolean()
+                                .description("Whether workflows may approve pull requests"),
+                        )
+                        .property(
+                            "fork_pr_approval_policy",
+                            SchemaBuilder::string()
+                                .enum_values(&[
+                                    "first_time_contributors_new_to_github",
+                                    "first_time_contributors",
+                                    "all_external_contributors",
+                                ])
+                                .description("Which fork PR authors need approval to run workflows"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("updated", SchemaBuilder::boolean())
+                        .property(
+                            "settings",
+                            SchemaBuilder::object()
+                                .property("enabled", SchemaBuilder::boolean())
+                                .property("allowed_actions", SchemaBuilder::string())
+                                .property("selected_actions", SchemaBuilder::object())
+                                .property("default_workflow_permissions", SchemaBuilder::string())
+                                .property("can_approve_pull_request_reviews", SchemaBuilder::boolean())
+                                .property("fork_pr_approval_policy", SchemaBuilder::string()),
+                        )
+                        .build(),
+                )
+                .example("Read settings", json!({"repo": "fast-gateway-protocol/daemon"}))
+                .example(
+                    "Harden a repo",
+                    json!({
+                        "repo": "fast-gateway-protocol/daemon",
+                        "allowed_actions": "local_only",
+                        "default_workflow_permissions": "read",
+                        "can_approve_pull_request_reviews": false,
+                        "fork_pr_approval_policy": "all_external_contributors"
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.releases - List releases
+            self.method_info("github.releases", "List releases for a repository")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(10))
+                                .description("Releases per page"),
+                        )
+                        .property(
+                            "page",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(1))
+                                .description("Page number"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("page", SchemaBuilder::integer())
+                        .property(
+                            "releases",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("tag_name", SchemaBuilder::string())
+                                    .property("name", SchemaBuilder::string())
+                                    .property("is_draft", SchemaBuilder::boolean())
+                                    .property("is_prerelease", SchemaBuilder::boolean())
+                                    .property("author", SchemaBuilder::string())
+                                    .property("published_at", SchemaBuilder::string().format("date-time"))
+                                    .property("asset_count", SchemaBuilder::integer()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("List recent releases", json!({"repo": "fast-gateway-protocol/daemon", "limit": 5}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.release - Get a release by tag or the latest release
+            self.method_info("github.release", "Get a release by tag, or the latest release")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("tag", SchemaBuilder::string().description("Release tag name"))
+                        .property(
+                            "latest",
+                            SchemaBuilder::boolean()
+                                .description("Fetch the latest published release instead of a tag"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("tag_name", SchemaBuilder::string())
+                        .property("name", SchemaBuilder::string())
+                        .property("body", SchemaBuilder::string())
+                        .property("target_commitish", SchemaBuilder::string())
+                        .property("published_at", SchemaBuilder::string().format("date-time"))
+                        .property(
+                            "assets",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("size", SchemaBuilder::integer())
+                                    .property("download_count", SchemaBuilder::integer())
+                                    .property("download_url", SchemaBuilder::string().format("uri")),
+                            ),
+                        )
+                        .build(),
+                )
+                .example("Latest release", json!({"repo": "fast-gateway-protocol/daemon", "latest": true}))
+                .example("Release by tag", json!({"repo": "fast-gateway-protocol/daemon", "tag": "v0.2.0"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.create_release - Create a release
+            self.method_info("github.create_release", "Create a release, optionally with generated notes")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("tag", SchemaBuilder::string().description("Tag to create or use"))
+                        .property(
+                            "target",
+                            SchemaBuilder::string()
+                                .description("Branch or SHA the tag is created from (default: default branch)"),
+                        )
+                        .property("name", SchemaBuilder::string().description("Release title"))
+                        .property(
+                            "body",
+                            SchemaBuilder::string()
+                                .description("Release notes (Markdown); placed above generated notes"),
+                        )
+                        .property("draft", SchemaBuilder::boolean().default_value(json!(false)))
+                        .property("prerelease", SchemaBuilder::boolean().default_value(json!(false)))
+                        .property(
+                            "generate_notes",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Generate notes from merged PRs since the previous release"),
+                        )
+                        .required(&["repo", "tag"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("created", SchemaBuilder::boolean())
+                        .property(
+                            "release",
+                            SchemaBuilder::object()
+                                .property("id", SchemaBuilder::integer())
+                                .property("tag_name", SchemaBuilder::string())
+                                .property("url", SchemaBuilder::string().format("uri"))
+                                .property("body", SchemaBuilder::string()),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Draft a release with generated notes",
+                    json!({
+                        "repo": "fast-gateway-protocol/daemon",
+                        "tag": "v0.3.0",
+                        "draft": true,
+                        "generate_notes": true
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.upload_asset - Upload a release asset
+            self.method_info("github.upload_asset", "Upload a local file as a release asset")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "release_id",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .description("Release ID (required for draft releases)"),
+                        )
+                        .property(
+                            "tag",
+                            SchemaBuilder::string()
+                                .description("Tag of a published release (alternative to release_id)"),
+                        )
+                        .property("path", SchemaBuilder::string().description("Local file to upload"))
+                        .property(
+                            "name",
+                            SchemaBuilder::string().description("Asset name (default: file name)"),
+                        )
+                        .property("label", SchemaBuilder::string().description("Display label"))
+                        .property(
+                            "content_type",
+                            SchemaBuilder::string()
+                                .description("MIME type (default: detected from extension)"),
+                        )
+                        .required(&["repo", "path"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("uploaded", SchemaBuilder::boolean())
+                        .property(
+                            "asset",
+                            SchemaBuilder::object()
+                                .property("id", SchemaBuilder::integer())
+                                .property("name", SchemaBuilder::string())
+                                .property("size", SchemaBuilder::integer())
+                                .property("download_url", SchemaBuilder::string().format("uri")),
+                        )
+                        .property(
+                            "progress",
+                            SchemaBuilder::object()
+                                .property("bytes_uploaded", SchemaBuilder::integer())
+                                .property("elapsed_ms", SchemaBuilder::integer())
+                                .property("bytes_per_sec", SchemaBuilder::integer()),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Upload a binary",
+                    json!({
+                        "repo": "fast-gateway-protocol/daemon",
+                        "tag": "v0.3.0",
+                        "path": "./target/release/fgp-daemon-x86_64.tar.gz"
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.download_asset - Download a release asset
+            self.method_info("github.download_asset", "Download a release asset to a local path")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("tag", SchemaBuilder::string().description("Release tag name"))
+                        .property(
+                            "latest",
+                            SchemaBuilder::boolean().description("Use the latest published release"),
+                        )
+                        .property("name", SchemaBuilder::string().description("Asset file name"))
+                        .property(
+                            "path",
+                            SchemaBuilder::string()
+                                .description("Destination file, or directory to save the asset into"),
+                        )
+                        .property(
+                            "sha256",
+                            SchemaBuilder::string()
+                                .pattern("^[a-fA-F0-9]{64}$")
+                                .description("Expected SHA-256; the file is removed on mismatch"),
+                        )
+                        .required(&["repo", "name", "path"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("downloaded", SchemaBuilder::boolean())
+                        .property("tag", SchemaBuilder::string())
+                        .property("path", SchemaBuilder::string())
+                        .property("bytes", SchemaBuilder::integer())
+                        .property("sha256", SchemaBuilder::string())
+                        .property("verified", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example(
+                    "Download latest binary",
+                    json!({
+                        "repo": "fast-gateway-protocol/daemon",
+                        "latest": true,
+                        "name": "fgp-daemon-x86_64.tar.gz",
+                        "path": "~/Downloads"
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.update_release - Edit a release
+            self.method_info("github.update_release", "Edit a release or publish a draft")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("release_id", SchemaBuilder::integer().minimum(1))
+                        .property(
+                            "tag",
+                            SchemaBuilder::string()
+                                .description("Tag of the release to edit (alternative to release_id)"),
+                        )
+                        .property("new_tag", SchemaBuilder::string().description("Rename the tag"))
+                        .property("name", SchemaBuilder::string().description("New release title"))
+                        .property("body", SchemaBuilder::string().description("New release notes"))
+                        .property(
+                            "draft",
+                            SchemaBuilder::boolean().description("Set false to publish a draft"),
+                        )
+                        .property("prerelease", SchemaBuilder::boolean())
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("updated", SchemaBuilder::boolean())
+                        .property(
+                            "release",
+                            SchemaBuilder::object()
+                                .property("id", SchemaBuilder::integer())
+                                .property("tag_name", SchemaBuilder::string())
+                                .property("is_draft", SchemaBuilder::boolean())
+                                .property("url", SchemaBuilder::string().format("uri")),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Publish a draft",
+                    json!({"repo": "fast-gateway-protocol/daemon", "tag": "v0.3.0", "draft": false}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.delete_release - Delete a release
+            self.method_info("github.delete_release", "Delete a release, optionally with its tag")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("release_id", SchemaBuilder::integer().minimum(1))
+                        .property("tag", SchemaBuilder::string().description("Tag of the release"))
+                        .property(
+                            "delete_tag",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Also delete the git tag (requires tag)"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("deleted", SchemaBuilder::boolean())
+                        .property("release_id", SchemaBuilder::integer())
+                        .property("tag_deleted", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example(
+                    "Delete a draft",
+                    json!({"repo": "fast-gateway-protocol/daemon", "tag": "v0.3.0-rc.1", "delete_tag": true}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.tags - List tags
+            self.method_info("github.tags", "List tags with their commits, newest first")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(20))
+                                .description("Tags per page"),
+                        )
+                        .property(
+                            "after",
+                            SchemaBuilder::string().description("Cursor from a previous next_cursor"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "tags",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("sha", SchemaBuilder::string())
+                                    .property("is_annotated", SchemaBuilder::boolean())
+                                    .property("tagger", SchemaBuilder::string())
+                                    .property("date", SchemaBuilder::string().format("date-time")),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("next_cursor", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Find the previous version", json!({"repo": "fast-gateway-protocol/daemon", "limit": 2}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.create_tag - Create a tag
+            self.method_info("github.create_tag", "Create an annotated or lightweight tag")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("tag", SchemaBuilder::string().description("Tag name"))
+                        .property(
+                            "target",
+                            SchemaBuilder::string()
+                                .description("Branch, tag, or SHA to tag (default: default branch)"),
+                        )
+                        .property(
+                            "message",
+                            SchemaBuilder::string()
+                                .description("Annotation message (default: the tag name)"),
+                        )
+                        .property(
+                            "lightweight",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Create a plain ref without a tag object"),
+                        )
+                        .required(&["repo", "tag"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("created", SchemaBuilder::boolean())
+                        .property(
+                            "tag",
+                            SchemaBuilder::object()
+                                .property("name", SchemaBuilder::string())
+                                .property("sha", SchemaBuilder::string())
+                                .property("is_annotated", SchemaBuilder::boolean())
+                                .property("tag_sha", SchemaBuilder::string()),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Tag main",
+                    json!({"repo": "fast-gateway-protocol/daemon", "tag": "v0.3.0", "message": "Release 0.3.0"}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.release_notes - Generate release notes without creating a release
+            self.method_info("github.release_notes", "Generate Markdown release notes between two tags")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "tag",
+                            SchemaBuilder::string()
+                                .description("Tag the notes are for (need not exist if target is set)"),
+                        )
+                        .property(
+                            "previous_tag",
+                            SchemaBuilder::string()
+                                .description("Starting tag (default: the previous release)"),
+                        )
+                        .property(
+                            "target",
+                            SchemaBuilder::string()
+                                .description("Branch or SHA used when tag does not exist yet"),
+                        )
+                        .required(&["repo", "tag"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("tag", SchemaBuilder::string())
+                        .property("previous_tag", SchemaBuilder::string())
+                        .property("name", SchemaBuilder::string())
+                        .property("notes", SchemaBuilder::string().description("Markdown notes"))
+                        .build(),
+                )
+                .example(
+                    "Notes for the next version",
+                    json!({
+                        "repo": "fast-gateway-protocol/daemon",
+                        "tag": "v0.3.0",
+                        "previous_tag": "v0.2.0",
+                        "target": "main"
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.gists - List your gists
+            self.method_info("github.gists", "List your gists with file names and descriptions")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(10))
+                                .description("Gists per page"),
+                        )
+                        .property(
+                            "page",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(1))
+                                .description("Page number"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("page", SchemaBuilder::integer())
+                        .property(
+                            "gists",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::string())
+                                    .property("description", SchemaBuilder::string())
+                                    .property("is_public", SchemaBuilder::boolean())
+                                    .property("url", SchemaBuilder::string().format("uri"))
+                                    .property(
+                                        "files",
+                                        SchemaBuilder::array().items(
+                                            SchemaBuilder::object()
+                                                .property("filename", SchemaBuilder::string())
+                                                .property("language", SchemaBuilder::string())
+                                                .property("size", SchemaBuilder::integer()),
+                                        ),
+                                    ),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("List recent gists", json!({"limit": 5})),
+
+            // github.gist - Get a gist with contents
+            self.method_info("github.gist", "Get a gist including file contents")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("id", SchemaBuilder::string().description("Gist ID"))
+                        .required(&["id"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("id", SchemaBuilder::string())
+                        .property("description", SchemaBuilder::string())
+                        .property("url", SchemaBuilder::string().format("uri"))
+                        .property(
+                            "files",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("filename", SchemaBuilder::string())
+                                    .property("content", SchemaBuilder::string())
+                                    .property("truncated", SchemaBuilder::boolean())
+                                    .property("raw_url", SchemaBuilder::string().format("uri")),
+                            ),
+                        )
+                        .build(),
+                )
+                .example("Get a gist", json!({"id": "aa5a315d61ae9438b18d"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.create_gist - Create a gist
+            self.method_info("github.create_gist", "Create a gist from a map of files")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "files",
+                            SchemaBuilder::object()
+                                .description("Map of filename to content (string or {\"content\": ...})"),
+                        )
+                        .property("description", SchemaBuilder::string())
+                        .property(
+                            "public",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Create a public gist instead of a secret one"),
+                        )
+                        .required(&["files"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("created", SchemaBuilder::boolean())
+                        .property("id", SchemaBuilder::string())
+                        .property("url", SchemaBuilder::string().format("uri"))
+                        .property(
+                            "raw_urls",
+                            SchemaBuilder::object().description("Map of filename to raw URL"),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Share a snippet",
+                    json!({"files": {"snippet.rs": "fn main() {}"}, "description": "Minimal repro"}),
+                )
+                .errors(&["UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.update_gist - Update a gist
+            self.method_info("github.update_gist", "Update a gist's files or description")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("id", SchemaBuilder::string().description("Gist ID"))
+                        .property(
+                            "files",
+                            SchemaBuilder::object().description(
+                                "Map of filename to new content, {\"filename\": ...} to rename, or null to delete",
+                            ),
+                        )
+                        .property("description", SchemaBuilder::string())
+                        .required(&["id"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("updated", SchemaBuilder::boolean())
+                        .property("id", SchemaBuilder::string())
+                        .property("url", SchemaBuilder::string().format("uri"))
+                        .property("raw_urls", SchemaBuilder::object())
+                        .build(),
+                )
+                .example(
+                    "Replace a file",
+                    json!({"id": "aa5a315d61ae9438b18d", "files": {"snippet.rs": "fn main() { println!(\"hi\"); }"}}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.compare_releases - Changelog between two releases
+            self.method_info("github.compare_releases", "Changelog of merged PRs between two tags, grouped by label")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("base", SchemaBuilder::string().description("Older tag or ref"))
+                        .property("head", SchemaBuilder::string().description("Newer tag or ref"))
+                        .required(&["repo", "base", "head"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "changelog",
+                            SchemaBuilder::object()
+                                .property("ahead_by", SchemaBuilder::integer())
+                                .property("commit_count", SchemaBuilder::integer())
+                                .property("pull_request_count", SchemaBuilder::integer())
+                                .property(
+                                    "groups",
+                                    SchemaBuilder::object()
+                                        .description("Label -> merged PRs (number, title, url, author)"),
+                                )
+                                .property(
+                                    "direct_commits",
+                                    SchemaBuilder::array().items(
+                                        SchemaBuilder::object()
+                                            .property("sha", SchemaBuilder::string())
+                                            .property("message", SchemaBuilder::string()),
+                                    ),
+                                ),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Changes since the last release",
+                    json!({"repo": "fast-gateway-protocol/daemon", "base": "v0.2.0", "head": "v0.3.0"}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.thread_subscription - Get/set/delete a thread subscription
+            self.method_info("github.thread_subscription", "Get, subscribe, ignore, or unsubscribe a notification thread")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "thread_id",
+                            SchemaBuilder::string().description("Notification thread ID"),
+                        )
+                        .property(
+                            "action",
+                            SchemaBuilder::string()
+                                .enum_values(&["get", "subscribe", "ignore", "unsubscribe"])
+                                .default_value(json!("get"))
+                                .description("'ignore' mutes the thread; 'unsubscribe' removes the subscription"),
+                        )
+                        .required(&["thread_id"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("thread_id", SchemaBuilder::string())
+                        .property("subscribed", SchemaBuilder::boolean())
+                        .property("ignored", SchemaBuilder::boolean())
+                        .property("reason", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Mute a noisy thread", json!({"thread_id": "12345", "action": "ignore"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.notification_events - Subscribe to new notifications
+            self.method_info(
+                "github.notification_events",
+                "Long-poll new/updated notification events from the background poller",
+            )
+            .schema(
+                SchemaBuilder::object()
+                    .property(
+                        "cursor",
+                        SchemaBuilder::integer()
+                            .minimum(0)
+                            .default_value(json!(0))
+                            .description("Return events after this sequence number (the previous response's cursor)"),
+                    )
+                    .property(
+                        "wait_ms",
+                        SchemaBuilder::integer()
+                            .minimum(0)
+                            .maximum(30000)
+                            .default_value(json!(0))
+                            .description("Wait up to this long for an event when none are pending"),
+                    )
+                    .property(
+                        "limit",
+                        SchemaBuilder::integer()
+                            .minimum(1)
+                            .default_value(json!(100))
+                            .description("Maximum events to return"),
+                    )
+                    .build(),
+            )
+            .returns(
+                SchemaBuilder::object()
+                    .property(
+                        "events",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("seq", SchemaBuilder::integer())
+                                .property("kind", SchemaBuilder::string().enum_values(&["new", "updated"]))
+                                .property("notification", SchemaBuilder::object())
+                                .property("observed_at", SchemaBuilder::string().format("date-time")),
+                        ),
+                    )
+                    .property("count", SchemaBuilder::integer())
+                    .property("cursor", SchemaBuilder::integer())
+                    .property(
+                        "poller",
+                        SchemaBuilder::object()
+                            .property("running", SchemaBuilder::boolean())
+                            .property("seeded", SchemaBuilder::boolean())
+                            .property("unread_count", SchemaBuilder::integer())
+                            .property("poll_interval_secs", SchemaBuilder::integer())
+                            .property("last_poll_at", SchemaBuilder::string())
+                            .property("last_error", SchemaBuilder::string()),
+                    )
+                    .build(),
+            )
+            .example("Start subscription", json!({}))
+            .example("Wait for events after cursor 12", json!({"cursor": 12, "wait_ms": 25000}))
+            .errors(&["UNAUTHORIZED"]),
+
+            // github.set_repo_subscription - Set repository watch mode
+            self.method_info(
+                "github.set_repo_subscription",
+                "Set how you watch a repository: all activity, participating only, or ignore. GitHub's public API cannot set the custom modes (releases only, security alerts only); those are rejected with a link to the repository's Watch > Custom menu",
+            )
+            .schema(
+                SchemaBuilder::object()
+                    .property(
+                        "repo",
+                        SchemaBuilder::string()
+                            .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                            .description("Repository in owner/repo format"),
+                    )
+                    .property(
+                        "mode",
+                        SchemaBuilder::string()
+                            .enum_values(&["all", "participating", "ignore", "releases", "security_alerts"])
+                            .description("Watch mode; releases and security_alerts have no public API and are rejected"),
+                    )
+                    .required(&["repo", "mode"])
+                    .build(),
+            )
+            .returns(
+                SchemaBuilder::object()
+                    .property("repo", SchemaBuilder::string())
+                    .property("mode", SchemaBuilder::string())
+                    .property("subscribed", SchemaBuilder::boolean())
+                    .property("ignored", SchemaBuilder::boolean())
+                    .property("created_at", SchemaBuilder::string().format("date-time"))
+                    .build(),
+            )
+            .example("Watch all activity", json!({"repo": "owner/repo", "mode": "all"}))
+            .example("Mute a repository", json!({"repo": "owner/repo", "mode": "ignore"}))
+            .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.notification_digest - Grouped unread notification summary
+            self.method_info(
+                "github.notification_digest",
+                "Unread notifications grouped by repo and reason with counts and top items",
+            )
+            .schema(
+                SchemaBuilder::object()
+                    .property(
+                        "top",
+                        SchemaBuilder::integer()
+                            .minimum(0)
+                            .default_value(json!(3))
+                            .description("Most recent items to include per group"),
+                    )
+                    .property(
+                        "limit",
+                        SchemaBuilder::integer()
+                            .minimum(1)
+                            .default_value(json!(100))
+                            .description("Maximum unread notifications to summarize"),
+                    )
+                    .build(),
+            )
+            .returns(
+                SchemaBuilder::object()
+                    .property("total", SchemaBuilder::integer())
+                    .property("repo_count", SchemaBuilder::integer())
+                    .property(
+                        "groups",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("repo", SchemaBuilder::string())
+                                .property("reason", SchemaBuilder::string())
+                                .property("count", SchemaBuilder::integer())
+                                .property("items", SchemaBuilder::array().items(SchemaBuilder::object())),
+                        ),
+                    )
+                    .build(),
+            )
+            .example("Morning summary", json!({}))
+            .example("Counts only", json!({"top": 0}))
+            .errors(&["UNAUTHORIZED"]),
+
+            // github.search_code - Search code
+            self.method_info("github.search_code", "Search code across GitHub with text match fragments")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "query",
+                            SchemaBuilder::string()
+                                .description("Search terms (GitHub code search syntax)"),
+                        )
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Limit to a repository (owner/repo)"),
+                        )
+                        .property("org", SchemaBuilder::string().description("Limit to an organization"))
+                        .property("user", SchemaBuilder::string().description("Limit to a user's repositories"))
+                        .property("path", SchemaBuilder::string().description("Limit to files under this path"))
+                        .property("language", SchemaBuilder::string().description("Limit to a language"))
+                        .property("filename", SchemaBuilder::string().description("Limit to files with this name"))
+                        .property("extension", SchemaBuilder::string().description("Limit to a file extension"))
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(30))
+                                .description("Results per page"),
+                        )
+                        .property(
+                            "page",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(1))
+                                .description("Page number"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("query", SchemaBuilder::string())
+                        .property("total_count", SchemaBuilder::integer())
+                        .property("incomplete_results", SchemaBuilder::boolean())
+                        .property(
+                            "results",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("path", SchemaBuilder::string())
+                                    .property("repo", SchemaBuilder::string())
+                                    .property("sha", SchemaBuilder::string())
+                                    .property("html_url", SchemaBuilder::string())
+                                    .property("fragments", SchemaBuilder::array().items(SchemaBuilder::string())),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Find a function", json!({"query": "fn rest_get", "repo": "owner/repo"}))
+                .example("Rust files in an org", json!({"query": "tokio::spawn", "org": "my-org", "language": "rust"}))
+                .errors(&["UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.search - Search issues or pull requests
+            self.method_info("github.search", "Search issues or pull requests with full GitHub query syntax")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "type",
+                            SchemaBuilder::string()
+                                .enum_values(&["issues", "prs"])
+                                .default_value(json!("issues"))
+                                .description("What to search for"),
+                        )
+                        .property(
+                            "query",
+                            SchemaBuilder::string()
+                                .description("Raw GitHub search query, e.g. 'is:open label:bug sort:updated-desc'"),
+                        )
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Limit to a repository (owner/repo)"),
+                        )
+                        .property("org", SchemaBuilder::string().description("Limit to an organization"))
+                        .property("author", SchemaBuilder::string().description("Filter by author login"))
+                        .property("assignee", SchemaBuilder::string().description("Filter by assignee login"))
+                        .property("label", SchemaBuilder::string().description("Filter by label"))
+                        .property("state", SchemaBuilder::string().enum_values(&["open", "closed"]))
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(30))
+                                .description("Results per page"),
+                        )
+                        .property(
+                            "after",
+                            SchemaBuilder::string().description("Cursor from a previous response's next_cursor"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("query", SchemaBuilder::string())
+                        .property("total_count", SchemaBuilder::integer())
+                        .property("incomplete_results", SchemaBuilder::boolean())
+                        .property(
+                            "results",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("repo", SchemaBuilder::string())
+                                    .property("number", SchemaBuilder::integer())
+                                    .property("title", SchemaBuilder::string())
+                                    .property("state", SchemaBuilder::string())
+                                    .property("url", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("next_cursor", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Open bugs in an org", json!({"query": "is:open label:bug", "org": "my-org"}))
+                .example("PRs awaiting my review", json!({"type": "prs", "query": "is:open review-requested:@me"}))
+                .errors(&["UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.search_repos - Search repositories
+            self.method_info("github.search_repos", "Search repositories by qualifiers with sort options")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("query", SchemaBuilder::string().description("Search terms (GitHub search syntax)"))
+                        .property("language", SchemaBuilder::string().description("Primary language, e.g. rust"))
+                        .property("stars", SchemaBuilder::string().description("Star range, e.g. '>1000' or '100..500'"))
+                        .property("topic", SchemaBuilder::string().description("Repository topic"))
+                        .property("pushed", SchemaBuilder::string().description("Last push date range, e.g. '>2026-01-01'"))
+                        .property("user", SchemaBuilder::string().description("Limit to a user's repositories"))
+                        .property("org", SchemaBuilder::string().description("Limit to an organization"))
+                        .property("archived", SchemaBuilder::boolean().description("Filter by archived state"))
+                        .property(
+                            "sort",
+                            SchemaBuilder::string()
+                                .enum_values(&["stars", "forks", "help-wanted-issues", "updated"])
+                                .description("Sort field (best match when omitted)"),
+                        )
+                        .property(
+                            "order",
+                            SchemaBuilder::string()
+                                .enum_values(&["desc", "asc"])
+                                .default_value(json!("desc")),
+                        )
+                        .property(
+                            "rank",
+                            SchemaBuilder::string()
+                                .enum_values(&["recent_stars"])
+                                .description("Re-rank the top matches (up to 30) by stars gained in the last `days` days"),
+                        )
+                        .property(
+                            "days",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(365)
+                                .default_value(json!(7))
+                                .description("Window for rank=recent_stars"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(30))
+                                .description("Results per page"),
+                        )
+                        .property(
+                            "page",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(1))
+                                .description("Page number"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("query", SchemaBuilder::string())
+                        .property("total_count", SchemaBuilder::integer())
+                        .property("incomplete_results", SchemaBuilder::boolean())
+                        .property(
+                            "results",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("recent_stars", SchemaBuilder::integer())
+                                    .property("recent_stars_capped", SchemaBuilder::boolean())
+                                    .property("name", SchemaBuilder::string())
+                                    .property("full_name", SchemaBuilder::string())
+                                    .property("description", SchemaBuilder::string())
+                                    .property("url", SchemaBuilder::string())
+                                    .property("stars", SchemaBuilder::integer())
+                                    .property("forks", SchemaBuilder::integer())
+                                    .property("language", SchemaBuilder::string())
+                                    .property("pushed_at", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Popular Rust HTTP crates", json!({"query": "http client", "language": "rust", "stars": ">1000", "sort": "stars"}))
+                .example("Recently active in a topic", json!({"topic": "mcp", "pushed": ">2026-10-01", "sort": "updated"}))
+                .example("Trending in a topic this week", json!({"topic": "mcp", "rank": "recent_stars", "days": 7}))
+                .errors(&["UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.search_users - Search users and organizations
+            self.method_info("github.search_users", "Search users and organizations")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "query",
+                            SchemaBuilder::string()
+                                .description("Login, name, or email fragment (a leading @ is ignored)"),
+                        )
+                        .property(
+                            "type",
+                            SchemaBuilder::string()
+                                .enum_values(&["user", "org"])
+                                .description("Limit to users or organizations"),
+                        )
+                        .property("location", SchemaBuilder::string().description("Filter by profile location"))
+                        .property("language", SchemaBuilder::string().description("Filter by repository language"))
+                        .property("followers", SchemaBuilder::string().description("Follower range, e.g. '>100'"))
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(30))
+                                .description("Results per page"),
+                        )
+                        .property(
+                            "after",
+                            SchemaBuilder::string().description("Cursor from a previous response's next_cursor"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("query", SchemaBuilder::string())
+                        .property("total_count", SchemaBuilder::integer())
+                        .property(
+                            "results",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("login", SchemaBuilder::string())
+                                    .property("type", SchemaBuilder::string().enum_values(&["user", "org"]))
+                                    .property("name", SchemaBuilder::string())
+                                    .property("followers", SchemaBuilder::integer())
+                                    .property("url", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("next_cursor", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Resolve a handle", json!({"query": "@octocat"}))
+                .example("Organizations by name", json!({"query": "rust", "type": "org"}))
+                .errors(&["UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.search_commits - Search commits
+            self.method_info("github.search_commits", "Search commits by message, author, and repo")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("query", SchemaBuilder::string().description("Terms to match in commit messages"))
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Limit to a repository (owner/repo)"),
+                        )
+                        .property("org", SchemaBuilder::string().description("Limit to an organization"))
+                        .property("user", SchemaBuilder::string().description("Limit to a user's repositories"))
+                        .property("author", SchemaBuilder::string().description("Author login"))
+                        .property("committer", SchemaBuilder::string().description("Committer login"))
+                        .property("author-date", SchemaBuilder::string().description("Author date range, e.g. '>2026-01-01'"))
+                        .property("committer-date", SchemaBuilder::string().description("Committer date range"))
+                        .property("hash", SchemaBuilder::string().description("Commit SHA prefix"))
+                        .property("merge", SchemaBuilder::boolean().description("Only merge commits (true) or exclude them (false)"))
+                        .property(
+                            "sort",
+                            SchemaBuilder::string()
+                                .enum_values(&["author-date", "committer-date"])
+                                .description("Sort field (best match when omitted)"),
+                        )
+                        .property(
+                            "order",
+                            SchemaBuilder::string()
+                                .enum_values(&["desc", "asc"])
+                                .default_value(json!("desc")),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(30))
+                                .description("Results per page"),
+                        )
+                        .property(
+                            "page",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(1))
+                                .description("Page number"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("query", SchemaBuilder::string())
+                        .property("total_count", SchemaBuilder::integer())
+                        .property("incomplete_results", SchemaBuilder::boolean())
+                        .property(
+                            "results",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("sha", SchemaBuilder::string())
+                                    .property("message", SchemaBuilder::string())
+                                    .property("date", SchemaBuilder::string())
+                                    .property("author", SchemaBuilder::string())
+                                    .property("repo", SchemaBuilder::string())
+                                    .property("html_url", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Who touched the token code", json!({"query": "token", "repo": "owner/repo", "sort": "author-date"}))
+                .example("Commits by an author this year", json!({"author": "octocat", "author-date": ">2026-01-01"}))
+                .errors(&["UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.search_topics - Search topics
+            self.method_info("github.search_topics", "Search repository topics")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("query", SchemaBuilder::string().description("Topic name or keywords"))
+                        .property(
+                            "is",
+                            SchemaBuilder::string()
+                                .enum_values(&["featured", "curated", "not-featured", "not-curated"])
+                                .description("Limit to featured or curated topics"),
+                        )
+                        .property(
+                            "repositories",
+                            SchemaBuilder::string().description("Repository count range, e.g. '>1000'"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(30))
+                                .description("Results per page"),
+                        )
+                        .property(
+                            "page",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(1))
+                                .description("Page number"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("query", SchemaBuilder::string())
+                        .property("total_count", SchemaBuilder::integer())
+                        .property(
+                            "results",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("display_name", SchemaBuilder::string())
+                                    .property("short_description", SchemaBuilder::string())
+                                    .property("featured", SchemaBuilder::boolean())
+                                    .property("curated", SchemaBuilder::boolean()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Find topics", json!({"query": "llm"}))
+                .example("Curated topics only", json!({"query": "web", "is": "curated"}))
+                .errors(&["UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.search_all - Unified search
+            self.method_info(
+                "github.search_all",
+                "Search any type through one envelope with total_count, incomplete_results, and next_cursor",
+            )
+            .schema(
+                SchemaBuilder::object()
+                    .property(
+                        "type",
+                        SchemaBuilder::string()
+                            .enum_values(&["code", "issues", "prs", "repos", "users", "commits", "topics"])
+                            .description("Search backend"),
+                    )
+                    .property(
+                        "query",
+                        SchemaBuilder::string()
+                            .description("Search terms; type-specific qualifier params are also accepted"),
+                    )
+                    .property(
+                        "cursor",
+                        SchemaBuilder::string().description("next_cursor from the previous response"),
+                    )
+                    .property(
+                        "limit",
+                        SchemaBuilder::integer()
+                            .minimum(1)
+                            .maximum(100)
+                            .default_value(json!(30))
+                            .description("Results per page"),
+                    )
+                    .required(&["type"])
+                    .build(),
+            )
+            .returns(
+                SchemaBuilder::object()
+                    .property("type", SchemaBuilder::string())
+                    .property("query", SchemaBuilder::string())
+                    .property("total_count", SchemaBuilder::integer())
+                    .property("incomplete_results", SchemaBuilder::boolean())
+                    .property("results", SchemaBuilder::array())
+                    .property("count", SchemaBuilder::integer())
+                    .property("next_cursor", SchemaBuilder::string())
+                    .build(),
+            )
+            .example("Search repos", json!({"type": "repos", "query": "daemon", "language": "rust"}))
+            .example("Next page", json!({"type": "code", "query": "rest_get", "cursor": "2"}))
+            .errors(&["UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.save_search - Save a named search
+            self.method_info("github.save_search", "Save a named search (replaces one with the same name)")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "name",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+$")
+                                .description("Name used to run the search"),
+                        )
+                        .property(
+                            "type",
+                            SchemaBuilder::string()
+                                .enum_values(&["code", "issues", "prs", "repos", "users", "commits", "topics"])
+                                .description("Search type, as for github.search_all"),
+                        )
+                        .property("query", SchemaBuilder::string().description("Search query"))
+                        .property(
+                            "params",
+                            SchemaBuilder::object().description("Extra github.search_all params, e.g. qualifiers or sort"),
+                        )
+                        .property("description", SchemaBuilder::string())
+                        .required(&["name", "type"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "saved",
+                            SchemaBuilder::object()
+                                .property("name", SchemaBuilder::string())
+                                .property("type", SchemaBuilder::string())
+                                .property("params", SchemaBuilder::object())
+                                .property("description", SchemaBuilder::string())
+                                .property("created_at", SchemaBuilder::string().format("date-time")),
+                        )
+                        .property("replaced", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example(
+                    "Team bug list",
+                    json!({"name": "team-bugs", "type": "issues", "query": "is:open label:bug team:my-org/core"}),
+                )
+                .errors(&["VALIDATION_FAILED"]),
+
+            // github.saved_searches - List saved searches
+            self.method_info("github.saved_searches", "List saved searches")
+                .schema(SchemaBuilder::object().build())
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "searches",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("type", SchemaBuilder::string())
+                                    .property("params", SchemaBuilder::object())
+                                    .property("description", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("path", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("List saved searches", json!({})),
+
+            // github.run_saved_search - Run a saved search
+            self.method_info("github.run_saved_search", "Run a saved search by name")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("name", SchemaBuilder::string().description("Saved search name"))
+                        .property(
+                            "cursor",
+                            SchemaBuilder::string().description("next_cursor from the previous response"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .description("Override results per page"),
+                        )
+                        .required(&["name"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("name", SchemaBuilder::string())
+                        .property("type", SchemaBuilder::string())
+                        .property("query", SchemaBuilder::string())
+                        .property("total_count", SchemaBuilder::integer())
+                        .property("incomplete_results", SchemaBuilder::boolean())
+                        .property("results", SchemaBuilder::array())
+                        .property("count", SchemaBuilder::integer())
+                        .property("next_cursor", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Run a saved search", json!({"name": "team-bugs"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.get_user_profile - Get any user's profile
+            self.method_info("github.get_user_profile", "Get a user's public profile and organization memberships")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "login",
+                            SchemaBuilder::string().description("GitHub username (a leading @ is ignored)"),
+                        )
+                        .required(&["login"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string())
+                        .property("name", SchemaBuilder::string())
+                        .property("email", SchemaBuilder::string().description("Public email, if set"))
+                        .property("bio", SchemaBuilder::string())
+                        .property("company", SchemaBuilder::string())
+                        .property("location", SchemaBuilder::string())
+                        .property("public_repos", SchemaBuilder::integer())
+                        .property("followers", SchemaBuilder::integer())
+                        .property("following", SchemaBuilder::integer())
+                        .property("created_at", SchemaBuilder::string().format("date-time"))
+                        .property("organizations", SchemaBuilder::array().items(SchemaBuilder::string()))
+                        .build(),
+                )
+                .example("Look up a user", json!({"login": "octocat"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.org_repos - List organization repositories
+            self.method_info("github.org_repos", "List an organization's repositories")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string().description("Organization login"))
+                        .property(
+                            "type",
+                            SchemaBuilder::string()
+                                .enum_values(&["all", "public", "private", "forks", "sources", "member"])
+                                .default_value(json!("all"))
+                                .description("Repository type filter"),
+                        )
+                        .property(
+                            "sort",
+                            SchemaBuilder::string()
+                                .enum_values(&["created", "updated", "pushed", "full_name"])
+                                .default_value(json!("updated")),
+                        )
+                        .property(
+                            "direction",
+                            SchemaBuilder::string()
+                                .enum_values(&["asc", "desc"])
+                                .description("Defaults to asc for full_name, desc otherwise"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(30))
+                                .description("Repositories per page"),
+                        )
+                        .property(
+                            "page",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(1))
+                                .description("Page number"),
+                        )
+                        .required(&["org"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string())
+                        .property("page", SchemaBuilder::integer())
+                        .property(
+                            "repos",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("full_name", SchemaBuilder::string())
+                                    .property("is_private", SchemaBuilder::boolean())
+                                    .property("is_fork", SchemaBuilder::boolean())
+                                    .property("stars", SchemaBuilder::integer())
+                                    .property("language", SchemaBuilder::string())
+                                    .property("pushed_at", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Recently pushed sources", json!({"org": "my-org", "type": "sources", "sort": "pushed"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.org_members - List organization members
+            self.method_info("github.org_members", "List organization members with role and 2FA status")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string().description("Organization login"))
+                        .property(
+                            "role",
+                            SchemaBuilder::string()
+                                .enum_values(&["admin", "member"])
+                                .description("Keep only members with this role (applied per page)"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(50))
+                                .description("Members per page"),
+                        )
+                        .property(
+                            "after",
+                            SchemaBuilder::string().description("Cursor from a previous response's next_cursor"),
+                        )
+                        .required(&["org"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string())
+                        .property(
+                            "members",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("login", SchemaBuilder::string())
+                                    .property("name", SchemaBuilder::string())
+                                    .property("role", SchemaBuilder::string().enum_values(&["admin", "member"]))
+                                    .property(
+                                        "two_factor_enabled",
+                                        SchemaBuilder::boolean().description("Null unless the viewer is an owner"),
+                                    ),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("next_cursor", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("List members", json!({"org": "my-org"}))
+                .example("Owners only", json!({"org": "my-org", "role": "admin"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.orgs - List my organizations
+            self.method_info("github.orgs", "List your organization memberships with role and plan")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "state",
+                            SchemaBuilder::string()
+                                .enum_values(&["active", "pending"])
+                                .description("Membership state filter (both when omitted)"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "orgs",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("org", SchemaBuilder::string())
+                                    .property("name", SchemaBuilder::string())
+                                    .property("description", SchemaBuilder::string())
+                                    .property("role", SchemaBuilder::string().enum_values(&["admin", "member"]))
+                                    .property("state", SchemaBuilder::string())
+                                    .property(
+                                        "plan",
+                                        SchemaBuilder::object()
+                                            .property("name", SchemaBuilder::string())
+                                            .property("seats", SchemaBuilder::integer())
+                                            .property("filled_seats", SchemaBuilder::integer())
+                                            .description("Null unless you are an owner"),
+                                    ),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("List my organizations", json!({}))
+                .errors(&["UNAUTHORIZED"]),
+
+            // github.teams - List organization teams
+            self.method_info("github.teams", "List an organization's teams with privacy and member count")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string().description("Organization login"))
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(50))
+                                .description("Teams per page"),
+                        )
+                        .property(
+                            "after",
+                            SchemaBuilder::string().description("Cursor from a previous response's next_cursor"),
+                        )
+                        .required(&["org"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string())
+                        .property(
+                            "teams",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("slug", SchemaBuilder::string())
+                                    .property("name", SchemaBuilder::string())
+                                    .property("description", SchemaBuilder::string())
+                                    .property("privacy", SchemaBuilder::string().enum_values(&["visible", "secret"]))
+                                    .property("member_count", SchemaBuilder::integer())
+                                    .property("parent", SchemaBuilder::string())
+                                    .property("url", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("next_cursor", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("List teams", json!({"org": "my-org"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.team_members - List team members
+            self.method_info("github.team_members", "List the members of a team")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string().description("Organization login"))
+                        .property("team", SchemaBuilder::string().description("Team slug"))
+                        .property(
+                            "immediate_only",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Exclude members inherited from child teams"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(100))
+                                .description("Members per page"),
+                        )
+                        .property(
+                            "after",
+                            SchemaBuilder::string().description("Cursor from a previous response's next_cursor"),
+                        )
+                        .required(&["org", "team"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string())
+                        .property("team", SchemaBuilder::string())
+                        .property(
+                            "members",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("login", SchemaBuilder::string())
+                                    .property("name", SchemaBuilder::string())
+                                    .property("role", SchemaBuilder::string().enum_values(&["maintainer", "member"])),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("next_cursor", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Resolve a team handle", json!({"org": "my-org", "team": "core"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.team_repos - List a team's repositories
+            self.method_info("github.team_repos", "List repositories a team can access and its permission on each")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string().description("Organization login"))
+                        .property("team", SchemaBuilder::string().description("Team slug"))
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(30))
+                                .description("Repositories per page"),
+                        )
+                        .property(
+                            "page",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(1))
+                                .description("Page number"),
+                        )
+                        .required(&["org", "team"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string())
+                        .property("team", SchemaBuilder::string())
+                        .property("page", SchemaBuilder::integer())
+                        .property(
+                            "repos",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("full_name", SchemaBuilder::string())
+                                    .property("is_private", SchemaBuilder::boolean())
+                                    .property("permission", SchemaBuilder::string())
+                                    .property("url", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Team access review", json!({"org": "my-org", "team": "core"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.add_team_repo - Grant a team access to a repository
+            self.method_info("github.add_team_repo", "Grant a team access to a repository, or change its permission")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string().description("Organization login"))
+                        .property("team", SchemaBuilder::string().description("Team slug"))
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .property(
+                            "permission",
+                            SchemaBuilder::string()
+                                .default_value(json!("pull"))
+                                .description("pull, triage, push, maintain, admin, or a custom role name"),
+                        )
+                        .required(&["org", "team", "repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string())
+                        .property("team", SchemaBuilder::string())
+                        .property("repo", SchemaBuilder::string())
+                        .property("permission", SchemaBuilder::string())
+                        .property("added", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example("Give core write access", json!({"org": "my-org", "team": "core", "repo": "my-org/api", "permission": "push"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.remove_team_repo - Remove a team's repository access
+            self.method_info("github.remove_team_repo", "Remove a team's access to a repository")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string().description("Organization login"))
+                        .property("team", SchemaBuilder::string().description("Team slug"))
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .required(&["org", "team", "repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string())
+                        .property("team", SchemaBuilder::string())
+                        .property("repo", SchemaBuilder::string())
+                        .property("removed", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example("Revoke access", json!({"org": "my-org", "team": "contractors", "repo": "my-org/api"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.org_invitations - List pending organization invitations
+            self.method_info("github.org_invitations", "List pending invitations to an organization")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string().description("Organization login"))
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(100))
+                                .description("Maximum invitations to return"),
+                        )
+                        .required(&["org"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string())
+                        .property(
+                            "invitations",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::integer())
+                                    .property("login", SchemaBuilder::string())
+                                    .property("email", SchemaBuilder::string())
+                                    .property("role", SchemaBuilder::string())
+                                    .property("inviter", SchemaBuilder::string())
+                                    .property("team_count", SchemaBuilder::integer())
+                                    .property("created_at", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Pending invites", json!({"org": "my-org"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.invite_to_org - Invite a user to an organization
+            self.method_info("github.invite_to_org", "Invite a user by login or email to an organization")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string().description("Organization login"))
+                        .property("login", SchemaBuilder::string().description("GitHub login to invite"))
+                        .property(
+                            "email",
+                            SchemaBuilder::string()
+                                .format("email")
+                                .description("Email address to invite (when login is not given)"),
+                        )
+                        .property(
+                            "role",
+                            SchemaBuilder::string()
+                                .enum_values(&["admin", "direct_member", "billing_manager", "reinstate"])
+                                .default_value(json!("direct_member"))
+                                .description("Role for the new member"),
+                        )
+                        .property(
+                            "team_ids",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::integer())
+                                .description("Team ids to add the invitee to"),
+                        )
+                        .required(&["org"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string())
+                        .property(
+                            "invitation",
+                            SchemaBuilder::object()
+                                .property("id", SchemaBuilder::integer())
+                                .property("login", SchemaBuilder::string())
+                                .property("email", SchemaBuilder::string())
+                                .property("role", SchemaBuilder::string()),
+                        )
+                        .build(),
+                )
+                .example("Invite by login", json!({"org": "my-org", "login": "octocat"}))
+                .example("Invite by email", json!({"org": "my-org", "email": "new-hire@example.com", "team_ids": [12]}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.cancel_org_invitation - Cancel a pending invitation
+            self.method_info("github.cancel_org_invitation", "Cancel a pending organization invitation")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string().description("Organization login"))
+                        .property("invitation_id", SchemaBuilder::integer().description("Invitation id from github.org_invitations"))
+                        .required(&["org", "invitation_id"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string())
+                        .property("invitation_id", SchemaBuilder::integer())
+                        .property("cancelled", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example("Cancel invite", json!({"org": "my-org", "invitation_id": 42}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.followers - List followers
+            self.method_info("github.followers", "List followers of the authenticated user or a given login")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string().description("GitHub login (default: authenticated user)"))
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(30))
+                                .description("Users per page"),
+                        )
+                        .property(
+                            "page",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(1))
+                                .description("Page number"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string())
+                        .property("page", SchemaBuilder::integer())
+                        .property(
+                            "followers",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("login", SchemaBuilder::string())
+                                    .property("avatar_url", SchemaBuilder::string())
+                                    .property("url", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("My followers", json!({}))
+                .example("Someone else's followers", json!({"login": "octocat", "page": 2}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.following - List followed accounts
+            self.method_info("github.following", "List accounts the authenticated user or a given login follows")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string().description("GitHub login (default: authenticated user)"))
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(30))
+                                .description("Users per page"),
+                        )
+                        .property(
+                            "page",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(1))
+                                .description("Page number"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string())
+                        .property("page", SchemaBuilder::integer())
+                        .property(
+                            "following",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("login", SchemaBuilder::string())
+                                    .property("avatar_url", SchemaBuilder::string())
+                                    .property("url", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Who I follow", json!({}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.follow - Follow a user
+            self.method_info("github.follow", "Follow a user as the authenticated user")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string().description("GitHub login to follow"))
+                        .required(&["login"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string())
+                        .property("following", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example("Follow a user", json!({"login": "octocat"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.unfollow - Unfollow a user
+            self.method_info("github.unfollow", "Stop following a user")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string().description("GitHub login to unfollow"))
+                        .required(&["login"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string())
+                        .property("following", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example("Unfollow a user", json!({"login": "octocat"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.contributions - Contribution calendar and stats
+            self.method_info("github.contributions", "Contribution calendar, totals by type, and streaks for a user")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string().description("GitHub login (default: authenticated user)"))
+                        .property(
+                            "from",
+                            SchemaBuilder::string()
+                                .description("Start date (YYYY-MM-DD or ISO 8601; default: one year ago)"),
+                        )
+                        .property(
+                            "to",
+                            SchemaBuilder::string()
+                                .description("End date (YYYY-MM-DD or ISO 8601; default: now). Range must not exceed one year"),
+                        )
+                        .property(
+                            "include_days",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(true))
+                                .description("Include daily counts"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string())
+                        .property("from", SchemaBuilder::string())
+                        .property("to", SchemaBuilder::string())
+                        .property("total", SchemaBuilder::integer())
+                        .property(
+                            "totals",
+                            SchemaBuilder::object()
+                                .property("commits", SchemaBuilder::integer())
+                                .property("issues", SchemaBuilder::integer())
+                                .property("pull_requests", SchemaBuilder::integer())
+                                .property("reviews", SchemaBuilder::integer())
+                                .property("repositories", SchemaBuilder::integer())
+                                .property("restricted", SchemaBuilder::integer()),
+                        )
+                        .property("current_streak", SchemaBuilder::integer())
+                        .property("longest_streak", SchemaBuilder::integer())
+                        .property(
+                            "days",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("date", SchemaBuilder::string())
+                                    .property("count", SchemaBuilder::integer()),
+                            ),
+                        )
+                        .build(),
+                )
+                .example("My past year", json!({}))
+                .example("A user's 2024 totals", json!({"login": "octocat", "from": "2024-01-01", "to": "2024-12-31", "include_days": false}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.events - User and repository event feeds
+            self.method_info("github.events", "Read user received, user public, or repository event feeds")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "feed",
+                            SchemaBuilder::string()
+                                .enum_values(&["received", "public", "repo"])
+                                .description("Feed to read (default: repo when repo is given, else received)"),
+                        )
+                        .property("login", SchemaBuilder::string().description("User for received/public feeds (default: authenticated user)"))
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in owner/repo format (repo feed)"),
+                        )
+                        .property(
+                            "type",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::string())
+                                .description("Event types to keep, e.g. PushEvent or push (string or array)"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(300)
+                                .default_value(json!(30))
+                                .description("Maximum events to return"),
+                        )
+                        .property(
+                            "include_payload",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Include the raw event payload"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("feed", SchemaBuilder::string())
+                        .property(
+                            "events",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::string())
+                                    .property("type", SchemaBuilder::string())
+                                    .property("actor", SchemaBuilder::string())
+                                    .property("repo", SchemaBuilder::string())
+                                    .property("action", SchemaBuilder::string())
+                                    .property("public", SchemaBuilder::boolean())
+                                    .property("created_at", SchemaBuilder::string())
+                                    .property("payload", SchemaBuilder::object()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("My received feed", json!({}))
+                .example("Pushes to a repo", json!({"repo": "owner/repo", "type": "push"}))
+                .example("A user's public activity", json!({"feed": "public", "login": "octocat"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.audit_log - Organization or enterprise audit log
+            self.method_info("github.audit_log", "Read an organization or enterprise audit log (GitHub Enterprise Cloud)")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string().description("Organization login"))
+                        .property("enterprise", SchemaBuilder::string().description("Enterprise slug (instead of org)"))
+                        .property(
+                            "phrase",
+                            SchemaBuilder::string()
+                                .description("Audit log search phrase, e.g. 'action:repo.create actor:octocat'"),
+                        )
+                        .property("since", SchemaBuilder::string().description("Only entries created on or after this date (YYYY-MM-DD)"))
+                        .property("until", SchemaBuilder::string().description("Only entries created on or before this date (YYYY-MM-DD)"))
+                        .property(
+                            "include",
+                            SchemaBuilder::string()
+                                .enum_values(&["web", "git", "all"])
+                                .default_value(json!("web"))
+                                .description("Event sources to include"),
+                        )
+                        .property(
+                            "order",
+                            SchemaBuilder::string()
+                                .enum_values(&["desc", "asc"])
+                                .default_value(json!("desc"))
+                                .description("Sort order by creation time"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(30))
+                                .description("Entries per page"),
+                        )
+                        .property("after", SchemaBuilder::string().description("Cursor from a previous next_cursor"))
+                        .property(
+                            "include_raw",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Include the full entry as returned by the API"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string())
+                        .property("enterprise", SchemaBuilder::string())
+                        .property(
+                            "entries",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::string())
+                                    .property("action", SchemaBuilder::string())
+                                    .property("actor", SchemaBuilder::string())
+                                    .property("user", SchemaBuilder::string())
+                                    .property("repo", SchemaBuilder::string())
+                                    .property("org", SchemaBuilder::string())
+                                    .property("country_code", SchemaBuilder::string())
+                                    .property("created_at", SchemaBuilder::string())
+                                    .property("raw", SchemaBuilder::object()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("next_cursor", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Recent member changes", json!({"org": "my-org", "phrase": "action:org.add_member"}))
+                .example("January repo deletions", json!({"org": "my-org", "phrase": "action:repo.destroy", "since": "2024-01-01", "until": "2024-01-31"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.keys - Manage SSH keys
+            self.method_info("github.keys", "List, add, or delete the authenticated user's SSH keys")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "action",
+                            SchemaBuilder::string()
+                                .enum_values(&["list", "add", "delete"])
+                                .default_value(json!("list"))
+                                .description("Operation to perform"),
+                        )
+                        .property("title", SchemaBuilder::string().description("Key title (add)"))
+                        .property("key", SchemaBuilder::string().description("Public key, e.g. 'ssh-ed25519 AAAA...' (add)"))
+                        .property("id", SchemaBuilder::integer().description("Key id (delete)"))
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(100))
+                                .description("Maximum keys to list"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "keys",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::integer())
+                                    .property("title", SchemaBuilder::string())
+                                    .property("key", SchemaBuilder::string())
+                                    .property("created_at", SchemaBuilder::string())
+                                    .property("verified", SchemaBuilder::boolean())
+                                    .property("read_only", SchemaBuilder::boolean()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("List keys", json!({}))
+                .example("Register a machine key", json!({"action": "add", "title": "build-01", "key": "ssh-ed25519 AAAAC3Nza..."}))
+                .example("Remove a key", json!({"action": "delete", "id": 2}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.gpg_keys - Manage GPG keys
+            self.method_info("github.gpg_keys", "List, add, or delete the authenticated user's GPG keys")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "action",
+                            SchemaBuilder::string()
+                                .enum_values(&["list", "add", "delete"])
+                                .default_value(json!("list"))
+                                .description("Operation to perform"),
+                        )
+                        .property("armored_public_key", SchemaBuilder::string().description("ASCII-armored public key (add)"))
+                        .property("name", SchemaBuilder::string().description("Key name (add)"))
+                        .property("id", SchemaBuilder::integer().description("Key id (delete)"))
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(100))
+                                .description("Maximum keys to list"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "keys",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::integer())
+                                    .property("name", SchemaBuilder::string())
+                                    .property("key_id", SchemaBuilder::string())
+                                    .property("emails", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                    .property("can_sign", SchemaBuilder::boolean())
+                                    .property("created_at", SchemaBuilder::string())
+                                    .property("expires_at", SchemaBuilder::string())
+                                    .property("revoked", SchemaBuilder::boolean()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("List GPG keys", json!({}))
+                .example("Register a signing key", json!({"action": "add", "name": "build signing", "armored_public_key": "-----BEGIN PGP PUBLIC KEY BLOCK-----\n..."}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.blocked_users - List blocked users
+            self.method_info("github.blocked_users", "List users blocked by the authenticated user or an organization")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string().description("Organization login (default: authenticated user's blocks)"))
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(100))
+                                .description("Maximum users to return"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string())
+                        .property(
+                            "blocked",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("login", SchemaBuilder::string())
+                                    .property("avatar_url", SchemaBuilder::string())
+                                    .property("url", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("My block list", json!({}))
+                .example("Organization block list", json!({"org": "my-org"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.block - Block a user
+            self.method_info("github.block", "Block a user for the authenticated user or an organization")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string().description("GitHub login to block"))
+                        .property("org", SchemaBuilder::string().description("Organization login (default: block for the authenticated user)"))
+                        .required(&["login"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string())
+                        .property("org", SchemaBuilder::string())
+                        .property("blocked", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example("Block for an org", json!({"login": "spammer", "org": "my-org"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.unblock - Unblock a user
+            self.method_info("github.unblock", "Unblock a user for the authenticated user or an organization")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string().description("GitHub login to unblock"))
+                        .property("org", SchemaBuilder::string().description("Organization login (default: unblock for the authenticated user)"))
+                        .required(&["login"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string())
+                        .property("org", SchemaBuilder::string())
+                        .property("blocked", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example("Unblock", json!({"login": "reformed-user"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.org_dashboard - Multi-repo issue/PR/CI report
+            self.method_info("github.org_dashboard", "Open issue/PR counts, oldest open PRs, and default-branch CI across an org's repos")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string().description("Organization login; its most recently pushed non-archived repos are used when repos is not given"))
+                        .property(
+                            "repos",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::string())
+                                .description("Explicit repositories in owner/repo format"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(500)
+                                .default_value(json!(50))
+                                .description("Maximum org repositories to include"),
+                        )
+                        .property(
+                            "oldest_prs",
+                            SchemaBuilder::integer()
+                                .minimum(0)
+                                .maximum(20)
+                                .default_value(json!(3))
+                                .description("Oldest open PRs to list per repository"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("org", SchemaBuilder::string())
+                        .property("open_issues", SchemaBuilder::integer())
+                        .property("open_prs", SchemaBuilder::integer())
+                        .property("failing_ci", SchemaBuilder::array().items(SchemaBuilder::string()))
+                        .property(
+                            "repos",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("repo", SchemaBuilder::string())
+                                    .property("url", SchemaBuilder::string())
+                                    .property("open_issues", SchemaBuilder::integer())
+                                    .property("open_prs", SchemaBuilder::integer())
+                                    .property(
+                                        "oldest_prs",
+                                        SchemaBuilder::array().items(
+                                            SchemaBuilder::object()
+                                                .property("number", SchemaBuilder::integer())
+                                                .property("title", SchemaBuilder::string())
+                                                .property("author", SchemaBuilder::string())
+                                                .property("is_draft", SchemaBuilder::boolean())
+                                                .property("created_at", SchemaBuilder::string())
+                                                .property("url", SchemaBuilder::string()),
+                                        ),
+                                    )
+                                    .property("default_branch", SchemaBuilder::string())
+                                    .property("ci_state", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("missing", SchemaBuilder::array().items(SchemaBuilder::string()))
+                        .build(),
+                )
+                .example("Whole org", json!({"org": "my-org"}))
+                .example("Team's repos", json!({"repos": ["my-org/api", "my-org/web"], "oldest_prs": 5}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.dependabot_alerts - List Dependabot alerts
+            self.method_info("github.dependabot_alerts", "List Dependabot alerts for a repository or organization")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .property("org", SchemaBuilder::string().description("Organization login (instead of repo)"))
+                        .property(
+                            "state",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::string().enum_values(&["open", "dismissed", "fixed", "auto_dismissed"]))
+                                .description("Alert states (string or array; default: open)"),
+                        )
+                        .property(
+                            "severity",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::string().enum_values(&["low", "medium", "high", "critical"]))
+                                .description("Severities to include (string or array)"),
+                        )
+                        .property(
+                            "package",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::string())
+                                .description("Package names (string or array)"),
+                        )
+                        .property(
+                            "ecosystem",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::string())
+                                .description("Ecosystems, e.g. npm, pip, cargo (string or array)"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(100))
+                                .description("Maximum alerts to return"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "alerts",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("number", SchemaBuilder::integer())
+                                    .property("repo", SchemaBuilder::string())
+                                    .property("state", SchemaBuilder::string())
+                                    .property("severity", SchemaBuilder::string())
+                                    .property("package", SchemaBuilder::string())
+                                    .property("ecosystem", SchemaBuilder::string())
+                                    .property("manifest_path", SchemaBuilder::string())
+                                    .property("scope", SchemaBuilder::string())
+                                    .property("ghsa_id", SchemaBuilder::string())
+                                    .property("cve_id", SchemaBuilder::string())
+                                    .property("summary", SchemaBuilder::string())
+                                    .property("vulnerable_version_range", SchemaBuilder::string())
+                                    .property("patched_version", SchemaBuilder::string())
+                                    .property("dismissed_reason", SchemaBuilder::string())
+                                    .property("created_at", SchemaBuilder::string())
+                                    .property("url", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Open critical alerts", json!({"repo": "owner/repo", "severity": ["high", "critical"]}))
+                .example("Org-wide npm alerts", json!({"org": "my-org", "ecosystem": "npm"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.dismiss_dependabot_alert - Dismiss a Dependabot alert
+            self.method_info("github.dismiss_dependabot_alert", "Dismiss a Dependabot alert with a reason")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .property("number", SchemaBuilder::integer().description("Alert number"))
+                        .property(
+                            "reason",
+                            SchemaBuilder::string()
+                                .enum_values(&["fix_started", "inaccurate", "no_bandwidth", "not_used", "tolerable_risk"])
+                                .description("Dismissal reason"),
+                        )
+                        .property("comment", SchemaBuilder::string().max_length(280).description("Dismissal comment"))
+                        .required(&["repo", "number", "reason"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("number", SchemaBuilder::integer())
+                        .property("state", SchemaBuilder::string())
+                        .property("dismissed_reason", SchemaBuilder::string())
+                        .property("url", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Dismiss dev-only finding", json!({"repo": "owner/repo", "number": 4, "reason": "not_used", "comment": "Only used in test fixtures"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.code_scanning_alerts - List code scanning alerts
+            self.method_info("github.code_scanning_alerts", "List code scanning alerts with rule, severity, and location")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .property("org", SchemaBuilder::string().description("Organization login (instead of repo)"))
+                        .property(
+                            "state",
+                            SchemaBuilder::string()
+                                .enum_values(&["open", "closed", "dismissed", "fixed"])
+                                .default_value(json!("open"))
+                                .description("Alert state"),
+                        )
+                        .property(
+                            "severity",
+                            SchemaBuilder::string()
+                                .enum_values(&["critical", "high", "medium", "low", "warning", "note", "error"])
+                                .description("Only alerts of this severity"),
+                        )
+                        .property("tool", SchemaBuilder::string().description("Analysis tool name, e.g. CodeQL"))
+                        .property("ref", SchemaBuilder::string().description("Git ref, e.g. refs/heads/main or refs/pull/42/merge"))
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(100))
+                                .description("Maximum alerts to return"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "alerts",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("number", SchemaBuilder::integer())
+                                    .property("repo", SchemaBuilder::string())
+                                    .property("state", SchemaBuilder::string())
+                                    .property("rule_id", SchemaBuilder::string())
+                                    .property("rule_description", SchemaBuilder::string())
+                                    .property("severity", SchemaBuilder::string())
+                                    .property("tool", SchemaBuilder::string())
+                                    .property("path", SchemaBuilder::string())
+                                    .property("start_line", SchemaBuilder::integer())
+                                    .property("end_line", SchemaBuilder::integer())
+                                    .property("message", SchemaBuilder::string())
+                                    .property("ref", SchemaBuilder::string())
+                                    .property("dismissed_reason", SchemaBuilder::string())
+                                    .property("created_at", SchemaBuilder::string())
+                                    .property("url", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Open high-severity alerts", json!({"repo": "owner/repo", "severity": "high"}))
+                .example("Alerts on a PR", json!({"repo": "owner/repo", "ref": "refs/pull/42/merge"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.update_code_scanning_alert - Dismiss or reopen a code scanning alert
+            self.method_info("github.update_code_scanning_alert", "Dismiss or reopen a code scanning alert")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .property("number", SchemaBuilder::integer().description("Alert number"))
+                        .property(
+                            "state",
+                            SchemaBuilder::string()
+                                .enum_values(&["dismissed", "open"])
+                                .description("New state"),
+                        )
+                        .property(
+                            "reason",
+                            SchemaBuilder::string()
+                                .enum_values(&["false positive", "won't fix", "used in tests"])
+                                .description("Dismissal reason (required when dismissing)"),
+                        )
+                        .property("comment", SchemaBuilder::string().max_length(280).description("Dismissal comment"))
+                        .required(&["repo", "number", "state"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("number", SchemaBuilder::integer())
+                        .property("state", SchemaBuilder::string())
+                        .property("dismissed_reason", SchemaBuilder::string())
+                        .property("url", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Dismiss a false positive", json!({"repo": "owner/repo", "number": 12, "state": "dismissed", "reason": "false positive"}))
+                .example("Reopen", json!({"repo": "owner/repo", "number": 12, "state": "open"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.secret_scanning_alerts - List secret scanning alerts
+            self.method_info("github.secret_scanning_alerts", "List detected secrets with type, state, and locations (secret values are never returned)")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .property("org", SchemaBuilder::string().description("Organization login (instead of repo)"))
+                        .property(
+                            "state",
+                            SchemaBuilder::string()
+                                .enum_values(&["open", "resolved"])
+                                .default_value(json!("open"))
+                                .description("Alert state"),
+                        )
+                        .property(
+                            "secret_type",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::string())
+                                .description("Secret types, e.g. github_personal_access_token (string or array)"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(100))
+                                .description("Maximum alerts to return"),
+                        )
+                        .property(
+                            "include_locations",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(true))
+                                .description("Fetch where each secret was found (one request per alert)"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "alerts",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("number", SchemaBuilder::integer())
+                                    .property("repo", SchemaBuilder::string())
+                                    .property("state", SchemaBuilder::string())
+                                    .property("secret_type", SchemaBuilder::string())
+                                    .property("secret_type_display_name", SchemaBuilder::string())
+                                    .property("validity", SchemaBuilder::string())
+                                    .property("resolution", SchemaBuilder::string())
+                                    .property("resolved_by", SchemaBuilder::string())
+                                    .property("created_at", SchemaBuilder::string())
+                                    .property("url", SchemaBuilder::string())
+                                    .property(
+                                        "locations",
+                                        SchemaBuilder::array().items(
+                                            SchemaBuilder::object()
+                                                .property("type", SchemaBuilder::string())
+                                                .property("path", SchemaBuilder::string())
+                                                .property("start_line", SchemaBuilder::integer())
+                                                .property("commit_sha", SchemaBuilder::string())
+                                                .property("url", SchemaBuilder::string()),
+                                        ),
+                                    ),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Open secrets in a repo", json!({"repo": "owner/repo"}))
+                .example("Leaked PATs across an org", json!({"org": "my-org", "secret_type": "github_personal_access_token", "include_locations": false}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.update_secret_scanning_alert - Resolve or reopen a secret scanning alert
+            self.method_info("github.update_secret_scanning_alert", "Resolve a secret scanning alert with a reason, or reopen it")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .property("number", SchemaBuilder::integer().description("Alert number"))
+                        .property(
+                            "state",
+                            SchemaBuilder::string()
+                                .enum_values(&["resolved", "open"])
+                                .default_value(json!("resolved"))
+                                .description("New state"),
+                        )
+                        .property(
+                            "resolution",
+                            SchemaBuilder::string()
+                                .enum_values(&["false_positive", "wont_fix", "revoked", "used_in_tests"])
+                                .description("Resolution reason (required when resolving)"),
+                        )
+                        .property("comment", SchemaBuilder::string().description("Resolution comment"))
+                        .required(&["repo", "number"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("number", SchemaBuilder::integer())
+                        .property("state", SchemaBuilder::string())
+                        .property("resolution", SchemaBuilder::string())
+                        .property("url", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Mark a rotated token revoked", json!({"repo": "owner/repo", "number": 2, "resolution": "revoked", "comment": "Rotated in INC-123"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.advisories - List repository security advisories
+            self.method_info("github.advisories", "List a repository's security advisories")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .property(
+                            "state",
+                            SchemaBuilder::string()
+                                .enum_values(&["triage", "draft", "published", "closed"])
+                                .description("Only advisories in this state (default: all)"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(100))
+                                .description("Maximum advisories to return"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "advisories",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("ghsa_id", SchemaBuilder::string())
+                                    .property("cve_id", SchemaBuilder::string())
+                                    .property("summary", SchemaBuilder::string())
+                                    .property("severity", SchemaBuilder::string())
+                                    .property("state", SchemaBuilder::string())
+                                    .property("cwe_ids", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                    .property("vulnerabilities", SchemaBuilder::array().items(SchemaBuilder::object()))
+                                    .property("created_at", SchemaBuilder::string())
+                                    .property("published_at", SchemaBuilder::string())
+                                    .property("url", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Draft advisories", json!({"repo": "owner/repo", "state": "draft"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.create_advisory - Draft a repository security advisory
+            self.method_info("github.create_advisory", "Draft a repository security advisory for coordinated disclosure")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .property("summary", SchemaBuilder::string().max_length(1024).description("Short summary"))
+                        .property("description", SchemaBuilder::string().description("Detailed description (Markdown)"))
+                        .property(
+                            "vulnerabilities",
+                            SchemaBuilder::array()
+                                .items(
+                                    SchemaBuilder::object()
+                                        .property("ecosystem", SchemaBuilder::string().description("e.g. npm, pip, rust, go"))
+                                        .property("package", SchemaBuilder::string())
+                                        .property("vulnerable_version_range", SchemaBuilder::string().description("e.g. < 1.2.3"))
+                                        .property("patched_versions", SchemaBuilder::string().description("e.g. 1.2.3"))
+                                        .required(&["ecosystem", "package"]),
+                                )
+                                .description("Affected packages"),
+                        )
+                        .property(
+                            "severity",
+                            SchemaBuilder::string()
+                                .enum_values(&["low", "medium", "high", "critical"])
+                                .description("Severity (or give cvss_vector)"),
+                        )
+                        .property("cvss_vector", SchemaBuilder::string().description("CVSS vector string (or give severity)"))
+                        .property("cve_id", SchemaBuilder::string().description("Existing CVE id, if any"))
+                        .property(
+                            "cwe_ids",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::string())
+                                .description("CWE ids, e.g. CWE-22"),
+                        )
+                        .required(&["repo", "summary", "description", "vulnerabilities"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("ghsa_id", SchemaBuilder::string())
+                        .property("state", SchemaBuilder::string())
+                        .property("url", SchemaBuilder::string())
+                        .build(),
+                )
+                .example(
+                    "Draft an advisory",
+                    json!({
+                        "repo": "owner/repo",
+                        "summary": "Path traversal in archive extraction",
+                        "description": "Crafted archives can write outside the target directory.",
+                        "severity": "high",
+                        "cwe_ids": ["CWE-22"],
+                        "vulnerabilities": [{"ecosystem": "rust", "package": "my-crate", "vulnerable_version_range": "< 1.2.3", "patched_versions": "1.2.3"}]
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.sbom - Export SPDX SBOM
+            self.method_info("github.sbom", "Export a repository's dependency graph as an SPDX SBOM")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .property("path", SchemaBuilder::string().description("Write the SBOM to this file instead of returning it"))
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("package_count", SchemaBuilder::integer())
+                        .property("path", SchemaBuilder::string())
+                        .property("sbom", SchemaBuilder::object().description("SPDX 2.3 document"))
+                        .build(),
+                )
+                .example("Inline SBOM", json!({"repo": "owner/repo"}))
+                .example("Save for compliance upload", json!({"repo": "owner/repo", "path": "~/sboms/repo.spdx.json"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.dependencies - List manifests and their dependencies
+            self.method_info("github.dependencies", "List dependency manifests and the dependencies each declares")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(100))
+                                .description("Maximum dependencies per manifest"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "manifests",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("filename", SchemaBuilder::string())
+                                    .property("path", SchemaBuilder::string())
+                                    .property("dependencies_count", SchemaBuilder::integer())
+                                    .property("parseable", SchemaBuilder::boolean())
+                                    .property(
+                                        "dependencies",
+                                        SchemaBuilder::array().items(
+                                            SchemaBuilder::object()
+                                                .property("package", SchemaBuilder::string())
+                                                .property("package_manager", SchemaBuilder::string())
+                                                .property("requirements", SchemaBuilder::string())
+                                                .property("has_dependencies", SchemaBuilder::boolean()),
+                                        ),
+                                    ),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Manifests", json!({"repo": "owner/repo"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.dependency_diff - Dependency review between two refs
+            self.method_info("github.dependency_diff", "Added/removed dependencies between two refs, flagging known vulnerabilities")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .property("base", SchemaBuilder::string().description("Base ref (branch, tag, or SHA)"))
+                        .property("head", SchemaBuilder::string().description("Head ref (branch, tag, or SHA)"))
+                        .property("manifest", SchemaBuilder::string().description("Only compare this manifest path"))
+                        .property(
+                            "fail_on_severity",
+                            SchemaBuilder::string()
+                                .enum_values(&["low", "moderate", "high", "critical"])
+                                .default_value(json!("low"))
+                                .description("Minimum vulnerability severity that fails the check"),
+                        )
+                        .required(&["repo", "base", "head"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("base", SchemaBuilder::string())
+                        .property("head", SchemaBuilder::string())
+                        .property("fail_on_severity", SchemaBuilder::string())
+                        .property("passed", SchemaBuilder::boolean())
+                        .property("vulnerable", SchemaBuilder::array().items(SchemaBuilder::object()))
+                        .property(
+                            "added",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("change_type", SchemaBuilder::string())
+                                    .property("manifest", SchemaBuilder::string())
+                                    .property("ecosystem", SchemaBuilder::string())
+                                    .property("name", SchemaBuilder::string())
+                                    .property("version", SchemaBuilder::string())
+                                    .property("license", SchemaBuilder::string())
+                                    .property("scope", SchemaBuilder::string())
+                                    .property(
+                                        "vulnerabilities",
+                                        SchemaBuilder::array().items(
+                                            SchemaBuilder::object()
+                                                .property("severity", SchemaBuilder::string())
+                                                .property("ghsa_id", SchemaBuilder::string())
+                                                .property("summary", SchemaBuilder::string())
+                                                .property("url", SchemaBuilder::string()),
+                                        ),
+                                    ),
+                            ),
+                        )
+                        .property("removed", SchemaBuilder::array().items(SchemaBuilder::object()))
+                        .build(),
+                )
+                .example("Gate a PR branch", json!({"repo": "owner/repo", "base": "main", "head": "feature/bump-deps", "fail_on_severity": "high"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.security_features - Get or toggle repository security features
+            self.method_info("github.security_features", "Get or toggle Dependabot alerts, security updates, and secret scanning on a repository")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .property("dependabot_alerts", SchemaBuilder::boolean().description("Enable or disable Dependabot alerts"))
+                        .property(
+                            "dependabot_security_updates",
+                            SchemaBuilder::boolean().description("Enable or disable Dependabot security update PRs (requires alerts)"),
+                        )
+                        .property("secret_scanning", SchemaBuilder::boolean().description("Enable or disable secret scanning"))
+                        .property("push_protection", SchemaBuilder::boolean().description("Enable or disable secret scanning push protection"))
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("updated", SchemaBuilder::boolean())
+                        .property(
+                            "features",
+                            SchemaBuilder::object()
+                                .property("dependabot_alerts", SchemaBuilder::boolean())
+                                .property("dependabot_security_updates", SchemaBuilder::boolean())
+                                .property("secret_scanning", SchemaBuilder::boolean())
+                                .property("push_protection", SchemaBuilder::boolean()),
+                        )
+                        .build(),
+                )
+                .example("Current state", json!({"repo": "owner/repo"}))
+                .example("Roll out Dependabot", json!({"repo": "owner/repo", "dependabot_alerts": true, "dependabot_security_updates": true}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.token_info - Inspect token scopes and method availability
+            self.method_info("github.token_info", "Inspect the current token's type and scopes, and list scope-gated methods it cannot use")
+                .schema(SchemaBuilder::object().build())
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "token",
+                            SchemaBuilder::object()
+                                .property("login", SchemaBuilder::string())
+                                .property("token_type", SchemaBuilder::string().enum_values(&["classic", "fine_grained", "oauth", "app_user", "app_installation", "unknown"]))
+                                .property("scopes", SchemaBuilder::array().items(SchemaBuilder::string()).description("Granted OAuth scopes; null when the token type does not report them"))
+                                .property("expires_at", SchemaBuilder::string().description("Token expiry, when GitHub reports one")),
+                        )
+                        .property("usable", SchemaBuilder::array().items(SchemaBuilder::string()).description("Scope-gated methods the token can call"))
+                        .property(
+                            "unavailable",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("method", SchemaBuilder::string())
+                                    .property("missing_scopes", SchemaBuilder::array().items(SchemaBuilder::string()).description("Scopes the method accepts; any one is enough")),
+                            ),
+                        )
+                        .property("unknown", SchemaBuilder::array().items(SchemaBuilder::string()).description("Gated methods that cannot be checked for this token type"))
+                        .property("note", SchemaBuilder::string())
+                        .build(),
+                )
+                .errors(&["UNAUTHORIZED"]),
+
+            // github.security_overview - Repository security posture report
+            self.method_info("github.security_overview", "Report default branch protection, signed-commit enforcement, security features, and open alert counts by severity per repository")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repos",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::string().pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$"))
+                                .description("Repositories in owner/repo format (a single string is also accepted)"),
+                        )
+                        .required(&["repos"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "reports",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("repo", SchemaBuilder::string())
+                                    .property("default_branch", SchemaBuilder::string())
+                                    .property("protection_readable", SchemaBuilder::boolean().description("Classic protection needs admin access to read"))
+                                    .property("branch_protection", SchemaBuilder::object().description("Classic protection; null when unprotected or unreadable"))
+                                    .property("branch_rules", SchemaBuilder::array().items(SchemaBuilder::string()).description("Active ruleset rule types"))
+                                    .property("signed_commits_required", SchemaBuilder::boolean())
+                                    .property("features", SchemaBuilder::object())
+                                    .property("dependabot_alerts", SchemaBuilder::object().description("Open alerts: total and by_severity"))
+                                    .property("code_scanning_alerts", SchemaBuilder::object())
+                                    .property("secret_scanning_alerts", SchemaBuilder::integer()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property(
+                            "errors",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("repo", SchemaBuilder::string())
+                                    .property("error", SchemaBuilder::string()),
+                            ),
+                        )
+                        .build(),
+                )
+                .example("Audit two repositories", json!({"repos": ["octocat/Hello-World", "octocat/Spoon-Knife"]}))
+                .errors(&["VALIDATION_FAILED", "UNAUTHORIZED"]),
+
+            // github.dependabot_config - Validate Dependabot config and coverage
+            self.method_info("github.dependabot_config", "Validate .github/dependabot.yml and report which manifest directories its updates cover")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("branch", SchemaBuilder::string())
+                        .property("path", SchemaBuilder::string().description("Config file path; null when missing"))
+                        .property("valid", SchemaBuilder::boolean())
+                        .property(
+                            "issues",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("severity", SchemaBuilder::string().enum_values(&["error", "warning"]))
+                                    .property("path", SchemaBuilder::string())
+                                    .property("message", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property(
+                            "updates",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("ecosystem", SchemaBuilder::string())
+                                    .property("directories", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                    .property("interval", SchemaBuilder::string())
+                                    .property("target_branch", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property(
+                            "coverage",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("ecosystem", SchemaBuilder::string())
+                                    .property("configured", SchemaBuilder::boolean())
+                                    .property("directories", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                    .property("uncovered", SchemaBuilder::array().items(SchemaBuilder::string())),
+                            ),
+                        )
+                        .property("unmatched_updates", SchemaBuilder::array().items(SchemaBuilder::string()).description("Configured entries that match no manifest"))
+                        .property("tree_truncated", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.discussions - List discussions
+            self.method_info("github.discussions", "List repository discussions, most recently updated first")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "category",
+                            SchemaBuilder::string().description("Category name or slug"),
+                        )
+                        .property(
+                            "answered",
+                            SchemaBuilder::boolean().description("Only answered (true) or unanswered (false) discussions"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(20))
+                                .description("Discussions per page"),
+                        )
+                        .property(
+                            "after",
+                            SchemaBuilder::string().description("Cursor from a previous next_cursor"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "discussions",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::string())
+                                    .property("number", SchemaBuilder::integer())
+                                    .property("title", SchemaBuilder::string())
+                                    .property("category", SchemaBuilder::string())
+                                    .property("author", SchemaBuilder::string())
+                                    .property("is_answered", SchemaBuilder::boolean())
+                                    .property("locked", SchemaBuilder::boolean())
+                                    .property("upvote_count", SchemaBuilder::integer())
+                                    .property("comment_count", SchemaBuilder::integer())
+                                    .property("url", SchemaBuilder::string().format("uri"))
+                                    .property("updated_at", SchemaBuilder::string().format("date-time")),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("next_cursor", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Unanswered questions", json!({"repo": "octocat/Hello-World", "category": "q-a", "answered": false}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.discussion - Get a discussion with comments
+            self.method_info("github.discussion", "Get a discussion's body and comment tree")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("number", SchemaBuilder::integer().minimum(1).description("Discussion number"))
+                        .property(
+                            "comments",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(50))
+                                .description("Top-level comments to include, oldest first"),
+                        )
+                        .property(
+                            "replies",
+                            SchemaBuilder::integer()
+                                .minimum(0)
+                                .maximum(100)
+                                .default_value(json!(20))
+                                .description("Replies to include per comment"),
+                        )
+                        .required(&["repo", "number"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("number", SchemaBuilder::integer())
+                        .property("title", SchemaBuilder::string())
+                        .property("body", SchemaBuilder::string())
+                        .property("answer_id", SchemaBuilder::string().description("Node ID of the answer comment"))
+                        .property(
+                            "comments",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::string())
+                                    .property("author", SchemaBuilder::string())
+                                    .property("body", SchemaBuilder::string())
+                                    .property("is_answer", SchemaBuilder::boolean())
+                                    .property("reply_count", SchemaBuilder::integer())
+                                    .property("replies", SchemaBuilder::array().items(SchemaBuilder::object())),
+                            ),
+                        )
+                        .build(),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.create_discussion - Start a discussion
+            self.method_info("github.create_discussion", "Start a discussion in a category")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("category", SchemaBuilder::string().description("Category name or slug"))
+                        .property("title", SchemaBuilder::string().min_length(1).description("Discussion title"))
+                        .property("body", SchemaBuilder::string().description("Discussion body (markdown)"))
+                        .required(&["repo", "category", "title", "body"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("id", SchemaBuilder::string())
+                        .property("number", SchemaBuilder::integer())
+                        .property("title", SchemaBuilder::string())
+                        .property("category", SchemaBuilder::string())
+                        .property("url", SchemaBuilder::string().format("uri"))
+                        .build(),
+                )
+                .example("Ask a question", json!({"repo": "octocat/Hello-World", "category": "q-a", "title": "How do I enable caching?", "body": "Details..."}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.discussion_comment - Comment on or reply in a discussion
+            self.method_info("github.discussion_comment", "Comment on a discussion or reply to one of its comments")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("number", SchemaBuilder::integer().minimum(1).description("Discussion number"))
+                        .property("body", SchemaBuilder::string().min_length(1).description("Comment body (markdown)"))
+                        .property(
+                            "reply_to",
+                            SchemaBuilder::string().description("Node ID of a top-level comment to reply to"),
+                        )
+                        .required(&["repo", "number", "body"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("id", SchemaBuilder::string())
+                        .property("author", SchemaBuilder::string())
+                        .property("body", SchemaBuilder::string())
+                        .property("url", SchemaBuilder::string().format("uri"))
+                        .property("created_at", SchemaBuilder::string().format("date-time"))
+                        .build(),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.discussion_categories - List discussion categories
+            self.method_info("github.discussion_categories", "List a repository's discussion categories")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "categories",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::string())
+                                    .property("name", SchemaBuilder::string())
+                                    .property("slug", SchemaBuilder::string())
+                                    .property("emoji", SchemaBuilder::string())
+                                    .property("description", SchemaBuilder::string())
+                                    .property("is_answerable", SchemaBuilder::boolean().description("Q&A category")),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.mark_answer - Mark a discussion comment as the answer
+            self.method_info("github.mark_answer", "Mark a discussion comment as the answer (Q&A categories)")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "comment_id",
+                            SchemaBuilder::string().description("Comment node ID, from github.discussion"),
+                        )
+                        .required(&["comment_id"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("comment_id", SchemaBuilder::string())
+                        .property("is_answer", SchemaBuilder::boolean())
+                        .property(
+                            "discussion",
+                            SchemaBuilder::object()
+                                .property("number", SchemaBuilder::integer())
+                                .property("title", SchemaBuilder::string())
+                                .property("is_answered", SchemaBuilder::boolean()),
+                        )
+                        .build(),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.unmark_answer - Clear a discussion's answer
+            self.method_info("github.unmark_answer", "Unmark a discussion comment as the answer")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "comment_id",
+                            SchemaBuilder::string().description("Comment node ID, from github.discussion"),
+                        )
+                        .required(&["comment_id"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("comment_id", SchemaBuilder::string())
+                        .property("is_answer", SchemaBuilder::boolean())
+                        .property("discussion", SchemaBuilder::object())
+                        .build(),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.projects - List Projects (v2)
+            self.method_info("github.projects", "List Projects (v2) owned by a user or organization")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "owner",
+                            SchemaBuilder::string().description("User or organization login (default: authenticated user)"),
+                        )
+                        .property(
+                            "include_closed",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Include closed projects"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(20))
+                                .description("Projects per page"),
+                        )
+                        .property(
+                            "after",
+                            SchemaBuilder::string().description("Cursor from a previous next_cursor"),
+                        )
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "projects",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::string().description("Project node ID"))
+                                    .property("number", SchemaBuilder::integer())
+                                    .property("title", SchemaBuilder::string())
+                                    .property("owner", SchemaBuilder::string())
+                                    .property("description", SchemaBuilder::string())
+                                    .property("url", SchemaBuilder::string().format("uri"))
+                                    .property("closed", SchemaBuilder::boolean())
+                                    .property("public", SchemaBuilder::boolean())
+                                    .property("item_count", SchemaBuilder::integer())
+                                    .property("updated_at", SchemaBuilder::string().format("date-time")),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("next_cursor", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Organization projects", json!({"owner": "octo-org"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.project_items - List project items with field values
+            self.method_info("github.project_items", "List a project's items with status, iteration, and custom field values")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "owner",
+                            SchemaBuilder::string().description("User or organization login (default: authenticated user)"),
+                        )
+                        .property("number", SchemaBuilder::integer().minimum(1).description("Project number"))
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(50))
+                                .description("Items per page"),
+                        )
+                        .property(
+                            "after",
+                            SchemaBuilder::string().description("Cursor from a previous next_cursor"),
+                        )
+                        .required(&["number"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("project", SchemaBuilder::string().description("Project title"))
+                        .property("number", SchemaBuilder::integer())
+                        .property(
+                            "items",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::string().description("Item node ID"))
+                                    .property("type", SchemaBuilder::string().enum_values(&["ISSUE", "PULL_REQUEST", "DRAFT_ISSUE", "REDACTED"]))
+                                    .property("title", SchemaBuilder::string())
+                                    .property("number", SchemaBuilder::integer())
+                                    .property("repo", SchemaBuilder::string())
+                                    .property("state", SchemaBuilder::string())
+                                    .property("assignees", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                    .property("status", SchemaBuilder::string())
+                                    .property(
+                                        "iteration",
+                                        SchemaBuilder::object()
+                                            .property("title", SchemaBuilder::string())
+                                            .property("start_date", SchemaBuilder::string().format("date"))
+                                            .property("duration_days", SchemaBuilder::integer()),
+                                    )
+                                    .property("fields", SchemaBuilder::object().description("Field values keyed by field name")),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("next_cursor", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Items on an org project", json!({"owner": "octo-org", "number": 3}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.project_add - Add an issue or PR to a project
+            self.method_info("github.project_add", "Add an issue or pull request to a project")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "owner",
+                            SchemaBuilder::string().description("Project owner login (default: authenticated user)"),
+                        )
+                        .property("number", SchemaBuilder::integer().minimum(1).description("Project number"))
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository of the issue or PR in 'owner/repo' format"),
+                        )
+                        .property("issue", SchemaBuilder::integer().minimum(1).description("Issue or pull request number"))
+                        .required(&["number", "repo", "issue"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("item_id", SchemaBuilder::string().description("Project item node ID"))
+                        .property("project", SchemaBuilder::integer())
+                        .property("repo", SchemaBuilder::string())
+                        .property("issue", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("File an issue onto a board", json!({"owner": "octo-org", "number": 3, "repo": "octo-org/api", "issue": 42}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.project_update_item - Set project item field values
+            self.method_info("github.project_update_item", "Set a project item's status, iteration, number, text, or date fields by field name")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "owner",
+                            SchemaBuilder::string().description("Project owner login (default: authenticated user)"),
+                        )
+                        .property("number", SchemaBuilder::integer().minimum(1).description("Project number"))
+                        .property(
+                            "item_id",
+                            SchemaBuilder::string().description("Project item node ID, from github.project_items or github.project_add"),
+                        )
+                        .property(
+                            "fields",
+                            SchemaBuilder::object().description(
+                                "Field name to value. Single-select takes an option name, iteration a title or @current/@next, date YYYY-MM-DD; null clears",
+                            ),
+                        )
+                        .required(&["number", "item_id", "fields"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("item_id", SchemaBuilder::string())
+                        .property(
+                            "updated",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("field", SchemaBuilder::string())
+                                    .property("value", SchemaBuilder::object().description("Value input sent; null when cleared")),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Move to In Progress", json!({"owner": "octo-org", "number": 3, "item_id": "PVTI_lADOA", "fields": {"Status": "In Progress", "Sprint": "@current"}}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.project_board - Project board snapshot
+            self.method_info("github.project_board", "Snapshot a project as columns grouped by status, with per-column counts")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "owner",
+                            SchemaBuilder::string().description("Project owner login (default: authenticated user)"),
+                        )
+                        .property("number", SchemaBuilder::integer().minimum(1).description("Project number"))
+                        .property(
+                            "field",
+                            SchemaBuilder::string()
+                                .default_value(json!("Status"))
+                                .description("Single-select field to group by"),
+                        )
+                        .property(
+                            "per_column",
+                            SchemaBuilder::integer()
+                                .minimum(0)
+                                .maximum(500)
+                                .default_value(json!(25))
+                                .description("Items to include per column (counts cover all items)"),
+                        )
+                        .required(&["number"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("project", SchemaBuilder::string())
+                        .property("number", SchemaBuilder::integer())
+                        .property("field", SchemaBuilder::string())
+                        .property("total", SchemaBuilder::integer())
+                        .property(
+                            "columns",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("count", SchemaBuilder::integer())
+                                    .property("items", SchemaBuilder::array().items(SchemaBuilder::object())),
+                            ),
+                        )
+                        .property("truncated", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example("Kanban snapshot", json!({"owner": "octo-org", "number": 3, "per_column": 10}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.project_report - Project progress report
+            self.method_info("github.project_report", "Report weekly completions, open vs. closed per iteration, and unassigned items for a project")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "owner",
+                            SchemaBuilder::string().description("Project owner login (default: authenticated user)"),
+                        )
+                        .property("number", SchemaBuilder::integer().minimum(1).description("Project number"))
+                        .property(
+                            "weeks",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(52)
+                                .default_value(json!(8))
+                                .description("Weeks of completion history, including the current week"),
+                        )
+                        .required(&["number"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("project", SchemaBuilder::string())
+                        .property("total", SchemaBuilder::integer())
+                        .property("open", SchemaBuilder::integer())
+                        .property("closed", SchemaBuilder::integer())
+                        .property("unassigned_open", SchemaBuilder::integer())
+                        .property("unassigned_total", SchemaBuilder::integer())
+                        .property(
+                            "completed_per_week",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("week_start", SchemaBuilder::string().format("date"))
+                                    .property("completed", SchemaBuilder::integer()),
+                            ),
+                        )
+                        .property(
+                            "iterations",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("title", SchemaBuilder::string())
+                                    .property("start_date", SchemaBuilder::string().format("date"))
+                                    .property("open", SchemaBuilder::integer())
+                                    .property("closed", SchemaBuilder::integer()),
+                            ),
+                        )
+                        .property("truncated", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example("Quarterly burndown", json!({"owner": "octo-org", "number": 3, "weeks": 13}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.commits - List commit history
+            self.method_info("github.commits", "List commits on a branch or ref, newest first, with CI state")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "ref",
+                            SchemaBuilder::string().description("Branch, tag, or SHA (default: default branch)"),
+                        )
+                        .property("path", SchemaBuilder::string().description("Only commits touching this path"))
+                        .property("author", SchemaBuilder::string().description("Author login or email"))
+                        .property("since", SchemaBuilder::string().description("YYYY-MM-DD or ISO 8601 timestamp"))
+                        .property("until", SchemaBuilder::string().description("YYYY-MM-DD or ISO 8601 timestamp"))
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(30))
+                                .description("Commits per page"),
+                        )
+                        .property(
+                            "after",
+                            SchemaBuilder::string().description("Cursor from a previous next_cursor"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "commits",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("sha", SchemaBuilder::string())
+                                    .property("message", SchemaBuilder::string().description("Message headline"))
+                                    .property("author", SchemaBuilder::string())
+                                    .property("author_email", SchemaBuilder::string())
+                                    .property("date", SchemaBuilder::string().format("date-time"))
+                                    .property("ci_state", SchemaBuilder::string())
+                                    .property(
+                                        "verification",
+                                        SchemaBuilder::object()
+                                            .property("verified", SchemaBuilder::boolean())
+                                            .property("reason", SchemaBuilder::string())
+                                            .property("signer", SchemaBuilder::string()),
+                                    )
+                                    .property("url", SchemaBuilder::string().format("uri")),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("next_cursor", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Recent changes to a directory", json!({"repo": "octocat/Hello-World", "path": "src/", "since": "2026-10-01"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.commit - Single commit detail
+            self.method_info("github.commit", "Get a commit's full message, parents, stats, and changed files")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "sha",
+                            SchemaBuilder::string().description("Commit SHA, branch, or tag"),
+                        )
+                        .property(
+                            "patches",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Include per-file unified diff patches"),
+                        )
+                        .required(&["repo", "sha"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "commit",
+                            SchemaBuilder::object()
+                                .property("sha", SchemaBuilder::string())
+                                .property("message", SchemaBuilder::string())
+                                .property("author", SchemaBuilder::string())
+                                .property("author_email", SchemaBuilder::string())
+                                .property("committer", SchemaBuilder::string())
+                                .property("date", SchemaBuilder::string().format("date-time"))
+                                .property("parents", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                .property("additions", SchemaBuilder::integer())
+                                .property("deletions", SchemaBuilder::integer())
+                                .property(
+                                    "files",
+                                    SchemaBuilder::array().items(
+                                        SchemaBuilder::object()
+                                            .property("filename", SchemaBuilder::string())
+                                            .property("status", SchemaBuilder::string())
+                                            .property("additions", SchemaBuilder::integer())
+                                            .property("deletions", SchemaBuilder::integer())
+                                            .property("previous_filename", SchemaBuilder::string())
+                                            .property("patch", SchemaBuilder::string()),
+                                    ),
+                                )
+                                .property(
+                                    "verification",
+                                    SchemaBuilder::object()
+                                        .property("verified", SchemaBuilder::boolean())
+                                        .property("reason", SchemaBuilder::string()),
+                                )
+                                .property("url", SchemaBuilder::string().format("uri")),
+                        )
+                        .property("file_count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Inspect a commit with patches", json!({"repo": "octocat/Hello-World", "sha": "7fd1a60", "patches": true}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.compare - Compare two refs
+            self.method_info("github.compare", "Compare two branches, tags, or SHAs: ahead/behind, commits, files, and merge base")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("base", SchemaBuilder::string().description("Base ref"))
+                        .property(
+                            "head",
+                            SchemaBuilder::string().description("Head ref ('owner:branch' for forks)"),
+                        )
+                        .property(
+                            "patches",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Include per-file unified diff patches"),
+                        )
+                        .required(&["repo", "base", "head"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "comparison",
+                            SchemaBuilder::object()
+                                .property("base", SchemaBuilder::string())
+                                .property("head", SchemaBuilder::string())
+                                .property(
+                                    "status",
+                                    SchemaBuilder::string().enum_values(&["ahead", "behind", "diverged", "identical"]),
+                                )
+                                .property("ahead_by", SchemaBuilder::integer())
+                                .property("behind_by", SchemaBuilder::integer())
+                                .property("merge_base", SchemaBuilder::string())
+                                .property("commits", SchemaBuilder::array().items(SchemaBuilder::object()))
+                                .property("total_commits", SchemaBuilder::integer())
+                                .property("files", SchemaBuilder::array().items(SchemaBuilder::object()))
+                                .property("url", SchemaBuilder::string().format("uri")),
+                        )
+                        .build(),
+                )
+                .example("What's unreleased since the last tag", json!({"repo": "octocat/Hello-World", "base": "v1.2.0", "head": "main"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.file - Read file contents
+            self.method_info("github.file", "Get a file's decoded contents, size, and SHA at a ref, or write it to disk")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("path", SchemaBuilder::string().description("File path in the repository"))
+                        .property(
+                            "ref",
+                            SchemaBuilder::string().description("Branch, tag, or SHA (default: default branch)"),
+                        )
+                        .property(
+                            "max_bytes",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(100000))
+                                .description("Truncate inlined content beyond this many bytes"),
+                        )
+                        .property(
+                            "output",
+                            SchemaBuilder::string()
+                                .description("Local path to write the file to instead of inlining it"),
+                        )
+                        .required(&["repo", "path"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "file",
+                            SchemaBuilder::object()
+                                .property("path", SchemaBuilder::string())
+                                .property("sha", SchemaBuilder::string())
+                                .property("size", SchemaBuilder::integer())
+                                .property("content", SchemaBuilder::string())
+                                .property("truncated", SchemaBuilder::boolean())
+                                .property("binary", SchemaBuilder::boolean())
+                                .property("url", SchemaBuilder::string().format("uri")),
+                        )
+                        .property("written_to", SchemaBuilder::string().description("Set when output was given"))
+                        .property("bytes", SchemaBuilder::integer())
+                        .property("sha256", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Read a config file on a branch", json!({"repo": "octocat/Hello-World", "path": "Cargo.toml", "ref": "develop"}))
+                .example("Save a large file locally", json!({"repo": "octocat/Hello-World", "path": "data/dump.sql", "output": "~/Downloads/dump.sql"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.put_file - Create or update a file
+            self.method_info("github.put_file", "Create or update a file as a commit, guarded by the expected blob SHA")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("path", SchemaBuilder::string().description("File path in the repository"))
+                        .property("content", SchemaBuilder::string().description("New file content (text)"))
+                        .property("message", SchemaBuilder::string().description("Commit message"))
+                        .property(
+                            "branch",
+                            SchemaBuilder::string().description("Target branch (default: default branch)"),
+                        )
+                        .property(
+                            "sha",
+                            SchemaBuilder::string()
+                                .description("Current blob SHA from github.file; required to update an existing file"),
+                        )
+                        .required(&["repo", "path", "content", "message"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "file",
+                            SchemaBuilder::object()
+                                .property("path", SchemaBuilder::string())
+                                .property("created", SchemaBuilder::boolean())
+                                .property("sha", SchemaBuilder::string())
+                                .property("commit_sha", SchemaBuilder::string())
+                                .property("commit_url", SchemaBuilder::string().format("uri")),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Fix a config file on a branch",
+                    json!({
+                        "repo": "octocat/Hello-World",
+                        "path": ".editorconfig",
+                        "content": "root = true\n",
+                        "message": "Add editorconfig",
+                        "branch": "chore/editorconfig",
+                        "sha": "3d21ec53a331a6f037a91c368710b99387d012c1"
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED", "CONFLICT"]),
+
+            // github.delete_file - Delete a file
+            self.method_info("github.delete_file", "Delete a file as a commit, guarded by the expected blob SHA")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("path", SchemaBuilder::string().description("File path in the repository"))
+                        .property("message", SchemaBuilder::string().description("Commit message"))
+                        .property(
+                            "branch",
+                            SchemaBuilder::string().description("Target branch (default: default branch)"),
+                        )
+                        .property(
+                            "sha",
+                            SchemaBuilder::string().description("Current blob SHA from github.file"),
+                        )
+                        .required(&["repo", "path", "message", "sha"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("deleted", SchemaBuilder::boolean())
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "file",
+                            SchemaBuilder::object()
+                                .property("path", SchemaBuilder::string())
+                                .property("commit_sha", SchemaBuilder::string())
+                                .property("commit_url", SchemaBuilder::string().format("uri")),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Remove a stale workflow",
+                    json!({
+                        "repo": "octocat/Hello-World",
+                        "path": ".github/workflows/old.yml",
+                        "message": "Remove unused workflow",
+                        "sha": "3d21ec53a331a6f037a91c368710b99387d012c1"
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED", "CONFLICT"]),
+
+            // github.tree - Repository file tree
+            self.method_info("github.tree", "List the file tree at a ref with paths, types, and sizes")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "ref",
+                            SchemaBuilder::string().description("Branch, tag, or SHA (default: default branch)"),
+                        )
+                        .property("path", SchemaBuilder::string().description("Only list entries under this directory"))
+                        .property(
+                            "recursive",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Include nested directories"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(1000))
+                                .description("Maximum entries to return"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("count", SchemaBuilder::integer())
+                        .property(
+                            "tree",
+                            SchemaBuilder::object()
+                                .property("ref", SchemaBuilder::string())
+                                .property("sha", SchemaBuilder::string())
+                                .property("path", SchemaBuilder::string())
+                                .property(
+                                    "entries",
+                                    SchemaBuilder::array().items(
+                                        SchemaBuilder::object()
+                                            .property("path", SchemaBuilder::string())
+                                            .property(
+                                                "type",
+                                                SchemaBuilder::string().enum_values(&["blob", "tree", "commit"]),
+                                            )
+                                            .property("size", SchemaBuilder::integer())
+                                            .property("sha", SchemaBuilder::string()),
+                                    ),
+                                )
+                                .property("truncated", SchemaBuilder::boolean())
+                                .property("capped", SchemaBuilder::boolean()),
+                        )
+                        .build(),
+                )
+                .example("Map a source directory", json!({"repo": "octocat/Hello-World", "path": "src", "recursive": true}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.blame - File blame
+            self.method_info("github.blame", "Blame a file: line ranges with the commit, author, and age that last changed them")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("path", SchemaBuilder::string().description("File path in the repository"))
+                        .property(
+                            "ref",
+                            SchemaBuilder::string().description("Branch, tag, or SHA (default: default branch)"),
+                        )
+                        .property(
+                            "start_line",
+                            SchemaBuilder::integer().minimum(1).description("Only ranges touching this line or later"),
+                        )
+                        .property(
+                            "end_line",
+                            SchemaBuilder::integer().minimum(1).description("Only ranges touching this line or earlier"),
+                        )
+                        .required(&["repo", "path"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("path", SchemaBuilder::string())
+                        .property(
+                            "ranges",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("start_line", SchemaBuilder::integer())
+                                    .property("end_line", SchemaBuilder::integer())
+                                    .property("age", SchemaBuilder::integer().description("1 (newest) to 10 (oldest)"))
+                                    .property("sha", SchemaBuilder::string())
+                                    .property("message", SchemaBuilder::string())
+                                    .property("author", SchemaBuilder::string())
+                                    .property("author_email", SchemaBuilder::string())
+                                    .property("date", SchemaBuilder::string().format("date-time"))
+                                    .property("url", SchemaBuilder::string().format("uri")),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Who wrote these lines", json!({"repo": "octocat/Hello-World", "path": "src/main.rs", "start_line": 40, "end_line": 60}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.refs - List git refs
+            self.method_info("github.refs", "List git refs matching a prefix such as heads/ or tags/")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "prefix",
+                            SchemaBuilder::string()
+                                .default_value(json!("heads/"))
+                                .description("Ref prefix without 'refs/' (e.g. 'tags/v2.')"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(100))
+                                .description("Maximum refs to return"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "refs",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("sha", SchemaBuilder::string())
+                                    .property("object_type", SchemaBuilder::string().enum_values(&["commit", "tag"])),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("List release tags", json!({"repo": "octocat/Hello-World", "prefix": "tags/v"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+            // github.resolve_ref - Resolve a ref to a SHA
+            self.method_info("github.resolve_ref", "Resolve a branch, tag, or commit expression to an exact commit SHA")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("ref", SchemaBuilder::string().description("Branch, tag, or SHA"))
+                        .required(&["repo", "ref"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "ref",
+                            SchemaBuilder::object()
+                                .property("input", SchemaBuilder::string())
+                                .property("kind", SchemaBuilder::string().enum_values(&["tag", "branch", "commit"]))
+                                .property("qualified_name", SchemaBuilder::string())
+                                .property("sha", SchemaBuilder::string()),
+                        )
+                        .build(),
+                )
+                .example("Pin a release tag", json!({"repo": "octocat/Hello-World", "ref": "v1.2.0"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.create_blob - Create a git blob
+            self.method_info("github.create_blob", "Store content as a git blob and return its SHA")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("content", SchemaBuilder::string().description("Blob content"))
+                        .property(
+                            "encoding",
+                            SchemaBuilder::string()
+                                .enum_values(&["utf-8", "base64"])
+                                .default_value(json!("utf-8")),
+                        )
+                        .required(&["repo", "content"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("sha", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Store a binary file", json!({"repo": "octocat/Hello-World", "content": "iVBORw0KGgo=", "encoding": "base64"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+            // github.create_tree - Create a git tree
+            self.method_info("github.create_tree", "Create a git tree from entries, optionally on top of a base tree")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "entries",
+                            SchemaBuilder::array()
+                                .items(
+                                    SchemaBuilder::object()
+                                        .property("path", SchemaBuilder::string())
+                                        .property(
+                                            "mode",
+                                            SchemaBuilder::string()
+                                                .enum_values(&["100644", "100755", "040000", "160000", "120000"])
+                                                .default_value(json!("100644")),
+                                        )
+                                        .property("type", SchemaBuilder::string().enum_values(&["blob", "tree", "commit"]))
+                                        .property("sha", SchemaBuilder::string().description("Existing object SHA"))
+                                        .property("content", SchemaBuilder::string().description("Inline UTF-8 content"))
+                                        .required(&["path"]),
+                                )
+                                .description("Entries; omit both sha and content to delete a path from base_tree"),
+                        )
+                        .property(
+                            "base_tree",
+                            SchemaBuilder::string().description("Tree SHA to layer the entries over"),
+                        )
+                        .required(&["repo", "entries"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("sha", SchemaBuilder::string())
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example(
+                    "Replace one file and delete another",
+                    json!({
+                        "repo": "octocat/Hello-World",
+                        "base_tree": "9fb037999f264ba9a7fc6274d15fa3ae2ab98312",
+                        "entries": [
+                            {"path": "README.md", "content": "# Hello\n"},
+                            {"path": "old.txt"}
+                        ]
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+            // github.create_commit - Create a git commit object
+            self.method_info("github.create_commit", "Create a commit object for a tree; move a ref to it with github.update_ref")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("message", SchemaBuilder::string().description("Commit message"))
+                        .property("tree", SchemaBuilder::string().description("Root tree SHA"))
+                        .property(
+                            "parents",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::string())
+                                .description("Parent commit SHAs (empty for a root commit)"),
+                        )
+                        .required(&["repo", "message", "tree"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "commit",
+                            SchemaBuilder::object()
+                                .property("sha", SchemaBuilder::string())
+                                .property("message", SchemaBuilder::string())
+                                .property("tree", SchemaBuilder::string())
+                                .property("parents", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                .property("url", SchemaBuilder::string().format("uri")),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Commit a prepared tree",
+                    json!({
+                        "repo": "octocat/Hello-World",
+                        "message": "Update docs",
+                        "tree": "cd8274d15fa3ae2ab983129fb037999f264ba9a7",
+                        "parents": ["7638417db6d59f3c431d3e1f261cc637155684cd"]
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+            // github.update_ref - Move or create a git ref
+            self.method_info("github.update_ref", "Point a branch or tag ref at a commit, optionally creating it or forcing")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("ref", SchemaBuilder::string().description("Ref name such as 'heads/main'"))
+                        .property("sha", SchemaBuilder::string().description("Commit SHA"))
+                        .property(
+                            "force",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Allow non-fast-forward updates"),
+                        )
+                        .property(
+                            "create",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Create the ref instead of updating it"),
+                        )
+                        .required(&["repo", "ref", "sha"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("created", SchemaBuilder::boolean())
+                        .property(
+                            "ref",
+                            SchemaBuilder::object()
+                                .property("name", SchemaBuilder::string())
+                                .property("sha", SchemaBuilder::string())
+                                .property("object_type", SchemaBuilder::string()),
+                        )
+                        .build(),
+                )
+                .example("Fast-forward a branch", json!({"repo": "octocat/Hello-World", "ref": "heads/main", "sha": "aa218f56b14c9653891f9e74264a383fa43fefbd"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.commit_files - Multi-file verified commit
+            self.method_info("github.commit_files", "Commit several file additions and deletions to a branch as one verified commit")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("branch", SchemaBuilder::string().description("Existing branch to commit to"))
+                        .property(
+                            "message",
+                            SchemaBuilder::string().description("Commit message; first line is the headline"),
+                        )
+                        .property(
+                            "files",
+                            SchemaBuilder::object()
+                                .description("Map of path to new UTF-8 contents, or null to delete the file"),
+                        )
+                        .property(
+                            "expected_head",
+                            SchemaBuilder::string()
+                                .description("Branch head SHA the change is based on (default: current head)"),
+                        )
+                        .required(&["repo", "branch", "message", "files"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "commit",
+                            SchemaBuilder::object()
+                                .property("sha", SchemaBuilder::string())
+                                .property("message", SchemaBuilder::string())
+                                .property("tree", SchemaBuilder::string())
+                                .property("parents", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                .property("url", SchemaBuilder::string().format("uri")),
+                        )
+                        .property("changed", SchemaBuilder::integer())
+                        .property("deleted", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example(
+                    "Bot config fix touching two files",
+                    json!({
+                        "repo": "octocat/Hello-World",
+                        "branch": "bot/config-fix",
+                        "message": "Update CI config\n\nPins the toolchain and drops the legacy workflow.",
+                        "files": {
+                            "rust-toolchain.toml": "[toolchain]\nchannel = \"1.82\"\n",
+                            ".github/workflows/legacy.yml": null
+                        }
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED", "CONFLICT"]),
+
+            // github.branch_status - Branch ahead/behind status
+            self.method_info("github.branch_status", "Branch ahead/behind counts against a base, last commit, and open PR")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "branch",
+                            SchemaBuilder::string().description("Branch to check (default: all branches)"),
+                        )
+                        .property(
+                            "base",
+                            SchemaBuilder::string().description("Base branch (default: default branch)"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(50))
+                                .description("Branches per page when listing all"),
+                        )
+                        .property(
+                            "after",
+                            SchemaBuilder::string().description("Cursor from a previous next_cursor"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "branches",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("base", SchemaBuilder::string())
+                                    .property("ahead_by", SchemaBuilder::integer())
+                                    .property("behind_by", SchemaBuilder::integer())
+                                    .property(
+                                        "status",
+                                        SchemaBuilder::string().enum_values(&["ahead", "behind", "diverged", "identical"]),
+                                    )
+                                    .property("last_commit_sha", SchemaBuilder::string())
+                                    .property("last_commit_date", SchemaBuilder::string().format("date-time"))
+                                    .property("last_author", SchemaBuilder::string())
+                                    .property(
+                                        "pull_request",
+                                        SchemaBuilder::object()
+                                            .property("number", SchemaBuilder::integer())
+                                            .property("title", SchemaBuilder::string())
+                                            .property("is_draft", SchemaBuilder::boolean())
+                                            .property("url", SchemaBuilder::string().format("uri")),
+                                    ),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("next_cursor", SchemaBuilder::string())
+                        .build(),
+                )
+                .example("Find stale branches", json!({"repo": "octocat/Hello-World"}))
+                .example("Check one branch against develop", json!({"repo": "octocat/Hello-World", "branch": "feature/login", "base": "develop"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.verify_commits - Check commit signatures in a range
+            self.method_info("github.verify_commits", "Check that every commit between two refs has a verified signature")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("base", SchemaBuilder::string().description("Range start (exclusive)"))
+                        .property("head", SchemaBuilder::string().description("Range end (inclusive)"))
+                        .property("limit", SchemaBuilder::integer().minimum(1).default_value(json!(1000)).description("Most commits to check"))
+                        .required(&["repo", "base", "head"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("base", SchemaBuilder::string())
+                        .property("head", SchemaBuilder::string())
+                        .property("total_commits", SchemaBuilder::integer())
+                        .property("checked", SchemaBuilder::integer())
+                        .property("verified", SchemaBuilder::integer())
+                        .property("all_verified", SchemaBuilder::boolean().description("Every commit in the range was checked and verified"))
+                        .property(
+                            "unverified",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("sha", SchemaBuilder::string())
+                                    .property("message", SchemaBuilder::string())
+                                    .property("author", SchemaBuilder::string())
+                                    .property("reason", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property(
+                            "complete",
+                            SchemaBuilder::boolean().description("False when the range has more commits than limit"),
+                        )
+                        .build(),
+                )
+                .example("Check a release range", json!({"repo": "octocat/Hello-World", "base": "v1.1.0", "head": "v1.2.0"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.archive - Download a repository snapshot
+            self.method_info("github.archive", "Download a tarball or zipball of the repository at a ref")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "path",
+                            SchemaBuilder::string().description("Destination file or directory"),
+                        )
+                        .property(
+                            "ref",
+                            SchemaBuilder::string().description("Branch, tag, or SHA (default: default branch)"),
+                        )
+                        .property(
+                            "format",
+                            SchemaBuilder::string()
+                                .enum_values(&["tarball", "zipball"])
+                                .default_value(json!("tarball")),
+                        )
+                        .required(&["repo", "path"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("downloaded", SchemaBuilder::boolean())
+                        .property("repo", SchemaBuilder::string())
+                        .property("path", SchemaBuilder::string())
+                        .property("bytes", SchemaBuilder::integer())
+                        .property("sha256", SchemaBuilder::string())
+                        .property("elapsed_ms", SchemaBuilder::integer())
+                        .property("bytes_per_sec", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Fetch a release snapshot", json!({"repo": "octocat/Hello-World", "ref": "v1.2.0", "path": "/tmp"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.file_history - Commits touching a path
+            self.method_info("github.file_history", "List commits that modified a file, following renames")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("path", SchemaBuilder::string().description("File path in the repository"))
+                        .property(
+                            "ref",
+                            SchemaBuilder::string().description("Branch, tag, or SHA (default: default branch)"),
+                        )
+                        .property(
+                            "limit",
                             SchemaBuilder::integer()
                                 .minimum(1)
-                                .maximum(100)
-                                .default_value(json!(10))
-                                .description("Maximum issues to return"),
+                                .maximum(500)
+                                .default_value(json!(50))
+                                .description("Maximum commits to return"),
                         )
-                        .required(&["repo"])
+                        .property(
+                            "follow",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(true))
+                                .description("Continue through renames"),
+                        )
+                        .required(&["repo", "path"])
                         .build(),
                 )
                 .returns(
                     SchemaBuilder::object()
                         .property("repo", SchemaBuilder::string())
-                        .property("state", SchemaBuilder::string())
+                        .property("path", SchemaBuilder::string())
                         .property(
-                            "issues",
+                            "commits",
                             SchemaBuilder::array().items(
                                 SchemaBuilder::object()
-                                    .property("number", SchemaBuilder::integer())
-                                    .property("title", SchemaBuilder::string())
-                                    .property("state", SchemaBuilder::string())
-                                    .property("created_at", SchemaBuilder::string().format("date-time"))
+                                    .property("path", SchemaBuilder::string().description("File path at this commit"))
+                                    .property("sha", SchemaBuilder::string())
+                                    .property("message", SchemaBuilder::string())
+                                    .property("author", SchemaBuilder::string())
+                                    .property("date", SchemaBuilder::string().format("date-time"))
                                     .property("url", SchemaBuilder::string().format("uri")),
                             ),
                         )
                         .property("count", SchemaBuilder::integer())
+                        .property(
+                            "renames",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("sha", SchemaBuilder::string())
+                                    .property("from", SchemaBuilder::string())
+                                    .property("to", SchemaBuilder::string()),
+                            ),
+                        )
                         .build(),
                 )
-                .example(
-                    "List open issues",
-                    json!({"repo": "fast-gateway-protocol/daemon"}),
-                )
-                .example(
-                    "List closed issues",
-                    json!({"repo": "fast-gateway-protocol/daemon", "state": "closed", "limit": 5}),
-                )
+                .example("Trace a moved module", json!({"repo": "octocat/Hello-World", "path": "src/api/client.rs"}))
                 .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
 
-            // github.prs - List pull requests
-            MethodInfo::new("github.prs", "List pull requests for a repository")
+            // github.owners_for_path - CODEOWNERS lookup
+            self.method_info("github.owners_for_path", "Look up CODEOWNERS owners for one or more paths")
                 .schema(
                     SchemaBuilder::object()
                         .property(
@@ -350,50 +9585,91 @@ impl FgpService for GitHubService {
                                 .description("Repository in 'owner/repo' format"),
                         )
                         .property(
-                            "state",
-                            SchemaBuilder::string()
-                                .enum_values(&["open", "closed", "all"])
-                                .default_value(json!("open"))
-                                .description("PR state filter"),
+                            "paths",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::string())
+                                .description("File paths to look up"),
                         )
                         .property(
-                            "limit",
-                            SchemaBuilder::integer()
-                                .minimum(1)
-                                .maximum(100)
-                                .default_value(json!(10))
-                                .description("Maximum PRs to return"),
+                            "ref",
+                            SchemaBuilder::string().description("Branch, tag, or SHA to read CODEOWNERS from (default: default branch)"),
                         )
-                        .required(&["repo"])
+                        .required(&["repo", "paths"])
                         .build(),
                 )
                 .returns(
                     SchemaBuilder::object()
                         .property("repo", SchemaBuilder::string())
-                        .property("state", SchemaBuilder::string())
                         .property(
-                            "prs",
+                            "codeowners_path",
+                            SchemaBuilder::string().description("Location of the CODEOWNERS file, null if none"),
+                        )
+                        .property(
+                            "owners",
                             SchemaBuilder::array().items(
                                 SchemaBuilder::object()
-                                    .property("number", SchemaBuilder::integer())
-                                    .property("title", SchemaBuilder::string())
-                                    .property("state", SchemaBuilder::string())
-                                    .property("head_ref", SchemaBuilder::string())
-                                    .property("base_ref", SchemaBuilder::string())
-                                    .property("url", SchemaBuilder::string().format("uri")),
+                                    .property("path", SchemaBuilder::string())
+                                    .property("owners", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                    .property("pattern", SchemaBuilder::string())
+                                    .property("line", SchemaBuilder::integer()),
                             ),
                         )
-                        .property("count", SchemaBuilder::integer())
+                        .property("unowned", SchemaBuilder::integer())
                         .build(),
                 )
-                .example(
-                    "List open PRs",
-                    json!({"repo": "fast-gateway-protocol/daemon"}),
+                .example("Who owns these files", json!({"repo": "octocat/Hello-World", "paths": ["src/api/client.rs", "docs/README.md"]}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.commit_activity - Weekly commit statistics
+            self.method_info("github.commit_activity", "Weekly commit counts for the last year, with daily breakdown and owner share")
+                .schema(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "weeks",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(52)
+                                .default_value(json!(52))
+                                .description("Most recent weeks to return"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                )
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "activity",
+                            SchemaBuilder::object()
+                                .property(
+                                    "weeks",
+                                    SchemaBuilder::array().items(
+                                        SchemaBuilder::object()
+                                            .property("week_start", SchemaBuilder::string().format("date"))
+                                            .property("total", SchemaBuilder::integer())
+                                            .property("owner", SchemaBuilder::integer())
+                                            .property("days", SchemaBuilder::array().items(SchemaBuilder::integer())),
+                                    ),
+                                )
+                                .property("total", SchemaBuilder::integer())
+                                .property(
+                                    "pending",
+                                    SchemaBuilder::boolean().description("Stats still being computed; retry shortly"),
+                                ),
+                        )
+                        .build(),
                 )
+                .example("Last quarter's activity", json!({"repo": "octocat/Hello-World", "weeks": 13}))
                 .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
 
-            // github.pr - Get PR details
-            MethodInfo::new("github.pr", "Get pull request details with reviews and status checks")
+            // github.contributor_stats - Weekly contributor statistics
+            self.method_info("github.contributor_stats", "Per-contributor weekly additions, deletions, and commits")
                 .schema(
                     SchemaBuilder::object()
                         .property(
@@ -403,125 +9679,225 @@ impl FgpService for GitHubService {
                                 .description("Repository in 'owner/repo' format"),
                         )
                         .property(
-                            "number",
+                            "weeks",
                             SchemaBuilder::integer()
                                 .minimum(1)
-                                .description("Pull request number"),
+                                .default_value(json!(12))
+                                .description("Most recent weeks to include"),
                         )
-                        .required(&["repo", "number"])
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(25))
+                                .description("Maximum contributors to return, most commits first"),
+                        )
+                        .required(&["repo"])
                         .build(),
                 )
                 .returns(
                     SchemaBuilder::object()
-                        .property("number", SchemaBuilder::integer())
-                        .property("title", SchemaBuilder::string())
-                        .property("body", SchemaBuilder::string())
-                        .property("state", SchemaBuilder::string())
-                        .property("mergeable", SchemaBuilder::boolean())
-                        .property("head_ref", SchemaBuilder::string())
-                        .property("base_ref", SchemaBuilder::string())
+                        .property("repo", SchemaBuilder::string())
                         .property(
-                            "reviews",
-                            SchemaBuilder::array().items(
-                                SchemaBuilder::object()
-                                    .property("author", SchemaBuilder::string())
-                                    .property("state", SchemaBuilder::string()),
-                            ),
+                            "pending",
+                            SchemaBuilder::boolean().description("Stats still being computed; retry shortly"),
                         )
+                        .property("weeks", SchemaBuilder::integer())
                         .property(
-                            "status_checks",
+                            "contributors",
                             SchemaBuilder::array().items(
                                 SchemaBuilder::object()
-                                    .property("context", SchemaBuilder::string())
-                                    .property("state", SchemaBuilder::string()),
+                                    .property("login", SchemaBuilder::string())
+                                    .property("commits", SchemaBuilder::integer())
+                                    .property("additions", SchemaBuilder::integer())
+                                    .property("deletions", SchemaBuilder::integer())
+                                    .property(
+                                        "weeks",
+                                        SchemaBuilder::array().items(
+                                            SchemaBuilder::object()
+                                                .property("week_start", SchemaBuilder::string().format("date"))
+                                                .property("additions", SchemaBuilder::integer())
+                                                .property("deletions", SchemaBuilder::integer())
+                                                .property("commits", SchemaBuilder::integer()),
+                                        ),
+                                    ),
                             ),
                         )
+                        .property("count", SchemaBuilder::integer())
+                        .property("active_contributors", SchemaBuilder::integer())
                         .build(),
                 )
-                .example(
-                    "Get PR #42",
-                    json!({"repo": "fast-gateway-protocol/daemon", "number": 42}),
-                )
+                .example("Quarterly contribution report", json!({"repo": "octocat/Hello-World", "weeks": 13}))
                 .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
 
-            // github.notifications - Get notifications
-            MethodInfo::new("github.notifications", "Get unread GitHub notifications")
-                .schema(SchemaBuilder::object().build())
+            // github.installations - List GitHub App installations
+            self.method_info("github.installations", "List installations of the GitHub App the daemon authenticates as (requires GITHUB_APP_ID and a private key)")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("limit", SchemaBuilder::integer().minimum(1).maximum(100).default_value(json!(30)).description("Maximum installations to return"))
+                        .build(),
+                )
                 .returns(
                     SchemaBuilder::object()
                         .property(
-                            "notifications",
+                            "installations",
                             SchemaBuilder::array().items(
                                 SchemaBuilder::object()
-                                    .property("id", SchemaBuilder::string())
-                                    .property("reason", SchemaBuilder::string())
-                                    .property("unread", SchemaBuilder::boolean())
-                                    .property("subject_title", SchemaBuilder::string())
-                                    .property("subject_type", SchemaBuilder::string())
-                                    .property("repo_full_name", SchemaBuilder::string()),
+                                    .property("id", SchemaBuilder::integer())
+                                    .property("account", SchemaBuilder::string().description("User or organization the app is installed on"))
+                                    .property("target_type", SchemaBuilder::string().enum_values(&["User", "Organization"]))
+                                    .property("repository_selection", SchemaBuilder::string().enum_values(&["all", "selected"]))
+                                    .property("permissions", SchemaBuilder::object().description("Permission name to access level"))
+                                    .property("events", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                    .property("created_at", SchemaBuilder::string().format("date-time"))
+                                    .property("suspended", SchemaBuilder::boolean())
+                                    .property("url", SchemaBuilder::string().format("uri")),
                             ),
                         )
-                        .property("unread_count", SchemaBuilder::integer())
+                        .property("count", SchemaBuilder::integer())
+                        .property("token_expires_at", SchemaBuilder::string().format("date-time").description("Expiry of the current installation token"))
                         .build(),
                 )
-                .example("Get notifications", json!({})),
+                .example("List installations", json!({}))
+                .errors(&["UNAUTHORIZED", "VALIDATION_FAILED"]),
 
-            // github.create_issue - Create issue
-            MethodInfo::new("github.create_issue", "Create a new issue in a repository")
+            // github.accounts - List configured accounts
+            self.method_info("github.accounts", "List configured accounts. Every method accepts an optional `account` parameter naming the account to call as; otherwise the first matching `repo_accounts` rule in config.yaml picks it, falling back to the default account.")
                 .schema(
+                    SchemaBuilder::object()
+                        .property("verify", SchemaBuilder::boolean().default_value(json!(false)).description("Look up each account's login to check its token still works"))
+                        .build(),
+                )
+                .returns(
                     SchemaBuilder::object()
                         .property(
-                            "repo",
-                            SchemaBuilder::string()
-                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
-                                .description("Repository in 'owner/repo' format"),
+                            "accounts",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("default", SchemaBuilder::boolean())
+                                    .property("login", SchemaBuilder::string().description("Stored login, or the live one with verify"))
+                                    .property("source", SchemaBuilder::string().description("Where the token came from: explicit, keychain, the environment variable name, github_app, credential_store, gh_hosts, or gh_auth_token"))
+                                    .property("app", SchemaBuilder::boolean().description("Authenticates as a GitHub App installation"))
+                                    .property(
+                                        "rate_limits",
+                                        SchemaBuilder::array().items(
+                                            SchemaBuilder::object()
+                                                .property("resource", SchemaBuilder::string())
+                                                .property("limit", SchemaBuilder::integer())
+                                                .property("remaining", SchemaBuilder::integer())
+                                                .property("used", SchemaBuilder::integer())
+                                                .property("reset_at", SchemaBuilder::string().format("date-time")),
+                                        ).description("Standing per resource from this account's recent responses"),
+                                    )
+                                    .property("error", SchemaBuilder::string().description("Why verification failed")),
+                            ),
                         )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("List accounts", json!({}))
+                .example("Check every token works", json!({"verify": true}))
+                .example("Call another method as the work account", json!({"account": "work"}))
+                .errors(&["UNAUTHORIZED"]),
+
+            // github.reload_auth - Re-read tokens without restarting
+            self.method_info("github.reload_auth", "Re-read every token source (keychain, environment, credential store, gh config) and rebuild the clients, e.g. after rotating a PAT. When GitHub answers 401, the affected account alone is re-read automatically, at most once a minute.")
+                .schema(SchemaBuilder::object().build())
+                .returns(
+                    SchemaBuilder::object()
+                        .property("reloaded", SchemaBuilder::boolean())
                         .property(
-                            "title",
-                            SchemaBuilder::string()
-                                .min_length(1)
-                                .max_length(256)
-                                .description("Issue title"),
+                            "accounts",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("app", SchemaBuilder::boolean())
+                                    .property("login", SchemaBuilder::string().description("Login the new token authenticates as; null for app installations"))
+                                    .property("error", SchemaBuilder::string().description("Why the new token could not be verified")),
+                            ),
                         )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Pick up a rotated token", json!({}))
+                .errors(&["UNAUTHORIZED"]),
+
+            // github.sso_status - Organizations the token lacks SSO authorization for
+            self.method_info("github.sso_status", "List your organizations that enforce SAML single sign-on and have not authorized this token, with the URL to authorize it. Calls refused for SSO fail with an error carrying the same URL.")
+                .schema(SchemaBuilder::object().build())
+                .returns(
+                    SchemaBuilder::object()
+                        .property("authorized", SchemaBuilder::array().items(SchemaBuilder::string()).description("Organizations the token can access"))
                         .property(
-                            "body",
-                            SchemaBuilder::string()
-                                .description("Issue body (Markdown supported)"),
+                            "unauthorized",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::integer())
+                                    .property("login", SchemaBuilder::string())
+                                    .property("authorize_url", SchemaBuilder::string().format("uri")),
+                            ),
                         )
-                        .required(&["repo", "title"])
+                        .property("all_authorized", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example("Check SSO authorization", json!({}))
+                .example("Check the work account", json!({"account": "work"}))
+                .errors(&["UNAUTHORIZED"]),
+
+            // github.rate_limit - Rate-limit standing per resource
+            self.method_info("github.rate_limit", "Rate-limit standing per resource (core, graphql, search, ...): GitHub's rate_limit endpoint, which does not count against the limit, plus what the daemon has tracked from response headers.")
+                .schema(
+                    SchemaBuilder::object()
+                        .property("live", SchemaBuilder::boolean().default_value(json!(true)).description("Query GitHub; false returns only tracked values without a request"))
                         .build(),
                 )
                 .returns(
                     SchemaBuilder::object()
-                        .property("created", SchemaBuilder::boolean())
                         .property(
-                            "issue",
-                            SchemaBuilder::object()
-                                .property("number", SchemaBuilder::integer())
-                                .property("title", SchemaBuilder::string())
-                                .property("url", SchemaBuilder::string().format("uri")),
+                            "resources",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("resource", SchemaBuilder::string())
+                                    .property("limit", SchemaBuilder::integer())
+                                    .property("remaining", SchemaBuilder::integer())
+                                    .property("used", SchemaBuilder::integer())
+                                    .property("reset_at", SchemaBuilder::string().format("date-time")),
+                            ).description("Live standing from GitHub; null when live is false"),
+                        )
+                        .property(
+                            "tracked",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("resource", SchemaBuilder::string())
+                                    .property("limit", SchemaBuilder::integer())
+                                    .property("remaining", SchemaBuilder::integer())
+                                    .property("used", SchemaBuilder::integer())
+                                    .property("reset_at", SchemaBuilder::string().format("date-time")),
+                            ).description("Latest values seen in this account's response headers"),
                         )
                         .build(),
                 )
-                .example(
-                    "Create a bug report",
-                    json!({
-                        "repo": "fast-gateway-protocol/daemon",
-                        "title": "Bug: Socket timeout after 30s",
-                        "body": "## Description\nThe socket times out unexpectedly..."
-                    }),
-                )
-                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+                .example("Current standing", json!({}))
+                .example("Tracked values only", json!({"live": false}))
+                .errors(&["UNAUTHORIZED"]),
         ]
     }
 
     fn on_start(&self) -> Result<()> {
         tracing::info!("GitHubService starting, verifying API connection...");
-        let client = self.client.clone();
+        let client = self.client();
         self.runtime.block_on(async move {
             match client.ping().await {
                 Ok(true) => {
                     tracing::info!("GitHub API connection verified");
+                    if let Some(granted) = client.granted_scopes() {
+                        tracing::info!("Token scopes: {}", granted.join(", "));
+                    }
+                    if let Some(expiry) = client.token_expiry().filter(|_| !client.is_app()) {
+                        tracing::info!("Token expires {}", expiry.to_rfc3339());
+                    }
                     Ok(())
                 }
                 Ok(false) => {
@@ -536,10 +9912,15 @@ impl FgpService for GitHubService {
         })
     }
 
+    fn on_stop(&self) -> Result<()> {
+        self.stop_pollers();
+        Ok(())
+    }
+
     fn health_check(&self) -> HashMap<String, HealthStatus> {
         let mut checks = HashMap::new();
 
-        let client = self.client.clone();
+        let client = self.client();
         let start = std::time::Instant::now();
         let result = self.runtime.block_on(async move { client.ping().await });
 
@@ -563,6 +9944,109 @@ impl FgpService for GitHubService {
             }
         }
 
+        if let Some(expiry) = self.client().token_expiry() {
+            if expiry <= chrono::Utc::now() {
+                checks.insert(
+                    "token_expiry".into(),
+                    HealthStatus::unhealthy(format!("Token expired {}", expiry.to_rfc3339())),
+                );
+            }
+        }
+
         checks
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_query() {
+        let params: HashMap<String, Value> = serde_json::from_value(json!({
+            "query": "rest_get",
+            "language": "rust",
+            "path": "src/api",
+            "org": "my org",
+            "stars": 100,
+        }))
+        .unwrap();
+
+        let query =
+            GitHubService::search_query(&params, &["repo", "org", "path", "language", "stars"])
+                .unwrap();
+        assert_eq!(
+            query,
+            "rest_get org:\"my org\" path:src/api language:rust stars:100"
+        );
+
+        assert!(GitHubService::search_query(&HashMap::new(), &["repo"]).is_err());
+    }
+
+    #[test]
+    fn test_gist_files_normalization() {
+        let files = json!({"a.txt": "hello", "b.md": {"content": "# hi"}, "c.txt": null});
+
+        let normalized = GitHubService::gist_files(&files, true).unwrap();
+        assert_eq!(normalized["a.txt"]["content"], "hello");
+        assert_eq!(normalized["b.md"]["content"], "# hi");
+        assert!(normalized["c.txt"].is_null());
+
+        assert!(GitHubService::gist_files(&files, false).is_err());
+        assert!(GitHubService::gist_files(&json!("nope"), true).is_err());
+    }
+
+    #[test]
+    fn test_with_common_params() {
+        let schema = with_common_params(
+            json!({
+                "type": "object",
+                "properties": {"repo": {"type": "string"}},
+            }),
+            false,
+        );
+        assert!(schema["properties"]["repo"].is_object());
+        assert!(schema["properties"].get("account").is_some());
+        assert!(schema["properties"].get("fresh").is_none());
+
+        let schema = with_common_params(
+            json!({
+                "type": "object",
+                "properties": {"account": {"type": "string", "description": "Own"}},
+            }),
+            true,
+        );
+        assert_eq!(schema["properties"]["account"]["description"], "Own");
+        assert!(schema["properties"].get("fresh").is_some());
+
+        let schema = with_common_params(json!({"type": "object"}), false);
+        assert!(schema["properties"].get("account").is_some());
+    }
+
+    #[test]
+    fn test_active_client_guard_restores_previous() {
+        let client = |token: &str| {
+            Arc::new(
+                GitHubClient::from_credential_store(
+                    token.to_string(),
+                    crate::config::Endpoints::for_host("github.com"),
+                )
+                .unwrap(),
+            )
+        };
+        let active = || ACTIVE_CLIENT.with(|active| active.borrow().clone());
+
+        let outer = client("outer");
+        {
+            let _outer = ActiveClientGuard::set("outer", outer.clone());
+            {
+                let _inner = ActiveClientGuard::set("inner", client("inner"));
+                assert_eq!(active().unwrap().0, "inner");
+            }
+            let (name, restored) = active().unwrap();
+            assert_eq!(name, "outer");
+            assert!(Arc::ptr_eq(&restored, &outer));
+        }
+        assert!(active().is_none());
+    }
+}
@@ -0,0 +1,128 @@
+//! Persistent daemon state.
+//!
+//! Small JSON document next to the daemon socket holding user-managed data
+//! (saved searches). Each change is a load-modify-save cycle written through
+//! a temp file and rename, so the file is never left half-written.
+//!
+//! # CHANGELOG (recent first, max 5 entries)
+//! 10/16/2026 - Initial implementation with saved searches
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Default state file location.
+const DEFAULT_STATE_PATH: &str = "~/.fgp/services/github/state.json";
+
+/// Everything persisted by the daemon. Unknown fields default so older
+/// files keep loading as new sections are added.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DaemonState {
+    #[serde(default)]
+    pub saved_searches: BTreeMap<String, SavedSearch>,
+}
+
+/// A named `github.search_all` invocation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSearch {
+    pub name: String,
+    /// Search type, as accepted by `github.search_all`.
+    #[serde(rename = "type")]
+    pub search_type: String,
+    /// Parameters passed to `github.search_all` (query and qualifiers).
+    pub params: Map<String, Value>,
+    pub description: Option<String>,
+    pub created_at: String,
+}
+
+/// Handle to the state file on disk.
+pub struct StateStore {
+    path: PathBuf,
+}
+
+impl StateStore {
+    /// Store at the default location (`~/.fgp/services/github/state.json`).
+    pub fn new() -> Self {
+        Self::at(shellexpand::tilde(DEFAULT_STATE_PATH).to_string())
+    }
+
+    /// Store at an explicit path.
+    pub fn at(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Load state, treating a missing file as empty.
+    pub fn load(&self) -> Result<DaemonState> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(content) => serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse {}", self.path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(DaemonState::default()),
+            Err(e) => Err(e).with_context(|| format!("Failed to read {}", self.path.display())),
+        }
+    }
+
+    /// Write state atomically.
+    pub fn save(&self, state: &DaemonState) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create state directory")?;
+        }
+
+        let tmp = self.path.with_extension("json.tmp");
+        let content = serde_json::to_string_pretty(state)?;
+        std::fs::write(&tmp, content)
+            .with_context(|| format!("Failed to write {}", tmp.display()))?;
+        std::fs::rename(&tmp, &self.path)
+            .with_context(|| format!("Failed to replace {}", self.path.display()))
+    }
+
+    /// Load, apply `f`, and save.
+    pub fn update<T>(&self, f: impl FnOnce(&mut DaemonState) -> Result<T>) -> Result<T> {
+        let mut state = self.load()?;
+        let result = f(&mut state)?;
+        self.save(&state)?;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_round_trip() {
+        let dir = std::env::temp_dir().join(format!("fgp-github-state-{}", std::process::id()));
+        let store = StateStore::at(dir.join("state.json"));
+
+        assert!(store.load().unwrap().saved_searches.is_empty());
+
+        store
+            .update(|state| {
+                state.saved_searches.insert(
+                    "team-bugs".to_string(),
+                    SavedSearch {
+                        name: "team-bugs".to_string(),
+                        search_type: "issues".to_string(),
+                        params: serde_json::from_value(
+                            serde_json::json!({"query": "is:open label:bug"}),
+                        )
+                        .unwrap(),
+                        description: None,
+                        created_at: "2026-10-16T00:00:00Z".to_string(),
+                    },
+                );
+                Ok(())
+            })
+            .unwrap();
+
+        let state = store.load().unwrap();
+        assert_eq!(state.saved_searches["team-bugs"].search_type, "issues");
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}